@@ -0,0 +1,234 @@
+//! Guessing whether the terminal's background is dark or light, so
+//! `--theme auto` can pick the bundled [`Theme`] that reads on it. Runs
+//! before the presenter takes the screen, which then draws with whatever
+//! was decided here.
+//!
+//! Two sources, cheapest first: the `COLORFGBG` variable some terminals
+//! (rxvt, Konsole, iTerm2) export, then an OSC 11 query asking the terminal
+//! for its background color. The query is fenced by a DA1 request, which
+//! every terminal answers, and terminals answer in order: once the DA1
+//! reply is in, any OSC 11 reply is too. Everything read before the fence
+//! is drained from crossterm's event queue, which hands the reply over as
+//! a run of key events, so none of it reaches the presenter as keystrokes.
+//! A terminal that doesn't know OSC 11, or no terminal at all, means the
+//! dark theme.
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal;
+use crossterm::tty::IsTty;
+use fireside_tui::Theme;
+
+/// The bundled theme for the terminal's background: from `COLORFGBG` if it
+/// says, else from asking the terminal, else [`Theme::Dark`]. Only asks a
+/// real terminal, so piped or redirected runs never write a query.
+#[must_use]
+pub(crate) fn detect_theme() -> Theme {
+    if let Some(theme) = std::env::var("COLORFGBG")
+        .ok()
+        .as_deref()
+        .and_then(theme_from_colorfgbg)
+    {
+        return theme;
+    }
+    if !io::stdout().is_tty() || !io::stdin().is_tty() {
+        return Theme::default();
+    }
+    query_background()
+        .as_deref()
+        .and_then(theme_from_osc11)
+        .unwrap_or_default()
+}
+
+/// The theme `COLORFGBG` implies: `"fg;bg"`, or rxvt's `"fg;default;bg"`,
+/// where the last field is the background's ANSI color number. Colors 0–6
+/// and 8 are the dark ones, as vim reads them; `None` when the background
+/// isn't a number (`"default"`).
+fn theme_from_colorfgbg(value: &str) -> Option<Theme> {
+    let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    Some(if bg <= 6 || bg == 8 {
+        Theme::Dark
+    } else {
+        Theme::Light
+    })
+}
+
+/// The theme an OSC 11 reply implies — `ESC ] 11 ; rgb:RRRR/GGGG/BBBB`,
+/// with one to four hex digits per channel — by the background's relative
+/// luminance. `None` when `reply` holds no such color.
+fn theme_from_osc11(reply: &str) -> Option<Theme> {
+    let color = reply.split_once("]11;rgb:")?.1;
+    let end = color
+        .find(|c: char| !(c.is_ascii_hexdigit() || c == '/'))
+        .unwrap_or(color.len());
+    let mut channels = color[..end].split('/').map(|hex| {
+        let digits = u32::try_from(hex.len())
+            .ok()
+            .filter(|n| (1..=4).contains(n))?;
+        let max = 16_u32.pow(digits) - 1;
+        u32::from_str_radix(hex, 16)
+            .ok()
+            .map(|v| f64::from(v) / f64::from(max))
+    });
+    let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+    let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    Some(if luminance > 0.5 {
+        Theme::Light
+    } else {
+        Theme::Dark
+    })
+}
+
+/// Asks the terminal for its background color and returns the reply as
+/// text. `None` when it can't be asked or has no OSC 11 reply.
+///
+/// crossterm keeps a DA1 reply to itself, so the fence is its keyboard
+/// enhancement query, which sends DA1 last and waits until that is
+/// answered. The events queued by then are everything that came before.
+fn query_background() -> Option<String> {
+    terminal::enable_raw_mode().ok()?;
+    let asked = io::stdout()
+        .write_all(b"\x1b]11;?\x1b\\")
+        .and_then(|()| io::stdout().flush())
+        .is_ok();
+    let fenced = asked && terminal::supports_keyboard_enhancement().is_ok();
+    let queued = std::iter::from_fn(|| event::poll(Duration::ZERO).ok()?.then(event::read)?.ok())
+        .filter_map(|event| match event {
+            Event::Key(key) => Some(key),
+            _ => None,
+        });
+    let reply = reply_in(queued);
+    let _ = terminal::disable_raw_mode();
+    reply.filter(|_| fenced)
+}
+
+/// The OSC 11 reply among `keys`, reading every one of them so none is
+/// left for the presenter. Keys outside the reply — one typed while the
+/// terminal was answering — are dropped.
+fn reply_in(keys: impl IntoIterator<Item = KeyEvent>) -> Option<String> {
+    let mut reply = String::new();
+    let mut found = None;
+    for key in keys {
+        if found.is_some() {
+            continue;
+        }
+        match push_reply_key(&mut reply, key) {
+            Some(true) => found = Some(std::mem::take(&mut reply)),
+            Some(false) => reply.clear(),
+            None => {}
+        }
+    }
+    found
+}
+
+/// Adds one key of an OSC reply to `reply`. crossterm doesn't know the
+/// sequence, so it arrives as keys: `ESC ]` as Alt+`]`, the color as plain
+/// characters, and the terminator as Alt+`\` (`ESC \`) or Ctrl+G (BEL).
+/// `Some(true)` once the reply has ended, `Some(false)` for a key that
+/// can't be part of one, and `None` while it goes on.
+fn push_reply_key(reply: &mut String, key: KeyEvent) -> Option<bool> {
+    match (key.code, key.modifiers) {
+        (KeyCode::Char(']'), KeyModifiers::ALT) if reply.is_empty() => {
+            reply.push_str("\x1b]");
+            None
+        }
+        _ if reply.is_empty() => Some(false),
+        (KeyCode::Char('\\'), KeyModifiers::ALT) => Some(true),
+        (KeyCode::Char('g'), KeyModifiers::CONTROL) => Some(true),
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+            reply.push(c);
+            None
+        }
+        _ => Some(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colorfgbg_names_the_background_last() {
+        for (value, theme) in [
+            ("15;0", Some(Theme::Dark)),
+            ("7;8", Some(Theme::Dark)),
+            ("0;15", Some(Theme::Light)),
+            ("0;7", Some(Theme::Light)),
+            ("12;default;0", Some(Theme::Dark)),
+            ("0;default;15", Some(Theme::Light)),
+            ("default;default", None),
+            ("", None),
+        ] {
+            assert_eq!(theme_from_colorfgbg(value), theme, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn an_osc_11_reply_is_read_by_its_luminance() {
+        let reply = |rgb: &str| format!("\x1b]11;rgb:{rgb}");
+        assert_eq!(
+            theme_from_osc11(&reply("ffff/ffff/ffff")),
+            Some(Theme::Light)
+        );
+        assert_eq!(
+            theme_from_osc11(&reply("1e1e/1e1e/2e2e")),
+            Some(Theme::Dark)
+        );
+        assert_eq!(theme_from_osc11(&reply("fd/f6/e3")), Some(Theme::Light));
+        assert_eq!(theme_from_osc11("\x1b]11;"), None);
+    }
+
+    #[test]
+    fn a_reply_is_rebuilt_from_the_keys_crossterm_reads_it_as() {
+        let key = |c, modifiers| KeyEvent::new(KeyCode::Char(c), modifiers);
+        let mut reply = String::new();
+        assert_eq!(
+            push_reply_key(&mut reply, key(']', KeyModifiers::ALT)),
+            None
+        );
+        for c in "11;rgb:fd/f6/e3".chars() {
+            assert_eq!(push_reply_key(&mut reply, key(c, KeyModifiers::NONE)), None);
+        }
+        assert_eq!(
+            push_reply_key(&mut reply, key('\\', KeyModifiers::ALT)),
+            Some(true)
+        );
+        assert_eq!(theme_from_osc11(&reply), Some(Theme::Light));
+
+        let mut stray = String::new();
+        assert_eq!(
+            push_reply_key(&mut stray, key('q', KeyModifiers::NONE)),
+            Some(false),
+            "a keypress isn't a reply"
+        );
+    }
+
+    #[test]
+    fn everything_before_the_da1_fence_is_consumed() {
+        let key = |c, modifiers| KeyEvent::new(KeyCode::Char(c), modifiers);
+        let plain = |text: &str| {
+            text.chars()
+                .map(|c| key(c, KeyModifiers::NONE))
+                .collect::<Vec<_>>()
+        };
+        let mut queued = plain("q");
+        queued.push(key(']', KeyModifiers::ALT));
+        queued.extend(plain("11;rgb:1e1e/1e1e/2e2e"));
+        queued.push(key('g', KeyModifiers::CONTROL));
+        queued.extend(plain("1g"));
+        let mut keys = queued.into_iter();
+
+        let reply = reply_in(keys.by_ref());
+        assert_eq!(
+            reply.as_deref().and_then(theme_from_osc11),
+            Some(Theme::Dark)
+        );
+        assert!(
+            keys.next().is_none(),
+            "a stray key or a late `1` would choose a branch option"
+        );
+        assert_eq!(reply_in(plain("qx")), None, "no reply, still drained");
+    }
+}
@@ -0,0 +1,36 @@
+//! Copying a code block out of the presenter, so a speaker running a live
+//! demo can paste it into a real shell.
+//!
+//! The system clipboard isn't always there — a headless box, an SSH
+//! session with no display forwarded — so when it can't be reached the
+//! snippet is written to a file in the temp directory instead and the
+//! presenter is told where. This is the [`fireside_tui::CopySink`] the
+//! presenter and the editor are given; the TUI itself never touches the
+//! clipboard or the filesystem.
+
+use std::path::PathBuf;
+
+use fireside_tui::Copied;
+
+/// Puts `text` on the system clipboard, or — when there is none — writes it
+/// to a temp file. `Err` carries a human-readable message for the footer.
+pub(crate) fn copy(text: &str) -> Result<Copied, String> {
+    let clipboard = arboard::Clipboard::new().and_then(|mut c| c.set_text(text));
+    if clipboard.is_ok() {
+        return Ok(Copied::Clipboard);
+    }
+    let path = snippet_path();
+    match std::fs::write(&path, text) {
+        Ok(()) => Ok(Copied::File(path)),
+        Err(err) => Err(format!(
+            "Couldn't copy — no clipboard, and {} couldn't be written: {err}",
+            path.display()
+        )),
+    }
+}
+
+/// One file per presenter process, overwritten by each fallback copy — the
+/// path a presenter is told stays the same for the whole talk.
+fn snippet_path() -> PathBuf {
+    std::env::temp_dir().join(format!("fireside-snippet-{}.txt", std::process::id()))
+}
@@ -0,0 +1,172 @@
+//! `fireside present --control <addr>`: a line-based remote control for
+//! driving the presenter from a clicker bridge or a phone.
+//!
+//! Each client line is one command (`next`, `back`, `goto N`,
+//! `choose KEY`), answered with `ok` or `error: <why>`. Parsed commands
+//! travel over a channel that the presenter's event loop drains every tick.
+//!
+//! **There is no authentication.** Anyone who can reach the socket can
+//! drive the deck, so TCP addresses must be loopback and a Unix socket is
+//! only as private as the directory holding it. Forwarding a phone's
+//! traffic to it is the bridge's job.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use anyhow::{Context, Result, bail};
+use fireside_tui::RemoteCommand;
+
+/// A listening control socket and the channel its connections feed.
+/// Dropping it removes a Unix socket's file; the listener thread itself
+/// simply ends with the process.
+pub(crate) struct Control {
+    commands: Receiver<RemoteCommand>,
+    socket_path: Option<PathBuf>,
+}
+
+impl Control {
+    /// Starts listening on `addr`: a path (anything with a `/` in it) is a
+    /// Unix socket, anything else a loopback `host:port`.
+    ///
+    /// # Errors
+    ///
+    /// Fails for a non-loopback TCP address, a socket path that already
+    /// exists, or any bind failure.
+    pub(crate) fn listen(addr: &str) -> Result<Self> {
+        let (tx, commands) = mpsc::channel();
+        let socket_path = if addr.contains('/') {
+            Some(listen_unix(addr, tx)?)
+        } else {
+            listen_tcp(addr, tx)?;
+            None
+        };
+        Ok(Self {
+            commands,
+            socket_path,
+        })
+    }
+
+    /// The next command a client sent, if one is waiting.
+    #[must_use]
+    pub(crate) fn try_next(&self) -> Option<RemoteCommand> {
+        self.commands.try_recv().ok()
+    }
+}
+
+impl Drop for Control {
+    fn drop(&mut self) {
+        if let Some(path) = &self.socket_path {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn listen_tcp(addr: &str, tx: Sender<RemoteCommand>) -> Result<()> {
+    let resolved: Vec<_> = addr
+        .to_socket_addrs()
+        .with_context(|| format!("--control {addr} isn't a host:port or a socket path"))?
+        .collect();
+    if resolved.is_empty() || resolved.iter().any(|a| !a.ip().is_loopback()) {
+        bail!(
+            "--control {addr} isn't a loopback address — the control socket has no authentication, so it only listens on this machine (try 127.0.0.1:{port})",
+            port = resolved.first().map_or(0, std::net::SocketAddr::port)
+        );
+    }
+    let listener = TcpListener::bind(resolved.as_slice())
+        .with_context(|| format!("couldn't listen on {addr}"))?;
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let Ok(reader) = stream.try_clone() else {
+                continue;
+            };
+            let tx = tx.clone();
+            thread::spawn(move || serve(BufReader::new(reader), stream, &tx));
+        }
+    });
+    Ok(())
+}
+
+#[cfg(unix)]
+fn listen_unix(path: &str, tx: Sender<RemoteCommand>) -> Result<PathBuf> {
+    use std::os::unix::net::UnixListener;
+
+    let path = PathBuf::from(path);
+    if path.exists() {
+        bail!(
+            "{} already exists — remove it if no other presenter is using it",
+            path.display()
+        );
+    }
+    let listener = UnixListener::bind(&path)
+        .with_context(|| format!("couldn't listen on {}", path.display()))?;
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let Ok(reader) = stream.try_clone() else {
+                continue;
+            };
+            let tx = tx.clone();
+            thread::spawn(move || serve(BufReader::new(reader), stream, &tx));
+        }
+    });
+    Ok(path)
+}
+
+#[cfg(not(unix))]
+fn listen_unix(path: &str, _tx: Sender<RemoteCommand>) -> Result<PathBuf> {
+    bail!("--control {path}: Unix sockets aren't available here — use 127.0.0.1:PORT")
+}
+
+/// Answers one connection until it closes: every non-blank line is parsed,
+/// forwarded, and acknowledged with `ok`, or refused with `error: <why>`.
+/// Returns early once the presenter has stopped listening.
+fn serve(reader: impl BufRead, mut writer: impl Write, tx: &Sender<RemoteCommand>) {
+    for line in reader.lines() {
+        let Ok(line) = line else { return };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = match line.parse::<RemoteCommand>() {
+            Ok(command) => {
+                if tx.send(command).is_err() {
+                    return;
+                }
+                "ok".to_owned()
+            }
+            Err(message) => format!("error: {message}"),
+        };
+        if writeln!(writer, "{reply}").is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_line_is_forwarded_or_refused_with_a_reply() {
+        let (tx, rx) = mpsc::channel();
+        let mut out = Vec::new();
+        serve("next\nwarp 9\n\ngoto 3\n".as_bytes(), &mut out, &tx);
+        let out = String::from_utf8(out).expect("utf-8");
+        let replies: Vec<_> = out.lines().collect();
+        assert_eq!(replies.len(), 3, "{out}");
+        assert_eq!(replies[0], "ok");
+        assert!(replies[1].starts_with("error: unknown command"), "{out}");
+        assert_eq!(replies[2], "ok");
+        assert_eq!(
+            rx.try_iter().collect::<Vec<_>>(),
+            [RemoteCommand::Next, RemoteCommand::Goto(3)]
+        );
+    }
+
+    #[test]
+    fn a_non_loopback_address_is_refused() {
+        let err = Control::listen("0.0.0.0:0").err().expect("refused");
+        assert!(err.to_string().contains("loopback"), "{err}");
+    }
+}
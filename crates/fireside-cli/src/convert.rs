@@ -0,0 +1,221 @@
+//! `fireside convert <input> <output>`: rewrite a deck as JSON or YAML,
+//! each path's extension naming its format — `.yaml` or `.yml` for YAML,
+//! anything else for JSON. The output is canonical, the way `fireside fmt`
+//! writes a deck.
+//!
+//! YAML is read and written here rather than in the engine: it's the
+//! same document with other punctuation, so it's turned into a [`Graph`]
+//! (or JSON text, for the unknown-field check) at the edge and the
+//! engine only ever sees the model.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use fireside_core::Graph;
+use fireside_engine::load::unknown_fields;
+
+use crate::{load_with_text, missing_file_error};
+
+/// The text formats a deck can be saved in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DeckFormat {
+    /// The protocol's own format.
+    #[default]
+    Json,
+    /// The same document as YAML — the same fields, in the same places.
+    Yaml,
+}
+
+impl DeckFormat {
+    /// The format a deck at `path` is in, by its extension: YAML for
+    /// `.yaml` and `.yml`, in any case, and JSON for everything else.
+    pub(crate) fn from_path(path: &Path) -> Self {
+        let yaml = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+        if yaml { Self::Yaml } else { Self::Json }
+    }
+
+    /// Parses `text`, in this format, as a deck. Properties the protocol
+    /// doesn't define are ignored, as [`Graph::from_json`] ignores them.
+    pub(crate) fn parse(self, text: &str) -> Result<Graph> {
+        Ok(match self {
+            Self::Json => Graph::from_json(text)?,
+            Self::Yaml => serde_yaml_ng::from_str(text).context("not a valid Fireside document")?,
+        })
+    }
+
+    /// `graph` as this format's canonical text, the way `fireside fmt`
+    /// writes it: fields in protocol order, ending in one newline.
+    pub(crate) fn to_string(self, graph: &Graph) -> Result<String> {
+        Ok(match self {
+            Self::Json => graph.to_json_pretty()? + "\n",
+            Self::Yaml => serde_yaml_ng::to_string(graph)?,
+        })
+    }
+
+    /// `text`, in this format, as JSON text — what [`unknown_fields`]
+    /// reads. Borrowed as-is when it's JSON already.
+    fn to_json_text(self, text: &str) -> Result<Cow<'_, str>> {
+        Ok(match self {
+            Self::Json => Cow::Borrowed(text),
+            Self::Yaml => {
+                let value: serde_json::Value =
+                    serde_yaml_ng::from_str(text).context("not a valid Fireside document")?;
+                Cow::Owned(value.to_string())
+            }
+        })
+    }
+}
+
+/// Reads the deck at `input` in its format and writes it to `output` in
+/// the other's. The input is never modified, and an existing `output` is
+/// never overwritten; with `dry_run` nothing is written at all.
+///
+/// # Errors
+///
+/// Fails on an unreadable or unparseable deck, and on one carrying fields
+/// the protocol doesn't define — Fireside's model has nowhere to keep
+/// them, so converting would silently drop them.
+pub(crate) fn convert_file(input: &Path, output: &Path, dry_run: bool) -> Result<()> {
+    if output.exists() {
+        bail!("{} already exists — pick another name", output.display());
+    }
+    let from = DeckFormat::from_path(input);
+    let (graph, text) = match from {
+        DeckFormat::Json => load_with_text(input)?,
+        DeckFormat::Yaml => {
+            let text = match std::fs::read_to_string(input) {
+                Ok(text) => text,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => missing_file_error(input),
+                Err(err) => {
+                    return Err(err).with_context(|| format!("could not read {}", input.display()));
+                }
+            };
+            let graph = from
+                .parse(&text)
+                .with_context(|| format!("could not load {}", input.display()))?;
+            (graph, text)
+        }
+    };
+    let unknown = unknown_fields(&from.to_json_text(&text)?)?;
+    if !unknown.is_empty() {
+        let list: Vec<String> = unknown.iter().map(|u| format!("  {u}")).collect();
+        bail!(
+            "{} has fields the protocol doesn't define, which converting would drop:\n{}",
+            input.display(),
+            list.join("\n")
+        );
+    }
+    let converted = DeckFormat::from_path(output)
+        .to_string(&graph)
+        .context("could not serialize the deck")?;
+    if !dry_run {
+        crate::edit::atomic_write(output, &converted)
+            .with_context(|| format!("could not write {}", output.display()))?;
+    }
+    println!(
+        "{} {} to {}{}",
+        if dry_run {
+            "Would convert"
+        } else {
+            "Converted"
+        },
+        input.display(),
+        output.display(),
+        if dry_run {
+            " — nothing written."
+        } else {
+            "."
+        }
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fireside_engine::load::{LoadOptions, load_graph};
+
+    use super::*;
+
+    const STRICT: LoadOptions = LoadOptions {
+        deny_unknown_fields: true,
+    };
+
+    #[test]
+    fn json_to_yaml_and_back_keeps_every_field() {
+        let hello = include_str!("../../../docs/examples/hello.json");
+        // Strings YAML would read as something else if left bare, code
+        // whose whitespace matters, and every optional field on the way.
+        let rich = r##"{
+            "fireside-version": "0.1.0", "title": "yes", "author": "null",
+            "date": "2026-10-16", "version": "1.10",
+            "defaults": {"view-mode": "fullscreen", "transition": "fade"},
+            "nodes": [
+                {"id": "001", "title": "Deep: or not?", "section": "~",
+                 "duration": "1:30", "speaker-notes": "line one\n  line two\n",
+                 "background": "#1e1e2e", "accent": "#fa0",
+                 "traversal": {"branch-point": {"prompt": "Which?",
+                    "timeout": "10s", "default-option": "y",
+                    "options": [
+                        {"label": "On", "key": "y", "target": "on",
+                         "description": "true", "icon": "★"},
+                        {"label": "Off", "target": "on"}]}},
+                 "content": [
+                    {"kind": "heading", "level": 2, "text": "- not a list", "id": "top",
+                     "visible-if": "a == 'b' || !c"},
+                    {"kind": "code", "language": "yaml", "source": "key: value\n\n  indented  \n",
+                     "highlight-lines": [1, 3], "show-line-numbers": false, "runnable": true,
+                     "reveal": 2},
+                    {"kind": "list", "ordered": true, "items": ["1", "on", ""], "incremental": true},
+                    {"kind": "image", "src": "a.png", "alt": "", "decorative": true,
+                     "caption": "# not a comment", "width": 40, "height": 12},
+                    {"kind": "video", "src": "v.mp4", "poster": "p.png", "caption": "c"},
+                    {"kind": "divider"},
+                    {"kind": "container", "layout": "grid", "columns": 2,
+                     "children": [{"kind": "text", "body": "  padded  "}]},
+                    {"kind": "columns", "ratios": [2, 1],
+                     "columns": [[{"kind": "text", "body": "l"}], [{"kind": "text", "body": "r"}]]},
+                    {"kind": "ascii-art", "art": " /\\_/\\\n( o.o )\n", "alt": "a cat"}
+                 ]},
+                {"id": "on", "hidden": true, "view-mode": "default", "transition": "none",
+                 "duration": 45, "traversal": {"next": "001"}, "content": []}
+            ]
+        }"##;
+        for json in [hello, rich] {
+            let graph = load_graph(json, STRICT).unwrap();
+            let yaml = DeckFormat::Yaml.to_string(&graph).unwrap();
+            let back = DeckFormat::Yaml.parse(&yaml).unwrap();
+            assert_eq!(back, graph, "{yaml}");
+            let as_json = DeckFormat::Yaml.to_json_text(&yaml).unwrap();
+            assert_eq!(unknown_fields(&as_json).unwrap(), [], "{yaml}");
+            let json = DeckFormat::Json.to_string(&back).unwrap();
+            assert_eq!(load_graph(&json, STRICT).unwrap(), graph);
+        }
+    }
+
+    #[test]
+    fn the_extension_picks_the_format_and_yaml_is_checked_too() {
+        assert_eq!(
+            DeckFormat::from_path(Path::new("talk.YML")),
+            DeckFormat::Yaml
+        );
+        assert_eq!(
+            DeckFormat::from_path(Path::new("talk.yaml")),
+            DeckFormat::Yaml
+        );
+        assert_eq!(
+            DeckFormat::from_path(Path::new("talk.json")),
+            DeckFormat::Json
+        );
+        assert_eq!(DeckFormat::from_path(Path::new("talk")), DeckFormat::Json);
+
+        let yaml = "nodes:\n- id: a\n  content: []\n  transiton: fade\n";
+        assert_eq!(DeckFormat::Yaml.parse(yaml).unwrap().nodes.len(), 1);
+        let unknown = unknown_fields(&DeckFormat::Yaml.to_json_text(yaml).unwrap()).unwrap();
+        assert_eq!(unknown[0].path, "nodes[0].transiton");
+        assert!(DeckFormat::Yaml.parse("nodes: [").is_err());
+    }
+}
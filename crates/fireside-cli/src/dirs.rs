@@ -0,0 +1,74 @@
+//! Where Fireside keeps its own per-user files — resume positions, live
+//! session heartbeats, editor drafts — resolved in one place so every one
+//! of them moves together.
+//!
+//! `FIRESIDE_STATE_DIR` wins outright (tests, portable installs), then
+//! `$XDG_STATE_HOME/fireside`, then `~/.local/state/fireside`. Manual
+//! `std::env`/`std::path` construction rather than a `dirs`-style crate —
+//! flagged in research.md §2 as a reviewable, no-new-dependency default.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Overrides the whole state directory, used as given.
+const STATE_DIR_VAR: &str = "FIRESIDE_STATE_DIR";
+
+/// Fireside's state directory, or `None` when nothing says where home is
+/// — every caller treats that as "nothing to persist".
+#[must_use]
+pub(crate) fn state_dir() -> Option<PathBuf> {
+    state_dir_from(|name| std::env::var_os(name))
+}
+
+/// [`state_dir`] over an arbitrary environment lookup. An empty variable
+/// counts as unset, as the XDG spec asks.
+fn state_dir_from(var: impl Fn(&str) -> Option<OsString>) -> Option<PathBuf> {
+    let var = |name: &str| var(name).filter(|value| !value.is_empty());
+    if let Some(dir) = var(STATE_DIR_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+    let base = var("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".local/state")))?;
+    Some(base.join("fireside"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<OsString> {
+        let pairs: Vec<(String, OsString)> = pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), OsString::from(v)))
+            .collect();
+        move |name| {
+            pairs
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.clone())
+        }
+    }
+
+    #[test]
+    fn the_override_wins_over_xdg_and_home() {
+        let dir = state_dir_from(env(&[
+            ("FIRESIDE_STATE_DIR", "/portable/state"),
+            ("XDG_STATE_HOME", "/xdg"),
+            ("HOME", "/home/me"),
+        ]));
+        assert_eq!(dir, Some(PathBuf::from("/portable/state")));
+    }
+
+    #[test]
+    fn without_the_override_xdg_then_home_are_used() {
+        let xdg = env(&[("XDG_STATE_HOME", "/xdg"), ("HOME", "/home/me")]);
+        assert_eq!(state_dir_from(xdg), Some(PathBuf::from("/xdg/fireside")));
+        let home = env(&[("XDG_STATE_HOME", ""), ("HOME", "/home/me")]);
+        assert_eq!(
+            state_dir_from(home),
+            Some(PathBuf::from("/home/me/.local/state/fireside"))
+        );
+        assert_eq!(state_dir_from(env(&[])), None);
+    }
+}
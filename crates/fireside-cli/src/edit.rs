@@ -14,6 +14,7 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 use fireside_core::{CoreError, Graph};
+use fireside_engine::authoring::IdStrategy;
 use fireside_tui::WriteBackError;
 use fireside_tui::editor::DraftPrompt;
 
@@ -27,7 +28,7 @@ use crate::new::starter_deck;
 /// status-banner for anything else, and the open-time draft-vs-saved-file
 /// prompt (spec 013 US4, FR-020) when a draft sidecar disagrees with the
 /// file just loaded.
-pub(crate) fn edit_deck(file: &Path) -> Result<()> {
+pub(crate) fn edit_deck(file: &Path, ids: IdStrategy) -> Result<()> {
     let (graph, created) = load_or_create(file)?;
     // Leads with the fact that matters, unlike the println below: the
     // hint line doesn't wrap (P1-6 already flagged this truncation class
@@ -48,8 +49,9 @@ pub(crate) fn edit_deck(file: &Path) -> Result<()> {
         .and_then(|path| resolve_draft_prompt(path, file, &graph));
 
     let mut fingerprint_at_open = crate::watch::fingerprint(file);
+    let mut create_parent = false;
     let mut sink = |g: &Graph| -> Result<(), WriteBackError> {
-        let result = write_back(file, &mut fingerprint_at_open, g);
+        let result = write_back(file, &mut fingerprint_at_open, &mut create_parent, g);
         if result.is_ok()
             && let Some(path) = &draft_path
         {
@@ -70,9 +72,11 @@ pub(crate) fn edit_deck(file: &Path) -> Result<()> {
         graph,
         draft_prompt,
         created_notice,
+        ids,
         &mut sink,
         &mut draft_sink,
         Some(&mut art_generator),
+        &mut crate::clipboard::copy,
     );
     // The editor only ever returns normally via a deliberate, voluntary
     // quit (nothing-to-save, an explicit save, or an explicit discard —
@@ -92,22 +96,26 @@ pub(crate) fn edit_deck(file: &Path) -> Result<()> {
 /// Writes `graph` to `file`, refusing (spec 013 US4, T062) if the file
 /// changed on disk since this session last observed it — the same
 /// fingerprint-resync contract `watch::Watcher::write_back` already gives
-/// the presenter's quick-edit save (FR-021). Kept separate from the
-/// `sink` closure so it is directly unit-testable without a terminal.
+/// the presenter's quick-edit save (FR-021), and the same save-again
+/// retry for a folder that has gone missing (`create_parent`, see
+/// [`crate::watch::ensure_parent`]). Kept separate from the `sink` closure
+/// so it is directly unit-testable without a terminal.
 fn write_back(
     file: &Path,
     fingerprint_at_open: &mut Option<(SystemTime, u64)>,
+    create_parent: &mut bool,
     graph: &Graph,
 ) -> Result<(), WriteBackError> {
+    let created = crate::watch::ensure_parent(file, create_parent)?;
     let current = crate::watch::fingerprint(file);
-    if current != *fingerprint_at_open {
+    if !created && current != *fingerprint_at_open {
         *fingerprint_at_open = current;
         return Err(WriteBackError::Conflict);
     }
     let json = graph
         .to_json_pretty()
         .map_err(|err| WriteBackError::Io(err.to_string()))?;
-    atomic_write(file, &(json + "\n")).map_err(|err| WriteBackError::Io(err.to_string()))?;
+    atomic_write(file, &(json + "\n")).map_err(|err| WriteBackError::from_io(&err, file))?;
     *fingerprint_at_open = crate::watch::fingerprint(file);
     Ok(())
 }
@@ -117,7 +125,7 @@ fn write_back(
 /// crash mid-write) never observes a partially written deck — the same
 /// technique `fireside-cli::session.rs::write` already uses for its own
 /// state file.
-fn atomic_write(path: &Path, contents: &str) -> std::io::Result<()> {
+pub(crate) fn atomic_write(path: &Path, contents: &str) -> std::io::Result<()> {
     let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
     let tmp_name = format!(
         ".tmp-{}-{}",
@@ -158,7 +166,11 @@ fn load_or_create(file: &Path) -> Result<(Graph, bool)> {
         }
     };
     match Graph::from_json(&text) {
-        Ok(graph) => Ok((graph, false)),
+        Ok(mut graph) => {
+            crate::resolve_embeds(&mut graph, file)
+                .with_context(|| format!("could not load {}", file.display()))?;
+            Ok((graph, false))
+        }
         Err(CoreError::Parse(err)) => {
             if crate::is_markdown_path(file) {
                 markdown_hint(file);
@@ -220,22 +232,18 @@ const DRAFT_SCHEMA_VERSION: u64 = 1;
 
 /// `$XDG_STATE_HOME/fireside/drafts/<fnv1a64-hex-of-canonicalized-deck-path>.json`,
 /// falling back to `~/.local/state/fireside/drafts/...` — one path
-/// segment deeper than `resume::resume_path`, same base-directory
-/// resolution and the exact `fnv1a64` hash `session.rs::session_path_for`
-/// already uses (`data-model.md`'s Draft sidecar section, research.md §3).
+/// segment deeper than `resume::resume_path`, same
+/// [`crate::dirs::state_dir`] and the exact `fnv1a64` hash
+/// `session.rs::session_path_for` already uses (`data-model.md`'s Draft
+/// sidecar section, research.md §3).
 fn drafts_dir() -> Option<PathBuf> {
-    let base = std::env::var_os("XDG_STATE_HOME")
-        .map(PathBuf::from)
-        .or_else(|| {
-            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state"))
-        })?;
-    Some(base.join("fireside").join("drafts"))
+    Some(crate::dirs::state_dir()?.join("drafts"))
 }
 
 fn draft_path_for(key: &str) -> Option<PathBuf> {
     Some(drafts_dir()?.join(format!(
         "{:016x}.json",
-        crate::session::fnv1a64(key.as_bytes())
+        fireside_core::fnv1a64(key.as_bytes())
     )))
 }
 
@@ -349,7 +357,7 @@ mod tests {
         let mut fp = crate::watch::fingerprint(&deck);
 
         let graph = Graph::from_json(SPOTLESS_DECK).expect("fixture parses");
-        write_back(&deck, &mut fp, &graph).expect("save should succeed");
+        write_back(&deck, &mut fp, &mut false, &graph).expect("save should succeed");
 
         let saved = std::fs::read_to_string(&deck).expect("read back");
         let reparsed = Graph::from_json(&saved).expect("saved file still parses");
@@ -369,10 +377,60 @@ mod tests {
         std::fs::write(&deck, r#"{"nodes":[{"id":"b","content":[]}]}"#).expect("external edit");
 
         let graph = Graph::from_json(SPOTLESS_DECK).expect("fixture parses");
-        let result = write_back(&deck, &mut fp, &graph);
+        let result = write_back(&deck, &mut fp, &mut false, &graph);
         assert_eq!(result, Err(WriteBackError::Conflict));
     }
 
+    #[test]
+    fn write_back_to_a_missing_folder_says_so_then_creates_it_on_the_retry() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let folder = dir.path().join("talks").join("2026");
+        let deck = folder.join("deck.json");
+        std::fs::create_dir_all(&folder).expect("make the folder");
+        std::fs::write(&deck, SPOTLESS_DECK).expect("write fixture");
+        let mut fp = crate::watch::fingerprint(&deck);
+        let mut create_parent = false;
+        // The folder goes away while the deck is open.
+        std::fs::remove_dir_all(dir.path().join("talks")).expect("remove the folder");
+
+        let graph = Graph::from_json(SPOTLESS_DECK).expect("fixture parses");
+        let result = write_back(&deck, &mut fp, &mut create_parent, &graph);
+        assert_eq!(
+            result,
+            Err(WriteBackError::NoSuchDirectory(
+                folder.display().to_string()
+            ))
+        );
+        assert!(!folder.exists(), "nothing is created until the retry");
+
+        write_back(&deck, &mut fp, &mut create_parent, &graph).expect("the retry saves");
+        let saved = std::fs::read_to_string(&deck).expect("read back");
+        assert_eq!(Graph::from_json(&saved).expect("parses"), graph);
+        assert!(!create_parent, "the offer is used up");
+    }
+
+    #[test]
+    fn an_io_failure_is_sorted_by_what_to_do_about_it() {
+        let path = Path::new("talks/deck.json");
+        let kind = |kind| WriteBackError::from_io(&std::io::Error::from(kind), path);
+        assert_eq!(
+            kind(std::io::ErrorKind::PermissionDenied),
+            WriteBackError::PermissionDenied("talks/deck.json".to_owned())
+        );
+        assert_eq!(
+            kind(std::io::ErrorKind::NotFound),
+            WriteBackError::NoSuchDirectory("talks".to_owned())
+        );
+        assert_eq!(
+            kind(std::io::ErrorKind::StorageFull),
+            WriteBackError::DiskFull("talks/deck.json".to_owned())
+        );
+        assert!(matches!(
+            kind(std::io::ErrorKind::Interrupted),
+            WriteBackError::Io(_)
+        ));
+    }
+
     #[test]
     fn write_back_leaves_no_temp_file_and_the_saved_content_is_whole() {
         let dir = tempfile::tempdir().expect("tempdir");
@@ -381,7 +439,7 @@ mod tests {
         let mut fp = crate::watch::fingerprint(&deck);
 
         let graph = Graph::from_json(SPOTLESS_DECK).expect("fixture parses");
-        write_back(&deck, &mut fp, &graph).expect("save should succeed");
+        write_back(&deck, &mut fp, &mut false, &graph).expect("save should succeed");
 
         let leftovers: Vec<_> = std::fs::read_dir(dir.path())
             .expect("read dir")
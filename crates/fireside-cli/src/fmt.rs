@@ -0,0 +1,70 @@
+//! `fireside fmt <file> [--check]`: rewrite a hand-edited deck the way
+//! Fireside itself saves one — fields in protocol order, two-space
+//! indents, one trailing newline — so diffs show content changes, not
+//! drift in layout.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use fireside_engine::authoring::reindex_branch_keys;
+use fireside_engine::load::unknown_fields;
+
+use crate::{load_with_text, output};
+
+/// Rewrites the deck at `path` in its canonical form, or with `check`
+/// only reports: a deck that isn't canonical prints a diff and exits `1`,
+/// leaving the file alone. `dry_run` prints the same diff but exits `0`,
+/// like every other command's `--dry-run`. `assign_keys` also rewrites
+/// branch answers' shortcut keys — see [`reindex_branch_keys`], which
+/// `preserve_keys` is passed on to.
+///
+/// # Errors
+///
+/// Fails on an unreadable or unparseable deck, and on one carrying fields
+/// the protocol doesn't define — Fireside's model has nowhere to keep
+/// them, so formatting would silently drop them.
+pub(crate) fn format_file(
+    path: &Path,
+    check: bool,
+    dry_run: bool,
+    assign_keys: bool,
+    preserve_keys: bool,
+) -> Result<()> {
+    let (mut graph, text) = load_with_text(path)?;
+    let unknown = unknown_fields(&text)?;
+    if !unknown.is_empty() {
+        let list: Vec<String> = unknown.iter().map(|u| format!("  {u}")).collect();
+        bail!(
+            "{} has fields the protocol doesn't define, which formatting would drop:\n{}",
+            path.display(),
+            list.join("\n")
+        );
+    }
+    if assign_keys {
+        reindex_branch_keys(&mut graph, preserve_keys);
+    }
+    let canonical = graph
+        .to_json_pretty()
+        .context("could not serialize the deck")?
+        + "\n";
+    if canonical == text {
+        println!("{} is already formatted.", path.display());
+        return Ok(());
+    }
+    if check {
+        print!("{}", output::diff(&text, &canonical));
+        eprintln!(
+            "{} isn't formatted — \"fireside fmt {}\" rewrites it.",
+            path.display(),
+            path.display()
+        );
+        std::process::exit(1);
+    }
+    output::write_output(path, &graph, dry_run)?;
+    if dry_run {
+        println!("Would format {} — nothing written.", path.display());
+    } else {
+        println!("Formatted {}.", path.display());
+    }
+    Ok(())
+}
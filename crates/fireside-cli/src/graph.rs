@@ -0,0 +1,85 @@
+//! `fireside graph <file>`: a deck's edge structure on stdout — a readable
+//! adjacency list by default, or Graphviz DOT with `--dot` for piping into
+//! `dot -Tsvg`.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::Result;
+use fireside_core::{Edge, Graph};
+
+use crate::load;
+
+/// Loads the deck at `path` and prints its edges.
+pub(crate) fn graph_file(path: &Path, dot: bool) -> Result<()> {
+    let graph = load(path)?;
+    if dot {
+        print!("{}", graph.to_dot());
+    } else {
+        print!("{}", adjacency(&graph));
+    }
+    Ok(())
+}
+
+/// Every node in deck order, each followed by the moves out of it. A node
+/// with none says so, so a dead end is as visible as a branch.
+fn adjacency(graph: &Graph) -> String {
+    let mut out = String::new();
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let _ = write!(out, "{}", node.id);
+        if node.hidden {
+            out.push_str(" (hidden)");
+        }
+        out.push('\n');
+        let edges = graph.outgoing_edges(i);
+        if edges.is_empty() {
+            out.push_str("  (end of path)\n");
+        }
+        for edge in edges {
+            match edge {
+                Edge::Next { target } => {
+                    let _ = writeln!(out, "  → {target}");
+                }
+                Edge::Branch {
+                    key: Some(key),
+                    label,
+                    target,
+                } => {
+                    let _ = writeln!(out, "  {key}) {label} → {target}");
+                }
+                Edge::Branch {
+                    key: None,
+                    label,
+                    target,
+                } => {
+                    let _ = writeln!(out, "  {label} → {target}");
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacency_lists_every_move_and_dead_end() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"Left","key":"l","target":"b"},
+                    {"label":"Right","target":"c"}
+                ]}},"content":[]},
+                {"id":"b","hidden":true,"traversal":"c","content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        )
+        .expect("fixture parses");
+        assert_eq!(
+            adjacency(&graph),
+            "a\n  l) Left → b\n  Right → c\nb (hidden)\n  → c\nc\n  (end of path)\n"
+        );
+    }
+}
@@ -364,8 +364,11 @@ fn try_paragraph_as_image(
             return (
                 Some(ContentBlock::Image {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     src,
                     alt: (!alt.is_empty()).then_some(alt),
+                    decorative: false,
                     caption,
                     width: None,
                     height: None,
@@ -525,10 +528,14 @@ fn parse_table(events: &[(Event<'_>, Range<usize>)], i: usize) -> (ContentBlock,
     (
         ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: None,
             source: lines.join("\n"),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         },
         dropped,
         table_end,
@@ -683,9 +690,15 @@ fn resolve_branch(
             key: src.key,
             target,
             description: None,
+            icon: None,
         });
     }
-    Ok(BranchPoint { prompt, options })
+    Ok(BranchPoint {
+        prompt,
+        options,
+        timeout: None,
+        default_option: None,
+    })
 }
 
 /// One `##`-delimited region of the source document, converted.
@@ -796,6 +809,8 @@ fn parse_sections(
                     }
                     blocks.push(ContentBlock::Heading {
                         reveal: None,
+                        id: None,
+                        visible_if: None,
                         level: level_u8,
                         text: text.trim().to_owned(),
                     });
@@ -836,6 +851,8 @@ fn parse_sections(
                     }
                     blocks.push(ContentBlock::Text {
                         reveal: None,
+                        id: None,
+                        visible_if: None,
                         body: text,
                     });
                 }
@@ -860,6 +877,8 @@ fn parse_sections(
                     if lang == "ascii-art" {
                         blocks.push(ContentBlock::AsciiArt {
                             reveal: None,
+                            id: None,
+                            visible_if: None,
                             art: body,
                             alt: None,
                         });
@@ -867,10 +886,14 @@ fn parse_sections(
                     }
                     blocks.push(ContentBlock::Code {
                         reveal: None,
+                        id: None,
+                        visible_if: None,
                         language: (!lang.is_empty()).then_some(lang),
                         source: body,
                         highlight_lines: None,
                         show_line_numbers: None,
+                        runnable: false,
+                        wrap: None,
                     });
                 }
                 Event::Start(Tag::List(start_num)) => {
@@ -885,8 +908,11 @@ fn parse_sections(
                     }
                     blocks.push(ContentBlock::List {
                         reveal: None,
+                        id: None,
+                        visible_if: None,
                         ordered: Some(ordered),
                         items,
+                        incremental: false,
                     });
                 }
                 Event::Start(Tag::Table(_)) => {
@@ -927,7 +953,13 @@ fn parse_sections(
                             section: heading_text,
                         });
                     }
-                    blocks.push(ContentBlock::Divider { reveal: None });
+                    blocks.push(ContentBlock::Divider {
+                        reveal: None,
+                        id: None,
+                        visible_if: None,
+                        style: None,
+                        label: None,
+                    });
                 }
                 _ => i += 1,
             }
@@ -968,6 +1000,12 @@ fn build_graph(frontmatter: Frontmatter, sections: Vec<Section>) -> Graph {
                 transition: None,
                 speaker_notes: None,
                 traversal,
+                section: None,
+                duration: None,
+                hidden: false,
+                terminal: false,
+                background: None,
+                accent: None,
                 content: section.blocks,
             }
         })
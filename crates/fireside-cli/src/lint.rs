@@ -0,0 +1,607 @@
+//! `fireside lint <deck>`: every non-fatal check in one pass, each finding
+//! paired with the change that would fix it.
+//!
+//! Layers a few authoring-hygiene checks that only make sense with the
+//! deck's file in hand (missing image files, empty blocks, slides that
+//! repeat each other) on top of `fireside_engine::validate`. `--fix`
+//! applies the purely mechanical repairs through the same
+//! `fireside_engine::authoring` ops the editor uses, then re-saves.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Result;
+use fireside_core::{ContentBlock, Graph, Node};
+use fireside_engine::authoring::{self, Op};
+use fireside_engine::repair::is_empty_block;
+use fireside_engine::{Diagnostic, Severity, validate};
+
+use crate::{load, output};
+
+/// One lint finding: what's wrong, where, and what to do about it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LintFinding {
+    /// How serious this finding is.
+    pub(crate) severity: Severity,
+    /// Stable rule identifier — a validation rule id, or one of lint's own.
+    pub(crate) rule: &'static str,
+    /// The node this finding is about, when there is one.
+    pub(crate) node: Option<String>,
+    /// What's wrong, in plain language.
+    pub(crate) message: String,
+    /// What the author should change.
+    pub(crate) suggestion: String,
+    /// The mechanical repair `--fix` applies, for findings that have one.
+    pub(crate) fix: Option<Op>,
+}
+
+/// Every finding for one deck, most serious first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct LintReport {
+    pub(crate) findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    /// `0` clean (notes only), `1` warnings, `2` errors.
+    #[must_use]
+    pub(crate) fn exit_code(&self) -> i32 {
+        match self.findings.iter().map(|f| f.severity).max() {
+            Some(Severity::Error) => 2,
+            Some(Severity::Warning) => 1,
+            Some(Severity::Info) | None => 0,
+        }
+    }
+
+    /// The repairs `--fix` would apply, in the order they must be applied.
+    fn fixes(&self) -> impl Iterator<Item = &Op> {
+        self.findings.iter().filter_map(|f| f.fix.as_ref())
+    }
+}
+
+/// Runs every check against `graph`. `deck_dir` is where relative image
+/// paths are resolved from.
+#[must_use]
+pub(crate) fn lint(graph: &Graph, deck_dir: &Path) -> LintReport {
+    let mut findings: Vec<LintFinding> = validate(graph)
+        .into_iter()
+        // Lint reports duplicate keys itself, per option, so it can offer
+        // a fix for each.
+        .filter(|d| d.rule != "unique-branch-keys")
+        .map(from_diagnostic)
+        .collect();
+    check_duplicate_branch_keys(graph, &mut findings);
+    check_empty_blocks(graph, &mut findings);
+    check_missing_assets(graph, deck_dir, &mut findings);
+    check_near_duplicate_nodes(graph, &mut findings);
+
+    // Stable, so fixes for the same node keep the order they were built in.
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+    LintReport { findings }
+}
+
+fn from_diagnostic(d: Diagnostic) -> LintFinding {
+    let node = d.node.as_deref().unwrap_or("this slide");
+    let suggestion = match d.rule {
+        "unique-node-ids" => "rename one of the slides so every id is unique".to_owned(),
+        "valid-traversal-target" => format!("point \"{node}\" at a slide that exists"),
+        "next-branch-point-conflict" => {
+            format!("remove either the next link or the choice from \"{node}\"")
+        }
+        "empty-branch-options" => format!("add an answer to \"{node}\", or remove the choice"),
+        "reserved-branch-key" | "unique-branch-keys" => {
+            format!("pick a different key for the option on \"{node}\"")
+        }
+        "valid-default-option" => {
+            format!("make \"{node}\"'s default option one of its answers' keys")
+        }
+        "container-nesting-depth-exceeded" => {
+            format!("flatten the nested containers on \"{node}\"")
+        }
+        "hidden-branch-target" => {
+            format!("unhide the option's target on \"{node}\", or give it a next link")
+        }
+        "empty-traversal" => format!("remove the empty traversal from \"{node}\""),
+        "reveal-masked-by-container" => {
+            format!("raise the container's reveal step on \"{node}\", or lower its children's")
+        }
+        "ascii-art-too-wide" => format!("regenerate the art on \"{node}\" at a narrower width"),
+        "ascii-art-empty" => format!("add art to the block on \"{node}\", or delete it"),
+        "duplicate-block-anchor" => format!("give each block on \"{node}\" its own anchor"),
+        "section-not-contiguous" => {
+            format!("move \"{node}\" next to the rest of its section, or rename its section")
+        }
+        "duration-out-of-range" => {
+            format!("give \"{node}\" a duration between 1s and 1h, or remove it")
+        }
+        "invalid-node-color" => format!(
+            "write \"{node}\"'s background or accent as a hex color like \"#1e1e2e\", or remove it"
+        ),
+        "heading-level-skip" => format!("change the deeper heading's level on \"{node}\""),
+        "long-branch-description" => {
+            format!("shorten the answer's description on \"{node}\"")
+        }
+        "malformed-link-url" => format!("fix the link address on \"{node}\""),
+        "missing-alt-text" => format!(
+            "describe the image in its alt text on \"{node}\", or mark it \"decorative\": true"
+        ),
+        "unreachable-node" => {
+            format!("remove unreachable slide \"{node}\", or link to it from another slide")
+        }
+        "self-loop" | "trivial-cycle" => {
+            format!("point one of the links on \"{node}\" somewhere new")
+        }
+        "unmarked-dead-end" => format!(
+            "give \"{node}\" a \"next\", or mark it \"terminal\": true if the talk ends there"
+        ),
+        "dead-end-branch" => format!("nothing to do, unless \"{node}\" shouldn't end the talk"),
+        _ => format!("review \"{node}\""),
+    };
+    LintFinding {
+        severity: d.severity,
+        rule: d.rule,
+        node: d.node,
+        message: d.message,
+        suggestion,
+        fix: None,
+    }
+}
+
+/// ERROR: two options on one branch point share a key. The first keeps
+/// it; every later one is offered a fix that gives it the next key no
+/// option uses, or clears its key once none is left.
+fn check_duplicate_branch_keys(graph: &Graph, findings: &mut Vec<LintFinding>) {
+    for node in &graph.nodes {
+        let Some(bp) = node.branch_point() else {
+            continue;
+        };
+        let mut free = authoring::free_answer_keys(bp);
+        let mut seen: HashMap<&str, &str> = HashMap::new();
+        for (index, opt) in bp.options.iter().enumerate() {
+            let Some(key) = opt.key.as_deref() else {
+                continue;
+            };
+            let Some(other) = seen.get(key) else {
+                seen.insert(key, &opt.label);
+                continue;
+            };
+            let fresh = free.next();
+            findings.push(LintFinding {
+                severity: Severity::Error,
+                rule: "unique-branch-keys",
+                node: Some(node.id.clone()),
+                message: format!(
+                    "\"{}\" assigns key \"{key}\" to both \"{other}\" and \"{}\"",
+                    node.id, opt.label
+                ),
+                suggestion: match &fresh {
+                    Some(fresh) => format!("change option \"{}\"'s key to \"{fresh}\"", opt.label),
+                    None => format!("remove option \"{}\"'s key", opt.label),
+                },
+                fix: Some(Op::SetAnswerKey {
+                    id: node.id.clone(),
+                    index,
+                    key: fresh,
+                }),
+            });
+        }
+    }
+}
+
+/// WARNING: a top-level block has no content. Empty blocks at the end of
+/// a slide are offered a fix that deletes them — last first, so each
+/// fix's path is still valid after the one before it.
+fn check_empty_blocks(graph: &Graph, findings: &mut Vec<LintFinding>) {
+    for node in &graph.nodes {
+        let trailing_from = node
+            .content
+            .iter()
+            .rposition(|b| !is_empty_block(b))
+            .map_or(0, |i| i + 1);
+        for (index, block) in node.content.iter().enumerate().rev() {
+            if !is_empty_block(block) {
+                continue;
+            }
+            let trailing = index >= trailing_from;
+            findings.push(LintFinding {
+                severity: Severity::Warning,
+                rule: "empty-block",
+                node: Some(node.id.clone()),
+                message: format!(
+                    "\"{}\" has an empty block at position {}",
+                    node.id,
+                    index + 1
+                ),
+                suggestion: if trailing {
+                    format!("drop the empty block at the end of \"{}\"", node.id)
+                } else {
+                    format!("fill in or delete block {} on \"{}\"", index + 1, node.id)
+                },
+                fix: trailing.then(|| Op::DeleteBlock {
+                    node: node.id.clone(),
+                    path: vec![index],
+                }),
+            });
+        }
+    }
+}
+
+/// WARNING: an image's `src`, or a video's `poster`, is a local path with
+/// no file behind it. URLs (anything with a `scheme:`) are never checked.
+fn check_missing_assets(graph: &Graph, deck_dir: &Path, findings: &mut Vec<LintFinding>) {
+    fn walk(blocks: &[ContentBlock], node: &Node, deck_dir: &Path, out: &mut Vec<LintFinding>) {
+        for block in blocks {
+            match block {
+                ContentBlock::Image { src, .. }
+                    if is_local_path(src) && !deck_dir.join(src).exists() =>
+                {
+                    out.push(LintFinding {
+                        severity: Severity::Warning,
+                        rule: "missing-asset",
+                        node: Some(node.id.clone()),
+                        message: format!(
+                            "\"{}\" shows image \"{src}\", but there's no such file",
+                            node.id
+                        ),
+                        suggestion: format!("add \"{src}\" next to the deck, or correct the path"),
+                        fix: None,
+                    });
+                }
+                ContentBlock::Video {
+                    poster: Some(poster),
+                    ..
+                } if is_local_path(poster) && !deck_dir.join(poster).exists() => {
+                    out.push(LintFinding {
+                        severity: Severity::Warning,
+                        rule: "missing-asset",
+                        node: Some(node.id.clone()),
+                        message: format!(
+                            "\"{}\" has a video whose poster \"{poster}\" doesn't exist",
+                            node.id
+                        ),
+                        suggestion: format!(
+                            "add \"{poster}\" next to the deck, correct the path, or drop the poster"
+                        ),
+                        fix: None,
+                    });
+                }
+                ContentBlock::Container { children, .. } => walk(children, node, deck_dir, out),
+                ContentBlock::Columns { columns, .. } => {
+                    for column in columns {
+                        walk(column, node, deck_dir, out);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    for node in &graph.nodes {
+        walk(&node.content, node, deck_dir, findings);
+    }
+}
+
+fn is_local_path(src: &str) -> bool {
+    let src = src.trim();
+    !src.is_empty()
+        && !src
+            .split_once(':')
+            .is_some_and(|(scheme, _)| scheme.len() > 1 && !scheme.contains(['/', '\\']))
+}
+
+/// WARNING: two slides say the same thing — identical text once case and
+/// whitespace are ignored — which is usually a copy that was never edited.
+fn check_near_duplicate_nodes(graph: &Graph, findings: &mut Vec<LintFinding>) {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for node in &graph.nodes {
+        let text = normalized_text(&node.content);
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(first) = seen.get(&text) {
+            findings.push(LintFinding {
+                severity: Severity::Warning,
+                rule: "near-duplicate-node",
+                node: Some(node.id.clone()),
+                message: format!("\"{}\" repeats the content of \"{first}\"", node.id),
+                suggestion: format!(
+                    "edit \"{}\" so it says something new, or remove it",
+                    node.id
+                ),
+                fix: None,
+            });
+        } else {
+            seen.insert(text, &node.id);
+        }
+    }
+}
+
+fn normalized_text(blocks: &[ContentBlock]) -> String {
+    fn collect(blocks: &[ContentBlock], out: &mut Vec<String>) {
+        for block in blocks {
+            match block {
+                ContentBlock::Heading { text, .. } => out.push(text.clone()),
+                ContentBlock::Text { body, .. } => out.push(body.clone()),
+                ContentBlock::Code { source, .. } => out.push(source.clone()),
+                ContentBlock::List { items, .. } => out.extend(items.iter().cloned()),
+                ContentBlock::Image { src, .. } | ContentBlock::Video { src, .. } => {
+                    out.push(src.clone());
+                }
+                ContentBlock::Embed { source, range, .. } => {
+                    out.push(format!("{source} {}", range.as_deref().unwrap_or_default()));
+                }
+                ContentBlock::AsciiArt { art, .. } => out.push(art.clone()),
+                ContentBlock::Container { children, .. } => collect(children, out),
+                ContentBlock::Columns { columns, .. } => {
+                    for column in columns {
+                        collect(column, out);
+                    }
+                }
+                ContentBlock::Divider { .. } => {}
+            }
+        }
+    }
+    let mut parts = Vec::new();
+    collect(blocks, &mut parts);
+    parts
+        .iter()
+        .flat_map(|p| p.split_whitespace())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Render a report in `validate`'s style, with each suggestion on the line
+/// beneath its finding.
+fn render(path: &Path, report: &LintReport) -> String {
+    if report.findings.is_empty() {
+        return format!("✓ {} — nothing to lint", path.display());
+    }
+    let mut out = String::new();
+    for f in &report.findings {
+        let icon = match f.severity {
+            Severity::Error => "✗",
+            Severity::Warning => "⚠",
+            Severity::Info => "ℹ",
+        };
+        out.push_str(&format!("  {icon} {}\n", f.message));
+        out.push_str(&format!("      → {}\n", f.suggestion));
+    }
+    let count = |s| report.findings.iter().filter(|f| f.severity == s).count();
+    let fixable = report.fixes().count();
+    out.push_str(&format!(
+        "\n{}: {} errors, {} warnings, {} notes",
+        path.display(),
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Info),
+    ));
+    if fixable > 0 {
+        out.push_str(&format!(
+            " — {fixable} can be fixed automatically with --fix"
+        ));
+    }
+    out
+}
+
+/// Apply every fix in `report` to `graph`. A fix that no longer applies
+/// (an earlier one already covered it) is skipped rather than failing the
+/// whole run.
+fn apply_fixes(graph: &Graph, report: &LintReport) -> (Graph, usize) {
+    let mut fixed = graph.clone();
+    let mut applied = 0;
+    for op in report.fixes() {
+        if let Ok(next) = authoring::apply(&fixed, op) {
+            fixed = next;
+            applied += 1;
+        }
+    }
+    (fixed, applied)
+}
+
+/// Entry point for `fireside lint <file> [--fix [--dry-run]]`. Exits `0`
+/// when clean, `1` when warnings remain, `2` when errors remain — after
+/// the fixes, even when `dry_run` kept them off the disk.
+pub(crate) fn lint_file(path: &Path, fix: bool, dry_run: bool) -> Result<()> {
+    let mut graph = load(path)?;
+    let deck_dir = path.parent().unwrap_or(Path::new("."));
+    let mut report = lint(&graph, deck_dir);
+
+    if fix {
+        let (fixed, applied) = apply_fixes(&graph, &report);
+        if applied > 0 {
+            output::write_output(path, &fixed, dry_run)?;
+            let verb = if dry_run { "Would apply" } else { "Applied" };
+            println!("{verb} {} to {}.\n", plural_fix(applied), path.display());
+            graph = fixed;
+            report = lint(&graph, deck_dir);
+        }
+    }
+
+    println!("{}", render(path, &report));
+    match report.exit_code() {
+        0 => Ok(()),
+        code => std::process::exit(code),
+    }
+}
+
+fn plural_fix(count: usize) -> String {
+    if count == 1 {
+        "1 fix".to_owned()
+    } else {
+        format!("{count} fixes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint_json(json: &str) -> LintReport {
+        lint(&Graph::from_json(json).expect("parse"), Path::new("."))
+    }
+
+    fn rules(report: &LintReport) -> Vec<&'static str> {
+        report.findings.iter().map(|f| f.rule).collect()
+    }
+
+    #[test]
+    fn hello_lints_clean() {
+        let hello = include_str!("../../../docs/examples/hello.json");
+        let report = lint_json(hello);
+        assert_eq!(report.exit_code(), 0, "{report:?}");
+    }
+
+    #[test]
+    fn duplicate_branch_key_is_an_error_with_a_fix() {
+        let report = lint_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"B","key":"1","target":"b"},
+                    {"label":"C","key":"1","target":"c"}
+                ]}},"content":[]},
+                {"id":"b","content":[{"kind":"text","body":"b"}]},
+                {"id":"c","content":[{"kind":"text","body":"c"}]}
+            ]}"#,
+        );
+        assert_eq!(report.exit_code(), 2);
+        let finding = &report.findings[0];
+        assert_eq!(finding.rule, "unique-branch-keys");
+        assert_eq!(finding.suggestion, "change option \"C\"'s key to \"2\"");
+        assert_eq!(
+            finding.fix,
+            Some(Op::SetAnswerKey {
+                id: "a".into(),
+                index: 1,
+                key: Some("2".into())
+            })
+        );
+    }
+
+    #[test]
+    fn each_repeated_key_is_offered_a_different_free_one() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"B","key":"1","target":"b"},
+                    {"label":"C","key":"1","target":"b"},
+                    {"label":"D","key":"2","target":"b"},
+                    {"label":"E","key":"1","target":"b"}
+                ]}},"content":[{"kind":"text","body":"pick"}]},
+                {"id":"b","terminal":true,"content":[{"kind":"text","body":"b"}]}
+            ]}"#,
+        )
+        .expect("parse");
+        let (fixed, applied) = apply_fixes(&graph, &lint(&graph, Path::new(".")));
+        assert_eq!(applied, 2);
+        let keys: Vec<_> = fixed.nodes[0]
+            .branch_point()
+            .expect("still a branch point")
+            .options
+            .iter()
+            .map(|o| o.key.as_deref())
+            .collect();
+        assert_eq!(keys, [Some("1"), Some("3"), Some("2"), Some("4")]);
+    }
+
+    #[test]
+    fn only_trailing_empty_blocks_are_fixable() {
+        let report = lint_json(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"text","body":" "},
+                {"kind":"text","body":"real"},
+                {"kind":"text","body":""},
+                {"kind":"list","items":[]}
+            ]}]}"#,
+        );
+        let fixes: Vec<_> = report.fixes().cloned().collect();
+        assert_eq!(
+            fixes,
+            vec![
+                Op::DeleteBlock {
+                    node: "a".into(),
+                    path: vec![3]
+                },
+                Op::DeleteBlock {
+                    node: "a".into(),
+                    path: vec![2]
+                },
+            ]
+        );
+        assert_eq!(
+            rules(&report)
+                .iter()
+                .filter(|r| **r == "empty-block")
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn fixes_apply_cleanly_and_leave_a_clean_deck() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"B","key":"1","target":"b"},
+                    {"label":"C","key":"1","target":"c"}
+                ]}},"content":[{"kind":"text","body":"pick"},{"kind":"text","body":""}]},
+                {"id":"b","terminal":true,"content":[{"kind":"text","body":"b"}]},
+                {"id":"c","content":[{"kind":"text","body":"c"}]}
+            ]}"#,
+        )
+        .expect("parse");
+        let report = lint(&graph, Path::new("."));
+        let (fixed, applied) = apply_fixes(&graph, &report);
+        assert_eq!(applied, 2);
+        assert_eq!(lint(&fixed, Path::new(".")).exit_code(), 0);
+    }
+
+    #[test]
+    fn missing_local_image_is_flagged_but_urls_are_not() {
+        let report = lint_json(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"image","src":"no-such-file.png"},
+                {"kind":"image","src":"https://example.com/x.png"}
+            ]}]}"#,
+        );
+        let missing: Vec<_> = report
+            .findings
+            .iter()
+            .filter(|f| f.rule == "missing-asset")
+            .collect();
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].message.contains("no-such-file.png"));
+    }
+
+    #[test]
+    fn missing_local_video_poster_is_flagged() {
+        let report = lint_json(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"video","src":"demo.mp4","poster":"no-such-poster.png"},
+                {"kind":"video","src":"demo.mp4"}
+            ]}]}"#,
+        );
+        let missing: Vec<_> = report
+            .findings
+            .iter()
+            .filter(|f| f.rule == "missing-asset")
+            .collect();
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].message.contains("no-such-poster.png"));
+    }
+
+    #[test]
+    fn repeated_slide_content_is_flagged_once() {
+        let report = lint_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[{"kind":"text","body":"Same  thing"}]},
+                {"id":"b","content":[{"kind":"text","body":"same thing"}]}
+            ]}"#,
+        );
+        let dupes: Vec<_> = report
+            .findings
+            .iter()
+            .filter(|f| f.rule == "near-duplicate-node")
+            .collect();
+        assert_eq!(dupes.len(), 1);
+        assert_eq!(dupes[0].node.as_deref(), Some("b"));
+    }
+}
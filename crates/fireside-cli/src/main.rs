@@ -11,14 +11,29 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use fireside_core::{CoreError, Graph};
-use fireside_engine::{Severity, validate};
+use fireside_engine::load::LoadOptions;
+use fireside_engine::{EndBehavior, Severity, validate};
 
 mod art;
+mod background;
+mod clipboard;
+mod control;
+mod convert;
+mod dirs;
 mod edit;
+mod fmt;
+mod graph;
 mod import;
+mod lint;
+mod merge;
 mod new;
+mod outline;
+mod output;
+mod print;
+mod repair;
+mod replace;
 mod report;
 mod resume;
 mod session;
@@ -44,18 +59,122 @@ struct Cli {
     /// Path to a deck (.fireside.json) — shorthand for `fireside present <file>`.
     file: Option<PathBuf>,
 
+    #[command(flatten)]
+    flags: PresentFlags,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// The flags `fireside <file>` and `fireside present <file>` share: how
+/// the presenter opens and runs, and what it reports on quit. `Default` is
+/// the unflagged presenter.
+#[derive(Debug, Default, Args)]
+struct PresentFlags {
     /// Start from the beginning, ignoring any saved resume position for
     /// this deck.
     #[arg(long)]
     restart: bool,
 
+    /// Open on this slide — its id, or its number (1 is the first) —
+    /// instead of the first slide or a saved resume position.
+    #[arg(long, value_name = "ID|N", conflicts_with = "path")]
+    start: Option<String>,
+
     /// Start already in fullscreen view (equivalent to pressing `f` once
     /// the presentation opens) — for dragging straight to a projector.
     #[arg(long)]
     fullscreen: bool,
 
-    #[command(subcommand)]
-    command: Option<Command>,
+    /// Present without motion — no fades, no blinking, no redraws on a
+    /// timer — for a slow SSH link or a terminal recording.
+    #[arg(long)]
+    no_animations: bool,
+
+    /// Colors for a dark or light terminal; `auto` (the default) asks the
+    /// terminal which it is.
+    #[arg(long, value_enum, default_value_t)]
+    theme: ThemeChoice,
+
+    /// Ring the terminal bell on arriving at a slide that asks for a
+    /// choice — a cue to look up without watching the screen.
+    #[arg(long)]
+    notify_on_branch: bool,
+
+    /// Make an option's number, key, or a click only select it, so a stray
+    /// keypress can't choose; Enter takes the selected option.
+    #[arg(long)]
+    confirm_branch_choice: bool,
+
+    /// Open with a path someone else walked already taken — the code
+    /// printed when they quit. Takes precedence over resume.
+    #[arg(long, value_name = "CODE")]
+    path: Option<String>,
+
+    /// How the header shows progress; `P` cycles it while presenting.
+    #[arg(long, value_enum, default_value_t)]
+    progress: Progress,
+
+    /// Cap the slide content at COLS columns, centered — a readable
+    /// measure on an ultra-wide terminal.
+    #[arg(long, value_name = "COLS", value_parser = clap::value_parser!(u16).range(20..))]
+    max_width: Option<u16>,
+
+    /// Minutes the side countdown runs when `T` starts it (default 10).
+    #[arg(long, value_name = "MINUTES")]
+    countdown: Option<u64>,
+
+    /// Show at most N stops in the route an ending traces (default 8);
+    /// a longer walk keeps its start, its choices, and its last stops.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(3..))]
+    route_stops: Option<u64>,
+
+    /// Wrap around at the ends: next on a last slide returns to the first,
+    /// and back on the first goes to the last.
+    #[arg(long)]
+    wrap: bool,
+
+    /// Loop the deck: `restart` wraps around like `--wrap`, `bounce`
+    /// walks back to the first slide and forward again, `hold` stays on
+    /// the last slide.
+    #[arg(
+        long = "loop",
+        value_enum,
+        value_name = "BEHAVIOR",
+        conflicts_with = "wrap"
+    )]
+    end: Option<Loop>,
+
+    /// Play unattended: each slide stays up for its own `duration`, or
+    /// SECS (default 10), then moves on, starting over at the end.
+    #[arg(
+        long,
+        value_name = "SECS",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "10"
+    )]
+    auto: Option<u64>,
+
+    /// What `--auto` does on a slide that asks for a choice.
+    #[arg(long, value_enum, default_value_t, requires = "auto")]
+    auto_branch: AutoBranch,
+
+    /// Accept remote-control commands (`next`, `back`, `goto N`,
+    /// `choose KEY`, one per line) on a loopback HOST:PORT or a Unix
+    /// socket path. No authentication — local use only.
+    #[arg(long, value_name = "ADDR")]
+    control: Option<String>,
+
+    /// On quit, list the time spent on each slide, flagging any that ran
+    /// past its own `duration`.
+    #[arg(long)]
+    rehearse: bool,
+
+    /// On quit, say how many of the deck's branch options were taken,
+    /// and list the ones that weren't.
+    #[arg(long)]
+    coverage: bool,
 }
 
 #[derive(Debug, Subcommand)]
@@ -65,16 +184,23 @@ enum Command {
         /// Path to the deck file.
         file: PathBuf,
 
-        /// Start from the beginning, ignoring any saved resume position for
-        /// this deck.
-        #[arg(long)]
-        restart: bool,
+        #[command(flatten)]
+        flags: PresentFlags,
 
-        /// Start already in fullscreen view (equivalent to pressing `f`
-        /// once the presentation opens) — for dragging straight to a
-        /// projector.
-        #[arg(long)]
-        fullscreen: bool,
+        /// Print slide N (1 is the first), or every slide with `all`, as
+        /// plain text to stdout instead of opening the presenter.
+        #[arg(long, value_name = "N|all", conflicts_with = "start")]
+        print: Option<print::PrintTarget>,
+
+        /// With `--print all`, start at this slide: its id, or its number
+        /// (1 is the first).
+        #[arg(long, value_name = "ID|N", requires = "print")]
+        from: Option<String>,
+
+        /// With `--print all`, stop after this slide: its id, or its
+        /// number.
+        #[arg(long, value_name = "ID|N", requires = "print")]
+        to: Option<String>,
     },
 
     /// Follow a presenter from a second screen: shows the current slide's
@@ -93,6 +219,154 @@ enum Command {
         /// Keep checking the file and re-report on every save.
         #[arg(long)]
         watch: bool,
+
+        /// The least severe diagnostic that fails the check. Defaults to
+        /// `error`, so warnings and notes alone still exit 0.
+        #[arg(long, value_enum, value_name = "SEVERITY")]
+        fail_on: Option<FailOn>,
+
+        /// Fail on warnings too — shorthand for `--fail-on warning` — and
+        /// on any property the protocol doesn't define, for gating CI on a
+        /// clean deck.
+        #[arg(long, conflicts_with = "fail_on")]
+        strict: bool,
+
+        /// Report every image without alt text that isn't marked
+        /// `decorative` as an error, for decks that must be accessible.
+        #[arg(long)]
+        require_alt: bool,
+
+        /// How to print the report: `human` for the terminal (the default)
+        /// or `markdown` for CI summaries and docs pipelines.
+        #[arg(long, value_enum, default_value_t, conflicts_with = "watch")]
+        format: ReportFormat,
+
+        /// Print only the one-line summary — slide and diagnostic counts
+        /// and PASS or FAIL — for large decks and CI logs.
+        #[arg(long, conflicts_with_all = ["watch", "format"])]
+        quiet: bool,
+    },
+
+    /// Run every non-fatal check too, each with a suggested fix. Exits 0
+    /// when clean, 1 on warnings, 2 on errors.
+    Lint {
+        /// Path to the deck file.
+        file: PathBuf,
+
+        /// Apply the safe, mechanical fixes (rekey duplicate branch keys,
+        /// drop empty blocks at the end of a slide) and save the deck.
+        #[arg(long)]
+        fix: bool,
+
+        /// With --fix: do everything but write — print what would change
+        /// and leave the disk alone.
+        #[arg(long, requires = "fix")]
+        dry_run: bool,
+    },
+
+    /// Rewrite a deck the way Fireside saves one — fields in protocol
+    /// order, two-space indents — so hand edits don't leave noisy diffs.
+    Fmt {
+        /// Path to the deck file.
+        file: PathBuf,
+
+        /// Don't write: print what would change and exit 1 if anything
+        /// would, for gating CI.
+        #[arg(long)]
+        check: bool,
+
+        /// Do everything but write: print what would change and leave the
+        /// disk alone.
+        #[arg(long, conflicts_with = "check")]
+        dry_run: bool,
+
+        /// Also give every branch answer a unique shortcut key: 1–9, then
+        /// the letters the presenter doesn't reserve, in order.
+        #[arg(long)]
+        assign_keys: bool,
+
+        /// With --assign-keys: keep the keys answers already have, and
+        /// only fill in the missing, reserved, or duplicated ones.
+        #[arg(long, requires = "assign_keys")]
+        preserve_keys: bool,
+    },
+
+    /// Replace a word or phrase on every slide and save the deck in place.
+    /// Exits 1, leaving the file alone, when nothing matches.
+    Replace {
+        /// Path to the deck file.
+        file: PathBuf,
+
+        /// The text to find.
+        find: String,
+
+        /// What to put in its place.
+        replace: String,
+
+        /// Match regardless of case: `acme` also finds `Acme` and `ACME`.
+        #[arg(long)]
+        ignore_case: bool,
+
+        /// Do everything but write: print what would change and leave the
+        /// disk alone.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Fix a deck's mechanical breaks — missing or repeated slide ids,
+    /// links to slides that don't exist, empty blocks ending a slide —
+    /// and list every change made.
+    Repair {
+        /// Path to the deck file.
+        file: PathBuf,
+
+        /// Save the repaired deck here instead of over the original.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Do everything but write: print what would change and leave the
+        /// disk alone.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Rewrite a deck as JSON or YAML, by each path's extension:
+    /// `.yaml` or `.yml` is YAML, anything else JSON.
+    Convert {
+        /// The deck to read.
+        input: PathBuf,
+
+        /// Path for the converted deck. Must not exist yet.
+        output: PathBuf,
+
+        /// Do everything but write: check the deck converts and leave the
+        /// disk alone.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Append one deck to another and write the result as a new deck.
+    /// Slides whose ids collide are renamed, and their links follow.
+    Merge {
+        /// The deck that comes first; its title and settings are kept.
+        first: PathBuf,
+
+        /// The deck appended after it.
+        second: PathBuf,
+
+        /// Path for the merged deck. Must not exist yet.
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Lead the first deck's last slide into the second deck's first,
+        /// so presenting flows straight on.
+        #[arg(long)]
+        link: bool,
+
+        /// Do everything but write: print what would change and leave the
+        /// disk alone.
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Create a starter deck you can present immediately. Omit the name to
@@ -134,11 +408,35 @@ enum Command {
         mode: ArtMode,
     },
 
+    /// Show every move out of every slide — or, with `--dot`, the whole
+    /// deck as a Graphviz graph to pipe into `dot -Tsvg`.
+    Graph {
+        /// Path to the deck file.
+        file: PathBuf,
+
+        /// Print Graphviz DOT instead of the plain adjacency list.
+        #[arg(long)]
+        dot: bool,
+    },
+
+    /// Print a deck's table of contents: sections, numbered slides, and
+    /// the headings on each, in the order they're presented.
+    Outline {
+        /// Path to the deck file.
+        file: PathBuf,
+    },
+
     /// Open a deck in the full-screen authoring studio (spec 013). Omit an
     /// existing file to be offered a new one, reusing `new`'s templates.
     Edit {
         /// Path to the deck file.
         file: PathBuf,
+
+        /// How new and duplicated slides are named: from their title
+        /// (`intro-to-rust`), by position (`slide-4`), or as a short hash
+        /// (`3f9a0c1e`).
+        #[arg(long, value_enum, default_value_t = IdStyle::Slug)]
+        ids: IdStyle,
     },
 }
 
@@ -190,6 +488,159 @@ enum ArtCharset {
     Slight,
 }
 
+/// `fireside present --theme`: one of [`fireside_tui::Theme`], or `auto`
+/// to ask the terminal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum ThemeChoice {
+    /// Dark when the terminal's background is, light when it's light —
+    /// dark when it can't tell.
+    #[default]
+    Auto,
+    /// For a dark background.
+    Dark,
+    /// For a light background.
+    Light,
+}
+
+impl ThemeChoice {
+    /// The theme to draw with, detecting it for `auto`.
+    fn resolve(self) -> fireside_tui::Theme {
+        match self {
+            Self::Auto => background::detect_theme(),
+            Self::Dark => fireside_tui::Theme::Dark,
+            Self::Light => fireside_tui::Theme::Light,
+        }
+    }
+}
+
+/// `fireside present --progress`: the CLI face of
+/// [`fireside_tui::ProgressStyle`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum Progress {
+    /// `3/12 seen` — the default.
+    #[default]
+    Count,
+    /// A filled bar of slides seen.
+    Bar,
+    /// One dot per slide.
+    Dots,
+    /// A bar for the stretch of path since the last choice.
+    Path,
+}
+
+impl From<Progress> for fireside_tui::ProgressStyle {
+    fn from(progress: Progress) -> Self {
+        match progress {
+            Progress::Count => Self::Count,
+            Progress::Bar => Self::Bar,
+            Progress::Dots => Self::Dots,
+            Progress::Path => Self::Path,
+        }
+    }
+}
+
+/// `fireside present --auto-branch`: the CLI face of
+/// [`fireside_tui::AutoBranch`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum AutoBranch {
+    /// Wait for someone to choose — the default.
+    #[default]
+    Pause,
+    /// Take the first option once the slide's time is up.
+    First,
+}
+
+impl From<AutoBranch> for fireside_tui::AutoBranch {
+    fn from(branch: AutoBranch) -> Self {
+        match branch {
+            AutoBranch::Pause => Self::Pause,
+            AutoBranch::First => Self::First,
+        }
+    }
+}
+
+/// `fireside present --loop`: the looping faces of [`EndBehavior`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum Loop {
+    /// Back to the first slide after the last.
+    Restart,
+    /// Back through the slides seen, then forward again.
+    Bounce,
+    /// Stay on the last slide.
+    Hold,
+}
+
+impl From<Loop> for EndBehavior {
+    fn from(end: Loop) -> Self {
+        match end {
+            Loop::Restart => Self::Restart,
+            Loop::Bounce => Self::Bounce,
+            Loop::Hold => Self::Hold,
+        }
+    }
+}
+
+/// `fireside validate --fail-on`: the least severe
+/// [`fireside_engine::Severity`] that fails the check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum FailOn {
+    /// Only errors fail — the default.
+    Error,
+    /// Warnings and errors fail.
+    Warning,
+    /// Any diagnostic at all fails.
+    Info,
+}
+
+impl From<FailOn> for Severity {
+    fn from(fail_on: FailOn) -> Self {
+        match fail_on {
+            FailOn::Error => Self::Error,
+            FailOn::Warning => Self::Warning,
+            FailOn::Info => Self::Info,
+        }
+    }
+}
+
+/// `fireside validate --format`: how the report is printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum ReportFormat {
+    /// Icons and a summary line, for a terminal — the default.
+    #[default]
+    Human,
+    /// A counts table and a checklist per severity, for CI summaries.
+    Markdown,
+}
+
+/// `fireside edit --ids`: the CLI face of
+/// [`fireside_engine::authoring::IdStrategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum IdStyle {
+    /// From the slide's title — the default.
+    Slug,
+    /// `slide-N`, by position.
+    Sequential,
+    /// Eight hex digits.
+    Short,
+}
+
+impl From<IdStyle> for fireside_engine::authoring::IdStrategy {
+    fn from(style: IdStyle) -> Self {
+        match style {
+            IdStyle::Slug => Self::Slug,
+            IdStyle::Sequential => Self::Sequential,
+            IdStyle::Short => Self::Short,
+        }
+    }
+}
+
 /// The shape of deck `fireside new` scaffolds. Each demonstrates one
 /// traversal pattern so the author has a working example to edit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -206,17 +657,102 @@ enum Template {
 fn main() -> Result<()> {
     let cli = Cli::parse();
     match (cli.file, cli.command) {
-        (Some(file), _) => present(&file, cli.restart, cli.fullscreen),
+        (Some(file), _) => present(&file, &cli.flags),
+        (
+            None,
+            Some(Command::Present {
+                file,
+                print: Some(target),
+                from,
+                to,
+                ..
+            }),
+        ) => print::print_file(
+            &file,
+            target,
+            print::Bounds {
+                from: from.as_deref(),
+                to: to.as_deref(),
+            },
+        ),
         (
             None,
             Some(Command::Present {
                 file,
-                restart,
-                fullscreen,
+                flags,
+                print: None,
+                ..
             }),
-        ) => present(&file, restart, fullscreen),
+        ) => present(&file, &flags),
         (None, Some(Command::Notes { file })) => notes(&file),
-        (None, Some(Command::Validate { file, watch })) => report::validate_file(&file, watch),
+        (
+            None,
+            Some(Command::Validate {
+                file,
+                watch,
+                fail_on,
+                strict,
+                require_alt,
+                format,
+                quiet,
+            }),
+        ) => {
+            let threshold = match (strict, fail_on) {
+                (true, _) => Severity::Warning,
+                (false, fail_on) => fail_on.map_or(Severity::Error, Severity::from),
+            };
+            let options = LoadOptions {
+                deny_unknown_fields: strict,
+            };
+            report::validate_file(&file, watch, threshold, options, require_alt, format, quiet)
+        }
+        (None, Some(Command::Lint { file, fix, dry_run })) => lint::lint_file(&file, fix, dry_run),
+        (
+            None,
+            Some(Command::Fmt {
+                file,
+                check,
+                dry_run,
+                assign_keys,
+                preserve_keys,
+            }),
+        ) => fmt::format_file(&file, check, dry_run, assign_keys, preserve_keys),
+        (
+            None,
+            Some(Command::Replace {
+                file,
+                find,
+                replace,
+                ignore_case,
+                dry_run,
+            }),
+        ) => replace::replace_in_deck(&file, &find, &replace, ignore_case, dry_run),
+        (
+            None,
+            Some(Command::Convert {
+                input,
+                output,
+                dry_run,
+            }),
+        ) => convert::convert_file(&input, &output, dry_run),
+        (
+            None,
+            Some(Command::Repair {
+                file,
+                output,
+                dry_run,
+            }),
+        ) => repair::repair_file(&file, output.as_deref(), dry_run),
+        (
+            None,
+            Some(Command::Merge {
+                first,
+                second,
+                output,
+                link,
+                dry_run,
+            }),
+        ) => merge::merge_decks(&first, &second, &output, link, dry_run),
         (
             None,
             Some(Command::New {
@@ -226,12 +762,14 @@ fn main() -> Result<()> {
                 banner,
             }),
         ) => match new::new_deck(name, template, author, banner)? {
-            Some(path) => present(&path, false, false),
+            Some(path) => present(&path, &PresentFlags::default()),
             None => Ok(()),
         },
         (None, Some(Command::Demo)) => demo(),
         (None, Some(Command::Import { input, output })) => import_file(&input, output.as_deref()),
-        (None, Some(Command::Edit { file })) => edit::edit_deck(&file),
+        (None, Some(Command::Graph { file, dot })) => graph::graph_file(&file, dot),
+        (None, Some(Command::Outline { file })) => outline::outline_file(&file),
+        (None, Some(Command::Edit { file, ids })) => edit::edit_deck(&file, ids.into()),
         (None, Some(Command::Art { mode })) => match mode {
             ArtMode::Text { phrase } => art::art_text(&phrase),
             ArtMode::Image {
@@ -248,15 +786,23 @@ fn main() -> Result<()> {
             println!("  fireside demo              see what a deck can do");
             println!("  fireside <file>            present a deck");
             println!("  fireside <file> --restart  present from the start, ignoring resume");
+            println!("  fireside <file> --start <id|n>  present, opening on that slide");
             println!("  fireside <file> --fullscreen  present, starting in fullscreen view");
             println!("  fireside notes <file>      follow a presenter from a second screen");
             println!("  fireside validate <file>   check a deck for problems");
+            println!("  fireside lint <file>       every check, with suggested fixes");
+            println!("  fireside replace <file> <find> <replace>  fix a term on every slide");
+            println!("  fireside merge <a> <b> -o <out>  append one deck to another");
+            println!("  fireside repair <file>     fix broken ids and links in a deck");
+            println!("  fireside convert <in> <out>  rewrite a deck as JSON or YAML");
             println!("  fireside new               create a deck (asks a few questions)");
             println!("  fireside new <name>        create a starter deck instantly");
             println!("  fireside import <file.md>  compile a Markdown talk into a deck");
             println!("  fireside art text <phrase> generate a text banner to paste in");
             println!("  fireside art image <file>  convert a picture to ASCII art");
             println!("  fireside edit <file>       open a deck in the full-screen editor");
+            println!("  fireside graph <file> --dot  export the deck's paths for Graphviz");
+            println!("  fireside outline <file>    print the deck's table of contents");
             println!("\nTry: fireside demo");
             Ok(())
         }
@@ -267,6 +813,20 @@ fn main() -> Result<()> {
 /// gets one plain-language line with the fix, and a broken file prints the
 /// offending line with a caret — neither shows a raw anyhow/serde chain.
 fn load(path: &Path) -> Result<Graph> {
+    load_with_text(path).map(|(graph, _)| graph)
+}
+
+/// Fills in `graph`'s `embed` blocks from the files they name, which are
+/// relative to the deck at `path`.
+fn resolve_embeds(graph: &mut Graph, path: &Path) -> Result<(), fireside_engine::EngineError> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    fireside_engine::embed::resolve_embeds(graph, |source| {
+        std::fs::read_to_string(dir.join(source))
+    })
+}
+
+/// [`load`], keeping the text the deck was parsed from.
+fn load_with_text(path: &Path) -> Result<(Graph, String)> {
     let text = match std::fs::read_to_string(path) {
         Ok(text) => text,
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -282,7 +842,11 @@ fn load(path: &Path) -> Result<Graph> {
         }
     };
     match Graph::from_json(&text) {
-        Ok(graph) => Ok(graph),
+        Ok(mut graph) => {
+            resolve_embeds(&mut graph, path)
+                .with_context(|| format!("could not load {}", path.display()))?;
+            Ok((graph, text))
+        }
         Err(CoreError::Parse(err)) => {
             if is_markdown_path(path) {
                 eprintln!(
@@ -342,7 +906,70 @@ fn format_present_summary(seen: usize, total: usize, elapsed: Duration) -> Strin
     )
 }
 
-fn present(path: &Path, restart: bool, fullscreen: bool) -> Result<()> {
+/// Formats `--rehearse`'s report: a `Time per slide:` header, then one
+/// line per slide with its time, and its `duration` — with how far past
+/// it — when it sets one.
+#[must_use]
+fn format_slide_times(times: &[fireside_tui::SlideTime]) -> String {
+    let clock = |d: Duration| format!("{}:{:02}", d.as_secs() / 60, d.as_secs() % 60);
+    let width = times
+        .iter()
+        .map(|t| t.id.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut out = String::from("Time per slide:\n");
+    for time in times {
+        let budget = match (time.budget, time.overrun()) {
+            (Some(budget), Some(over)) => {
+                format!("  ({} planned, {} over)", clock(budget), clock(over))
+            }
+            (Some(budget), None) => format!("  ({} planned)", clock(budget)),
+            (None, _) => String::new(),
+        };
+        out.push_str(&format!(
+            "  {:<width$}  {:>5}{budget}\n",
+            time.id,
+            clock(time.spent)
+        ));
+    }
+    out
+}
+
+/// Formats `--coverage`'s report: `Branch coverage: visited N of M
+/// branch options.`, then each option never taken as `<slide> → [key]
+/// label`.
+#[must_use]
+fn format_branch_coverage(coverage: &fireside_engine::BranchCoverage) -> String {
+    let mut out = format!("Branch coverage: {coverage}.\n");
+    let mut untaken = coverage.untaken().peekable();
+    if untaken.peek().is_some() {
+        out.push_str("Not taken:\n");
+    }
+    for (node, option) in untaken {
+        out.push_str(&format!("  {node} → [{}] {}\n", option.key, option.label));
+    }
+    out
+}
+
+/// `--wrap` and `--loop` as the session takes them: `--wrap` is
+/// `--loop restart`, and without either the deck stops at its ends.
+fn end_behavior(wrap: bool, end: Option<Loop>) -> EndBehavior {
+    match end {
+        Some(end) => end.into(),
+        None if wrap => EndBehavior::Restart,
+        None => EndBehavior::Stop,
+    }
+}
+
+/// `--auto[=SECS]` and `--auto-branch` as the presenter takes them.
+fn auto_advance(secs: Option<u64>, branch: AutoBranch) -> Option<fireside_tui::AutoAdvance> {
+    secs.map(|secs| fireside_tui::AutoAdvance {
+        dwell: Duration::from_secs(secs.max(1)),
+        at_branch: branch.into(),
+    })
+}
+
+fn present(path: &Path, flags: &PresentFlags) -> Result<()> {
     let graph = load(path)?;
     let diags = validate(&graph);
     let errors: Vec<_> = diags
@@ -357,6 +984,16 @@ fn present(path: &Path, restart: bool, fullscreen: bool) -> Result<()> {
         eprintln!("\nFix the above, or run `fireside validate` for the full report.");
         std::process::exit(1);
     }
+    if let Some(code) = &flags.path {
+        check_path_code(&graph, code);
+    }
+    let start = flags
+        .start
+        .as_deref()
+        .map(|bound| {
+            fireside_engine::range::resolve(&graph, bound).map(|i| graph.nodes[i].id.clone())
+        })
+        .transpose()?;
     let watcher = RefCell::new(watch::Watcher::new(path));
 
     // Resume-from-path (spec 007, P1-1): a resume position is host-local
@@ -366,7 +1003,7 @@ fn present(path: &Path, restart: bool, fullscreen: bool) -> Result<()> {
     // means editing the file no longer orphans the saved position.
     let key = resume::resume_key(path);
     let mut store = resume::ResumeStore::load();
-    let initial_node = store.resolve_initial_node(key.as_deref(), restart);
+    let initial_node = store.resolve_initial_node(key.as_deref(), flags.restart);
     let graph_for_resume = graph.clone();
 
     // Live session state (spec 012): a separate, per-deck heartbeat file —
@@ -375,11 +1012,23 @@ fn present(path: &Path, restart: bool, fullscreen: bool) -> Result<()> {
     let session_path = key.as_deref().and_then(session::session_path_for);
     let deck_path_display = path.display().to_string();
 
+    let control = flags
+        .control
+        .as_deref()
+        .map(control::Control::listen)
+        .transpose()?;
+    let mut next_command = || control.as_ref().and_then(control::Control::try_next);
+    let remote: Option<fireside_tui::RemoteSource<'_>> = if control.is_some() {
+        Some(&mut next_command)
+    } else {
+        None
+    };
+
     let result = fireside_tui::present_authoring(
         graph,
         &mut || watcher.borrow_mut().poll(),
         &mut |graph| watcher.borrow_mut().write_back(graph),
-        initial_node.as_deref(),
+        &mut clipboard::copy,
         &mut |node_id| {
             let Some(key) = &key else { return };
             let terminal = graph_for_resume
@@ -397,19 +1046,78 @@ fn present(path: &Path, restart: bool, fullscreen: bool) -> Result<()> {
             };
             session::write(session_path, &deck_path_display, &tick);
         },
-        fullscreen,
+        remote,
+        fireside_tui::PresentOptions {
+            start: start.as_deref(),
+            initial_node: initial_node.as_deref(),
+            path: flags.path.as_deref(),
+            fullscreen: flags.fullscreen,
+            animations: !flags.no_animations,
+            theme: flags.theme.resolve(),
+            notify_on_branch: flags.notify_on_branch,
+            confirm_branch_choice: flags.confirm_branch_choice,
+            progress: flags.progress.into(),
+            max_width: flags.max_width,
+            countdown: flags
+                .countdown
+                .map_or(fireside_tui::DEFAULT_COUNTDOWN, |minutes| {
+                    Duration::from_secs(minutes.saturating_mul(60))
+                }),
+            route_stops: flags
+                .route_stops
+                .map_or(fireside_tui::DEFAULT_ROUTE_STOPS, |n| {
+                    usize::try_from(n).unwrap_or(usize::MAX)
+                }),
+            end: end_behavior(flags.wrap, flags.end),
+            auto: auto_advance(flags.auto, flags.auto_branch),
+        },
     );
     if let Some(session_path) = &session_path {
         session::delete(session_path);
     }
+    // Closed before `exit_on_not_a_tty`, which exits without running drops
+    // and would leave a Unix socket's file behind to refuse the next run.
+    drop(control);
     let summary = exit_on_not_a_tty(result)?;
     println!(
         "{}",
         format_present_summary(summary.seen, summary.total, summary.elapsed)
     );
+    println!(
+        "Replay this path: fireside present {} --path {}",
+        path.display(),
+        summary.path
+    );
+    if flags.rehearse {
+        print!("{}", format_slide_times(&summary.slide_times));
+    }
+    if flags.coverage {
+        print!("{}", format_branch_coverage(&summary.coverage));
+    }
     Ok(())
 }
 
+/// Checks a `--path` code against the deck before the presenter opens: a
+/// code this version can't read, or one whose slides are all gone, exits
+/// with one plain line; slides the deck no longer has are named on stderr
+/// and the rest of the path is still replayed.
+fn check_path_code(graph: &Graph, code: &str) {
+    let decoded = fireside_engine::Session::new(graph.clone())
+        .map_err(anyhow::Error::from)
+        .and_then(|mut session| Ok(session.decode_path(code)?));
+    match decoded {
+        Ok(skipped) => {
+            for id in skipped {
+                eprintln!("⚠ skipping \"{id}\" from --path — this deck has no slide with that id");
+            }
+        }
+        Err(err) => {
+            eprintln!("Can't replay --path: {err}.");
+            std::process::exit(1);
+        }
+    }
+}
+
 /// `fireside notes <deck>`: a read-only follower on a second screen (spec
 /// 012) — loads and watches the same deck the presenter is showing, polls
 /// its live session-state file, and never writes anything.
@@ -446,7 +1154,7 @@ fn exit_on_not_a_tty<T>(result: Result<T, fireside_tui::TuiError>) -> Result<T>
 
 fn demo() -> Result<()> {
     let graph = Graph::from_json(DEMO_DECK).context("the built-in demo deck is broken")?;
-    let summary = exit_on_not_a_tty(fireside_tui::present(graph))?;
+    let summary = exit_on_not_a_tty(fireside_tui::present(graph, &mut clipboard::copy))?;
     println!(
         "{}",
         format_present_summary(summary.seen, summary.total, summary.elapsed)
@@ -502,19 +1210,11 @@ fn import_file(input: &Path, output: Option<&Path>) -> Result<()> {
 }
 
 /// Turns arbitrary text into a lowercase, hyphen-separated identifier safe
-/// for both filenames (`new_deck`) and node ids (`import`): lowercase,
-/// non-alphanumeric runs collapse to a single `-`, leading/trailing `-`
-/// trimmed.
+/// for both filenames (`new_deck`) and node ids (`import`) — the core's
+/// [`fireside_core::normalize_node_id`], or the empty string when the text
+/// has no letters or digits.
 pub(crate) fn slugify(text: &str) -> String {
-    text.trim()
-        .to_lowercase()
-        .chars()
-        .map(|c| if c.is_alphanumeric() { c } else { '-' })
-        .collect::<String>()
-        .split('-')
-        .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>()
-        .join("-")
+    fireside_core::normalize_node_id(text).unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -541,6 +1241,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_slide_times_flags_slides_that_ran_long() {
+        let time = |id: &str, spent: u64, budget: Option<u64>| fireside_tui::SlideTime {
+            id: id.into(),
+            spent: Duration::from_secs(spent),
+            budget: budget.map(Duration::from_secs),
+        };
+        assert_eq!(
+            format_slide_times(&[
+                time("intro", 45, None),
+                time("demo", 190, Some(120)),
+                time("wrap", 20, Some(60)),
+            ]),
+            "Time per slide:\n  \
+             intro   0:45\n  \
+             demo    3:10  (2:00 planned, 1:10 over)\n  \
+             wrap    0:20  (1:00 planned)\n"
+        );
+    }
+
+    #[test]
+    fn format_branch_coverage_lists_the_options_not_taken() {
+        let mut session = fireside_engine::Session::new(
+            Graph::from_json(
+                r#"{"nodes":[
+                    {"id":"ask","content":[],"traversal":{"branch-point":{"options":[
+                        {"label":"Deep","key":"d","target":"end"},
+                        {"label":"Skim","key":"s","target":"end"}
+                    ]}}},
+                    {"id":"end","content":[]}
+                ]}"#,
+            )
+            .expect("parse"),
+        )
+        .expect("non-empty");
+        session.choose(0);
+        assert_eq!(
+            format_branch_coverage(&session.branch_coverage()),
+            "Branch coverage: visited 1 of 2 branch options.\nNot taken:\n  ask → [s] Skim\n"
+        );
+        session.back();
+        session.choose(1);
+        assert_eq!(
+            format_branch_coverage(&session.branch_coverage()),
+            "Branch coverage: visited 2 of 2 branch options.\n"
+        );
+    }
+
     #[test]
     fn demo_deck_parses_and_validates_clean() {
         let graph = Graph::from_json(DEMO_DECK).expect("demo deck parses");
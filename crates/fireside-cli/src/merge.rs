@@ -0,0 +1,45 @@
+//! `fireside merge <first> <second> -o <out>`: append one deck to another —
+//! a Q&A section after a talk, a shared closing after every workshop — and
+//! write the result as a new deck.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use fireside_engine::merge::append;
+
+use crate::load;
+use crate::output::write_output;
+
+/// Appends `second` to `first` (see `fireside_engine::merge::append`) and
+/// writes the merged deck to `output`, reporting every slide of `second`
+/// that had to be renamed. Neither input is modified, and an existing
+/// `output` is never overwritten; with `dry_run` nothing is written at all.
+pub(crate) fn merge_decks(
+    first: &Path,
+    second: &Path,
+    output: &Path,
+    link: bool,
+    dry_run: bool,
+) -> Result<()> {
+    if output.exists() {
+        bail!("{} already exists — pick another name", output.display());
+    }
+    let mut graph = load(first)?;
+    let other = load(second)?;
+    let renames = append(&mut graph, other, link)
+        .with_context(|| format!("could not append {}", second.display()))?;
+
+    write_output(output, &graph, dry_run)?;
+
+    println!(
+        "{} {} into {} as {}.",
+        if dry_run { "Would merge" } else { "Merged" },
+        second.display(),
+        first.display(),
+        output.display()
+    );
+    for rename in &renames {
+        println!("  renamed \"{}\" to \"{}\"", rename.from, rename.to);
+    }
+    Ok(())
+}
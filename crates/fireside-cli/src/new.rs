@@ -92,6 +92,8 @@ fn add_title_banner(graph: &mut Graph, title: &str) -> bool {
         0,
         ContentBlock::AsciiArt {
             reveal: None,
+            id: None,
+            visible_if: None,
             art,
             alt: Some(title.to_owned()),
         },
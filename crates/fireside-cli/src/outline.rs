@@ -0,0 +1,63 @@
+//! `fireside outline <file>`: a deck's table of contents on stdout —
+//! sections, the slides in each by number and title, and the headings on
+//! every slide, indented as a tree in the order a presenter walks them.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::Result;
+use fireside_core::{Graph, OutlineKind};
+
+use crate::load;
+
+/// Loads the deck at `path` and prints its outline.
+pub(crate) fn outline_file(path: &Path) -> Result<()> {
+    let graph = load(path)?;
+    print!("{}", tree(&graph));
+    Ok(())
+}
+
+/// [`Graph::outline`] as text: two spaces per level, each slide led by
+/// the number `goto` takes and each heading by `·`.
+fn tree(graph: &Graph) -> String {
+    let mut out = String::new();
+    for entry in graph.outline() {
+        let indent = "  ".repeat(entry.depth);
+        let _ = match entry.kind {
+            OutlineKind::Section => writeln!(out, "{indent}{}", entry.label),
+            OutlineKind::Node => {
+                let hidden = if graph.nodes[entry.index].hidden {
+                    " (hidden)"
+                } else {
+                    ""
+                };
+                writeln!(out, "{indent}{}. {}{hidden}", entry.index + 1, entry.label)
+            }
+            OutlineKind::Heading => writeln!(out, "{indent}· {}", entry.label),
+        };
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_tree_numbers_slides_and_indents_sections_and_headings() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","section":"Intro","traversal":"c","content":[
+                    {"kind":"heading","level":1,"text":"Hello"},
+                    {"kind":"heading","level":2,"text":"Agenda"}]},
+                {"id":"b","hidden":true,"content":[]},
+                {"id":"c","title":"Wrap-up","content":[]}
+            ]}"#,
+        )
+        .expect("fixture parses");
+        assert_eq!(
+            tree(&graph),
+            "Intro\n  1. Hello\n    · Agenda\n3. Wrap-up\n2. b (hidden)\n"
+        );
+    }
+}
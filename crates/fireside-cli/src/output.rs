@@ -0,0 +1,90 @@
+//! Where every command that rewrites a deck — `lint --fix`, `replace`,
+//! `merge`, `fmt`, `repair` — saves it, so `--dry-run` means the same thing in each:
+//! do the whole job in memory, show what would change, write nothing.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use fireside_core::Graph;
+
+/// Saves `graph` to `path` the way Fireside writes every deck — canonical
+/// JSON, one trailing newline, replaced atomically. With `dry_run` the
+/// disk is left alone: an existing file gets a diff of the lines that
+/// would change, a new one a note of what would be created.
+///
+/// # Errors
+///
+/// Fails when the deck can't be serialized or, for a real write, saved.
+pub(crate) fn write_output(path: &Path, graph: &Graph, dry_run: bool) -> Result<()> {
+    let text = graph
+        .to_json_pretty()
+        .context("could not serialize the deck")?
+        + "\n";
+    if !dry_run {
+        return crate::edit::atomic_write(path, &text)
+            .with_context(|| format!("could not write {}", path.display()));
+    }
+    match std::fs::read_to_string(path) {
+        Ok(old) if old == text => println!("{} would be unchanged.", path.display()),
+        Ok(old) => print!("{}", diff(&old, &text)),
+        Err(_) => {
+            let slides = graph.nodes.len();
+            let noun = if slides == 1 { "slide" } else { "slides" };
+            println!("Would create {} with {slides} {noun}.", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// The lines `new` changes in `old`, as one hunk: the differing stretch
+/// between their common leading and trailing lines, `-` for what goes and
+/// `+` for what comes, under the line number it starts at.
+pub(crate) fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let head = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let tail = old[head..]
+        .iter()
+        .rev()
+        .zip(new[head..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut out = format!("@@ line {} @@\n", head + 1);
+    for line in &old[head..old.len() - tail] {
+        out.push_str(&format!("-{line}\n"));
+    }
+    for line in &new[head..new.len() - tail] {
+        out.push_str(&format!("+{line}\n"));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_diff_is_the_stretch_between_the_shared_ends() {
+        let old = "{\n  \"a\": 1,\n    \"b\": 2\n}\n";
+        let new = "{\n  \"a\": 1,\n  \"b\": 2\n}\n";
+        assert_eq!(diff(old, new), "@@ line 3 @@\n-    \"b\": 2\n+  \"b\": 2\n");
+        assert_eq!(diff("x\n", "x\ny\n"), "@@ line 2 @@\n+y\n");
+    }
+
+    #[test]
+    fn a_dry_run_leaves_the_file_alone() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let deck = temp.path().join("deck.fireside.json");
+        std::fs::write(&deck, "{}").expect("write fixture");
+        let graph = Graph::from_json(r#"{"nodes":[{"id":"a","content":[]}]}"#).expect("parse");
+
+        write_output(&deck, &graph, true).expect("dry run");
+        assert_eq!(std::fs::read_to_string(&deck).expect("read"), "{}");
+        write_output(&deck, &graph, false).expect("write");
+        assert!(
+            std::fs::read_to_string(&deck)
+                .expect("read")
+                .ends_with("}\n")
+        );
+    }
+}
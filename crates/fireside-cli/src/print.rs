@@ -0,0 +1,270 @@
+//! `fireside present <file> --print N|all`: a deck's slides as plain text on
+//! stdout, without ever entering the TUI — for `grep`-ing deck content,
+//! piping into other tools, or pasting into docs.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Result, bail};
+use fireside_core::{ContentBlock, DividerStyle, Graph, Node};
+use fireside_engine::range::node_range;
+
+use crate::load;
+
+/// The rule printed between slides by `--print all`.
+const SLIDE_RULE: &str = "════════════════════════════════════════════════════════════════════════";
+
+/// The rule a plain `divider` block prints as.
+const DIVIDER_RULE: &str = "────────────────────────────────────────";
+
+/// Which slides `--print` writes: one, by its 1-based position in the deck
+/// (the same numbering the presenter's goto prompt and the map use), or
+/// every one in deck order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrintTarget {
+    Slide(usize),
+    All,
+}
+
+impl FromStr for PrintTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+        match s.parse::<usize>() {
+            Ok(n) if n >= 1 => Ok(Self::Slide(n)),
+            _ => Err(format!(
+                "expected a slide number (1 is the first) or \"all\", got {s:?}"
+            )),
+        }
+    }
+}
+
+/// The ends of `--from`/`--to`: each a slide's id or 1-based number, both
+/// included — see [`node_range`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Bounds<'a> {
+    pub(crate) from: Option<&'a str>,
+    pub(crate) to: Option<&'a str>,
+}
+
+/// Loads the deck at `path` and prints the chosen slides to stdout.
+pub(crate) fn print_file(path: &Path, target: PrintTarget, bounds: Bounds) -> Result<()> {
+    let graph = load(path)?;
+    print!("{}", render(&graph, target, bounds)?);
+    Ok(())
+}
+
+/// The plain-text output `--print` writes for `target`, `all` narrowed to
+/// `bounds`.
+pub(crate) fn render(graph: &Graph, target: PrintTarget, bounds: Bounds) -> Result<String> {
+    match target {
+        PrintTarget::Slide(_) if bounds.from.is_some() || bounds.to.is_some() => {
+            bail!(
+                "--from and --to pick slides for \"--print all\" — \"--print N\" already picks one"
+            )
+        }
+        PrintTarget::Slide(n) => match graph.nodes.get(n - 1) {
+            Some(node) => Ok(node_text(node)),
+            None => bail!(
+                "there is no slide {n} — this deck has {} slide{}",
+                graph.nodes.len(),
+                if graph.nodes.len() == 1 { "" } else { "s" }
+            ),
+        },
+        PrintTarget::All => Ok(graph.nodes[node_range(graph, bounds.from, bounds.to)?]
+            .iter()
+            .map(node_text)
+            .collect::<Vec<_>>()
+            .join(&format!("\n{SLIDE_RULE}\n\n"))),
+    }
+}
+
+/// One slide as text: every block fully revealed, blank-line separated,
+/// followed by its branch choices if it has any. Speaker notes are left
+/// out — this is what the audience sees.
+fn node_text(node: &Node) -> String {
+    let mut parts: Vec<String> = node.content.iter().map(block_text).collect();
+    if let Some(bp) = node.branch_point() {
+        let mut menu = String::new();
+        if let Some(prompt) = &bp.prompt {
+            let _ = writeln!(menu, "{prompt}");
+        }
+        for (i, option) in bp.options.iter().enumerate() {
+            let key = option.key.clone().unwrap_or_else(|| (i + 1).to_string());
+            let _ = writeln!(menu, "  {key}) {} → {}", option.label, option.target);
+        }
+        parts.push(menu.trim_end().to_owned());
+    }
+    parts.retain(|part| !part.is_empty());
+    let mut out = parts.join("\n\n");
+    out.push('\n');
+    out
+}
+
+/// A divider as [`DIVIDER_RULE`]'s length of its style's line, with any
+/// label set into the middle.
+fn divider_text(style: Option<DividerStyle>, label: Option<&str>) -> String {
+    let glyph = match style.unwrap_or_default() {
+        DividerStyle::Solid => '─',
+        DividerStyle::Dashed => '╌',
+        DividerStyle::Dotted => '┈',
+        DividerStyle::Double => '═',
+    };
+    let rule = DIVIDER_RULE.chars().count();
+    let Some(label) = label.map(str::trim).filter(|l| !l.is_empty()) else {
+        return glyph.to_string().repeat(rule);
+    };
+    let sides = rule.saturating_sub(label.chars().count() + 2);
+    let left = glyph.to_string().repeat(sides / 2);
+    let right = glyph.to_string().repeat(sides - sides / 2);
+    format!("{left} {label} {right}")
+}
+
+fn block_text(block: &ContentBlock) -> String {
+    match block {
+        ContentBlock::Heading { level, text, .. } => {
+            format!("{} {text}", "#".repeat(usize::from(*level).clamp(1, 6)))
+        }
+        ContentBlock::Text { body, .. } => body.clone(),
+        ContentBlock::Code {
+            language, source, ..
+        } => format!(
+            "```{}\n{}\n```",
+            language.as_deref().unwrap_or_default(),
+            source.trim_end_matches('\n')
+        ),
+        ContentBlock::List { ordered, items, .. } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| match ordered {
+                Some(true) => format!("{}. {item}", i + 1),
+                _ => format!("- {item}"),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ContentBlock::Image {
+            src, alt, caption, ..
+        } => {
+            let mut out = format!("[image: {}]", alt.as_deref().unwrap_or(src));
+            if let Some(caption) = caption {
+                let _ = write!(out, "\n{caption}");
+            }
+            out
+        }
+        ContentBlock::Video { src, caption, .. } => {
+            let mut out = format!("[video: {src}]");
+            if let Some(caption) = caption {
+                let _ = write!(out, "\n{caption}");
+            }
+            out
+        }
+        ContentBlock::Embed {
+            resolved: Some(inner),
+            ..
+        } => block_text(inner),
+        ContentBlock::Embed { source, .. } => format!("[embed: {source}]"),
+        ContentBlock::Divider { style, label, .. } => divider_text(*style, label.as_deref()),
+        ContentBlock::Container { .. } | ContentBlock::Columns { .. } => block
+            .child_blocks()
+            .into_iter()
+            .flatten()
+            .map(block_text)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        ContentBlock::AsciiArt { art, .. } => art.trim_end_matches('\n').to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck() -> Graph {
+        Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[
+                    {"kind":"heading","level":2,"text":"Hello"},
+                    {"kind":"text","body":"Some **bold** words.","reveal":1},
+                    {"kind":"list","ordered":true,"items":["one","two"]},
+                    {"kind":"code","language":"rust","source":"fn main() {}\n"},
+                    {"kind":"image","src":"cat.png","alt":"A cat"},
+                    {"kind":"divider"}
+                ]},
+                {"id":"b","traversal":{"branch-point":{"prompt":"Where now?","options":[
+                    {"label":"Left","key":"l","target":"a"},
+                    {"label":"Right","target":"a"}
+                ]}},"content":[{"kind":"text","body":"Pick one"}]}
+            ]}"#,
+        )
+        .expect("fixture parses")
+    }
+
+    #[test]
+    fn a_slide_prints_every_block_fully_revealed() {
+        let text = render(&deck(), PrintTarget::Slide(1), Bounds::default()).expect("slide 1");
+        assert_eq!(
+            text,
+            format!(
+                "## Hello\n\nSome **bold** words.\n\n1. one\n2. two\n\n\
+                 ```rust\nfn main() {{}}\n```\n\n[image: A cat]\n\n{DIVIDER_RULE}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn branch_choices_follow_the_content() {
+        let text = render(&deck(), PrintTarget::Slide(2), Bounds::default()).expect("slide 2");
+        assert_eq!(
+            text,
+            "Pick one\n\nWhere now?\n  l) Left → a\n  2) Right → a\n"
+        );
+    }
+
+    #[test]
+    fn all_separates_slides_with_a_rule() {
+        let text = render(&deck(), PrintTarget::All, Bounds::default()).expect("all");
+        assert_eq!(text.matches(SLIDE_RULE).count(), 1);
+        assert!(text.starts_with("## Hello"));
+        assert!(text.ends_with("2) Right → a\n"));
+    }
+
+    #[test]
+    fn all_narrows_to_the_slides_from_and_to_pick() {
+        let only =
+            |from, to| render(&deck(), PrintTarget::All, Bounds { from, to }).expect("in range");
+        let second = only(Some("b"), None);
+        assert!(second.starts_with("Pick one"), "{second}");
+        assert_eq!(second.matches(SLIDE_RULE).count(), 0);
+        assert_eq!(only(Some("1"), Some("a")), only(None, Some("1")));
+        assert!(only(None, Some("1")).starts_with("## Hello"));
+
+        let backward = Bounds {
+            from: Some("b"),
+            to: Some("a"),
+        };
+        let err = render(&deck(), PrintTarget::All, backward).expect_err("b is after a");
+        assert!(err.to_string().contains("\"b\" comes after \"a\""), "{err}");
+        let err = render(&deck(), PrintTarget::Slide(1), backward).expect_err("one slide");
+        assert!(err.to_string().contains("--print all"), "{err}");
+    }
+
+    #[test]
+    fn out_of_range_slide_is_an_error() {
+        let err =
+            render(&deck(), PrintTarget::Slide(3), Bounds::default()).expect_err("only two slides");
+        assert!(err.to_string().contains("this deck has 2 slides"));
+    }
+
+    #[test]
+    fn target_parses_numbers_and_all_but_not_zero() {
+        assert_eq!("3".parse(), Ok(PrintTarget::Slide(3)));
+        assert_eq!("all".parse(), Ok(PrintTarget::All));
+        assert!("0".parse::<PrintTarget>().is_err());
+        assert!("intro".parse::<PrintTarget>().is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! `fireside repair <file> [-o <out>]`: fix the structural breaks that have
+//! one obvious fix — slides without ids, ids used twice, links to slides
+//! that don't exist, empty blocks left at the end of a slide — and say
+//! what was changed.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use fireside_core::{CoreError, Graph, parse_node_id};
+use fireside_engine::authoring::slug;
+use fireside_engine::repair::{Repair, repair};
+use fireside_engine::{has_errors, validate};
+use serde_json::Value;
+
+use crate::missing_file_error;
+use crate::output::write_output;
+
+/// Repairs the deck at `input` (see `fireside_engine::repair`) and saves
+/// it to `output`, or back over `input` without one, listing every
+/// change. A deck needing no repairs is left alone; with `dry_run`
+/// nothing is written at all.
+///
+/// # Errors
+///
+/// Fails on an unreadable deck, and on one broken in a way the repairs
+/// don't cover.
+pub(crate) fn repair_file(input: &Path, output: Option<&Path>, dry_run: bool) -> Result<()> {
+    let text = match std::fs::read_to_string(input) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => missing_file_error(input),
+        Err(err) => {
+            return Err(err).with_context(|| format!("could not read {}", input.display()));
+        }
+    };
+    let (graph, repairs) =
+        load_repaired(&text).with_context(|| format!("could not load {}", input.display()))?;
+    if repairs.is_empty() {
+        println!("{} needs no repairs.", input.display());
+        return Ok(());
+    }
+
+    let output = output.unwrap_or(input);
+    write_output(output, &graph, dry_run)?;
+
+    let count = repairs.len();
+    println!(
+        "{} {count} {} to {}{}:",
+        if dry_run { "Would make" } else { "Made" },
+        if count == 1 { "repair" } else { "repairs" },
+        input.display(),
+        if output == input {
+            String::new()
+        } else {
+            format!(", saved as {}", output.display())
+        }
+    );
+    for repair in &repairs {
+        println!("  {repair}");
+    }
+    if has_errors(&validate(&graph)) {
+        println!(
+            "Some problems need a person — \"fireside validate {}\" lists them.",
+            output.display()
+        );
+    }
+    Ok(())
+}
+
+/// Parses `text` as a JSON deck, first giving every node without a usable
+/// id one of its own, then applies [`repair`]. The repairs made come back
+/// alongside the graph, id assignments first.
+///
+/// # Errors
+///
+/// When `text` isn't JSON, or is broken in a way these repairs don't
+/// cover.
+fn load_repaired(text: &str) -> Result<(Graph, Vec<Repair>)> {
+    let mut value: Value = serde_json::from_str(text).map_err(CoreError::from)?;
+    let mut repairs = Vec::new();
+    if let Some(nodes) = value.get_mut("nodes").and_then(Value::as_array_mut) {
+        assign_missing_ids(nodes, &mut repairs);
+    }
+    let mut graph: Graph = serde_json::from_value(value).map_err(CoreError::from)?;
+    repairs.extend(repair(&mut graph));
+    Ok((graph, repairs))
+}
+
+/// Gives each node object whose `id` is missing, not a string, or not a
+/// valid id a fresh one: its old id, title, or first heading slugged with
+/// [`slug`], unique among the ids already in `nodes`.
+fn assign_missing_ids(nodes: &mut [Value], repairs: &mut Vec<Repair>) {
+    let mut taken: Vec<String> = nodes
+        .iter()
+        .filter_map(|n| n.get("id")?.as_str())
+        .filter(|id| parse_node_id(id).is_ok())
+        .map(str::to_owned)
+        .collect();
+    for (index, node) in nodes.iter_mut().enumerate() {
+        let Some(node) = node.as_object_mut() else {
+            continue;
+        };
+        let id = node.get("id").and_then(Value::as_str);
+        if id.is_some_and(|id| parse_node_id(id).is_ok()) {
+            continue;
+        }
+        let seed = id
+            .or_else(|| node.get("title").and_then(Value::as_str))
+            .or_else(|| first_heading(node.get("content")))
+            .unwrap_or_default();
+        let id = slug(seed, &taken);
+        taken.push(id.clone());
+        node.insert("id".to_owned(), Value::String(id.clone()));
+        repairs.push(Repair::AssignedId { index, id });
+    }
+}
+
+fn first_heading(content: Option<&Value>) -> Option<&str> {
+    content?.as_array()?.iter().find_map(|block| {
+        (block.get("kind")?.as_str()? == "heading")
+            .then(|| block.get("text")?.as_str())
+            .flatten()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodes_without_ids_get_one_and_trailing_empty_blocks_go() {
+        let (graph, repairs) = load_repaired(
+            r#"{"nodes":[
+                {"title":"Why Rust?","traversal":"intro","content":[
+                    {"kind":"text","body":"Speed."},
+                    {"kind":"text","body":"  "},
+                    {"kind":"list","items":[]}
+                ]},
+                {"id":42,"content":[{"kind":"heading","level":1,"text":"Intro"}]},
+                {"content":[]}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            repairs,
+            [
+                Repair::AssignedId {
+                    index: 0,
+                    id: "why-rust".into()
+                },
+                Repair::AssignedId {
+                    index: 1,
+                    id: "intro".into()
+                },
+                Repair::AssignedId {
+                    index: 2,
+                    id: "slide".into()
+                },
+                Repair::DroppedEmptyBlocks {
+                    node: "why-rust".into(),
+                    count: 2
+                },
+            ]
+        );
+        assert_eq!(graph.nodes[0].content.len(), 1);
+        assert_eq!(graph.nodes[0].next_target(), Some("intro"));
+    }
+}
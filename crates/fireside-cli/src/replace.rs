@@ -0,0 +1,42 @@
+//! `fireside replace <file> <find> <replace>`: fix a term across a whole
+//! deck in one go — a renamed product, a typo repeated on every slide —
+//! and save it in place.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{load, output};
+
+/// Replaces every match of `find` in the deck's slide content (see
+/// `Graph::replace_text`) and saves the file, or with `dry_run` shows the
+/// change and saves nothing. A deck with no match is left untouched and
+/// the process exits `1`, so a script can tell.
+pub(crate) fn replace_in_deck(
+    path: &Path,
+    find: &str,
+    replace: &str,
+    ignore_case: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let mut graph = load(path)?;
+    let count = graph.replace_text(find, replace, !ignore_case);
+    if count == 0 {
+        eprintln!(
+            "Nothing in {} matches \"{find}\" — the file is unchanged.",
+            path.display()
+        );
+        std::process::exit(1);
+    }
+    output::write_output(path, &graph, dry_run)?;
+    let noun = if count == 1 { "match" } else { "matches" };
+    if dry_run {
+        println!(
+            "Would replace {count} {noun} in {} — nothing written.",
+            path.display()
+        );
+    } else {
+        println!("Replaced {count} {noun} in {}.", path.display());
+    }
+    Ok(())
+}
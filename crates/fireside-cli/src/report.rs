@@ -5,10 +5,11 @@ use std::path::Path;
 
 use anyhow::Result;
 use fireside_core::{CoreError, Graph};
+use fireside_engine::load::{LoadOptions, unknown_fields};
 use fireside_engine::{Diagnostic, Severity, validate};
 
-use crate::load;
 use crate::watch::watch_loop;
+use crate::{ReportFormat, load_with_text};
 
 /// A parse failure the author can act on: the line before, the offending
 /// line, and a caret under the exact column.
@@ -45,44 +46,92 @@ pub(crate) fn strip_position(err: &serde_json::Error) -> String {
     full.split(" at line ").next().unwrap_or(&full).to_owned()
 }
 
-/// Render a validation result exactly as `validate` has always printed it:
-/// a success line, or the full diagnostic list plus a summary. Shared by
-/// the one-shot path and the watch loop so their output never drifts apart.
-fn diagnostics_report(path: &Path, diags: &[Diagnostic]) -> String {
+/// Render a validation result: a success line, or the full diagnostic
+/// list, then the [`summary_line`]. Shared by the one-shot path and the
+/// watch loop so their output never drifts apart.
+fn diagnostics_report(
+    path: &Path,
+    slides: usize,
+    diags: &[Diagnostic],
+    threshold: Severity,
+) -> String {
+    let summary = summary_line(path, slides, diags, threshold);
     if diags.is_empty() {
-        return format!("✓ {} — no problems found", path.display());
+        return format!("✓ {} — no problems found\n{summary}", path.display());
     }
 
-    let mut errors = 0usize;
-    let mut warnings = 0usize;
     let mut lines: Vec<String> = diags
         .iter()
         .map(|d| {
             let icon = match d.severity {
-                Severity::Error => {
-                    errors += 1;
-                    "✗"
-                }
-                Severity::Warning => {
-                    warnings += 1;
-                    "⚠"
-                }
+                Severity::Error => "✗",
+                Severity::Warning => "⚠",
                 Severity::Info => "ℹ",
             };
             format!("  {icon} {}", d.message)
         })
         .collect();
-    let notes = diags.len() - errors - warnings;
-    lines.push(format!(
-        "\n{}: {}, {}, {}",
-        path.display(),
-        plural(errors, "error"),
-        plural(warnings, "warning"),
-        plural(notes, "note"),
-    ));
+    lines.push(format!("\n{summary}"));
     lines.join("\n")
 }
 
+/// The one-line verdict every human report ends with, and all `--quiet`
+/// prints: `talk.json: 12 slides, 1 error, 0 warnings, 2 notes — ✗ FAIL`,
+/// failing when any diagnostic is at least as severe as `threshold`.
+fn summary_line(path: &Path, slides: usize, diags: &[Diagnostic], threshold: Severity) -> String {
+    let count = |severity| diags.iter().filter(|d| d.severity == severity).count();
+    format!(
+        "{}: {}, {}, {}, {} — {}",
+        path.display(),
+        plural(slides, "slide"),
+        plural(count(Severity::Error), "error"),
+        plural(count(Severity::Warning), "warning"),
+        plural(count(Severity::Info), "note"),
+        if failing(diags, threshold) == 0 {
+            "✓ PASS"
+        } else {
+            "✗ FAIL"
+        },
+    )
+}
+
+/// The same result as Markdown, for a CI job summary or a docs page: a
+/// counts table, then a checklist per severity, most severe first, each
+/// item linking to the node it is about.
+pub(crate) fn render_markdown(path: &Path, diags: &[Diagnostic]) -> String {
+    let mut out = format!("## Validation: `{}`\n\n", path.display());
+    if diags.is_empty() {
+        out.push_str("No problems found.\n");
+        return out;
+    }
+
+    let groups = [
+        (Severity::Error, "Errors"),
+        (Severity::Warning, "Warnings"),
+        (Severity::Info, "Notes"),
+    ];
+    out.push_str("| Severity | Count |\n| -------- | ----: |\n");
+    for (severity, heading) in groups {
+        let count = diags.iter().filter(|d| d.severity == severity).count();
+        out.push_str(&format!("| {heading} | {count} |\n"));
+    }
+    for (severity, heading) in groups {
+        let mut items = diags.iter().filter(|d| d.severity == severity).peekable();
+        if items.peek().is_none() {
+            continue;
+        }
+        out.push_str(&format!("\n### {heading}\n\n"));
+        for d in items {
+            out.push_str(&format!("- [ ] `{}` — {}", d.rule, d.message));
+            if let Some(node) = &d.node {
+                out.push_str(&format!(" ([`{node}`](#{node}))"));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
 /// `1 error`, `2 errors`, `0 errors` — never the placeholder `error(s)`.
 fn plural(count: usize, noun: &str) -> String {
     if count == 1 {
@@ -92,21 +141,82 @@ fn plural(count: usize, noun: &str) -> String {
     }
 }
 
-pub(crate) fn validate_file(path: &Path, watch: bool) -> Result<()> {
+/// Checks the deck once and exits `1` if any diagnostic is at least as
+/// severe as `threshold`. A threshold other than the default `Error` adds a
+/// verdict line naming it, so a CI log says why a deck with only warnings
+/// failed. With `options` denying unknown fields, each one is reported as
+/// an `unknown-field` error ahead of the validation diagnostics, and with
+/// `require_alt` every `missing-alt-text` note becomes an error. `quiet`
+/// prints only the summary line. `--watch` never exits, so none of these
+/// apply there.
+pub(crate) fn validate_file(
+    path: &Path,
+    watch: bool,
+    threshold: Severity,
+    options: LoadOptions,
+    require_alt: bool,
+    format: ReportFormat,
+    quiet: bool,
+) -> Result<()> {
     if watch {
         return watch_loop(path);
     }
 
-    let graph = load(path)?;
-    let diags = validate(&graph);
-    let has_errors = diags.iter().any(|d| d.severity == Severity::Error);
-    println!("{}", diagnostics_report(path, &diags));
-    if has_errors {
+    let (graph, text) = load_with_text(path)?;
+    let mut diags = Vec::new();
+    if options.deny_unknown_fields {
+        diags.extend(
+            unknown_fields(&text)?
+                .into_iter()
+                .map(|unknown| Diagnostic {
+                    severity: Severity::Error,
+                    rule: "unknown-field",
+                    node: unknown.node.clone(),
+                    message: unknown.to_string(),
+                }),
+        );
+    }
+    diags.extend(validate(&graph));
+    if require_alt {
+        for diag in diags.iter_mut().filter(|d| d.rule == "missing-alt-text") {
+            diag.severity = Severity::Error;
+        }
+        diags.sort_by_key(|d| std::cmp::Reverse(d.severity));
+    }
+    let failing = failing(&diags, threshold);
+    let slides = graph.nodes.len();
+    match format {
+        _ if quiet => println!("{}", summary_line(path, slides, &diags, threshold)),
+        ReportFormat::Human => println!("{}", diagnostics_report(path, slides, &diags, threshold)),
+        ReportFormat::Markdown => println!("{}", render_markdown(path, &diags)),
+    }
+    if threshold != Severity::Error && !quiet {
+        println!("{}", verdict(threshold, failing));
+    }
+    if failing > 0 {
         std::process::exit(1);
     }
     Ok(())
 }
 
+/// How many diagnostics are at least as severe as `threshold`.
+fn failing(diags: &[Diagnostic], threshold: Severity) -> usize {
+    diags.iter().filter(|d| d.severity >= threshold).count()
+}
+
+/// `✗ fails at --fail-on warning: 2 diagnostics at or above it`, or the
+/// passing line.
+fn verdict(threshold: Severity, failing: usize) -> String {
+    if failing == 0 {
+        format!("✓ passes at --fail-on {threshold}")
+    } else {
+        format!(
+            "✗ fails at --fail-on {threshold}: {} at or above it",
+            plural(failing, "diagnostic")
+        )
+    }
+}
+
 /// Check the file once and render the result — a success line, the
 /// diagnostic list, a caret-pointed parse report, or a one-line message if
 /// the file can't currently be read. Never exits the process, so it is
@@ -118,7 +228,9 @@ pub(crate) fn watch_report(path: &Path) -> String {
     };
     match Graph::from_json(&text) {
         Err(CoreError::Parse(err)) => parse_report(path, &text, &err),
-        Ok(graph) => diagnostics_report(path, &validate(&graph)),
+        Ok(graph) => {
+            diagnostics_report(path, graph.nodes.len(), &validate(&graph), Severity::Error)
+        }
     }
 }
 
@@ -190,6 +302,54 @@ mod tests {
         assert_eq!(plural(2, "error"), "2 errors");
     }
 
+    #[test]
+    fn the_threshold_decides_which_diagnostics_fail() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[{"id":"a","terminal":true,"content":[]},{"id":"orphan","content":[]}]}"#,
+        )
+        .expect("parse");
+        let diags = validate(&graph);
+        assert!(diags.iter().all(|d| d.severity < Severity::Error));
+        assert_eq!(failing(&diags, Severity::Error), 0);
+        assert_eq!(failing(&diags, Severity::Warning), 1);
+        assert_eq!(
+            verdict(Severity::Warning, 1),
+            "✗ fails at --fail-on warning: 1 diagnostic at or above it"
+        );
+        assert_eq!(verdict(Severity::Info, 0), "✓ passes at --fail-on info");
+    }
+
+    #[test]
+    fn render_markdown_groups_a_checklist_by_severity() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"ghost","content":[]},
+                {"id":"orphan","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let diags = validate(&graph);
+        let markdown = render_markdown(Path::new("deck.json"), &diags);
+        let expected = format!(
+            "## Validation: `deck.json`\n\n\
+             | Severity | Count |\n\
+             | -------- | ----: |\n\
+             | Errors | 1 |\n\
+             | Warnings | 1 |\n\
+             | Notes | 0 |\n\
+             \n### Errors\n\n\
+             - [ ] `{}` — {} ([`a`](#a))\n\
+             \n### Warnings\n\n\
+             - [ ] `{}` — {} ([`orphan`](#orphan))\n",
+            diags[0].rule, diags[0].message, diags[1].rule, diags[1].message,
+        );
+        assert_eq!(markdown, expected);
+        assert_eq!(
+            render_markdown(Path::new("deck.json"), &[]),
+            "## Validation: `deck.json`\n\nNo problems found.\n"
+        );
+    }
+
     #[test]
     fn watch_report_shows_a_caret_report_for_malformed_json() {
         let temp = tempfile::tempdir().expect("temp dir");
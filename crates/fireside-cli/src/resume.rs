@@ -107,17 +107,11 @@ impl ResumeStore {
     }
 }
 
+/// `resume.json` in [`crate::dirs::state_dir`] — by default
 /// `$XDG_STATE_HOME/fireside/resume.json`, falling back to
-/// `~/.local/state/fireside/resume.json`. Manual `std::env`/`std::path`
-/// construction rather than a `dirs`-style crate — flagged in research.md
-/// §2 as a reviewable, no-new-dependency default.
+/// `~/.local/state/fireside/resume.json`.
 fn resume_path() -> Option<PathBuf> {
-    let base = std::env::var_os("XDG_STATE_HOME")
-        .map(PathBuf::from)
-        .or_else(|| {
-            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state"))
-        })?;
-    Some(base.join("fireside").join("resume.json"))
+    Some(crate::dirs::state_dir()?.join("resume.json"))
 }
 
 /// A deck's resume-state map key: its canonicalized absolute path,
@@ -12,6 +12,7 @@
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use fireside_core::fnv1a64;
 use fireside_tui::{SessionSnapshot, SessionStatus, SessionTick};
 use serde_json::json;
 
@@ -25,14 +26,9 @@ const SCHEMA_VERSION: u64 = 1;
 
 /// `$XDG_STATE_HOME/fireside/sessions/<key>.json`, falling back to
 /// `~/.local/state/fireside/sessions/<key>.json` — one path segment deeper
-/// than `resume::resume_path`, same base-directory resolution.
+/// than `resume::resume_path`, same [`crate::dirs::state_dir`].
 fn sessions_dir() -> Option<PathBuf> {
-    let base = std::env::var_os("XDG_STATE_HOME")
-        .map(PathBuf::from)
-        .or_else(|| {
-            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state"))
-        })?;
-    Some(base.join("fireside").join("sessions"))
+    Some(crate::dirs::state_dir()?.join("sessions"))
 }
 
 /// The session file's path for a deck already keyed by
@@ -47,25 +43,6 @@ pub(crate) fn session_path_for(key: &str) -> Option<PathBuf> {
     Some(sessions_dir()?.join(format!("{:016x}.json", fnv1a64(key.as_bytes()))))
 }
 
-/// FNV-1a, 64-bit. Chosen over `DefaultHasher` (not guaranteed stable
-/// across compiler versions — the presenter and a separately launched
-/// follower process must derive the same filename from the same path) and
-/// over `watch::fingerprint`'s `(mtime, len)` pair (a staleness check, not
-/// a stable identifier — see ADR-015). `pub(crate)`: `edit.rs`'s draft
-/// sidecar (spec 013 US4, T059) reuses this exact hash rather than
-/// implementing a third copy (research.md §3).
-#[must_use]
-pub(crate) fn fnv1a64(bytes: &[u8]) -> u64 {
-    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
-    const PRIME: u64 = 0x0000_0100_0000_01b3;
-    let mut hash = OFFSET_BASIS;
-    for &byte in bytes {
-        hash ^= u64::from(byte);
-        hash = hash.wrapping_mul(PRIME);
-    }
-    hash
-}
-
 /// Writes the presenter's current position to `path`, refreshing the
 /// heartbeat — called once per event-loop tick regardless of whether the
 /// position changed (a motionless-but-alive presenter must still look
@@ -16,6 +16,9 @@ use crate::report::{strip_position, watch_report};
 pub(crate) struct Watcher {
     path: PathBuf,
     fingerprint: Option<(SystemTime, u64)>,
+    /// A save already reported the deck's folder missing, so the next one
+    /// creates it — see [`ensure_parent`].
+    create_parent: bool,
 }
 
 impl Watcher {
@@ -23,6 +26,7 @@ impl Watcher {
         Self {
             path: path.to_path_buf(),
             fingerprint: fingerprint(path),
+            create_parent: false,
         }
     }
 
@@ -41,14 +45,20 @@ impl Watcher {
             .unwrap_or_else(|| self.path.display().to_string());
         Some(match std::fs::read_to_string(&self.path) {
             Err(err) => Err(format!("Reload failed — could not read {name}: {err}")),
-            Ok(text) => Graph::from_json(&text).map_err(|CoreError::Parse(err)| {
-                format!(
-                    "Reload failed — {name}:{}:{} — {}",
-                    err.line(),
-                    err.column(),
-                    strip_position(&err),
-                )
-            }),
+            Ok(text) => Graph::from_json(&text)
+                .map_err(|CoreError::Parse(err)| {
+                    format!(
+                        "Reload failed — {name}:{}:{} — {}",
+                        err.line(),
+                        err.column(),
+                        strip_position(&err),
+                    )
+                })
+                .and_then(|mut graph| {
+                    crate::resolve_embeds(&mut graph, &self.path)
+                        .map_err(|err| format!("Reload failed — {err}"))?;
+                    Ok(graph)
+                }),
         })
     }
 
@@ -66,10 +76,12 @@ impl Watcher {
     /// can retry: since the fingerprint is now current, a repeat save
     /// succeeds as a deliberate overwrite. This is the "choose to overwrite
     /// or abandon" FR-013 asks for — pressing save again is the overwrite
-    /// choice; Esc is the abandon choice.
+    /// choice; Esc is the abandon choice. A missing folder gets the same
+    /// save-again retry, through [`ensure_parent`].
     pub(crate) fn write_back(&mut self, graph: &Graph) -> Result<(), WriteBackError> {
+        let created = ensure_parent(&self.path, &mut self.create_parent)?;
         let current = fingerprint(&self.path);
-        if current != self.fingerprint {
+        if !created && current != self.fingerprint {
             self.fingerprint = current;
             return Err(WriteBackError::Conflict);
         }
@@ -77,11 +89,33 @@ impl Watcher {
             .to_json_pretty()
             .map_err(|err| WriteBackError::Io(err.to_string()))?;
         std::fs::write(&self.path, json + "\n")
-            .map_err(|err| WriteBackError::Io(err.to_string()))?;
+            .map_err(|err| WriteBackError::from_io(&err, &self.path))?;
         Ok(())
     }
 }
 
+/// Before a save to `path`, checks that its folder still exists. The first
+/// save to find it gone fails with [`WriteBackError::NoSuchDirectory`] and
+/// sets `offered`; the next one creates it, folders above it and all, and
+/// returns `true` — a deck whose folder was just made has no file on disk
+/// to conflict with.
+pub(crate) fn ensure_parent(path: &Path, offered: &mut bool) -> Result<bool, WriteBackError> {
+    let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) else {
+        return Ok(false);
+    };
+    if dir.is_dir() {
+        *offered = false;
+        return Ok(false);
+    }
+    if !*offered {
+        *offered = true;
+        return Err(WriteBackError::NoSuchDirectory(dir.display().to_string()));
+    }
+    std::fs::create_dir_all(dir).map_err(|err| WriteBackError::from_io(&err, dir))?;
+    *offered = false;
+    Ok(true)
+}
+
 /// The file's (mtime, size) pair — enough to notice editor saves.
 pub(crate) fn fingerprint(path: &Path) -> Option<(SystemTime, u64)> {
     let meta = std::fs::metadata(path).ok()?;
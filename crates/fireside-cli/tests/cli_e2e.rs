@@ -55,6 +55,14 @@ fn bare_invocation_mentions_edit() {
         .stdout(predicate::str::contains("fireside edit <file>"));
 }
 
+#[test]
+fn bare_invocation_mentions_outline() {
+    fireside()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fireside outline <file>"));
+}
+
 #[test]
 fn validate_hello_exits_zero() {
     fireside()
@@ -208,6 +216,119 @@ fn validate_reports_dangling_targets_in_plain_language() {
         .stdout(predicate::str::contains("no node has that id"));
 }
 
+#[test]
+fn validate_quiet_prints_only_the_summary_with_the_verdict() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let broken = temp.path().join("broken.json");
+    std::fs::write(
+        &broken,
+        r#"{"nodes":[{"id":"a","traversal":"ghost","content":[]}]}"#,
+    )
+    .expect("write fixture");
+    let clean = temp.path().join("clean.json");
+    std::fs::write(&clean, r#"{"nodes":[{"id":"a","content":[]}]}"#).expect("write fixture");
+
+    fireside()
+        .args(["validate", "--quiet"])
+        .arg(&broken)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "1 slide, 1 error, 0 warnings, 0 notes — ✗ FAIL",
+        ))
+        .stdout(predicate::str::contains("no node has that id").not());
+    fireside()
+        .args(["validate", "--quiet"])
+        .arg(&clean)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 errors"))
+        .stdout(predicate::str::contains("✓ PASS"))
+        .stdout(predicate::str::contains("no problems found").not());
+}
+
+#[test]
+fn validate_markdown_lists_findings_as_a_checklist_and_keeps_the_exit_code() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let broken = temp.path().join("broken.json");
+    std::fs::write(
+        &broken,
+        r#"{"nodes":[
+            {"id":"a","traversal":"ghost","content":[]},
+            {"id":"b","traversal":"b","content":[]}
+        ]}"#,
+    )
+    .expect("write fixture");
+    let notes_only = temp.path().join("notes.json");
+    std::fs::write(
+        &notes_only,
+        r#"{"nodes":[{"id":"a","content":[{"kind":"image","src":"a.png"}]}]}"#,
+    )
+    .expect("write fixture");
+
+    fireside()
+        .args(["validate", "--format", "markdown"])
+        .arg(&broken)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with("## Validation: `"))
+        .stdout(predicate::str::contains("| Errors | 1 |"))
+        .stdout(predicate::str::contains("| Warnings | 2 |"))
+        .stdout(predicate::str::contains(
+            "### Errors\n\n- [ ] `valid-traversal-target` — \"a\" points to \"ghost\"",
+        ))
+        .stdout(predicate::str::contains(
+            "- [ ] `self-loop` — \"b\" points to itself ([`b`](#b))",
+        ));
+    fireside()
+        .args(["validate", "--format", "markdown"])
+        .arg(&notes_only)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("| Notes | 1 |"))
+        .stdout(predicate::str::contains(
+            "### Notes\n\n- [ ] `missing-alt-text`",
+        ))
+        .stdout(predicate::str::contains("### Errors").not());
+}
+
+#[test]
+fn an_embed_shows_its_file_and_a_missing_one_fails_the_load() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    std::fs::create_dir(temp.path().join("src")).expect("make dir");
+    std::fs::write(
+        temp.path().join("src/main.rs"),
+        "fn main() {\n    println!(\"hi\");\n}\n",
+    )
+    .expect("write snippet");
+    let deck = temp.path().join("deck.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[{"id":"a","content":[{"kind":"embed","source":"src/main.rs","range":"2"}]}]}"#,
+    )
+    .expect("write fixture");
+    let broken = temp.path().join("broken.json");
+    std::fs::write(
+        &broken,
+        r#"{"nodes":[{"id":"a","content":[{"kind":"embed","source":"src/gone.rs"}]}]}"#,
+    )
+    .expect("write fixture");
+
+    fireside()
+        .args(["present", "--print", "1"])
+        .arg(&deck)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("println!(\"hi\");"))
+        .stdout(predicate::str::contains("fn main").not());
+    fireside()
+        .arg("validate")
+        .arg(&broken)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("embeds \"src/gone.rs\""));
+}
+
 #[test]
 fn present_refuses_a_broken_deck_before_taking_the_screen() {
     let temp = tempfile::tempdir().expect("temp dir");
@@ -873,3 +994,683 @@ fn edit_never_creates_a_draft_sidecar_merely_from_opening() {
         "no draft sidecar should exist after opening never reaches the event loop"
     );
 }
+
+#[test]
+fn edit_ids_takes_each_style_and_rejects_an_unknown_one() {
+    let hello = repo_root().join("docs/examples/hello.json");
+    for style in ["slug", "sequential", "short"] {
+        fireside()
+            .args(["edit", "--ids", style])
+            .arg(&hello)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("interactive terminal"));
+    }
+    fireside()
+        .args(["edit", "--ids", "uuid"])
+        .arg(&hello)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'uuid' for '--ids"))
+        .stderr(predicate::str::contains("interactive terminal").not());
+}
+
+#[test]
+fn lint_hello_exits_zero() {
+    fireside()
+        .arg("lint")
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 errors"));
+}
+
+#[test]
+fn lint_fix_clears_duplicate_keys_and_trailing_empty_blocks() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[
+            {"id":"a","traversal":{"branch-point":{"options":[
+                {"label":"B","key":"1","target":"b"},
+                {"label":"C","key":"1","target":"c"}
+            ]}},"content":[{"kind":"text","body":"pick"},{"kind":"text","body":""}]},
+            {"id":"b","terminal":true,"content":[{"kind":"text","body":"b"}]},
+            {"id":"c","content":[{"kind":"text","body":"c"}]}
+        ]}"#,
+    )
+    .expect("write deck");
+
+    fireside()
+        .arg("lint")
+        .arg(&deck)
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains(
+            "change option \"C\"'s key to \"2\"",
+        ))
+        .stdout(predicate::str::contains("2 can be fixed automatically"));
+
+    fireside()
+        .arg("lint")
+        .arg(&deck)
+        .arg("--fix")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Applied 2 fixes"));
+
+    let fixed = fireside_core::Graph::from_json(&std::fs::read_to_string(&deck).expect("read"))
+        .expect("the fixed deck parses");
+    let keys: Vec<_> = fixed.nodes[0]
+        .branch_point()
+        .expect("still a branch point")
+        .options
+        .iter()
+        .map(|o| o.key.as_deref())
+        .collect();
+    assert_eq!(keys, [Some("1"), Some("2")], "C got a free key, not none");
+    assert_eq!(fixed.nodes[0].content.len(), 1, "the empty block went");
+
+    fireside().arg("validate").arg(&deck).assert().success();
+}
+
+#[test]
+fn fmt_canonicalizes_a_deck_once_and_then_leaves_it_alone() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[{"content":[{"text":"Hi","level":1,"kind":"heading"}],
+            "duration":90,"id":"a"}],"title":"Drifted"}"#,
+    )
+    .expect("write deck");
+
+    fireside()
+        .args(["fmt", "--check"])
+        .arg(&deck)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("+  \"title\": \"Drifted\","))
+        .stderr(predicate::str::contains("isn't formatted"));
+    fireside()
+        .arg("fmt")
+        .arg(&deck)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Formatted"));
+    let once = std::fs::read_to_string(&deck).expect("read deck");
+    assert!(once.contains(r#""duration": "1:30""#), "{once}");
+
+    fireside()
+        .arg("fmt")
+        .arg(&deck)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already formatted"));
+    assert_eq!(std::fs::read_to_string(&deck).expect("read deck"), once);
+    fireside()
+        .args(["fmt", "--check"])
+        .arg(&deck)
+        .assert()
+        .success();
+}
+
+#[test]
+fn fmt_assign_keys_numbers_the_answers_and_can_keep_the_ones_set() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    let text = r#"{"nodes":[
+        {"id":"a","content":[],"traversal":{"branch-point":{"options":[
+            {"label":"One","target":"b"},
+            {"label":"Two","key":"x","target":"b"},
+            {"label":"Three","key":"X","target":"b"}
+        ]}}},
+        {"id":"b","content":[]}
+    ]}"#;
+    let keys = |deck: &Path| -> Vec<String> {
+        let graph =
+            fireside_core::Graph::from_json(&std::fs::read_to_string(deck).expect("read deck"))
+                .expect("deck parses");
+        let bp = graph.nodes[0].branch_point().expect("a branch point");
+        bp.options
+            .iter()
+            .map(|o| o.key.clone().unwrap_or_default())
+            .collect()
+    };
+
+    std::fs::write(&deck, text).expect("write deck");
+    fireside()
+        .args(["fmt", "--assign-keys", "--preserve-keys"])
+        .arg(&deck)
+        .assert()
+        .success();
+    assert_eq!(keys(&deck), ["1", "x", "2"]);
+
+    std::fs::write(&deck, text).expect("write deck");
+    fireside()
+        .args(["fmt", "--assign-keys"])
+        .arg(&deck)
+        .assert()
+        .success();
+    assert_eq!(keys(&deck), ["1", "2", "3"]);
+}
+
+#[test]
+fn fmt_refuses_a_deck_whose_unknown_fields_it_would_drop() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    let text = r#"{"nodes":[{"id":"a","x-speaker":"Sam","content":[]}]}"#;
+    std::fs::write(&deck, text).expect("write deck");
+
+    fireside()
+        .arg("fmt")
+        .arg(&deck)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("x-speaker"));
+    assert_eq!(std::fs::read_to_string(&deck).expect("read deck"), text);
+}
+
+#[test]
+fn replace_rewrites_every_slide_and_saves_in_place() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[
+            {"id":"a","traversal":"b","content":[{"kind":"heading","level":1,"text":"Acme"}]},
+            {"id":"b","content":[{"kind":"text","body":"Ask ACME"}]}
+        ]}"#,
+    )
+    .expect("write deck");
+
+    fireside()
+        .args(["replace", "--ignore-case"])
+        .arg(&deck)
+        .args(["acme", "Globex"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Replaced 2 matches"));
+    let saved = std::fs::read_to_string(&deck).expect("read deck");
+    assert!(saved.contains("\"Ask Globex\""), "{saved}");
+    assert!(!saved.contains("ACME"), "{saved}");
+
+    fireside()
+        .arg("replace")
+        .arg(&deck)
+        .args(["Initech", "Globex"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("the file is unchanged"));
+    assert_eq!(std::fs::read_to_string(&deck).expect("read deck"), saved);
+}
+
+#[test]
+fn replace_dry_run_reports_the_count_and_leaves_the_file_alone() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    let text = r#"{"nodes":[{"id":"a","content":[{"kind":"text","body":"Acme and Acme"}]}]}"#;
+    std::fs::write(&deck, text).expect("write deck");
+
+    fireside()
+        .args(["replace", "--dry-run"])
+        .arg(&deck)
+        .args(["Acme", "Globex"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""body": "Globex and Globex""#))
+        .stdout(predicate::str::contains("Would replace 2 matches"));
+    assert_eq!(std::fs::read_to_string(&deck).expect("read deck"), text);
+}
+
+#[test]
+fn repair_fixes_duplicate_ids_and_dangling_options_and_reports_them() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("broken.fireside.json");
+    let out = temp.path().join("fixed.fireside.json");
+    let broken = r#"{"nodes":[
+        {"id":"ask","traversal":{"branch-point":{"options":[
+            {"label":"On","target":"end"},
+            {"label":"Lost","target":"ghost"}
+        ]}},"content":[]},
+        {"id":"end","content":[]},
+        {"id":"end","content":[]}
+    ]}"#;
+    std::fs::write(&deck, broken).expect("write deck");
+
+    fireside()
+        .arg("repair")
+        .arg(&deck)
+        .arg("-o")
+        .arg(&out)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Made 2 repairs"))
+        .stdout(predicate::str::contains(
+            "renamed slide 3 from \"end\" to \"end-2\"",
+        ))
+        .stdout(predicate::str::contains("dropped \"Lost\" from \"ask\""));
+    assert_eq!(
+        std::fs::read_to_string(&deck).expect("read original"),
+        broken,
+        "the original is left alone"
+    );
+    fireside().arg("validate").arg(&out).assert().success();
+}
+
+#[test]
+fn merge_appends_the_second_deck_and_reports_renames() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let talk = temp.path().join("talk.fireside.json");
+    let qa = temp.path().join("qa.fireside.json");
+    let out = temp.path().join("merged.fireside.json");
+    std::fs::write(
+        &talk,
+        r#"{"title":"Talk","nodes":[{"id":"end","content":[]}]}"#,
+    )
+    .expect("write talk");
+    std::fs::write(
+        &qa,
+        r#"{"title":"Q&A","nodes":[
+            {"id":"ask","traversal":"end","content":[]},
+            {"id":"end","content":[]}
+        ]}"#,
+    )
+    .expect("write qa");
+
+    fireside()
+        .arg("merge")
+        .args([&talk, &qa])
+        .arg("--link")
+        .arg("-o")
+        .arg(&out)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("renamed \"end\" to \"end-2\""));
+    let merged: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&out).expect("read merged"))
+            .expect("merged parses");
+    assert_eq!(merged["title"], "Talk");
+    assert_eq!(merged["nodes"][0]["traversal"], "ask");
+    assert_eq!(merged["nodes"][1]["traversal"], "end-2");
+    fireside().arg("validate").arg(&out).assert().success();
+
+    fireside()
+        .arg("merge")
+        .args([&talk, &qa])
+        .arg("-o")
+        .arg(&out)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
+
+#[test]
+fn lint_exits_one_on_warnings_only() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("deck.fireside.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[{"id":"a","content":[{"kind":"image","src":"missing.png"}]}]}"#,
+    )
+    .expect("write deck");
+
+    fireside()
+        .arg("lint")
+        .arg(&deck)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("missing.png"));
+}
+
+#[test]
+fn present_print_writes_a_slide_as_text_without_a_terminal() {
+    fireside()
+        .args(["present", "--print", "1"])
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("# "));
+}
+
+#[test]
+fn present_print_all_narrows_to_from_and_to() {
+    fireside()
+        .args(["present", "--print", "all", "--from", "choose", "--to", "4"])
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("## Pick a Path")
+                .and(predicate::str::contains("## Code Block"))
+                .and(predicate::str::contains("Thanks!").not()),
+        );
+}
+
+#[test]
+fn present_print_rejects_a_slide_past_the_end() {
+    fireside()
+        .args(["present", "--print", "999"])
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("there is no slide 999"));
+}
+
+#[test]
+fn present_path_rejects_a_code_from_another_version() {
+    fireside()
+        .args(["present", "--path", "9.intro"])
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Can't replay --path"))
+        .stderr(predicate::str::contains("interactive terminal").not());
+}
+
+#[test]
+fn present_start_accepts_a_slide_number_or_id() {
+    for start in ["3", "choose"] {
+        fireside()
+            .args(["present", "--start", start])
+            .arg(repo_root().join("docs/examples/hello.json"))
+            .write_stdin("")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("interactive terminal"))
+            .stderr(predicate::str::contains("no slide").not());
+    }
+}
+
+#[test]
+fn present_start_rejects_an_unknown_slide_before_taking_the_screen() {
+    fireside()
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .args(["--start", "nope"])
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "there's no slide \"nope\" — give a slide's id, or a number from 1 to 6",
+        ))
+        .stderr(predicate::str::contains("interactive terminal").not());
+}
+
+#[test]
+fn outline_prints_sections_slides_and_headings_as_a_tree() {
+    fireside()
+        .arg("outline")
+        .arg(fixture("sections.json"))
+        .assert()
+        .success()
+        .stdout(
+            "Intro\n  1. Welcome\n    · Agenda\nBody\n  3. Setting up\n    · Install\n  4. Thanks\n2. draft (hidden)\n",
+        );
+}
+
+#[test]
+fn outline_missing_file_suggests_creating_it() {
+    fireside()
+        .arg("outline")
+        .arg("nonexistent.json")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "No deck named nonexistent.json — \"fireside new nonexistent\" creates one.",
+        ));
+}
+
+#[test]
+fn graph_dot_writes_a_digraph() {
+    fireside()
+        .args(["graph", "--dot"])
+        .arg(repo_root().join("docs/examples/hello.json"))
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("digraph "))
+        .stdout(predicate::str::contains(" -> "));
+}
+
+#[test]
+fn validate_strict_fails_on_warnings_that_pass_by_default() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("orphan.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[{"id":"a","content":[]},{"id":"orphan","content":[]}]}"#,
+    )
+    .expect("write fixture");
+
+    fireside().arg("validate").arg(&deck).assert().success();
+    fireside()
+        .args(["validate", "--strict"])
+        .arg(&deck)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("fails at --fail-on warning"));
+    fireside()
+        .args(["validate", "--strict", "--fail-on", "info"])
+        .arg(&deck)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn validate_require_alt_fails_an_undescribed_image_but_not_a_decorative_one() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("pictures.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[{"id":"intro","content":[
+            {"kind":"image","src":"flourish.png","alt":"","decorative":true},
+            {"kind":"image","src":"chart.png","alt":" "}
+        ]}]}"#,
+    )
+    .expect("write fixture");
+
+    fireside().arg("validate").arg(&deck).assert().success();
+    fireside()
+        .args(["validate", "--require-alt"])
+        .arg(&deck)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "\"chart.png\" on \"intro\" has no alt text",
+        ))
+        .stdout(predicate::str::contains("flourish.png").not());
+}
+
+#[test]
+fn validate_strict_names_fields_the_protocol_does_not_define() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let deck = temp.path().join("typo.json");
+    std::fs::write(
+        &deck,
+        r#"{"nodes":[{"id":"intro","transiton":"fade","content":[]}]}"#,
+    )
+    .expect("write fixture");
+
+    fireside().arg("validate").arg(&deck).assert().success();
+    fireside()
+        .args(["validate", "--strict"])
+        .arg(&deck)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "unknown field \"transiton\" at nodes[0].transiton (slide \"intro\") — did you mean \"transition\"?",
+        ));
+}
+
+#[test]
+fn convert_round_trips_a_deck_through_yaml() {
+    let temp = tempfile::tempdir().expect("temp dir");
+    let hello = repo_root().join("docs/examples/hello.json");
+    let yaml = temp.path().join("hello.yaml");
+    let back = temp.path().join("hello.json");
+
+    fireside()
+        .arg("convert")
+        .args([&hello, &yaml])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Converted"));
+    let written = std::fs::read_to_string(&yaml).expect("read yaml");
+    assert!(written.starts_with("fireside-version:"), "{written}");
+
+    fireside()
+        .arg("convert")
+        .args([&yaml, &back])
+        .assert()
+        .success();
+    let read = |path: &Path| {
+        fireside_core::Graph::from_json(&std::fs::read_to_string(path).expect("read deck"))
+            .expect("deck parses")
+    };
+    assert_eq!(read(&back), read(&hello));
+
+    fireside()
+        .arg("convert")
+        .args([&hello, &yaml])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
+
+// ─── presenter flags without a terminal ──────────────────────────────────
+//
+// These flags only change what the presenter does once it has a screen,
+// and `assert_cmd` has no pty — so each is checked up to the tty guard, on
+// the bare shorthand and on `present`: it parses, the run stops with the
+// plain `NotATty` line, and nothing meant for the end of a session (the
+// summary, `--rehearse`'s and `--coverage`'s reports) is printed, since no
+// session ran. The reports' formatting is unit-tested in `main.rs`.
+
+/// Runs `fireside <args> hello.json` and `fireside present <args>
+/// hello.json` without a terminal, asserting both got past parsing to the
+/// tty guard and printed nothing on stdout.
+fn assert_reaches_the_tty_guard(args: &[&str]) {
+    let hello = repo_root().join("docs/examples/hello.json");
+    for present in [&[][..], &["present"][..]] {
+        fireside()
+            .args(present)
+            .args(args)
+            .arg(&hello)
+            .write_stdin("")
+            .assert()
+            .failure()
+            .stdout(predicate::str::is_empty())
+            .stderr(predicate::str::contains(
+                "fireside needs an interactive terminal to present",
+            ))
+            .stderr(predicate::str::contains("panicked").not());
+    }
+}
+
+/// Runs `fireside <args> hello.json` and `fireside present <args>
+/// hello.json`, asserting both are refused with `message` before the
+/// presenter is ever reached.
+fn assert_refused_before_the_tty_guard(args: &[&str], message: &str) {
+    let hello = repo_root().join("docs/examples/hello.json");
+    for present in [&[][..], &["present"][..]] {
+        fireside()
+            .args(present)
+            .args(args)
+            .arg(&hello)
+            .write_stdin("")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message))
+            .stderr(predicate::str::contains("interactive terminal").not());
+    }
+}
+
+#[test]
+fn rehearse_parses_and_prints_no_report_without_a_session() {
+    assert_reaches_the_tty_guard(&["--rehearse"]);
+}
+
+#[test]
+fn coverage_parses_and_prints_no_report_without_a_session() {
+    assert_reaches_the_tty_guard(&["--coverage"]);
+    assert_reaches_the_tty_guard(&["--rehearse", "--coverage"]);
+}
+
+#[test]
+fn progress_takes_each_style_and_rejects_an_unknown_one() {
+    for style in ["count", "bar", "dots", "path"] {
+        assert_reaches_the_tty_guard(&["--progress", style]);
+    }
+    assert_refused_before_the_tty_guard(
+        &["--progress", "percent"],
+        "invalid value 'percent' for '--progress",
+    );
+}
+
+#[test]
+fn wrap_parses_and_conflicts_with_loop() {
+    assert_reaches_the_tty_guard(&["--wrap"]);
+    assert_refused_before_the_tty_guard(
+        &["--wrap", "--loop", "bounce"],
+        "'--wrap' cannot be used with '--loop",
+    );
+}
+
+#[test]
+fn auto_takes_an_optional_dwell_and_auto_branch_requires_it() {
+    assert_reaches_the_tty_guard(&["--auto"]);
+    assert_reaches_the_tty_guard(&["--auto=5", "--auto-branch", "first"]);
+    assert_refused_before_the_tty_guard(
+        &["--auto-branch", "first"],
+        "the following required arguments were not provided",
+    );
+    assert_refused_before_the_tty_guard(&["--auto=soon"], "invalid value 'soon' for '--auto");
+}
+
+#[test]
+fn control_listens_on_loopback_or_a_socket_and_cleans_the_socket_up() {
+    assert_reaches_the_tty_guard(&["--control", "127.0.0.1:0"]);
+    let temp = tempfile::tempdir().expect("temp dir");
+    let socket = temp.path().join("control.sock");
+    let socket = socket.to_str().expect("utf-8 temp path");
+    // Twice on the same path each way: a socket left behind by the first
+    // run would refuse the next.
+    assert_reaches_the_tty_guard(&["--control", socket]);
+    assert_reaches_the_tty_guard(&["--control", socket]);
+    assert!(!Path::new(socket).exists(), "the socket file was removed");
+}
+
+#[test]
+fn control_refuses_an_address_off_this_machine() {
+    assert_refused_before_the_tty_guard(
+        &["--control", "0.0.0.0:4000"],
+        "--control 0.0.0.0:4000 isn't a loopback address",
+    );
+}
+
+#[test]
+fn route_stops_takes_three_or_more() {
+    assert_reaches_the_tty_guard(&["--route-stops", "3"]);
+    assert_refused_before_the_tty_guard(
+        &["--route-stops", "2"],
+        "invalid value '2' for '--route-stops",
+    );
+}
+
+#[test]
+fn no_animations_parses_on_the_shorthand_and_on_present() {
+    assert_reaches_the_tty_guard(&["--no-animations"]);
+}
+
+#[test]
+fn notify_on_branch_parses_on_the_shorthand_and_on_present() {
+    assert_reaches_the_tty_guard(&["--notify-on-branch"]);
+}
+
+#[test]
+fn confirm_branch_choice_parses_on_the_shorthand_and_on_present() {
+    assert_reaches_the_tty_guard(&["--confirm-branch-choice"]);
+}
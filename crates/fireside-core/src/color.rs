@@ -0,0 +1,59 @@
+//! Node colors on the wire: CSS-style hex strings, `"#1e1e2e"` or the
+//! short `"#123"`, read into plain RGB so every frontend agrees on what a
+//! deck's `background` and `accent` mean.
+
+/// `text` as an RGB triple: `#` followed by three or six hex digits, in
+/// either case. The short form doubles each digit, as CSS does — `"#fa0"`
+/// is `"#ffaa00"`. Anything else, including surrounding whitespace, is
+/// `None`.
+#[must_use]
+pub fn parse_hex_color(text: &str) -> Option<[u8; 3]> {
+    let digits = text.strip_prefix('#')?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |hex: &str| u8::from_str_radix(hex, 16).ok();
+    match digits.len() {
+        3 => {
+            let mut rgb = [0; 3];
+            for (out, i) in rgb.iter_mut().zip(0..3) {
+                *out = channel(&digits[i..=i])? * 0x11;
+            }
+            Some(rgb)
+        }
+        6 => Some([
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+        ]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_and_short_hex_colors_parse_in_either_case() {
+        assert_eq!(parse_hex_color("#101010"), Some([0x10, 0x10, 0x10]));
+        assert_eq!(parse_hex_color("#1E1e2E"), Some([0x1e, 0x1e, 0x2e]));
+        assert_eq!(parse_hex_color("#fa0"), Some([0xff, 0xaa, 0x00]));
+    }
+
+    #[test]
+    fn anything_else_is_not_a_color() {
+        for text in [
+            "",
+            "#",
+            "101010",
+            "#1010",
+            "#10101g",
+            " #101010",
+            "red",
+            "#ffaa00ff",
+        ] {
+            assert_eq!(parse_hex_color(text), None, "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Graphviz DOT export of a graph's edge structure, for piping a deck
+//! into `dot -Tsvg` and seeing every path through it at once.
+//!
+//! Nodes are labelled with their title (falling back to the id), the
+//! entry node is drawn with a double border, and hidden nodes are dashed.
+//! `next` edges are plain; branch edges carry their option's key and
+//! label. Edges come from [`Graph::outgoing_edges`], so the picture shows
+//! exactly the moves a presenter can make — no fall-through edge appears
+//! for a terminal node.
+
+use std::fmt::Write as _;
+
+use crate::model::{Edge, Graph};
+
+impl Graph {
+    /// The whole graph as a Graphviz `digraph`, one statement per line.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        let name = self.title.as_deref().unwrap_or("fireside");
+        let _ = writeln!(out, "digraph {} {{", quote(name));
+        let _ = writeln!(out, "    node [shape=box];");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let mut attrs = vec![format!(
+                "label={}",
+                quote(node.title.as_deref().unwrap_or(&node.id))
+            )];
+            if i == 0 {
+                attrs.push("peripheries=2".to_owned());
+            }
+            if node.hidden {
+                attrs.push("style=dashed".to_owned());
+            }
+            let _ = writeln!(out, "    {} [{}];", quote(&node.id), attrs.join(", "));
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            for edge in self.outgoing_edges(i) {
+                let from = quote(&node.id);
+                let to = quote(edge.target());
+                match edge {
+                    Edge::Next { .. } => {
+                        let _ = writeln!(out, "    {from} -> {to};");
+                    }
+                    Edge::Branch { key, label, .. } => {
+                        let label = match key {
+                            Some(key) => format!("{key}) {label}"),
+                            None => label,
+                        };
+                        let _ = writeln!(out, "    {from} -> {to} [label={}];", quote(&label));
+                    }
+                }
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// A DOT quoted string: backslashes and quotes escaped, newlines kept as
+/// DOT's centred line break.
+fn quote(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn branches_are_labelled_and_the_entry_is_marked() {
+        let graph = Graph::from_json(
+            r#"{"title":"Talk","nodes":[
+                {"id":"intro","title":"Hello","traversal":{"branch-point":{"options":[
+                    {"label":"Deep dive","key":"d","target":"deep"},
+                    {"label":"Skip","target":"end"}
+                ]}},"content":[]},
+                {"id":"deep","hidden":true,"traversal":"end","content":[]},
+                {"id":"end","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            graph.to_dot(),
+            "digraph \"Talk\" {\n    node [shape=box];\n    \
+             \"intro\" [label=\"Hello\", peripheries=2];\n    \
+             \"deep\" [label=\"deep\", style=dashed];\n    \
+             \"end\" [label=\"end\"];\n    \
+             \"intro\" -> \"deep\" [label=\"d) Deep dive\"];\n    \
+             \"intro\" -> \"end\" [label=\"Skip\"];\n    \
+             \"deep\" -> \"end\";\n}\n"
+        );
+    }
+
+    #[test]
+    fn quotes_and_backslashes_are_escaped() {
+        assert_eq!(quote(r#"say "hi" \o/"#), r#""say \"hi\" \\o/""#);
+    }
+}
@@ -0,0 +1,206 @@
+//! Node durations on the wire: whole seconds (`90`), or text in one of
+//! three forms — `"90s"`, `"1m30s"`, `"1:30"` — all loading to the same
+//! [`Duration`] and saving back as `"M:SS"`, so a deck written by hand in
+//! any form reads the same once Fireside has saved it.
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::error::DurationError;
+
+/// `text` as a duration: bare seconds (`"90"`), units in `h`, `m`, `s`
+/// order with each at most once (`"90s"`, `"1m30s"`, `"2h"`), or minutes
+/// and two-digit seconds (`"1:30"`). No surrounding whitespace, signs, or
+/// fractions.
+///
+/// # Errors
+///
+/// Returns the [`DurationError`] saying why `text` isn't a duration.
+pub fn parse_duration(text: &str) -> Result<Duration, DurationError> {
+    if text.is_empty() {
+        return Err(DurationError::Empty);
+    }
+    let malformed = || DurationError::Malformed(text.to_owned());
+    let number = |digits: &str| -> Result<u64, DurationError> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(malformed());
+        }
+        digits
+            .parse()
+            .map_err(|_| DurationError::TooLong(text.to_owned()))
+    };
+    let too_long = || DurationError::TooLong(text.to_owned());
+
+    if let Some((minutes, seconds)) = text.split_once(':') {
+        if seconds.len() != 2 {
+            return Err(malformed());
+        }
+        let (minutes, seconds) = (number(minutes)?, number(seconds)?);
+        if seconds >= 60 {
+            return Err(malformed());
+        }
+        let total = minutes
+            .checked_mul(60)
+            .and_then(|s| s.checked_add(seconds))
+            .ok_or_else(too_long)?;
+        return Ok(Duration::from_secs(total));
+    }
+    if text.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(Duration::from_secs(number(text)?));
+    }
+
+    let mut total: u64 = 0;
+    let mut rest = text;
+    let mut units = ['h', 'm', 's'].as_slice();
+    while !rest.is_empty() {
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(malformed)?;
+        let (digits, tail) = rest.split_at(split);
+        let value = number(digits)?;
+        let mut chars = tail.chars();
+        let unit = chars.next().ok_or_else(malformed)?;
+        let at = units
+            .iter()
+            .position(|&u| u == unit)
+            .ok_or_else(malformed)?;
+        let scale = match unit {
+            'h' => 3600,
+            'm' => 60,
+            _ => 1,
+        };
+        total = value
+            .checked_mul(scale)
+            .and_then(|s| s.checked_add(total))
+            .ok_or_else(too_long)?;
+        units = &units[at + 1..];
+        rest = chars.as_str();
+    }
+    Ok(Duration::from_secs(total))
+}
+
+/// `duration` in the canonical `"M:SS"` form — `"1:30"`, `"0:45"`,
+/// `"75:00"` — dropping any fraction of a second.
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// `#[serde(with = "...")]` for an `Option<Duration>` field: reads a
+/// number of seconds or any [`parse_duration`] text, writes
+/// [`format_duration`].
+pub(crate) mod optional {
+    use super::{Deserializer, Duration, DurationVisitor, Serializer, format_duration};
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_str(&format_duration(*duration)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        deserializer.deserialize_any(DurationVisitor)
+    }
+}
+
+struct DurationVisitor;
+
+impl<'de> Visitor<'de> for DurationVisitor {
+    type Value = Option<Duration>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a number of seconds, or a duration like \"90s\", \"1m30s\", or \"1:30\"")
+    }
+
+    fn visit_u64<E: de::Error>(self, secs: u64) -> Result<Self::Value, E> {
+        Ok(Some(Duration::from_secs(secs)))
+    }
+
+    fn visit_i64<E: de::Error>(self, secs: i64) -> Result<Self::Value, E> {
+        u64::try_from(secs)
+            .map(|secs| Some(Duration::from_secs(secs)))
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(secs), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        parse_duration(text).map(Some).map_err(E::custom)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Graph;
+
+    fn secs(n: u64) -> Result<Duration, DurationError> {
+        Ok(Duration::from_secs(n))
+    }
+
+    #[test]
+    fn every_accepted_form_parses_to_the_same_duration() {
+        assert_eq!(parse_duration("90"), secs(90));
+        assert_eq!(parse_duration("90s"), secs(90));
+        assert_eq!(parse_duration("1m30s"), secs(90));
+        assert_eq!(parse_duration("1:30"), secs(90));
+        assert_eq!(parse_duration("2m"), secs(120));
+        assert_eq!(parse_duration("1h5s"), secs(3605));
+        assert_eq!(parse_duration("0:05"), secs(5));
+        assert_eq!(parse_duration("75:00"), secs(4500));
+    }
+
+    #[test]
+    fn malformed_durations_are_rejected() {
+        assert_eq!(parse_duration(""), Err(DurationError::Empty));
+        for text in [
+            " 90", "1.5", "-3", "90x", "s", "1s30m", "1m1m", "1:3", "1:60", ":30", "1:30:00",
+            "1m 30s", "1M",
+        ] {
+            assert!(
+                matches!(parse_duration(text), Err(DurationError::Malformed(_))),
+                "{text:?}"
+            );
+        }
+        assert!(matches!(
+            parse_duration("99999999999999999999"),
+            Err(DurationError::TooLong(_))
+        ));
+    }
+
+    #[test]
+    fn a_node_duration_loads_from_any_form_and_saves_as_minutes_and_seconds() {
+        for wire in ["90", "\"90\"", "\"90s\"", "\"1m30s\"", "\"1:30\""] {
+            let json = format!(r#"{{"nodes":[{{"id":"a","duration":{wire},"content":[]}}]}}"#);
+            let graph = Graph::from_json(&json).unwrap();
+            assert_eq!(
+                graph.nodes[0].duration,
+                Some(Duration::from_secs(90)),
+                "{wire}"
+            );
+            assert!(
+                graph
+                    .to_json_pretty()
+                    .unwrap()
+                    .contains(r#""duration": "1:30""#),
+                "{wire}"
+            );
+        }
+        for wire in ["-1", "1.5", "\"ninety\"", "true"] {
+            let json = format!(r#"{{"nodes":[{{"id":"a","duration":{wire},"content":[]}}]}}"#);
+            assert!(Graph::from_json(&json).is_err(), "{wire}");
+        }
+    }
+}
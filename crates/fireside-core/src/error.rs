@@ -10,3 +10,32 @@ pub enum CoreError {
     #[error("not a valid Fireside document: {0}")]
     Parse(#[from] serde_json::Error),
 }
+
+/// Why a string can't be a node id. See [`crate::model::parse_node_id`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum IdError {
+    /// The id is the empty string.
+    #[error("a node id can't be empty")]
+    Empty,
+    /// The id contains a space, tab, or line break.
+    #[error("node id \"{0}\" contains whitespace")]
+    Whitespace(String),
+    /// The id contains a character outside letters, digits, `-` and `_`.
+    #[error("node id \"{0}\" contains '{1}' — ids use only letters, digits, '-' and '_'")]
+    InvalidChar(String, char),
+}
+
+/// Why a string can't be a duration. See
+/// [`crate::duration::parse_duration`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DurationError {
+    /// The text is the empty string.
+    #[error("a duration can't be empty")]
+    Empty,
+    /// The text is in none of the accepted forms.
+    #[error("\"{0}\" isn't a duration — try 90, 90s, 1m30s, or 1:30")]
+    Malformed(String),
+    /// The text is a duration, but too many seconds to count.
+    #[error("\"{0}\" is too long for a duration")]
+    TooLong(String),
+}
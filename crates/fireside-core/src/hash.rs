@@ -0,0 +1,31 @@
+//! A hash that stays put: the same bytes give the same value on every run,
+//! build, and machine — for file names derived from a deck's path and for
+//! [`crate::Node::content_hash`].
+
+/// FNV-1a, 64-bit. Chosen over `DefaultHasher`, which is not guaranteed
+/// stable across compiler versions: a presenter and a separately launched
+/// follower process must derive the same value from the same bytes, and a
+/// cache keyed on one must survive an upgrade.
+#[must_use]
+pub fn fnv1a64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = OFFSET_BASIS;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_published_test_vectors() {
+        assert_eq!(fnv1a64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
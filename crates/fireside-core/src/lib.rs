@@ -2,16 +2,28 @@
 //!
 //! This crate is a faithful Rust mirror of the Fireside 0.1.0 protocol
 //! (`protocol/main.tsp` and its generated JSON schemas): parsing,
-//! serialization, and small read-time helpers (traversal accessors and
-//! default resolution). It performs no I/O, holds no state, and contains
+//! serialization, and small read-time helpers (traversal accessors,
+//! default resolution, an outline, a Graphviz export of the edge structure,
+//! and a strict pass that finds properties the protocol doesn't define). It performs no I/O, holds no state, and contains
 //! no rendering or validation logic — semantic validation lives in
 //! `fireside-engine`, presentation in `fireside-tui`.
 
+pub mod color;
+pub mod dot;
+pub mod duration;
 pub mod error;
+pub mod hash;
 pub mod model;
+pub mod outline;
+pub mod strict;
 
-pub use error::CoreError;
+pub use color::parse_hex_color;
+pub use duration::{format_duration, parse_duration};
+pub use error::{CoreError, DurationError, IdError};
+pub use hash::fnv1a64;
 pub use model::{
-    BranchOption, BranchPoint, ContainerLayout, ContentBlock, Graph, Node, NodeDefaults, NodeId,
-    Transition, Traversal, TraversalSpec, ViewMode,
+    BranchOption, BranchPoint, BranchStructure, CodeWrap, ContainerLayout, ContentBlock,
+    DividerStyle, Edge, Graph, Node, NodeDefaults, NodeId, Transition, Traversal, TraversalSpec,
+    ViewMode, normalize_node_id, parse_node_id,
 };
+pub use outline::{OutlineEntry, OutlineKind};
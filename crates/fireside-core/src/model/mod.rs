@@ -7,15 +7,68 @@
 //! read (the schema layer owns strictness) and absent optional fields stay
 //! absent on write, so load → save round-trips are faithful.
 
-use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
-use crate::error::CoreError;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::error::{CoreError, IdError};
 
 /// A unique string identifier for a node within a graph.
 ///
-/// IDs MUST be unique within a graph and SHOULD be kebab-case.
+/// IDs MUST be unique within a graph and SHOULD be kebab-case. A node's
+/// own id is checked against [`parse_node_id`] when a deck is parsed. The
+/// targets of `next` and branch options (the protocol's `NodeRef`) aren't:
+/// one that names no node is a dangling target for validation to report.
 pub type NodeId = String;
 
+/// `text` as a node id, if it is one: non-empty, and made only of letters,
+/// digits, `-` and `_`. Letters and digits in any script count, so an id
+/// slugged from a non-English title stays valid.
+///
+/// # Errors
+///
+/// Returns the [`IdError`] naming the first rule `text` breaks.
+pub fn parse_node_id(text: &str) -> Result<NodeId, IdError> {
+    if text.is_empty() {
+        return Err(IdError::Empty);
+    }
+    match text
+        .chars()
+        .find(|&c| !(c.is_alphanumeric() || c == '-' || c == '_'))
+    {
+        Some(c) if c.is_whitespace() => Err(IdError::Whitespace(text.to_owned())),
+        Some(c) => Err(IdError::InvalidChar(text.to_owned(), c)),
+        None => Ok(text.to_owned()),
+    }
+}
+
+/// Slugifies arbitrary text into a valid node id: lowercased, every run of
+/// other characters collapsed to one `-`, with none leading or trailing —
+/// `"Q&A: Wrap-up"` becomes `q-a-wrap-up`. `None` when nothing
+/// alphanumeric is left to build one from.
+#[must_use]
+pub fn normalize_node_id(text: &str) -> Option<NodeId> {
+    let mut out = String::new();
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if !out.is_empty() {
+            out.push('-');
+        }
+        out.extend(word.chars().flat_map(char::to_lowercase));
+    }
+    (!out.is_empty()).then_some(out)
+}
+
+/// `Node.id` on the wire: any string [`parse_node_id`] accepts, so a bad id
+/// fails the parse with an error naming it.
+fn node_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NodeId, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    parse_node_id(&text).map_err(serde::de::Error::custom)
+}
+
 // ─── Graph ───────────────────────────────────────────────────────────────────
 
 /// The top-level Fireside document: metadata, optional defaults, and the
@@ -90,6 +143,220 @@ impl Graph {
     pub fn entry(&self) -> Option<&Node> {
         self.nodes.first()
     }
+
+    /// The edges leaving the node at `index`, in the order a presenter
+    /// meets them: its `next` target, or one edge per branch option. A
+    /// terminal node — and an index past the end — has none. There is no
+    /// implicit fall-through to the following node in the array: a node
+    /// without traversal ends the path, so no edge is invented for it.
+    #[must_use]
+    pub fn outgoing_edges(&self, index: usize) -> Vec<Edge> {
+        let Some(node) = self.nodes.get(index) else {
+            return Vec::new();
+        };
+        if let Some(bp) = node.branch_point() {
+            return bp
+                .options
+                .iter()
+                .map(|option| Edge::Branch {
+                    key: option.key.clone(),
+                    label: option.label.clone(),
+                    target: option.target.clone(),
+                })
+                .collect();
+        }
+        node.next_target()
+            .map(|target| Edge::Next {
+                target: target.to_owned(),
+            })
+            .into_iter()
+            .collect()
+    }
+
+    /// Every branch point in the deck and the targets its options lead to,
+    /// in document order — the fork structure a compact view needs without
+    /// walking each node's traversal itself.
+    #[must_use]
+    pub fn branch_structure(&self) -> BranchStructure {
+        BranchStructure {
+            forks: self
+                .nodes
+                .iter()
+                .filter_map(|node| {
+                    let bp = node.branch_point()?;
+                    let targets = bp.options.iter().map(|o| o.target.clone()).collect();
+                    Some((node.id.clone(), targets))
+                })
+                .collect(),
+        }
+    }
+
+    /// The deck's chapters: each distinct `section` name, in order of its
+    /// first node, with the indices of every node in it. Nodes without a
+    /// section belong to none. A section interleaved with another still
+    /// gets one entry, listing all of its nodes.
+    #[must_use]
+    pub fn sections(&self) -> Vec<(String, Vec<usize>)> {
+        let mut sections: Vec<(String, Vec<usize>)> = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let Some(name) = &node.section else {
+                continue;
+            };
+            match sections.iter_mut().find(|(n, _)| n == name) {
+                Some((_, members)) => members.push(index),
+                None => sections.push((name.clone(), vec![index])),
+            }
+        }
+        sections
+    }
+
+    /// The first node of the next section after the node at `index`: the
+    /// nearest section starting past it, skipping its own.
+    #[must_use]
+    pub fn next_section_start(&self, index: usize) -> Option<usize> {
+        let own = self.nodes.get(index)?.section.as_deref();
+        self.sections()
+            .iter()
+            .filter(|(name, _)| Some(name.as_str()) != own)
+            .map(|(_, members)| members[0])
+            .filter(|&start| start > index)
+            .min()
+    }
+
+    /// The first node of the section before the one the node at `index` is
+    /// in — or, for a node outside any section, of the nearest section
+    /// starting before it.
+    #[must_use]
+    pub fn previous_section_start(&self, index: usize) -> Option<usize> {
+        let node = self.nodes.get(index)?;
+        let sections = self.sections();
+        let own_start = node
+            .section
+            .as_deref()
+            .and_then(|own| sections.iter().find(|(name, _)| name == own))
+            .map_or(index, |(_, members)| members[0]);
+        sections
+            .iter()
+            .map(|(_, members)| members[0])
+            .filter(|&start| start < own_start)
+            .max()
+    }
+
+    /// The indices of the nodes with no way forward — no `next` and no
+    /// branch point — in deck order. A presenter reaching one can only go
+    /// back.
+    #[must_use]
+    pub fn leaf_nodes(&self) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].is_terminal())
+            .collect()
+    }
+
+    /// The nearest node with a branch point after the node at `index`,
+    /// walking each node's `next` link where it has one and deck order
+    /// where it doesn't. `None` when the walk runs off the end or comes
+    /// round to a node it already passed.
+    #[must_use]
+    pub fn next_branch_point_from(&self, index: usize) -> Option<usize> {
+        self.branch_point_along(index, |at| match self.nodes[at].next_target() {
+            Some(target) => self.nodes.iter().position(|n| n.id == target),
+            None => Some(at + 1).filter(|&next| next < self.nodes.len()),
+        })
+    }
+
+    /// The nearest node with a branch point before the node at `index`,
+    /// walking back to the first node whose `next` leads to each one, or
+    /// the node before it in deck order when none does.
+    #[must_use]
+    pub fn previous_branch_point_from(&self, index: usize) -> Option<usize> {
+        self.branch_point_along(index, |at| {
+            let id = self.nodes[at].id.as_str();
+            self.nodes
+                .iter()
+                .position(|n| n.next_target() == Some(id))
+                .or_else(|| at.checked_sub(1))
+        })
+    }
+
+    /// The first node with a branch point `step` reaches from `index`,
+    /// not counting `index` itself.
+    fn branch_point_along(
+        &self,
+        index: usize,
+        step: impl Fn(usize) -> Option<usize>,
+    ) -> Option<usize> {
+        if index >= self.nodes.len() {
+            return None;
+        }
+        let mut seen = HashSet::from([index]);
+        let mut at = index;
+        loop {
+            at = step(at)?;
+            if !seen.insert(at) {
+                return None;
+            }
+            if self.nodes[at].branch_point().is_some() {
+                return Some(at);
+            }
+        }
+    }
+
+    /// Locate a block by its anchor id: `(node index, block index)`, where
+    /// the block index is the top-level block in that node's `content`
+    /// that carries the anchor or contains (at any depth) the block that
+    /// does. Anchors are only unique within a node, so the first node in
+    /// array order wins.
+    #[must_use]
+    pub fn find_block_anchor(&self, anchor: &str) -> Option<(usize, usize)> {
+        self.nodes.iter().enumerate().find_map(|(n, node)| {
+            node.content
+                .iter()
+                .position(|block| block.has_anchor(anchor))
+                .map(|b| (n, b))
+        })
+    }
+
+    /// Replace every occurrence of `find` with `replace` in the words of
+    /// every block on every node — the same fields
+    /// [`ContentBlock::plain_text`] reads, plus code sources — returning
+    /// how many were replaced. Titles, speaker notes, ids, and
+    /// traversal are left alone. Matches don't overlap and are taken left
+    /// to right; an empty `find` matches nothing.
+    pub fn replace_text(&mut self, find: &str, replace: &str, case_sensitive: bool) -> usize {
+        self.nodes
+            .iter_mut()
+            .flat_map(|node| node.content.iter_mut())
+            .map(|block| block.replace_text(find, replace, case_sensitive))
+            .sum()
+    }
+
+    /// Point every `next` and branch option target named in `mapping` at
+    /// the id it maps to, leaving any other reference as it was. Node ids
+    /// themselves are left alone — the caller renames the nodes — so every
+    /// command that renames slides rewires them the same way. `visible-if`
+    /// conditions name branch points too; parsing those is the engine's
+    /// job, so it renames them separately.
+    pub fn rewrite_references(&mut self, mapping: &HashMap<NodeId, NodeId>) {
+        let rewrite = |id: &mut NodeId| {
+            if let Some(to) = mapping.get(id.as_str()) {
+                id.clone_from(to);
+            }
+        };
+        for node in &mut self.nodes {
+            match &mut node.traversal {
+                Some(TraversalSpec::Target(target)) => rewrite(target),
+                Some(TraversalSpec::Rules(rules)) => {
+                    if let Some(next) = &mut rules.next {
+                        rewrite(next);
+                    }
+                    for option in rules.branch_point.iter_mut().flat_map(|bp| &mut bp.options) {
+                        rewrite(&mut option.target);
+                    }
+                }
+                None => {}
+            }
+        }
+    }
 }
 
 /// Default values applied to all nodes unless overridden at the node level.
@@ -112,12 +379,18 @@ pub struct NodeDefaults {
 #[serde(rename_all = "kebab-case")]
 pub struct Node {
     /// Unique identifier for this node.
+    #[serde(deserialize_with = "node_id")]
     pub id: NodeId,
 
     /// Human-readable node title for navigation UIs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
+    /// The chapter this node belongs to. Consecutive nodes sharing a name
+    /// form one section the presenter can jump between.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+
     /// Presentation frame mode for this node.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub view_mode: Option<ViewMode>,
@@ -126,6 +399,16 @@ pub struct Node {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transition: Option<Transition>,
 
+    /// How long this node stays on screen when the deck advances by itself
+    /// (an unattended kiosk). Presenting by hand ignores it. Read from
+    /// seconds or any [`crate::parse_duration`] form, written as `"M:SS"`.
+    #[serde(
+        default,
+        with = "crate::duration::optional",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub duration: Option<Duration>,
+
     /// Notes visible only to the presenter, not the audience.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speaker_notes: Option<String>,
@@ -134,6 +417,29 @@ pub struct Node {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traversal: Option<TraversalSpec>,
 
+    /// A draft or backup node kept in the deck but not shown live: `next`
+    /// and `back` step over it while presenting, though an explicit goto
+    /// still reaches it. Omitted from the wire when `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hidden: bool,
+
+    /// This node ends the talk on purpose, so validation doesn't warn that
+    /// a path stops here. The deck's last node needs no mark. Omitted from
+    /// the wire when `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub terminal: bool,
+
+    /// A hex color (`"#1e1e2e"`) filling this node's background in place
+    /// of the theme's — see [`crate::parse_hex_color`]. A value that
+    /// doesn't parse falls back to the theme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+
+    /// A hex color replacing the theme's accent on this node: its
+    /// top-level headings, prompt, and selection marker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+
     /// The content blocks displayed at this node, in render order.
     pub content: Vec<ContentBlock>,
 }
@@ -181,8 +487,15 @@ impl Node {
             .unwrap_or_default()
     }
 
+    /// Every block's [`ContentBlock::plain_text`], a line apart — the
+    /// slide's words without its title or speaker notes.
+    #[must_use]
+    pub fn plain_text(&self) -> String {
+        join_lines(self.content.iter().map(ContentBlock::plain_text))
+    }
+
     /// The distinct positive `reveal` values used anywhere in this node's
-    /// content, recursively through `Container` children, sorted
+    /// content, recursively through container children and columns, sorted
     /// ascending. An empty result means the node uses no reveal marks —
     /// `next()` never pauses for reveal on such a node. Steps are ordinal
     /// over these distinct values, not raw integer magnitudes, so a gap
@@ -196,6 +509,74 @@ impl Node {
         levels.dedup();
         levels
     }
+
+    /// Roughly how many terminal rows this node's content fills at `width`
+    /// columns, fully revealed: every block's
+    /// [`ContentBlock::estimated_render_lines`] with a blank row between
+    /// blocks, plus the branch menu's prompt, options, and their
+    /// descriptions. Cheap enough to
+    /// ask on every keypress, never smaller for more content, and never
+    /// smaller at a narrower width — but only an estimate: the renderer's
+    /// own line flow is the exact answer.
+    #[must_use]
+    pub fn estimated_render_lines(&self, width: u16) -> usize {
+        let mut lines = stacked_lines(&self.content, width);
+        if let Some(bp) = self.branch_point() {
+            let prompt = bp.prompt.as_deref().unwrap_or("Choose a path:");
+            lines += usize::from(lines > 0) + wrapped_lines(prompt, width) + 1 + bp.options.len();
+            // Descriptions wrap under their label, indented past the
+            // option's number.
+            let indent = width.saturating_sub(7);
+            lines += bp
+                .options
+                .iter()
+                .filter_map(|o| o.description.as_deref())
+                .map(|d| wrapped_lines(d, indent))
+                .sum::<usize>();
+        }
+        lines
+    }
+
+    /// A hash of what this node shows, the same on every run and build —
+    /// for telling whether a slide changed without keeping a copy of it.
+    ///
+    /// Covers the `title`, `view-mode`, `background`, `accent`, every
+    /// content block in order, and the branch point's prompt and options.
+    /// Leaves out where the node sits and how it's paced: its `id`,
+    /// `section`, `transition`, `duration`, `speaker-notes`, `hidden`, and
+    /// `next` target — so two slides that look the same hash the same.
+    /// Hashed as protocol JSON with [`crate::fnv1a64`]: fields go in
+    /// protocol order and absent ones are omitted, so adding an optional
+    /// field to the protocol leaves existing hashes alone.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        #[derive(Serialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Shown<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            title: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            view_mode: Option<ViewMode>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            background: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            accent: Option<&'a str>,
+            content: &'a [ContentBlock],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            branch_point: Option<&'a BranchPoint>,
+        }
+        let shown = Shown {
+            title: self.title.as_deref(),
+            view_mode: self.view_mode,
+            background: self.background.as_deref(),
+            accent: self.accent.as_deref(),
+            content: &self.content,
+            branch_point: self.branch_point(),
+        };
+        // Plain data with string keys: serializing it can't fail.
+        let json = serde_json::to_vec(&shown).unwrap_or_default();
+        crate::fnv1a64(&json)
+    }
 }
 
 fn collect_reveal_levels(blocks: &[ContentBlock], out: &mut Vec<u32>) {
@@ -205,7 +586,19 @@ fn collect_reveal_levels(blocks: &[ContentBlock], out: &mut Vec<u32>) {
         {
             out.push(level);
         }
-        collect_reveal_levels(block.children(), out);
+        if let ContentBlock::List {
+            items,
+            incremental: true,
+            reveal,
+            ..
+        } = block
+        {
+            let base = reveal.unwrap_or(0);
+            out.extend((1..items.len()).filter_map(|i| base.checked_add(u32::try_from(i).ok()?)));
+        }
+        for children in block.child_blocks() {
+            collect_reveal_levels(children, out);
+        }
     }
 }
 
@@ -247,6 +640,40 @@ pub struct BranchPoint {
 
     /// The available options. The schema requires at least one.
     pub options: Vec<BranchOption>,
+
+    /// How long the presenter has to choose before
+    /// [`BranchPoint::default_index`]'s option is taken for them. Read
+    /// and written like [`Node::duration`].
+    #[serde(
+        default,
+        with = "crate::duration::optional",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout: Option<Duration>,
+
+    /// The `key` of the option a [`BranchPoint::timeout`] takes. Without
+    /// one, the first option is taken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_option: Option<String>,
+}
+
+impl BranchPoint {
+    /// The option whose key is [`BranchPoint::default_option`], ignoring
+    /// ASCII case the way a presenter's keypress does — or the first
+    /// option when none is named or none matches.
+    #[must_use]
+    pub fn default_index(&self) -> usize {
+        self.default_option
+            .as_deref()
+            .and_then(|wanted| {
+                self.options.iter().position(|o| {
+                    o.key
+                        .as_deref()
+                        .is_some_and(|key| key.eq_ignore_ascii_case(wanted))
+                })
+            })
+            .unwrap_or(0)
+    }
 }
 
 /// A single choice available at a branch point.
@@ -266,6 +693,77 @@ pub struct BranchOption {
     /// Optional description providing more detail about this choice.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// A short glyph (typically one emoji or symbol) shown in its own
+    /// column before the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+}
+
+// ─── Edges ───────────────────────────────────────────────────────────────────
+
+/// One edge out of a node, as [`Graph::outgoing_edges`] reports it. Not
+/// part of the wire format — a read-time view of a node's traversal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edge {
+    /// The node's `next` target, from either traversal form.
+    Next {
+        /// The node `next()` moves to.
+        target: NodeId,
+    },
+
+    /// One option of the node's branch point.
+    Branch {
+        /// The option's shortcut key, if it declares one.
+        key: Option<String>,
+        /// The option's display label.
+        label: String,
+        /// The node choosing this option moves to.
+        target: NodeId,
+    },
+}
+
+impl Edge {
+    /// The node this edge leads to.
+    #[must_use]
+    pub fn target(&self) -> &str {
+        match self {
+            Self::Next { target } | Self::Branch { target, .. } => target,
+        }
+    }
+}
+
+/// The deck's branch points, as [`Graph::branch_structure`] reports them:
+/// each forking node's id with its options' targets in option order. A
+/// read-time view, not part of the wire format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchStructure {
+    forks: Vec<(NodeId, Vec<NodeId>)>,
+}
+
+impl BranchStructure {
+    /// Whether the node `id` is a branch point.
+    #[must_use]
+    pub fn is_branch_point(&self, id: &str) -> bool {
+        self.targets(id).is_some()
+    }
+
+    /// The targets of `id`'s options, in option order, or `None` when `id`
+    /// is not a branch point.
+    #[must_use]
+    pub fn targets(&self, id: &str) -> Option<&[NodeId]> {
+        self.forks
+            .iter()
+            .find(|(fork, _)| fork == id)
+            .map(|(_, targets)| targets.as_slice())
+    }
+
+    /// Every branch point with its targets, in document order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[NodeId])> {
+        self.forks
+            .iter()
+            .map(|(fork, targets)| (fork.as_str(), targets.as_slice()))
+    }
 }
 
 // ─── Content blocks ──────────────────────────────────────────────────────────
@@ -290,6 +788,16 @@ pub enum ContentBlock {
         /// immediately. See [`Node::reveal_levels`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor naming this block for deep links and
+        /// cross-references. Unique within its node when present; see
+        /// [`Graph::find_block_anchor`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A condition over the presentation's choices, like
+        /// `ask == "deep"`: the block shows only while it holds. Absent
+        /// means always shown. See `fireside_engine::expr` for the syntax.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// Heading level from 1 (largest) to 6 (smallest).
         level: u8,
         /// The heading text content.
@@ -302,6 +810,12 @@ pub enum ContentBlock {
         /// visible. See [`ContentBlock::Heading::reveal`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// The text content.
         body: String,
     },
@@ -312,6 +826,12 @@ pub enum ContentBlock {
         /// visible. See [`ContentBlock::Heading::reveal`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// Programming language identifier for syntax highlighting.
         #[serde(skip_serializing_if = "Option::is_none")]
         language: Option<String>,
@@ -323,6 +843,15 @@ pub enum ContentBlock {
         /// Whether to display line numbers.
         #[serde(skip_serializing_if = "Option::is_none")]
         show_line_numbers: Option<bool>,
+        /// Meant to be pasted into a real shell or editor during a live
+        /// demo: the presenter badges it as copyable. Omitted from the wire
+        /// when `false`.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        runnable: bool,
+        /// What happens to a line wider than the block: clipped (the
+        /// default), or wrapped onto continuation rows.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        wrap: Option<CodeWrap>,
     },
 
     /// An ordered or unordered list of items.
@@ -331,11 +860,23 @@ pub enum ContentBlock {
         /// visible. See [`ContentBlock::Heading::reveal`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// Whether the list is ordered (numbered) or unordered (bulleted).
         #[serde(skip_serializing_if = "Option::is_none")]
         ordered: Option<bool>,
         /// The list items as strings.
         items: Vec<String>,
+        /// Builds the list up one item per reveal step: the first item
+        /// appears with the list, each later one a step after the one
+        /// before. Omitted from the wire when `false`. See
+        /// [`ContentBlock::revealed_items`].
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        incremental: bool,
     },
 
     /// A visual element with source URI and accessibility metadata.
@@ -344,11 +885,22 @@ pub enum ContentBlock {
         /// visible. See [`ContentBlock::Heading::reveal`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// URI or file path to the image source.
         src: String,
         /// Alternative text for accessibility.
         #[serde(skip_serializing_if = "Option::is_none")]
         alt: Option<String>,
+        /// The image carries no information — a flourish, a divider
+        /// graphic — so it needs no `alt`. Omitted from the wire when
+        /// `false`.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        decorative: bool,
         /// Optional caption displayed below the image.
         #[serde(skip_serializing_if = "Option::is_none")]
         caption: Option<String>,
@@ -360,12 +912,76 @@ pub enum ContentBlock {
         height: Option<u16>,
     },
 
+    /// A video, such as a screen recording. A terminal can't play it, so
+    /// engines that can't show the poster frame show a placeholder naming
+    /// the source instead.
+    Video {
+        /// The incremental-reveal step at which this block becomes
+        /// visible. See [`ContentBlock::Heading::reveal`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
+        /// URI or file path to the video source.
+        src: String,
+        /// URI or file path to a still image standing in for the video.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        poster: Option<String>,
+        /// Optional caption displayed below the video.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+
+    /// Text pulled in from another file when the deck loads — a shared
+    /// snippet kept in one place. The deck keeps only the reference;
+    /// `resolved` holds what the engine read, as a code or text block,
+    /// and is never written back.
+    Embed {
+        /// The incremental-reveal step at which this block becomes
+        /// visible. See [`ContentBlock::Heading::reveal`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
+        /// Path to the embedded file, relative to the deck file.
+        source: String,
+        /// The lines to take, 1-based and inclusive: `"12"` or `"12-20"`.
+        /// Absent takes the whole file.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        range: Option<String>,
+        /// The content read from `source` at load time. `None` until an
+        /// engine resolves it.
+        #[serde(skip)]
+        resolved: Option<Box<ContentBlock>>,
+    },
+
     /// A horizontal rule separating content sections.
     Divider {
         /// The incremental-reveal step at which this block becomes
         /// visible. See [`ContentBlock::Heading::reveal`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
+        /// The rule's line: solid (the default), dashed, dotted, or double.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<DividerStyle>,
+        /// Text centered in the rule, making it a labeled section
+        /// separator: `──── Part 2 ────`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        label: Option<String>,
     },
 
     /// A container for nested content blocks with layout control.
@@ -376,11 +992,45 @@ pub enum ContentBlock {
         /// their own `reveal` values.
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// The child content blocks within this container.
         children: Vec<ContentBlock>,
         /// Layout hint controlling how children are arranged.
         #[serde(skip_serializing_if = "Option::is_none")]
         layout: Option<ContainerLayout>,
+        /// How many cells wide a [`ContainerLayout::Grid`] is. Without it
+        /// the grid is as close to square as the child count allows;
+        /// other layouts ignore it.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        columns: Option<u8>,
+    },
+
+    /// Side-by-side columns of blocks — the common two-up layout without
+    /// a container per column.
+    Columns {
+        /// The incremental-reveal step at which this block becomes
+        /// visible. See [`ContentBlock::Heading::reveal`]. Hiding it hides
+        /// every column.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
+        /// Each column's blocks, left to right.
+        columns: Vec<Vec<ContentBlock>>,
+        /// Relative column widths, one per column: `[2, 1]` gives the
+        /// first column two thirds. Without it — or when it doesn't have
+        /// one positive value per column — the columns are equal.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ratios: Option<Vec<u16>>,
     },
 
     /// Pre-rendered ASCII/text art, generated at authoring time. See
@@ -391,6 +1041,12 @@ pub enum ContentBlock {
         /// visible. See [`ContentBlock::Heading::reveal`].
         #[serde(skip_serializing_if = "Option::is_none")]
         reveal: Option<u32>,
+        /// An optional anchor. See [`ContentBlock::Heading::id`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// A visibility condition. See [`ContentBlock::Heading::visible_if`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        visible_if: Option<String>,
         /// The pre-rendered multi-line art content, as plain text.
         art: String,
         /// Alternative text description, for anyone who can't see the
@@ -411,18 +1067,331 @@ impl ContentBlock {
             | Self::Code { reveal, .. }
             | Self::List { reveal, .. }
             | Self::Image { reveal, .. }
-            | Self::Divider { reveal }
+            | Self::Video { reveal, .. }
+            | Self::Embed { reveal, .. }
+            | Self::Divider { reveal, .. }
             | Self::AsciiArt { reveal, .. }
-            | Self::Container { reveal, .. } => *reveal,
+            | Self::Container { reveal, .. }
+            | Self::Columns { reveal, .. } => *reveal,
         }
     }
 
-    fn children(&self) -> &[ContentBlock] {
+    /// This block's own anchor id, if it has one.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
         match self {
-            Self::Container { children, .. } => children,
+            Self::Heading { id, .. }
+            | Self::Text { id, .. }
+            | Self::Code { id, .. }
+            | Self::List { id, .. }
+            | Self::Image { id, .. }
+            | Self::Video { id, .. }
+            | Self::Embed { id, .. }
+            | Self::Divider { id, .. }
+            | Self::AsciiArt { id, .. }
+            | Self::Container { id, .. }
+            | Self::Columns { id, .. } => id.as_deref(),
+        }
+    }
+
+    /// This block's own visibility condition, if it has one — the text as
+    /// written, parsed only where it's evaluated or validated.
+    #[must_use]
+    pub fn visible_if(&self) -> Option<&str> {
+        match self {
+            Self::Heading { visible_if, .. }
+            | Self::Text { visible_if, .. }
+            | Self::Code { visible_if, .. }
+            | Self::List { visible_if, .. }
+            | Self::Image { visible_if, .. }
+            | Self::Video { visible_if, .. }
+            | Self::Embed { visible_if, .. }
+            | Self::Divider { visible_if, .. }
+            | Self::AsciiArt { visible_if, .. }
+            | Self::Container { visible_if, .. }
+            | Self::Columns { visible_if, .. } => visible_if.as_deref(),
+        }
+    }
+
+    /// This block's own visibility condition, to set, change, or clear.
+    pub fn visible_if_mut(&mut self) -> &mut Option<String> {
+        match self {
+            Self::Heading { visible_if, .. }
+            | Self::Text { visible_if, .. }
+            | Self::Code { visible_if, .. }
+            | Self::List { visible_if, .. }
+            | Self::Image { visible_if, .. }
+            | Self::Video { visible_if, .. }
+            | Self::Embed { visible_if, .. }
+            | Self::Divider { visible_if, .. }
+            | Self::AsciiArt { visible_if, .. }
+            | Self::Container { visible_if, .. }
+            | Self::Columns { visible_if, .. } => visible_if,
+        }
+    }
+
+    /// The items of a list shown at `reveal_level`: all of them, unless the
+    /// list is `incremental`, where item `i` (from `0`) appears at the
+    /// list's own reveal step plus `i`. Empty for every other block kind.
+    #[must_use]
+    pub fn revealed_items(&self, reveal_level: u32) -> &[String] {
+        match self {
+            Self::List {
+                items,
+                incremental: true,
+                reveal,
+                ..
+            } => {
+                let Some(reached) = reveal_level.checked_sub(reveal.unwrap_or(0)) else {
+                    return &[];
+                };
+                let shown = usize::try_from(reached)
+                    .map_or(items.len(), |reached| reached.saturating_add(1));
+                &items[..shown.min(items.len())]
+            }
+            Self::List { items, .. } => items,
             _ => &[],
         }
     }
+
+    /// The words this block puts in front of an audience, as one string —
+    /// for search, export, and counting, so each doesn't walk the block
+    /// kinds its own way. Lists give an item per line; an image its alt
+    /// text then caption; a video its caption; ascii art its alt text (the
+    /// art itself is a picture, not words); a container its children's
+    /// text, and a columns block its columns' text left to right, a line
+    /// apart. Inline Markdown in text bodies is left as
+    /// written. A divider gives its label, or the empty string, and an
+    /// embed the text it resolved to, if it has been.
+    #[must_use]
+    pub fn plain_text(&self) -> String {
+        match self {
+            Self::Heading { text, .. } => text.clone(),
+            Self::Text { body, .. } => body.clone(),
+            Self::Code { source, .. } => source.trim_end_matches('\n').to_owned(),
+            Self::List { items, .. } => items.join("\n"),
+            Self::Image { alt, caption, .. } => {
+                join_lines([alt.clone(), caption.clone()].into_iter().flatten())
+            }
+            Self::Video { caption, .. } => caption.clone().unwrap_or_default(),
+            Self::Embed { resolved, .. } => resolved
+                .as_deref()
+                .map(ContentBlock::plain_text)
+                .unwrap_or_default(),
+            Self::AsciiArt { alt, .. } => alt.clone().unwrap_or_default(),
+            Self::Divider { label, .. } => label.clone().unwrap_or_default(),
+            Self::Container { .. } | Self::Columns { .. } => join_lines(
+                self.child_blocks()
+                    .into_iter()
+                    .flatten()
+                    .map(ContentBlock::plain_text),
+            ),
+        }
+    }
+
+    /// [`Graph::replace_text`] for this block and, for a container or
+    /// columns, the blocks inside it.
+    fn replace_text(&mut self, find: &str, replace: &str, case_sensitive: bool) -> usize {
+        let mut fields: Vec<&mut String> = match self {
+            Self::Heading { text, .. } => vec![text],
+            Self::Text { body, .. } => vec![body],
+            Self::Code { source, .. } => vec![source],
+            Self::List { items, .. } => items.iter_mut().collect(),
+            Self::Image { alt, caption, .. } => [alt, caption].into_iter().flatten().collect(),
+            Self::Video { caption, .. } => caption.iter_mut().collect(),
+            Self::AsciiArt { alt, .. } => alt.iter_mut().collect(),
+            // An embed's text lives in its source file, which the deck
+            // doesn't own.
+            Self::Divider { .. } | Self::Embed { .. } => Vec::new(),
+            Self::Container { children, .. } => {
+                return children
+                    .iter_mut()
+                    .map(|child| child.replace_text(find, replace, case_sensitive))
+                    .sum();
+            }
+            Self::Columns { columns, .. } => {
+                return columns
+                    .iter_mut()
+                    .flatten()
+                    .map(|child| child.replace_text(find, replace, case_sensitive))
+                    .sum();
+            }
+        };
+        fields
+            .iter_mut()
+            .map(|field| replace_matches(field, find, replace, case_sensitive))
+            .sum()
+    }
+
+    /// True if this block, or any block nested inside it, carries
+    /// `anchor` as its id.
+    fn has_anchor(&self, anchor: &str) -> bool {
+        self.id() == Some(anchor)
+            || self
+                .child_blocks()
+                .into_iter()
+                .flatten()
+                .any(|c| c.has_anchor(anchor))
+    }
+
+    /// The block lists nested directly inside this one: a container's
+    /// children, or each column of a columns block. Empty for every other
+    /// kind — so a walk over a node's content can recurse without naming
+    /// the nesting kinds itself.
+    #[must_use]
+    pub fn child_blocks(&self) -> Vec<&[ContentBlock]> {
+        match self {
+            Self::Container { children, .. } => vec![children],
+            Self::Columns { columns, .. } => columns.iter().map(Vec::as_slice).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Roughly how many terminal rows this block fills at `width` columns:
+    /// prose word-wrapped, code one row per source line plus its frame, a
+    /// list one wrapped item after another, an image or video a
+    /// placeholder plate and its caption, an embed what it resolved to or
+    /// a plate until it has. A container stacks its children;
+    /// a columns block is as tall as its tallest column. Never less than
+    /// one row, even for an empty block.
+    #[must_use]
+    pub fn estimated_render_lines(&self, width: u16) -> usize {
+        match self {
+            Self::Heading { text, .. } => wrapped_lines(text, width),
+            Self::Text { body, .. } => wrapped_lines(body, width),
+            Self::Code { source, wrap, .. } => {
+                let rows = match wrap.unwrap_or_default() {
+                    CodeWrap::None => source.lines().count(),
+                    CodeWrap::Char | CodeWrap::Word => source
+                        .lines()
+                        .map(|line| {
+                            line.chars()
+                                .count()
+                                .max(1)
+                                .div_ceil(usize::from(width.max(1)))
+                        })
+                        .sum(),
+                };
+                rows.max(1) + 2
+            }
+            Self::List { items, .. } => items
+                .iter()
+                .map(|item| wrapped_lines(item, width.saturating_sub(LIST_MARKER_WIDTH)))
+                .sum::<usize>()
+                .max(1),
+            Self::Image { caption, .. } | Self::Video { caption, .. } => {
+                PLATE_LINES + caption.as_deref().map_or(0, |c| wrapped_lines(c, width))
+            }
+            Self::Divider { .. } => 1,
+            Self::Embed { resolved, .. } => resolved
+                .as_deref()
+                .map_or(PLATE_LINES, |block| block.estimated_render_lines(width)),
+            Self::AsciiArt { art, .. } => art.lines().count().max(1),
+            Self::Container { children, .. } => stacked_lines(children, width).max(1),
+            Self::Columns { columns, .. } => {
+                let count = u16::try_from(columns.len()).unwrap_or(u16::MAX).max(1);
+                columns
+                    .iter()
+                    .map(|column| stacked_lines(column, width / count))
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            }
+        }
+    }
+}
+
+/// Columns a list item's marker and its gap take before the item's text.
+const LIST_MARKER_WIDTH: u16 = 3;
+
+/// Rows an image or video placeholder plate takes: top border, label,
+/// bottom border.
+const PLATE_LINES: usize = 3;
+
+/// `blocks` one after another, a blank row apart.
+fn stacked_lines(blocks: &[ContentBlock], width: u16) -> usize {
+    let rows: usize = blocks.iter().map(|b| b.estimated_render_lines(width)).sum();
+    rows + blocks.len().saturating_sub(1)
+}
+
+/// Rows `text` fills when each of its lines is word-wrapped greedily at
+/// `width` columns, breaking any word longer than a whole row. Counts
+/// characters, not display cells, and at least one row.
+fn wrapped_lines(text: &str, width: u16) -> usize {
+    let width = usize::from(width.max(1));
+    let rows: usize = text
+        .lines()
+        .map(|line| {
+            let mut rows = 1;
+            let mut used = 0;
+            for word in line.split_whitespace() {
+                let len = word.chars().count();
+                if used > 0 && used + 1 + len <= width {
+                    used += 1 + len;
+                    continue;
+                }
+                if used > 0 {
+                    rows += 1;
+                }
+                rows += (len - 1) / width;
+                used = (len - 1) % width + 1;
+            }
+            rows
+        })
+        .sum();
+    rows.max(1)
+}
+
+/// `parts` a line apart, skipping the empty ones so a divider or a bare
+/// image leaves no blank line behind.
+fn join_lines(parts: impl IntoIterator<Item = String>) -> String {
+    parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Replace each non-overlapping match of `find` in `text`, left to right,
+/// returning the count. `text` is only rewritten when something matched.
+fn replace_matches(text: &mut String, find: &str, replace: &str, case_sensitive: bool) -> usize {
+    if find.is_empty() {
+        return 0;
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut count = 0;
+    let mut rest = text.as_str();
+    while let Some(c) = rest.chars().next() {
+        if let Some(len) = match_len(rest, find, case_sensitive) {
+            out.push_str(replace);
+            rest = &rest[len..];
+            count += 1;
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if count > 0 {
+        *text = out;
+    }
+    count
+}
+
+/// The byte length of the match of `find` at the start of `text`, if there
+/// is one. Ignoring case compares character by character, so a match's
+/// length is measured in `text`, not in `find`.
+fn match_len(text: &str, find: &str, case_sensitive: bool) -> Option<usize> {
+    if case_sensitive {
+        return text.starts_with(find).then_some(find.len());
+    }
+    let mut chars = text.char_indices();
+    for wanted in find.chars() {
+        let (_, got) = chars.next()?;
+        if !got.to_lowercase().eq(wanted.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(end, _)| end))
 }
 
 // ─── Enums ───────────────────────────────────────────────────────────────────
@@ -462,6 +1431,36 @@ pub enum ContainerLayout {
     Columns,
     /// Centered vertically and horizontally.
     Center,
+    /// Equally sized cells, filled row by row in array order.
+    Grid,
+}
+
+/// The line a divider is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DividerStyle {
+    /// A solid line (default).
+    #[default]
+    Solid,
+    /// A dashed line.
+    Dashed,
+    /// A dotted line.
+    Dotted,
+    /// Two parallel lines.
+    Double,
+}
+
+/// How a code block fits a line wider than itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CodeWrap {
+    /// Clip at the edge, marking the cut (default).
+    #[default]
+    None,
+    /// Break at the last character that fits.
+    Char,
+    /// Break at a word boundary where one fits, marking each continuation.
+    Word,
 }
 
 #[cfg(test)]
@@ -474,13 +1473,15 @@ mod proptest_support {
     //! never needs a proc-macro crate — this module is `#[cfg(test)]`
     //! only.
 
+    use std::time::Duration;
+
     use proptest::collection::vec;
     use proptest::option;
     use proptest::prelude::*;
 
     use super::{
-        BranchOption, BranchPoint, ContainerLayout, ContentBlock, Graph, Node, NodeDefaults,
-        Transition, Traversal, TraversalSpec, ViewMode,
+        BranchOption, BranchPoint, CodeWrap, ContainerLayout, ContentBlock, DividerStyle, Graph,
+        Node, NodeDefaults, Transition, Traversal, TraversalSpec, ViewMode,
     };
 
     /// Short, printable strings — arbitrary Unicode `String` is valid input
@@ -497,99 +1498,214 @@ mod proptest_support {
         prop_oneof![Just(Transition::None), Just(Transition::Fade)]
     }
 
+    fn arbitrary_code_wrap() -> impl Strategy<Value = CodeWrap> {
+        prop_oneof![
+            Just(CodeWrap::None),
+            Just(CodeWrap::Char),
+            Just(CodeWrap::Word)
+        ]
+    }
+
+    fn arbitrary_divider_style() -> impl Strategy<Value = DividerStyle> {
+        prop_oneof![
+            Just(DividerStyle::Solid),
+            Just(DividerStyle::Dashed),
+            Just(DividerStyle::Dotted),
+            Just(DividerStyle::Double),
+        ]
+    }
+
     fn arbitrary_container_layout() -> impl Strategy<Value = ContainerLayout> {
         prop_oneof![
             Just(ContainerLayout::Stack),
             Just(ContainerLayout::Columns),
             Just(ContainerLayout::Center),
+            Just(ContainerLayout::Grid),
         ]
     }
 
+    /// The `(reveal, id)` pair every block variant shares.
+    fn arbitrary_block_meta() -> impl Strategy<Value = (Option<u32>, Option<String>)> {
+        (option::of(any::<u32>()), option::of(arbitrary_string()))
+    }
+
     /// A non-container leaf block: every `ContentBlock` variant except
     /// `Container` itself, which `arbitrary_content_block` wraps this in
     /// via `prop_recursive`.
     fn arbitrary_leaf_block() -> impl Strategy<Value = ContentBlock> {
-        let reveal = option::of(any::<u32>());
         prop_oneof![
-            (reveal.clone(), 1u8..=6, arbitrary_string()).prop_map(|(reveal, level, text)| {
-                ContentBlock::Heading {
+            (arbitrary_block_meta(), 1u8..=6, arbitrary_string()).prop_map(
+                |((reveal, id), level, text)| {
+                    ContentBlock::Heading {
+                        reveal,
+                        id,
+                        visible_if: None,
+                        level,
+                        text,
+                    }
+                }
+            ),
+            (arbitrary_block_meta(), arbitrary_string()).prop_map(|((reveal, id), body)| {
+                ContentBlock::Text {
                     reveal,
-                    level,
-                    text,
+                    id,
+                    visible_if: None,
+                    body,
                 }
             }),
-            (reveal.clone(), arbitrary_string())
-                .prop_map(|(reveal, body)| ContentBlock::Text { reveal, body }),
             (
-                reveal.clone(),
+                arbitrary_block_meta(),
                 option::of(arbitrary_string()),
                 arbitrary_string(),
                 option::of(vec(any::<u32>(), 0..4)),
                 option::of(any::<bool>()),
+                any::<bool>(),
+                option::of(arbitrary_code_wrap()),
             )
                 .prop_map(
-                    |(reveal, language, source, highlight_lines, show_line_numbers)| {
+                    |(
+                        (reveal, id),
+                        language,
+                        source,
+                        highlight_lines,
+                        show_line_numbers,
+                        runnable,
+                        wrap,
+                    )| {
                         ContentBlock::Code {
                             reveal,
+                            id,
+                            visible_if: None,
                             language,
                             source,
                             highlight_lines,
                             show_line_numbers,
+                            runnable,
+                            wrap,
                         }
-                    }
+                    },
                 ),
             (
-                reveal.clone(),
+                arbitrary_block_meta(),
                 option::of(any::<bool>()),
                 vec(arbitrary_string(), 0..5),
+                any::<bool>(),
             )
-                .prop_map(|(reveal, ordered, items)| ContentBlock::List {
-                    reveal,
-                    ordered,
-                    items
+                .prop_map(|((reveal, id), ordered, items, incremental)| {
+                    ContentBlock::List {
+                        reveal,
+                        id,
+                        visible_if: None,
+                        ordered,
+                        items,
+                        incremental,
+                    }
                 }),
             (
-                reveal.clone(),
+                arbitrary_block_meta(),
                 arbitrary_string(),
                 option::of(arbitrary_string()),
+                any::<bool>(),
                 option::of(arbitrary_string()),
                 option::of(any::<u16>()),
                 option::of(any::<u16>()),
             )
-                .prop_map(|(reveal, src, alt, caption, width, height)| {
-                    ContentBlock::Image {
+                .prop_map(
+                    |((reveal, id), src, alt, decorative, caption, width, height)| {
+                        ContentBlock::Image {
+                            reveal,
+                            id,
+                            visible_if: None,
+                            src,
+                            alt,
+                            decorative,
+                            caption,
+                            width,
+                            height,
+                        }
+                    }
+                ),
+            (
+                arbitrary_block_meta(),
+                arbitrary_string(),
+                option::of(arbitrary_string()),
+                option::of(arbitrary_string()),
+            )
+                .prop_map(|((reveal, id), src, poster, caption)| {
+                    ContentBlock::Video {
                         reveal,
+                        id,
+                        visible_if: None,
                         src,
-                        alt,
+                        poster,
                         caption,
-                        width,
-                        height,
                     }
                 }),
-            reveal
-                .clone()
-                .prop_map(|reveal| ContentBlock::Divider { reveal }),
-            (reveal, arbitrary_string(), option::of(arbitrary_string()))
-                .prop_map(|(reveal, art, alt)| ContentBlock::AsciiArt { reveal, art, alt }),
+            (
+                arbitrary_block_meta(),
+                option::of(arbitrary_divider_style()),
+                option::of(arbitrary_string()),
+            )
+                .prop_map(|((reveal, id), style, label)| ContentBlock::Divider {
+                    reveal,
+                    id,
+                    visible_if: None,
+                    style,
+                    label,
+                }),
+            (
+                arbitrary_block_meta(),
+                arbitrary_string(),
+                option::of(arbitrary_string())
+            )
+                .prop_map(|((reveal, id), art, alt)| ContentBlock::AsciiArt {
+                    reveal,
+                    id,
+                    visible_if: None,
+                    art,
+                    alt
+                }),
         ]
     }
 
-    /// Bounds `Container` nesting to a shallow depth during generation —
+    /// Bounds `Container`/`Columns` nesting to a shallow depth during generation —
     /// independent of (and much smaller than) the validator's depth-8
     /// limit added by this same feature; this bound only exists to keep
     /// generated cases small and shrinking fast.
-    fn arbitrary_content_block() -> impl Strategy<Value = ContentBlock> {
+    pub(super) fn arbitrary_content_block() -> impl Strategy<Value = ContentBlock> {
         arbitrary_leaf_block().prop_recursive(3, 12, 4, |inner| {
-            (
-                option::of(any::<u32>()),
-                vec(inner, 1..4),
-                option::of(arbitrary_container_layout()),
-            )
-                .prop_map(|(reveal, children, layout)| ContentBlock::Container {
-                    reveal,
-                    children,
-                    layout,
-                })
+            prop_oneof![
+                (
+                    arbitrary_block_meta(),
+                    vec(inner.clone(), 1..4),
+                    option::of(arbitrary_container_layout()),
+                    option::of(1u8..=4),
+                )
+                    .prop_map(|((reveal, id), children, layout, columns)| {
+                        ContentBlock::Container {
+                            reveal,
+                            id,
+                            visible_if: None,
+                            children,
+                            layout,
+                            columns,
+                        }
+                    }),
+                (
+                    arbitrary_block_meta(),
+                    vec(vec(inner, 0..3), 1..3),
+                    option::of(vec(1u16..4, 1..3)),
+                )
+                    .prop_map(|((reveal, id), columns, ratios)| {
+                        ContentBlock::Columns {
+                            reveal,
+                            id,
+                            visible_if: None,
+                            columns,
+                            ratios,
+                        }
+                    }),
+            ]
         })
     }
 
@@ -599,12 +1715,14 @@ mod proptest_support {
             option::of(arbitrary_string()),
             arbitrary_string(),
             option::of(arbitrary_string()),
+            option::of(arbitrary_string()),
         )
-            .prop_map(|(label, key, target, description)| BranchOption {
+            .prop_map(|(label, key, target, description, icon)| BranchOption {
                 label,
                 key,
                 target,
                 description,
+                icon,
             })
     }
 
@@ -612,8 +1730,15 @@ mod proptest_support {
         (
             option::of(arbitrary_string()),
             vec(arbitrary_branch_option(), 1..4),
+            option::of((1u64..=600).prop_map(Duration::from_secs)),
+            option::of(arbitrary_string()),
         )
-            .prop_map(|(prompt, options)| BranchPoint { prompt, options })
+            .prop_map(|(prompt, options, timeout, default_option)| BranchPoint {
+                prompt,
+                options,
+                timeout,
+                default_option,
+            })
     }
 
     fn arbitrary_traversal_spec() -> impl Strategy<Value = TraversalSpec> {
@@ -630,25 +1755,56 @@ mod proptest_support {
         ]
     }
 
+    /// Ids [`super::parse_node_id`] accepts.
+    fn arbitrary_node_id() -> impl Strategy<Value = String> {
+        "[a-zA-Z0-9_-]{1,12}"
+    }
+
     pub(super) fn arbitrary_node() -> impl Strategy<Value = Node> {
         (
-            arbitrary_string(),
+            arbitrary_node_id(),
+            option::of(arbitrary_string()),
             option::of(arbitrary_string()),
             option::of(arbitrary_view_mode()),
             option::of(arbitrary_transition()),
+            option::of((1u64..=600).prop_map(Duration::from_secs)),
             option::of(arbitrary_string()),
             option::of(arbitrary_traversal_spec()),
+            (any::<bool>(), any::<bool>()),
+            option::of(arbitrary_string()),
+            option::of(arbitrary_string()),
             vec(arbitrary_content_block(), 0..4),
         )
             .prop_map(
-                |(id, title, view_mode, transition, speaker_notes, traversal, content)| Node {
+                |(
                     id,
                     title,
+                    section,
                     view_mode,
                     transition,
+                    duration,
                     speaker_notes,
                     traversal,
+                    (hidden, terminal),
+                    background,
+                    accent,
                     content,
+                )| {
+                    Node {
+                        id,
+                        title,
+                        section,
+                        view_mode,
+                        transition,
+                        duration,
+                        speaker_notes,
+                        traversal,
+                        hidden,
+                        terminal,
+                        background,
+                        accent,
+                        content,
+                    }
                 },
             )
     }
@@ -769,6 +1925,155 @@ mod tests {
         assert!(node.is_terminal());
     }
 
+    #[test]
+    fn outgoing_edges_follow_traversal_without_inventing_fall_through() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[]},
+                {"id":"b","traversal":{"branch-point":{"options":[
+                    {"label":"Left","key":"l","target":"c"},
+                    {"label":"Right","target":"a"}
+                ]}},"content":[]},
+                {"id":"c","content":[]},
+                {"id":"d","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            graph.outgoing_edges(0),
+            vec![Edge::Next { target: "b".into() }]
+        );
+        let branch = graph.outgoing_edges(1);
+        assert_eq!(branch.len(), 2);
+        assert_eq!(
+            branch[0],
+            Edge::Branch {
+                key: Some("l".into()),
+                label: "Left".into(),
+                target: "c".into()
+            }
+        );
+        assert_eq!(branch[1].target(), "a");
+        assert!(graph.outgoing_edges(2).is_empty(), "terminal: no edge to d");
+        assert!(graph.outgoing_edges(9).is_empty());
+    }
+
+    #[test]
+    fn sections_group_nodes_and_jump_between_chapter_starts() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"cover","content":[]},
+                {"id":"a1","section":"Intro","content":[]},
+                {"id":"a2","section":"Intro","content":[]},
+                {"id":"b1","section":"Demo","content":[]},
+                {"id":"b2","section":"Demo","content":[]},
+                {"id":"c1","section":"Wrap-up","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            graph.sections(),
+            [
+                ("Intro".to_owned(), vec![1, 2]),
+                ("Demo".to_owned(), vec![3, 4]),
+                ("Wrap-up".to_owned(), vec![5]),
+            ]
+        );
+        assert_eq!(graph.next_section_start(0), Some(1));
+        assert_eq!(graph.next_section_start(1), Some(3));
+        assert_eq!(graph.next_section_start(4), Some(5));
+        assert_eq!(graph.next_section_start(5), None);
+        assert_eq!(graph.previous_section_start(4), Some(1));
+        assert_eq!(graph.previous_section_start(3), Some(1));
+        assert_eq!(graph.previous_section_start(2), None);
+        assert_eq!(graph.previous_section_start(0), None);
+    }
+
+    #[test]
+    fn leaf_nodes_are_the_ones_with_no_way_forward() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"ask","traversal":{"branch-point":{"options":[
+                    {"label":"Stop","target":"stop"},
+                    {"label":"On","target":"end"}
+                ]}},"content":[]},
+                {"id":"stop","content":[]},
+                {"id":"empty","traversal":{},"content":[]},
+                {"id":"end","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(graph.leaf_nodes(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn branch_point_scans_follow_the_path_both_ways() {
+        // Branch points at 3 and 7; every other node leads to the next.
+        let nodes: Vec<String> = (0..9)
+            .map(|i| match i {
+                3 | 7 => format!(
+                    r#"{{"id":"n{i}","traversal":{{"branch-point":{{"options":[
+                        {{"label":"On","target":"n{}"}}
+                    ]}}}},"content":[]}}"#,
+                    i + 1
+                ),
+                8 => r#"{"id":"n8","content":[]}"#.to_owned(),
+                _ => format!(r#"{{"id":"n{i}","traversal":"n{}","content":[]}}"#, i + 1),
+            })
+            .collect();
+        let graph =
+            Graph::from_json(&format!(r#"{{"nodes":[{}]}}"#, nodes.join(","))).expect("parse");
+        assert_eq!(graph.next_branch_point_from(4), Some(7));
+        assert_eq!(graph.previous_branch_point_from(4), Some(3));
+        assert_eq!(graph.next_branch_point_from(3), Some(7));
+        assert_eq!(graph.previous_branch_point_from(7), Some(3));
+        assert_eq!(graph.next_branch_point_from(7), None);
+        assert_eq!(graph.previous_branch_point_from(3), None);
+        assert_eq!(graph.next_branch_point_from(99), None);
+    }
+
+    #[test]
+    fn branch_point_scans_follow_next_links_and_stop_at_a_loop() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"c","content":[]},
+                {"id":"b","traversal":{"branch-point":{"options":[
+                    {"label":"Back","target":"a"}
+                ]}},"content":[]},
+                {"id":"c","traversal":"a","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(graph.next_branch_point_from(0), None, "a → c → a loops");
+        assert_eq!(graph.previous_branch_point_from(2), None, "c ← a ← c loops");
+    }
+
+    #[test]
+    fn branch_structure_lists_forks_and_their_targets() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"B","target":"b"},{"label":"C","target":"c"}
+                ]}},"content":[]},
+                {"id":"b","traversal":"c","content":[]},
+                {"id":"c","traversal":{"branch-point":{"options":[
+                    {"label":"Again","target":"a"}
+                ]}},"content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let forks = graph.branch_structure();
+        assert!(forks.is_branch_point("a"));
+        assert!(!forks.is_branch_point("b"));
+        assert_eq!(
+            forks.targets("a"),
+            Some(&["b".to_owned(), "c".to_owned()][..])
+        );
+        assert_eq!(forks.targets("b"), None);
+        let order: Vec<&str> = forks.iter().map(|(id, _)| id).collect();
+        assert_eq!(order, ["a", "c"]);
+    }
+
     #[test]
     fn unknown_fields_are_ignored() {
         // Legacy/foreign fields (`after`, `theme`, node `layout`) parse fine;
@@ -822,6 +2127,66 @@ mod tests {
         assert!(!json.contains("alt"), "absent alt stays absent: {json}");
     }
 
+    #[test]
+    fn video_block_uses_the_video_kind() {
+        let block: ContentBlock =
+            serde_json::from_str(r#"{"kind":"video","src":"demo.mp4","poster":"demo.png"}"#)
+                .expect("parse");
+        let ContentBlock::Video {
+            src,
+            poster,
+            caption,
+            ..
+        } = &block
+        else {
+            panic!("expected Video");
+        };
+        assert_eq!(src, "demo.mp4");
+        assert_eq!(poster.as_deref(), Some("demo.png"));
+        assert_eq!(caption, &None);
+
+        let json = serde_json::to_string(&block).expect("serialize");
+        assert!(json.contains(r#""kind":"video""#));
+        assert!(
+            !json.contains("caption"),
+            "absent caption stays absent: {json}"
+        );
+    }
+
+    #[test]
+    fn columns_block_nests_a_list_of_blocks_per_column() {
+        let block: ContentBlock = serde_json::from_str(
+            r#"{"kind":"columns","ratios":[2,1],"columns":[
+                [{"kind":"heading","level":2,"text":"Left"}],
+                [{"kind":"text","body":"Right","reveal":1,"id":"r"}]
+            ]}"#,
+        )
+        .expect("parse");
+        let ContentBlock::Columns {
+            columns, ratios, ..
+        } = &block
+        else {
+            panic!("expected Columns");
+        };
+        assert_eq!(columns.len(), 2);
+        assert_eq!(ratios.as_deref(), Some(&[2, 1][..]));
+        assert_eq!(block.plain_text(), "Left\nRight");
+        assert!(block.has_anchor("r"));
+
+        let node = Node {
+            content: vec![block.clone()],
+            ..serde_json::from_str(r#"{"id":"a","content":[]}"#).expect("parse")
+        };
+        assert_eq!(
+            node.reveal_levels(),
+            [1],
+            "reveal marks inside columns count"
+        );
+
+        let json = serde_json::to_string(&block).expect("serialize");
+        assert!(json.contains(r#""kind":"columns""#));
+    }
+
     #[test]
     fn unknown_kind_produces_clear_parse_error() {
         let err = Graph::from_json(r#"{"nodes":[{"id":"a","content":[{"kind":"not-a-kind"}]}]}"#)
@@ -833,6 +2198,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn plain_text_covers_every_block_kind() {
+        let block = |json: &str| serde_json::from_str::<ContentBlock>(json).expect("block");
+        let cases = [
+            (r#"{"kind":"heading","level":1,"text":"Hi"}"#, "Hi"),
+            (r#"{"kind":"text","body":"Some **bold**"}"#, "Some **bold**"),
+            (
+                r#"{"kind":"code","source":"fn main() {}\n"}"#,
+                "fn main() {}",
+            ),
+            (r#"{"kind":"list","items":["one","two"]}"#, "one\ntwo"),
+            (
+                r#"{"kind":"image","src":"c.png","alt":"A cat","caption":"Fig. 1"}"#,
+                "A cat\nFig. 1",
+            ),
+            (
+                r#"{"kind":"image","src":"c.png","caption":"Fig. 1"}"#,
+                "Fig. 1",
+            ),
+            (r#"{"kind":"video","src":"v.mp4","caption":"Demo"}"#, "Demo"),
+            (
+                r#"{"kind":"ascii-art","art":"/\\","alt":"A roof"}"#,
+                "A roof",
+            ),
+            (r#"{"kind":"divider"}"#, ""),
+        ];
+        for (json, text) in cases {
+            assert_eq!(block(json).plain_text(), text, "{json}");
+        }
+    }
+
+    #[test]
+    fn plain_text_recurses_into_containers_and_skips_empty_blocks() {
+        let node: Node = serde_json::from_str(
+            r#"{"id":"a","title":"Not content","speaker-notes":"Nor this","content":[
+                {"kind":"heading","level":2,"text":"Plan"},
+                {"kind":"divider"},
+                {"kind":"container","layout":"columns","children":[
+                    {"kind":"list","items":["build","ship"]},
+                    {"kind":"container","children":[
+                        {"kind":"divider"},
+                        {"kind":"text","body":"then rest"}
+                    ]}
+                ]}
+            ]}"#,
+        )
+        .expect("node");
+        assert_eq!(node.plain_text(), "Plan\nbuild\nship\nthen rest");
+    }
+
+    #[test]
+    fn inherited_defaults_stay_off_the_nodes_when_saved() {
+        let json = r#"{"defaults":{"view-mode":"fullscreen","transition":"fade"},"nodes":[
+            {"id":"a","content":[]},
+            {"id":"b","transition":"none","content":[]}
+        ]}"#;
+        let graph = Graph::from_json(json).expect("parse");
+        let defaults = graph.defaults.as_ref();
+        assert_eq!(
+            graph.nodes[0].resolved_view_mode(defaults),
+            ViewMode::Fullscreen
+        );
+        assert_eq!(graph.nodes[0].view_mode, None, "inherited, not copied");
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&graph.to_json_pretty().expect("serialize")).expect("reparse");
+        let a = &saved["nodes"][0];
+        assert!(a.get("view-mode").is_none() && a.get("transition").is_none());
+        assert_eq!(saved["nodes"][1]["transition"], "none", "explicit kept");
+    }
+
     #[test]
     fn view_mode_resolution_cascade() {
         let defaults = NodeDefaults {
@@ -870,6 +2306,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn block_anchor_round_trips_and_defaults_to_none() {
+        let block: ContentBlock =
+            serde_json::from_str(r#"{"kind":"divider","id":"break"}"#).expect("parse");
+        assert_eq!(block.id(), Some("break"));
+        let json = serde_json::to_string(&block).expect("serialize");
+        assert!(json.contains(r#""id":"break""#));
+
+        let unanchored: ContentBlock =
+            serde_json::from_str(r#"{"kind":"text","body":"x"}"#).expect("parse");
+        assert_eq!(unanchored.id(), None);
+        let json = serde_json::to_string(&unanchored).expect("serialize");
+        assert!(!json.contains("\"id\""), "absent id stays absent: {json}");
+    }
+
+    #[test]
+    fn hidden_defaults_to_false_and_is_omitted_unless_set() {
+        let node: Node = serde_json::from_str(r#"{"id":"a","content":[]}"#).expect("parse");
+        assert!(!node.hidden);
+        let json = serde_json::to_string(&node).expect("serialize");
+        assert!(!json.contains("hidden"), "false stays off the wire: {json}");
+
+        let node: Node =
+            serde_json::from_str(r#"{"id":"a","hidden":true,"content":[]}"#).expect("parse");
+        assert!(node.hidden);
+        let json = serde_json::to_string(&node).expect("serialize");
+        assert!(json.contains(r#""hidden":true"#));
+    }
+
+    #[test]
+    fn find_block_anchor_reports_the_top_level_block_holding_it() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","content":[{"kind":"text","body":"x"}]},
+                {"id":"b","content":[
+                    {"kind":"heading","level":1,"text":"t","id":"top"},
+                    {"kind":"container","children":[
+                        {"kind":"text","body":"deep","id":"nested"}
+                    ]}
+                ]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(graph.find_block_anchor("top"), Some((1, 0)));
+        assert_eq!(graph.find_block_anchor("nested"), Some((1, 1)));
+        assert_eq!(graph.find_block_anchor("missing"), None);
+    }
+
     #[test]
     fn reveal_levels_collects_distinct_positive_values_recursively() {
         let node: Node = serde_json::from_str(
@@ -887,12 +2371,262 @@ mod tests {
         assert_eq!(node.reveal_levels(), vec![1, 3]);
     }
 
+    #[test]
+    fn an_incremental_list_adds_a_reveal_step_per_later_item() {
+        let node: Node = serde_json::from_str(
+            r#"{"id":"a","content":[
+                {"kind":"list","reveal":1,"incremental":true,"items":["x","y","z"]},
+                {"kind":"text","body":"w","reveal":2}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(node.reveal_levels(), vec![1, 2, 3]);
+        let list = &node.content[0];
+        assert!(list.revealed_items(0).is_empty());
+        assert_eq!(list.revealed_items(1), ["x"]);
+        assert_eq!(list.revealed_items(3), ["x", "y", "z"]);
+        assert_eq!(list.revealed_items(u32::MAX), ["x", "y", "z"]);
+
+        let plain: ContentBlock =
+            serde_json::from_str(r#"{"kind":"list","items":["x","y"]}"#).expect("parse");
+        assert_eq!(plain.revealed_items(0), ["x", "y"]);
+        assert!(
+            !serde_json::to_string(&plain)
+                .expect("serialize")
+                .contains("incremental")
+        );
+    }
+
+    #[test]
+    fn rewrite_references_follows_the_mapping_and_nothing_else() {
+        let mut graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"old","content":[]},
+                {"id":"ask","traversal":{"next":"old","branch-point":{"options":[
+                    {"label":"One","target":"old"},
+                    {"label":"Two","target":"gone"},
+                    {"label":"Three","target":"keep"}
+                ]}},"content":[]},
+                {"id":"old","content":[]},
+                {"id":"gone","content":[]},
+                {"id":"keep","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let unchanged = graph.clone();
+        graph.rewrite_references(&HashMap::new());
+        assert_eq!(graph, unchanged, "an empty mapping is a no-op");
+
+        graph.rewrite_references(&HashMap::from([
+            ("old".to_owned(), "new".to_owned()),
+            ("gone".to_owned(), "went".to_owned()),
+        ]));
+        assert_eq!(graph.nodes[0].next_target(), Some("new"));
+        assert_eq!(graph.nodes[1].next_target(), Some("new"));
+        let targets: Vec<_> = graph.nodes[1]
+            .branch_point()
+            .expect("still a choice")
+            .options
+            .iter()
+            .map(|o| o.target.as_str())
+            .collect();
+        assert_eq!(targets, ["new", "went", "keep"]);
+        assert_eq!(graph.nodes[2].id, "old", "node ids are the caller's");
+    }
+
+    #[test]
+    fn replace_text_rewrites_every_block_and_counts_the_matches() {
+        let mut graph = Graph::from_json(
+            r#"{"title":"Acme","nodes":[
+                {"id":"acme","title":"Acme","content":[
+                    {"kind":"heading","level":1,"text":"Acme rocks"},
+                    {"kind":"container","children":[
+                        {"kind":"list","items":["acme one","ACME two"]}
+                    ]}
+                ]},
+                {"id":"b","content":[{"kind":"image","src":"acme.png","alt":"Acme logo"}]}
+            ]}"#,
+        )
+        .expect("parse");
+        let mut ignoring_case = graph.clone();
+
+        assert_eq!(graph.replace_text("Acme", "Globex", true), 2);
+        assert_eq!(
+            graph.nodes[0].plain_text(),
+            "Globex rocks\nacme one\nACME two"
+        );
+        assert_eq!(graph.nodes[1].plain_text(), "Globex logo");
+        assert_eq!(
+            graph.nodes[0].title.as_deref(),
+            Some("Acme"),
+            "titles untouched"
+        );
+        assert_eq!(graph.nodes[0].id, "acme");
+
+        assert_eq!(ignoring_case.replace_text("acme", "Globex", false), 4);
+        assert_eq!(
+            ignoring_case.nodes[0].plain_text(),
+            "Globex rocks\nGlobex one\nGlobex two"
+        );
+        assert_eq!(ignoring_case.replace_text("", "x", false), 0);
+        assert_eq!(ignoring_case.replace_text("aa", "a", true), 0);
+    }
+
+    #[test]
+    fn node_ids_accept_letters_digits_dashes_and_underscores() {
+        assert_eq!(parse_node_id("intro_2-b").as_deref(), Ok("intro_2-b"));
+        assert_eq!(parse_node_id("café"), Ok("café".to_owned()));
+        assert_eq!(parse_node_id(""), Err(IdError::Empty));
+        assert_eq!(
+            parse_node_id("my slide"),
+            Err(IdError::Whitespace("my slide".into()))
+        );
+        assert_eq!(
+            parse_node_id("q&a"),
+            Err(IdError::InvalidChar("q&a".into(), '&'))
+        );
+
+        let err = Graph::from_json(r#"{"nodes":[{"id":"my slide","content":[]}]}"#)
+            .expect_err("whitespace in an id");
+        assert!(
+            err.to_string()
+                .contains("node id \"my slide\" contains whitespace"),
+            "{err}"
+        );
+
+        let graph =
+            Graph::from_json(r#"{"nodes":[{"id":"a","traversal":"my slide","content":[]}]}"#)
+                .expect("a target isn't held to the id rule");
+        assert_eq!(graph.nodes[0].next_target(), Some("my slide"));
+    }
+
+    #[test]
+    fn normalize_node_id_slugifies_anything_with_a_letter_or_digit() {
+        assert_eq!(
+            normalize_node_id("Q&A: Wrap-up").as_deref(),
+            Some("q-a-wrap-up")
+        );
+        assert_eq!(
+            normalize_node_id("  Café  au lait ").as_deref(),
+            Some("café-au-lait")
+        );
+        assert_eq!(
+            normalize_node_id("snake_case").as_deref(),
+            Some("snake-case")
+        );
+        assert_eq!(normalize_node_id("?!"), None);
+        let id = normalize_node_id("Chapter 2 — Setup").expect("has words");
+        assert_eq!(parse_node_id(&id), Ok(id.clone()));
+    }
+
     #[test]
     fn reveal_levels_is_empty_when_no_block_uses_reveal() {
         let graph = Graph::from_json(HELLO).expect("parse");
         assert!(graph.nodes[0].reveal_levels().is_empty());
     }
 
+    #[test]
+    fn estimated_render_lines_grows_with_content() {
+        let node = |content: &str| {
+            Graph::from_json(&format!(
+                r#"{{"nodes":[{{"id":"a","content":[{content}]}}]}}"#
+            ))
+            .expect("parse")
+            .nodes
+            .remove(0)
+        };
+        let text = node(r#"{"kind":"text","body":"One short line."}"#);
+        let more = node(
+            r#"{"kind":"text","body":"One short line."},
+               {"kind":"code","source":"fn main() {}\nmain();"},
+               {"kind":"list","items":["a","b","c"]},
+               {"kind":"image","src":"a.png","caption":"A figure"}"#,
+        );
+        assert_eq!(text.estimated_render_lines(40), 1);
+        // 1 text + gap + 4 code + gap + 3 list + gap + 3 plate + 1 caption.
+        assert_eq!(more.estimated_render_lines(40), 15);
+        assert!(node("").estimated_render_lines(40) < text.estimated_render_lines(40));
+    }
+
+    #[test]
+    fn estimated_render_lines_counts_branch_descriptions() {
+        let menu = |description: &str| {
+            Graph::from_json(&format!(
+                r#"{{"nodes":[{{"id":"a","traversal":{{"branch-point":{{"options":[
+                    {{"label":"Go","target":"a"{description}}}
+                ]}}}},"content":[]}}]}}"#
+            ))
+            .expect("parse")
+            .nodes
+            .remove(0)
+        };
+        // Prompt + gap + one option.
+        assert_eq!(menu("").estimated_render_lines(40), 3);
+        assert_eq!(
+            menu(r#","description":"The long way, past every stop on the line""#)
+                .estimated_render_lines(40),
+            5
+        );
+    }
+
+    #[test]
+    fn estimated_render_lines_wraps_more_at_a_narrower_width() {
+        let block = ContentBlock::Text {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            body: "the quick brown fox jumps over the lazy dog".into(),
+        };
+        assert_eq!(block.estimated_render_lines(80), 1);
+        assert_eq!(block.estimated_render_lines(20), 3);
+        assert_eq!(block.estimated_render_lines(4), 12);
+        assert!(block.estimated_render_lines(10) > block.estimated_render_lines(20));
+    }
+
+    fn node_from(json: &str) -> Node {
+        Graph::from_json(&format!(r#"{{"nodes":[{json}]}}"#))
+            .expect("parse")
+            .nodes
+            .remove(0)
+    }
+
+    #[test]
+    fn content_hash_ignores_where_a_node_sits_and_is_pinned_across_runs() {
+        let plain =
+            node_from(r#"{"id":"a","title":"Hi","content":[{"kind":"text","body":"one"}]}"#);
+        let elsewhere = node_from(
+            r#"{"id":"b","title":"Hi","section":"Intro","traversal":"c","hidden":true,
+                "speaker-notes":"psst","duration":30,
+                "content":[{"kind":"text","body":"one"}]}"#,
+        );
+        assert_eq!(plain.content_hash(), elsewhere.content_hash());
+        // FNV-1a over the protocol JSON, so this never drifts between builds.
+        assert_eq!(plain.content_hash(), 0x91a3_c933_e10e_a3c2);
+    }
+
+    #[test]
+    fn content_hash_changes_with_every_block_edit() {
+        let base = r#"{"id":"a","content":[
+            {"kind":"heading","level":1,"text":"Title"},
+            {"kind":"text","body":"one"}
+        ]}"#;
+        let hash = node_from(base).content_hash();
+        for edited in [
+            base.replace(r#""one""#, r#""two""#),
+            base.replace(r#""level":1"#, r#""level":2"#),
+            base.replace(r#""body":"one""#, r#""body":"one","reveal":1"#),
+            r#"{"id":"a","content":[
+                {"kind":"text","body":"one"},
+                {"kind":"heading","level":1,"text":"Title"}
+            ]}"#
+            .to_owned(),
+            r#"{"id":"a","content":[{"kind":"heading","level":1,"text":"Title"}]}"#.to_owned(),
+            base.replace(r#""id":"a","#, r##""id":"a","accent":"#fa0","##),
+        ] {
+            assert_ne!(node_from(&edited).content_hash(), hash, "{edited}");
+        }
+    }
+
     proptest::proptest! {
         /// Any structurally valid `Graph` value survives a
         /// serialize/deserialize round trip unchanged (spec 008 US1,
@@ -926,5 +2660,36 @@ mod tests {
             sorted.dedup();
             proptest::prop_assert_eq!(&levels, &sorted, "levels must already be sorted and deduped");
         }
+
+        /// More content never shrinks `estimated_render_lines`, and neither
+        /// does a narrower width — the two properties a caller deciding
+        /// whether a slide overflows relies on.
+        #[test]
+        fn estimated_render_lines_is_monotonic(
+            node in proptest_support::arbitrary_node(),
+            extra in proptest_support::arbitrary_content_block(),
+            width in 1u16..120,
+        ) {
+            let lines = node.estimated_render_lines(width);
+            let mut longer = node.clone();
+            longer.content.push(extra);
+            proptest::prop_assert!(longer.estimated_render_lines(width) > lines);
+            proptest::prop_assert!(node.estimated_render_lines(width.saturating_sub(1).max(1)) >= lines);
+        }
+
+        /// A node's content hash survives a JSON round trip, and adding a
+        /// block changes it.
+        #[test]
+        fn content_hash_is_stable_and_sees_new_blocks(
+            node in proptest_support::arbitrary_node(),
+            extra in proptest_support::arbitrary_content_block(),
+        ) {
+            let json = serde_json::to_string(&node).expect("serialize");
+            let again: Node = serde_json::from_str(&json).expect("re-parse");
+            proptest::prop_assert_eq!(again.content_hash(), node.content_hash());
+            let mut longer = node.clone();
+            longer.content.push(extra);
+            proptest::prop_assert_ne!(longer.content_hash(), node.content_hash());
+        }
     }
 }
@@ -0,0 +1,203 @@
+//! A deck's table of contents: its sections, the slides in each, and the
+//! headings on every slide, in the order a presenter walks them rather
+//! than the order they happen to be declared in.
+//!
+//! The walk is the editor outline's: depth-first from the entry, `next`
+//! before branch options in declared order, each slide listed once at its
+//! first visit, and slides no path reaches appended in declaration order.
+//! A section heads each run of slides in it, so a section the walk leaves
+//! and comes back to is listed again where it resumes.
+
+use std::collections::HashSet;
+
+use crate::model::{ContentBlock, Graph, Node};
+
+/// What one line of an [`OutlineEntry`] list stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineKind {
+    /// A section name, heading the slides after it.
+    Section,
+    /// A slide, by title — or its first heading, or its id.
+    Node,
+    /// A heading on the slide above it.
+    Heading,
+}
+
+/// One line of a deck's outline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    /// What the line is.
+    pub kind: OutlineKind,
+    /// How deeply it nests: `0` for a section, one deeper for a slide in
+    /// a section (a slide outside one is at `0`), and one deeper again
+    /// for a heading on a slide.
+    pub depth: usize,
+    /// The words shown.
+    pub label: String,
+    /// The index in [`Graph::nodes`] of the slide the line jumps to: the
+    /// slide itself, or the one a heading is on, or the first slide of a
+    /// section's run.
+    pub index: usize,
+}
+
+impl Graph {
+    /// The deck's table of contents, one entry per line — see the module
+    /// docs for the order. Every slide appears, headings or not.
+    #[must_use]
+    pub fn outline(&self) -> Vec<OutlineEntry> {
+        let mut out = Vec::new();
+        let mut section: Option<&str> = None;
+        for index in walk_order(self) {
+            let node = &self.nodes[index];
+            if node.section.as_deref() != section {
+                section = node.section.as_deref();
+                if let Some(name) = section {
+                    out.push(OutlineEntry {
+                        kind: OutlineKind::Section,
+                        depth: 0,
+                        label: name.to_owned(),
+                        index,
+                    });
+                }
+            }
+            let depth = usize::from(section.is_some());
+            let mut headings = Vec::new();
+            collect_headings(&node.content, &mut headings);
+            let label = node_label(node, &headings);
+            out.push(OutlineEntry {
+                kind: OutlineKind::Node,
+                depth,
+                label: label.clone(),
+                index,
+            });
+            out.extend(
+                headings
+                    .into_iter()
+                    .filter(|text| text.trim() != label.trim())
+                    .map(|text| OutlineEntry {
+                        kind: OutlineKind::Heading,
+                        depth: depth + 1,
+                        label: text.to_owned(),
+                        index,
+                    }),
+            );
+        }
+        out
+    }
+}
+
+/// Node indices depth-first from the entry, then every node never reached.
+fn walk_order(graph: &Graph) -> Vec<usize> {
+    let position = |id: &str| graph.nodes.iter().position(|n| n.id == id);
+    let mut order = Vec::with_capacity(graph.nodes.len());
+    let mut seen = HashSet::new();
+    let mut stack: Vec<usize> = if graph.nodes.is_empty() {
+        Vec::new()
+    } else {
+        vec![0]
+    };
+    while let Some(index) = stack.pop() {
+        if !seen.insert(index) {
+            continue;
+        }
+        order.push(index);
+        // A stack pops last-pushed first, so push targets in reverse.
+        let node = &graph.nodes[index];
+        if let Some(bp) = node.branch_point() {
+            stack.extend(bp.options.iter().rev().filter_map(|o| position(&o.target)));
+        } else if let Some(next) = node.next_target().and_then(position) {
+            stack.push(next);
+        }
+    }
+    order.extend((0..graph.nodes.len()).filter(|i| !seen.contains(i)));
+    order
+}
+
+/// Every heading's text in `blocks`, in reading order, including those
+/// inside containers and columns.
+fn collect_headings<'a>(blocks: &'a [ContentBlock], out: &mut Vec<&'a str>) {
+    for block in blocks {
+        if let ContentBlock::Heading { text, .. } = block {
+            out.push(text);
+        }
+        for children in block.child_blocks() {
+            collect_headings(children, out);
+        }
+    }
+}
+
+/// A slide's title, else its first non-blank heading, else its id.
+fn node_label(node: &Node, headings: &[&str]) -> String {
+    node.title
+        .as_deref()
+        .filter(|title| !title.trim().is_empty())
+        .or_else(|| headings.iter().copied().find(|h| !h.trim().is_empty()))
+        .unwrap_or(&node.id)
+        .trim()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(graph: &Graph) -> Vec<String> {
+        graph
+            .outline()
+            .iter()
+            .map(|e| format!("{}{}", "  ".repeat(e.depth), e.label))
+            .collect()
+    }
+
+    #[test]
+    fn sections_slides_and_headings_nest_in_walk_order() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"intro","title":"Welcome","section":"Start","traversal":"why",
+                 "content":[{"kind":"heading","level":1,"text":"Welcome"},
+                            {"kind":"container","layout":"stack","children":[
+                                {"kind":"heading","level":2,"text":"Housekeeping"}]}]},
+                {"id":"end","content":[{"kind":"text","body":"bye"}]},
+                {"id":"why","section":"Body","traversal":"end",
+                 "content":[{"kind":"heading","level":1,"text":"Why Rust"},
+                            {"kind":"heading","level":2,"text":"Safety"}]},
+                {"id":"spare","section":"Body","content":[]}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            labels(&graph),
+            [
+                "Start",
+                "  Welcome",
+                "    Housekeeping",
+                "Body",
+                "  Why Rust",
+                "    Safety",
+                "end",
+                "Body",
+                "  spare",
+            ]
+        );
+        let outline = graph.outline();
+        let indices: Vec<usize> = outline.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [0, 0, 0, 2, 2, 2, 1, 3, 3]);
+        assert_eq!(outline[3].kind, OutlineKind::Section);
+        assert_eq!(outline[5].kind, OutlineKind::Heading);
+    }
+
+    #[test]
+    fn branches_are_walked_in_option_order_and_cycles_end() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"q","content":[],"traversal":{"branch-point":{"options":[
+                    {"label":"B","target":"b"},{"label":"C","target":"c"}]}}},
+                {"id":"c","traversal":"q","content":[]},
+                {"id":"b","traversal":"c","content":[]}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(labels(&graph), ["q", "b", "c"]);
+        assert_eq!(Graph::from_json(r#"{"nodes":[]}"#).unwrap().outline(), []);
+    }
+}
@@ -0,0 +1,340 @@
+//! Finding the properties a deck has that the protocol doesn't define.
+//!
+//! The data model ignores unknown fields so a deck written for a newer
+//! Fireside still opens, which also means a typo like `"transiton"` is
+//! silently dropped. [`unknown_fields`] reads the text a second time as
+//! plain JSON and checks every object's keys against what its position in
+//! the deck allows, so a caller that wants a strict parse can refuse them.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::error::CoreError;
+
+/// A property the protocol doesn't define, and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField {
+    /// The property's name, exactly as written.
+    pub field: String,
+    /// Where it sits in the document, e.g. `nodes[2].content[0].langauge`.
+    pub path: String,
+    /// The id of the node it belongs to, when it's inside one.
+    pub node: Option<String>,
+    /// A defined property one or two typos away, if there is one.
+    pub suggestion: Option<&'static str>,
+}
+
+impl fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown field \"{}\" at {}", self.field, self.path)?;
+        if let Some(node) = &self.node {
+            write!(f, " (slide \"{node}\")")?;
+        }
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " — did you mean \"{suggestion}\"?")?;
+        }
+        Ok(())
+    }
+}
+
+/// Every property in `text` the protocol doesn't define, node by node
+/// and block by block. Objects whose shape is wrong for their position are skipped —
+/// that's the typed parse's error to report, not this one's.
+///
+/// # Errors
+///
+/// [`CoreError::Parse`] when `text` isn't JSON.
+pub fn unknown_fields(text: &str) -> Result<Vec<UnknownField>, CoreError> {
+    let value: Value = serde_json::from_str(text)?;
+    let mut found = Vec::new();
+    if let Value::Object(graph) = &value {
+        check_graph(graph, &mut found);
+    }
+    Ok(found)
+}
+
+const GRAPH_KEYS: &[&str] = &[
+    "fireside-version",
+    "title",
+    "author",
+    "date",
+    "description",
+    "version",
+    "defaults",
+    "nodes",
+];
+const DEFAULTS_KEYS: &[&str] = &["view-mode", "transition"];
+const NODE_KEYS: &[&str] = &[
+    "id",
+    "title",
+    "section",
+    "view-mode",
+    "transition",
+    "duration",
+    "speaker-notes",
+    "traversal",
+    "hidden",
+    "terminal",
+    "background",
+    "accent",
+    "content",
+];
+const TRAVERSAL_KEYS: &[&str] = &["next", "branch-point"];
+const BRANCH_POINT_KEYS: &[&str] = &["prompt", "options", "timeout", "default-option"];
+const OPTION_KEYS: &[&str] = &["label", "key", "target", "description", "icon"];
+const BLOCK_KEYS: &[&str] = &["kind", "reveal", "id", "visible-if"];
+
+/// The properties a block of `kind` has beyond [`BLOCK_KEYS`], or `None`
+/// for a kind the protocol doesn't define.
+fn block_keys(kind: &str) -> Option<&'static [&'static str]> {
+    Some(match kind {
+        "heading" => &["level", "text"],
+        "text" => &["body"],
+        "code" => &[
+            "language",
+            "source",
+            "highlight-lines",
+            "show-line-numbers",
+            "runnable",
+            "wrap",
+        ],
+        "list" => &["ordered", "items", "incremental"],
+        "image" => &["src", "alt", "decorative", "caption", "width", "height"],
+        "video" => &["src", "poster", "caption"],
+        "embed" => &["source", "range"],
+        "divider" => &["style", "label"],
+        "container" => &["children", "layout", "columns"],
+        "columns" => &["columns", "ratios"],
+        "ascii-art" => &["art", "alt"],
+        _ => return None,
+    })
+}
+
+/// Where the walk is: the path so far and the node it's inside.
+struct At<'a> {
+    path: String,
+    node: Option<&'a str>,
+}
+
+impl At<'_> {
+    fn key(&self, key: &str) -> String {
+        if self.path.is_empty() {
+            key.to_owned()
+        } else {
+            format!("{}.{key}", self.path)
+        }
+    }
+
+    fn report(
+        &self,
+        map: &Map<String, Value>,
+        known: &[&[&'static str]],
+        found: &mut Vec<UnknownField>,
+    ) {
+        for key in map.keys() {
+            if known.iter().any(|set| set.contains(&key.as_str())) {
+                continue;
+            }
+            found.push(UnknownField {
+                field: key.clone(),
+                path: self.key(key),
+                node: self.node.map(str::to_owned),
+                suggestion: suggest(key, known.iter().copied().flatten().copied()),
+            });
+        }
+    }
+}
+
+fn check_graph(graph: &Map<String, Value>, found: &mut Vec<UnknownField>) {
+    let root = At {
+        path: String::new(),
+        node: None,
+    };
+    root.report(graph, &[GRAPH_KEYS], found);
+    if let Some(Value::Object(defaults)) = graph.get("defaults") {
+        let at = At {
+            path: root.key("defaults"),
+            node: None,
+        };
+        at.report(defaults, &[DEFAULTS_KEYS], found);
+    }
+    let Some(Value::Array(nodes)) = graph.get("nodes") else {
+        return;
+    };
+    for (i, node) in nodes.iter().enumerate() {
+        if let Value::Object(node) = node {
+            let at = At {
+                path: format!("nodes[{i}]"),
+                node: node.get("id").and_then(Value::as_str),
+            };
+            check_node(node, &at, found);
+        }
+    }
+}
+
+fn check_node(node: &Map<String, Value>, at: &At<'_>, found: &mut Vec<UnknownField>) {
+    at.report(node, &[NODE_KEYS], found);
+    if let Some(Value::Object(traversal)) = node.get("traversal") {
+        let at = At {
+            path: at.key("traversal"),
+            node: at.node,
+        };
+        at.report(traversal, &[TRAVERSAL_KEYS], found);
+        if let Some(Value::Object(bp)) = traversal.get("branch-point") {
+            let at = At {
+                path: at.key("branch-point"),
+                node: at.node,
+            };
+            at.report(bp, &[BRANCH_POINT_KEYS], found);
+            if let Some(Value::Array(options)) = bp.get("options") {
+                for (i, option) in options.iter().enumerate() {
+                    if let Value::Object(option) = option {
+                        let at = At {
+                            path: format!("{}[{i}]", at.key("options")),
+                            node: at.node,
+                        };
+                        at.report(option, &[OPTION_KEYS], found);
+                    }
+                }
+            }
+        }
+    }
+    if let Some(Value::Array(content)) = node.get("content") {
+        check_blocks(content, &at.key("content"), at.node, found);
+    }
+}
+
+fn check_blocks(blocks: &[Value], path: &str, node: Option<&str>, found: &mut Vec<UnknownField>) {
+    for (i, block) in blocks.iter().enumerate() {
+        let Value::Object(block) = block else {
+            continue;
+        };
+        let Some(kind) = block.get("kind").and_then(Value::as_str) else {
+            continue;
+        };
+        let Some(keys) = block_keys(kind) else {
+            continue;
+        };
+        let at = At {
+            path: format!("{path}[{i}]"),
+            node,
+        };
+        at.report(block, &[BLOCK_KEYS, keys], found);
+        match (kind, block.get("children"), block.get("columns")) {
+            ("container", Some(Value::Array(children)), _) => {
+                check_blocks(children, &at.key("children"), node, found);
+            }
+            ("columns", _, Some(Value::Array(columns))) => {
+                for (c, column) in columns.iter().enumerate() {
+                    if let Value::Array(column) = column {
+                        check_blocks(column, &format!("{}[{c}]", at.key("columns")), node, found);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The candidate closest to `key` within two single-character edits,
+/// case-insensitively, if any is.
+fn suggest(key: &str, candidates: impl Iterator<Item = &'static str>) -> Option<&'static str> {
+    let key = key.to_ascii_lowercase();
+    candidates
+        .map(|c| (edit_distance(&key, c), c))
+        .filter(|&(d, _)| d <= 2)
+        .min_by_key(|&(d, _)| d)
+        .map(|(_, c)| c)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Graph;
+
+    #[test]
+    fn unknown_fields_are_found_at_every_depth() {
+        let text = r#"{"titel":"Talk","defaults":{"mode":"fullscreen"},"nodes":[
+            {"id":"a","traversal":{"branch-point":{"options":[
+                {"label":"Go","target":"a","colour":"red"}
+            ]}},"content":[
+                {"kind":"code","source":"","langauge":"rust"},
+                {"kind":"container","children":[{"kind":"text","body":"","style":"bold"}]},
+                {"kind":"columns","columns":[[],[{"kind":"divider","width":2}]]}
+            ]}
+        ]}"#;
+        let paths: Vec<_> = unknown_fields(text)
+            .unwrap()
+            .into_iter()
+            .map(|u| u.path)
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "titel",
+                "defaults.mode",
+                "nodes[0].traversal.branch-point.options[0].colour",
+                "nodes[0].content[0].langauge",
+                "nodes[0].content[1].children[0].style",
+                "nodes[0].content[2].columns[1][0].width",
+            ]
+        );
+    }
+
+    #[test]
+    fn a_fully_populated_deck_has_no_unknown_fields() {
+        let text = r##"{
+            "fireside-version":"0.1.0","title":"T","author":"A","date":"2026-01-01",
+            "description":"D","version":"1","defaults":{"view-mode":"default","transition":"fade"},
+            "nodes":[{"id":"a","title":"A","section":"S","view-mode":"fullscreen","transition":"none",
+                "duration":"1:30","speaker-notes":"n","hidden":true,
+                "background":"#101010","accent":"#fa0",
+                "traversal":{"next":"a","branch-point":{"prompt":"?","timeout":"10s","default-option":"l","options":[
+                    {"label":"L","key":"l","target":"a","description":"d","icon":"i"}]}},
+                "content":[
+                    {"kind":"heading","reveal":1,"id":"h","level":1,"text":"H"},
+                    {"kind":"text","body":"B"},
+                    {"kind":"code","language":"rust","source":"","highlight-lines":[1],
+                        "show-line-numbers":true,"runnable":true,"wrap":"word"},
+                    {"kind":"list","ordered":true,"items":[],"incremental":true},
+                    {"kind":"image","src":"a.png","alt":"a","caption":"c","width":1,"height":1},
+                    {"kind":"video","src":"a.mp4","poster":"p","caption":"c"},
+                    {"kind":"embed","source":"a.rs","range":"1-2"},
+                    {"kind":"divider"},
+                    {"kind":"container","children":[],"layout":"stack","columns":2},
+                    {"kind":"columns","columns":[[]],"ratios":[1]},
+                    {"kind":"ascii-art","art":"*","alt":"star"}
+                ]}]
+        }"##;
+        assert_eq!(unknown_fields(text).unwrap(), []);
+        assert!(Graph::from_json(text).is_ok());
+    }
+
+    #[test]
+    fn a_serialized_graph_only_uses_known_fields() {
+        let text = r#"{"nodes":[{"id":"a","duration":90,"content":[
+            {"kind":"code","source":"x","runnable":true}]}]}"#;
+        let saved = Graph::from_json(text).unwrap().to_json_pretty().unwrap();
+        assert_eq!(unknown_fields(&saved).unwrap(), []);
+    }
+}
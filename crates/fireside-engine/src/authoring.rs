@@ -11,10 +11,12 @@
 //! See `specs/013-authoring-editor/contracts/authoring-ops.md` for the
 //! full per-operation contract this module implements.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 use fireside_core::{
-    BranchOption, BranchPoint, ContainerLayout, ContentBlock, Graph, Node, Traversal, TraversalSpec,
+    BranchOption, BranchPoint, ContainerLayout, ContentBlock, Graph, IdError, Node, Traversal,
+    TraversalSpec, normalize_node_id, parse_node_id,
 };
 use thiserror::Error;
 
@@ -26,7 +28,7 @@ use thiserror::Error;
 /// immediate parent).
 pub type BlockPath = Vec<usize>;
 
-/// The eight authoring-facing block kinds (spec FR-006), used by
+/// The authoring-facing block kinds (spec FR-006), used by
 /// [`Op::AddBlock`] to pick a placeholder [`ContentBlock`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BlockKind {
@@ -38,6 +40,22 @@ pub enum BlockKind {
     Divider,
     Container,
     AsciiArt,
+    Video,
+    Columns,
+}
+
+/// How slides created in the editor get their ids. Whatever the strategy,
+/// the result is made unique against the deck with a `-2`, `-3`, … suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdStrategy {
+    /// Derived from the title: "Intro to Rust" becomes `intro-to-rust`.
+    #[default]
+    Slug,
+    /// `slide-N`, where N is the deck's slide count after adding it.
+    Sequential,
+    /// Eight hex digits hashed from the title and the deck's existing ids,
+    /// like `3f9a0c1e` — short, stable, and free of any naming scheme.
+    Short,
 }
 
 /// One authoring operation. See
@@ -48,16 +66,41 @@ pub enum Op {
     AddSlide {
         after: String,
         title: String,
+        ids: IdStrategy,
     },
     DeleteSlide {
         id: String,
     },
     DuplicateSlide {
         id: String,
+        ids: IdStrategy,
+    },
+    /// Moves slide `id`'s top-level blocks from `at` on into a new slide
+    /// right after it, which takes over how `id` leaves (its `next` or its
+    /// choice) while `id` leads into it. The new slide is called `new_id`,
+    /// or one `ids` generates; it keeps `id`'s section, view mode,
+    /// colors, and hiddenness. Reveal steps are renumbered in each half.
+    /// Both halves need a block — see
+    /// [`AuthoringError::CannotSplitAt`].
+    SplitSlide {
+        id: String,
+        at: usize,
+        new_id: Option<String>,
+        ids: IdStrategy,
     },
+    /// Under [`IdStrategy::Slug`] the slide's id follows its new title
+    /// (and every edge into it is rewritten); other strategies keep the id.
     RetitleSlide {
         id: String,
         title: String,
+        ids: IdStrategy,
+    },
+    /// Renames slide `id` to `new_id`, rewriting every `next` and branch
+    /// target that pointed at it. `SetSlideId { id: new_id, new_id: id }`
+    /// undoes it exactly.
+    SetSlideId {
+        id: String,
+        new_id: String,
     },
     /// Move `id` so it immediately precedes `before` in the `next` chain
     /// (`before: None` moves it to the end of its run). Only supported
@@ -99,6 +142,38 @@ pub enum Op {
         index: usize,
         target: String,
     },
+    /// Sets (or, with `key: None`, clears) the shortcut key of answer
+    /// `index` on branch point `id`.
+    SetAnswerKey {
+        id: String,
+        index: usize,
+        key: Option<String>,
+    },
+    /// Gives branch point `id`'s answers the keys `1`–`9` then `a`–`z` in
+    /// order, skipping presenter-reserved letters — see
+    /// [`reindex_branch_keys`]. With `preserve`, an answer's own usable key
+    /// is kept and only the rest are filled in. Fails with
+    /// [`AuthoringError::KeysAlreadyAssigned`] when no key would change.
+    AssignAnswerKeys {
+        id: String,
+        preserve: bool,
+    },
+    /// Replaces the prompt shown above branch point `id`'s answers;
+    /// `None` (or blank text) removes it.
+    SetChoicePrompt {
+        id: String,
+        prompt: Option<String>,
+    },
+    /// Relabels answer `index` on branch point `id` and sets (or, with
+    /// `None`, clears) its shortcut key and its description in one step. A
+    /// blank description clears it too.
+    EditAnswer {
+        id: String,
+        index: usize,
+        label: String,
+        key: Option<String>,
+        description: Option<String>,
+    },
     AddBlock {
         node: String,
         path: BlockPath,
@@ -111,7 +186,7 @@ pub enum Op {
     },
     /// Replaces the block at `path` with `content`, preserving the
     /// existing block's `reveal` value (reveal is only ever changed by
-    /// [`Op::SetRevealStep`]).
+    /// [`Op::SetRevealStep`]), anchor, and visibility condition.
     EditBlock {
         node: String,
         path: BlockPath,
@@ -122,11 +197,66 @@ pub enum Op {
         path: BlockPath,
         to: usize,
     },
+    /// Replaces a slide's whole `content` in one step. Every block is
+    /// checked first — the op fails with [`AuthoringError::InvalidBlock`]
+    /// naming the first problem, changing nothing — and reveal steps are
+    /// renumbered to stay consecutive, as [`Op::SetRevealStep`] does.
+    ReplaceContent {
+        node: String,
+        content: Vec<ContentBlock>,
+    },
     SetRevealStep {
         node: String,
         path: BlockPath,
         step: Option<u32>,
     },
+    /// Replaces `find` with `replace` across every slide's blocks — see
+    /// [`Graph::replace_text`]. Fails with [`AuthoringError::NoMatches`]
+    /// when nothing matches, so an undo step is never spent on a no-op.
+    ReplaceText {
+        find: String,
+        replace: String,
+        case_sensitive: bool,
+    },
+}
+
+impl Op {
+    /// The one slide this op edits when it leaves every slide's id and
+    /// every `next` and branch target as they were — so only that slide,
+    /// and the slides pointing at it, can gain or lose a validation
+    /// finding (see `validation::validate_incremental`). `None` for an op
+    /// that adds, removes, renames, reorders, or rewires slides, or that
+    /// touches every slide at once.
+    #[must_use]
+    pub fn edited_slide(&self) -> Option<&str> {
+        match self {
+            Self::AddBlock { node, .. }
+            | Self::DeleteBlock { node, .. }
+            | Self::EditBlock { node, .. }
+            | Self::MoveBlock { node, .. }
+            | Self::ReplaceContent { node, .. }
+            | Self::SetRevealStep { node, .. } => Some(node),
+            Self::SetAnswerKey { id, .. }
+            | Self::AssignAnswerKeys { id, .. }
+            | Self::SetChoicePrompt { id, .. }
+            | Self::EditAnswer { id, .. } => Some(id),
+            Self::AddSlide { .. }
+            | Self::DeleteSlide { .. }
+            | Self::DuplicateSlide { .. }
+            | Self::SplitSlide { .. }
+            | Self::RetitleSlide { .. }
+            | Self::SetSlideId { .. }
+            | Self::ReorderSlide { .. }
+            | Self::SetNext { .. }
+            | Self::ClearNext { .. }
+            | Self::TurnIntoChoice { .. }
+            | Self::TurnBackIntoSlide { .. }
+            | Self::AddAnswer { .. }
+            | Self::RemoveAnswer { .. }
+            | Self::RetargetAnswer { .. }
+            | Self::ReplaceText { .. } => None,
+        }
+    }
 }
 
 /// Every precondition failure an [`Op`] can hit. Each variant carries
@@ -140,6 +270,8 @@ pub enum AuthoringError {
     UnknownSlide(String),
     #[error("\"{0}\" is already used by another slide")]
     DuplicateId(String),
+    #[error("{0}")]
+    InvalidId(IdError),
     #[error("the first slide can't be deleted")]
     CannotDeleteEntry,
     #[error("\"{0}\" is reached only through a branch answer — change the answer's target instead")]
@@ -152,14 +284,26 @@ pub enum AuthoringError {
     AlreadyABranchPoint(String),
     #[error("a branch point needs at least one answer")]
     LastAnswer,
+    #[error("another answer on this slide already uses '{0}' as its key")]
+    DuplicateAnswerKey(String),
+    #[error("an answer needs a label")]
+    EmptyAnswerLabel,
     #[error("no answer at position {0} on \"{1}\"")]
     UnknownAnswer(usize, String),
     #[error("no block at that position on \"{0}\"")]
     UnknownBlock(String),
     #[error("that position doesn't exist on \"{0}\"")]
     InvalidPath(String),
+    #[error("can't use that content on \"{0}\": {1}")]
+    InvalidBlock(String, String),
     #[error("the graph has no slides")]
     EmptyGraph,
+    #[error("nothing on any slide matches \"{0}\"")]
+    NoMatches(String),
+    #[error("every answer on \"{0}\" already has its key")]
+    KeysAlreadyAssigned(String),
+    #[error("\"{1}\" can't be split at block {0} — both halves need a block")]
+    CannotSplitAt(usize, String),
 }
 
 /// Applies `op` to `graph`, returning a new [`Graph`] on success. `graph`
@@ -173,10 +317,17 @@ pub enum AuthoringError {
 pub fn apply(graph: &Graph, op: &Op) -> Result<Graph, AuthoringError> {
     let mut next = graph.clone();
     match op {
-        Op::AddSlide { after, title } => add_slide(&mut next, after, title)?,
+        Op::AddSlide { after, title, ids } => add_slide(&mut next, after, title, *ids)?,
         Op::DeleteSlide { id } => delete_slide(&mut next, id)?,
-        Op::DuplicateSlide { id } => duplicate_slide(&mut next, id)?,
-        Op::RetitleSlide { id, title } => retitle_slide(&mut next, id, title)?,
+        Op::DuplicateSlide { id, ids } => duplicate_slide(&mut next, id, *ids)?,
+        Op::SplitSlide {
+            id,
+            at,
+            new_id,
+            ids,
+        } => split_slide(&mut next, id, *at, new_id.as_deref(), *ids)?,
+        Op::RetitleSlide { id, title, ids } => retitle_slide(&mut next, id, title, *ids)?,
+        Op::SetSlideId { id, new_id } => set_slide_id(&mut next, id, new_id)?,
         Op::ReorderSlide { id, before } => reorder_slide(&mut next, id, before.as_deref())?,
         Op::SetNext { id, target } => set_next(&mut next, id, target)?,
         Op::ClearNext { id } => clear_next(&mut next, id)?,
@@ -197,6 +348,29 @@ pub fn apply(graph: &Graph, op: &Op) -> Result<Graph, AuthoringError> {
         Op::RetargetAnswer { id, index, target } => {
             retarget_answer(&mut next, id, *index, target)?;
         }
+        Op::SetAnswerKey { id, index, key } => {
+            set_answer_key(&mut next, id, *index, key.as_deref())?;
+        }
+        Op::AssignAnswerKeys { id, preserve } => {
+            if assign_answer_keys(branch_point_mut(&mut next.nodes, id)?, *preserve) == 0 {
+                return Err(AuthoringError::KeysAlreadyAssigned(id.clone()));
+            }
+        }
+        Op::SetChoicePrompt { id, prompt } => set_choice_prompt(&mut next, id, prompt.as_deref())?,
+        Op::EditAnswer {
+            id,
+            index,
+            label,
+            key,
+            description,
+        } => edit_answer(
+            &mut next,
+            id,
+            *index,
+            label,
+            key.as_deref(),
+            description.as_deref(),
+        )?,
         Op::AddBlock {
             node,
             path,
@@ -210,40 +384,66 @@ pub fn apply(graph: &Graph, op: &Op) -> Result<Graph, AuthoringError> {
             content,
         } => edit_block(&mut next, node, path, content.clone())?,
         Op::MoveBlock { node, path, to } => move_block(&mut next, node, path, *to)?,
+        Op::ReplaceContent { node, content } => replace_content(&mut next, node, content)?,
         Op::SetRevealStep { node, path, step } => set_reveal_step(&mut next, node, path, *step)?,
+        Op::ReplaceText {
+            find,
+            replace,
+            case_sensitive,
+        } => {
+            if next.replace_text(find, replace, *case_sensitive) == 0 {
+                return Err(AuthoringError::NoMatches(find.clone()));
+            }
+        }
     }
     Ok(next)
 }
 
 // ─── Id / slug algorithm ───────────────────────────────────────────────────
 
-/// Derives a unique node id from `title`: lowercase, every run of
-/// non-alphanumeric characters becomes a single `-`, leading/trailing `-`
-/// trimmed, an empty result falls back to `"slide"`, then deduped against
-/// `existing` with `-2`, `-3`, … suffixes.
+/// Derives a unique node id from `title`: [`normalize_node_id`], falling
+/// back to `"slide"` when the title has no letters or digits, then deduped
+/// against `existing` with `-2`, `-3`, … suffixes.
 #[must_use]
 pub fn slug(title: &str, existing: &[String]) -> String {
-    let mut out = String::new();
-    let mut last_was_dash = true; // suppresses a leading dash
-    for ch in title.chars() {
-        if ch.is_alphanumeric() {
-            out.extend(ch.to_lowercase());
-            last_was_dash = false;
-        } else if !last_was_dash {
-            out.push('-');
-            last_was_dash = true;
+    let base = normalize_node_id(title).unwrap_or_else(|| "slide".to_owned());
+    dedupe(&base, existing)
+}
+
+/// A fresh, unique id for a slide about to join `graph`, chosen by
+/// `strategy`. `seed_title` is what the slug and short strategies derive
+/// from — the new slide's title, or its first heading.
+#[must_use]
+pub fn generate_node_id(graph: &Graph, strategy: IdStrategy, seed_title: &str) -> String {
+    let existing: Vec<String> = graph.nodes.iter().map(|n| n.id.clone()).collect();
+    match strategy {
+        IdStrategy::Slug => slug(seed_title, &existing),
+        IdStrategy::Sequential => dedupe(&format!("slide-{}", existing.len() + 1), &existing),
+        IdStrategy::Short => {
+            let mut hasher = DefaultHasher::new();
+            seed_title.hash(&mut hasher);
+            existing.hash(&mut hasher);
+            dedupe(&format!("{:08x}", hasher.finish() as u32), &existing)
         }
     }
-    while out.ends_with('-') {
-        out.pop();
-    }
-    if out.is_empty() {
-        out.push_str("slide");
-    }
-    dedupe(&out, existing)
 }
 
-fn dedupe(base: &str, existing: &[String]) -> String {
+/// The text a duplicated slide's id is seeded from: its title, or failing
+/// that its first heading.
+fn seed_title(node: &Node) -> String {
+    node.title.clone().unwrap_or_else(|| {
+        node.content
+            .iter()
+            .find_map(|block| match block {
+                ContentBlock::Heading { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// `base`, or `base-2`, `base-3`, … — the first not in `existing`.
+pub(crate) fn dedupe(base: &str, existing: &[String]) -> String {
     let seen: HashSet<&str> = existing.iter().map(String::as_str).collect();
     if !seen.contains(base) {
         return base.to_owned();
@@ -267,10 +467,14 @@ fn node_index(nodes: &[Node], id: &str) -> Result<usize, AuthoringError> {
         .ok_or_else(|| AuthoringError::UnknownSlide(id.to_owned()))
 }
 
-fn add_slide(graph: &mut Graph, after: &str, title: &str) -> Result<(), AuthoringError> {
+fn add_slide(
+    graph: &mut Graph,
+    after: &str,
+    title: &str,
+    ids: IdStrategy,
+) -> Result<(), AuthoringError> {
     let after_idx = node_index(&graph.nodes, after)?;
-    let existing: Vec<String> = graph.nodes.iter().map(|n| n.id.clone()).collect();
-    let new_id = slug(title, &existing);
+    let new_id = generate_node_id(graph, ids, title);
     let wire_as_next = graph.nodes[after_idx].traversal.is_none();
     let new_node = Node {
         id: new_id.clone(),
@@ -279,6 +483,12 @@ fn add_slide(graph: &mut Graph, after: &str, title: &str) -> Result<(), Authorin
         transition: None,
         speaker_notes: None,
         traversal: None,
+        section: None,
+        duration: None,
+        hidden: false,
+        terminal: false,
+        background: None,
+        accent: None,
         content: Vec::new(),
     };
     graph.nodes.insert(after_idx + 1, new_node);
@@ -313,7 +523,11 @@ fn delete_slide(graph: &mut Graph, id: &str) -> Result<(), AuthoringError> {
                     rules.next = replacement.clone();
                 }
                 if let Some(bp) = &mut rules.branch_point {
+                    let had_default = named_default(bp).is_some();
                     bp.options.retain(|o| o.target != id);
+                    if had_default && named_default(bp).is_none() {
+                        bp.default_option = None;
+                    }
                     if bp.options.is_empty() {
                         rules.branch_point = None;
                     }
@@ -331,11 +545,9 @@ fn delete_slide(graph: &mut Graph, id: &str) -> Result<(), AuthoringError> {
     Ok(())
 }
 
-fn duplicate_slide(graph: &mut Graph, id: &str) -> Result<(), AuthoringError> {
+fn duplicate_slide(graph: &mut Graph, id: &str, ids: IdStrategy) -> Result<(), AuthoringError> {
     let idx = node_index(&graph.nodes, id)?;
-    let existing: Vec<String> = graph.nodes.iter().map(|n| n.id.clone()).collect();
-    let title = graph.nodes[idx].title.clone().unwrap_or_default();
-    let new_id = slug(&title, &existing);
+    let new_id = generate_node_id(graph, ids, &seed_title(&graph.nodes[idx]));
     let mut clone = graph.nodes[idx].clone();
     clone.id = new_id;
     clone.traversal = None;
@@ -343,8 +555,62 @@ fn duplicate_slide(graph: &mut Graph, id: &str) -> Result<(), AuthoringError> {
     Ok(())
 }
 
-fn retitle_slide(graph: &mut Graph, id: &str, title: &str) -> Result<(), AuthoringError> {
+fn split_slide(
+    graph: &mut Graph,
+    id: &str,
+    at: usize,
+    new_id: Option<&str>,
+    ids: IdStrategy,
+) -> Result<(), AuthoringError> {
+    let idx = node_index(&graph.nodes, id)?;
+    if at == 0 || at >= graph.nodes[idx].content.len() {
+        return Err(AuthoringError::CannotSplitAt(at, id.to_owned()));
+    }
+    let new_id = match new_id {
+        Some(new_id) => {
+            let new_id = parse_node_id(new_id).map_err(AuthoringError::InvalidId)?;
+            if graph.nodes.iter().any(|n| n.id == new_id) {
+                return Err(AuthoringError::DuplicateId(new_id));
+            }
+            new_id
+        }
+        None => generate_node_id(graph, ids, &seed_title(&graph.nodes[idx])),
+    };
+    let original = &mut graph.nodes[idx];
+    let mut moved = original.content.split_off(at);
+    renumber_reveals(&mut original.content);
+    renumber_reveals(&mut moved);
+    let second = Node {
+        id: new_id.clone(),
+        title: None,
+        view_mode: original.view_mode,
+        transition: None,
+        speaker_notes: None,
+        traversal: original.traversal.take(),
+        section: original.section.clone(),
+        duration: None,
+        hidden: original.hidden,
+        terminal: std::mem::take(&mut original.terminal),
+        background: original.background.clone(),
+        accent: original.accent.clone(),
+        content: moved,
+    };
+    set_next_field(original, Some(new_id));
+    graph.nodes.insert(idx + 1, second);
+    Ok(())
+}
+
+fn retitle_slide(
+    graph: &mut Graph,
+    id: &str,
+    title: &str,
+    ids: IdStrategy,
+) -> Result<(), AuthoringError> {
     let idx = node_index(&graph.nodes, id)?;
+    if ids != IdStrategy::Slug {
+        graph.nodes[idx].title = Some(title.to_owned());
+        return Ok(());
+    }
     let others: Vec<String> = graph
         .nodes
         .iter()
@@ -355,29 +621,33 @@ fn retitle_slide(graph: &mut Graph, id: &str, title: &str) -> Result<(), Authori
     let new_id = slug(title, &others);
     graph.nodes[idx].title = Some(title.to_owned());
     if new_id != id {
-        graph.nodes[idx].id = new_id.clone();
-        for node in &mut graph.nodes {
-            match &mut node.traversal {
-                Some(TraversalSpec::Target(t)) if t == id => *t = new_id.clone(),
-                Some(TraversalSpec::Rules(rules)) => {
-                    if rules.next.as_deref() == Some(id) {
-                        rules.next = Some(new_id.clone());
-                    }
-                    if let Some(bp) = &mut rules.branch_point {
-                        for opt in &mut bp.options {
-                            if opt.target == id {
-                                opt.target = new_id.clone();
-                            }
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        rename_node(graph, idx, &new_id);
     }
     Ok(())
 }
 
+fn set_slide_id(graph: &mut Graph, id: &str, new_id: &str) -> Result<(), AuthoringError> {
+    let idx = node_index(&graph.nodes, id)?;
+    let new_id = parse_node_id(new_id).map_err(AuthoringError::InvalidId)?;
+    if new_id == id {
+        return Ok(());
+    }
+    if graph.nodes.iter().any(|n| n.id == new_id) {
+        return Err(AuthoringError::DuplicateId(new_id));
+    }
+    rename_node(graph, idx, &new_id);
+    Ok(())
+}
+
+/// Gives `graph.nodes[idx]` the id `new_id` and points every `next`,
+/// branch target, and `visible-if` that named its old id at the new one.
+fn rename_node(graph: &mut Graph, idx: usize, new_id: &str) {
+    let old_id = std::mem::replace(&mut graph.nodes[idx].id, new_id.to_owned());
+    let mapping = HashMap::from([(old_id, new_id.to_owned())]);
+    graph.rewrite_references(&mapping);
+    crate::expr::rename_variables(graph, &mapping);
+}
+
 /// The node whose plain `next` edge points at `id`, if any — `id`'s
 /// linear-run predecessor. `None` covers both "no predecessor" (id is the
 /// entry node) and "id is not reached via a plain `next` edge at all"
@@ -502,11 +772,14 @@ fn turn_into_choice(
         next: None,
         branch_point: Some(BranchPoint {
             prompt,
+            timeout: None,
+            default_option: None,
             options: vec![BranchOption {
                 label: first_label.to_owned(),
                 key: None,
                 target: first_target.to_owned(),
                 description: None,
+                icon: None,
             }],
         }),
     }));
@@ -545,18 +818,15 @@ fn add_answer(
     target: &str,
 ) -> Result<(), AuthoringError> {
     node_index(&graph.nodes, target)?;
-    if let Some(k) = key
-        && let Some(c) = k.chars().next()
-        && crate::validation::RESERVED_PRESENTER_KEYS.contains(&c)
-    {
-        return Err(AuthoringError::ReservedBranchKey(c));
-    }
+    check_reserved_key(key)?;
     let bp = branch_point_mut(&mut graph.nodes, id)?;
+    check_key_unused(bp, key, None)?;
     bp.options.push(BranchOption {
         label: label.to_owned(),
         key: key.map(str::to_owned),
         target: target.to_owned(),
         description: None,
+        icon: None,
     });
     Ok(())
 }
@@ -569,10 +839,34 @@ fn remove_answer(graph: &mut Graph, id: &str, index: usize) -> Result<(), Author
     if bp.options.len() == 1 {
         return Err(AuthoringError::LastAnswer);
     }
+    let had_default = named_default(bp).is_some();
     bp.options.remove(index);
+    if had_default && named_default(bp).is_none() {
+        bp.default_option = None;
+    }
     Ok(())
 }
 
+/// The answer `bp`'s `default-option` names, by key.
+fn named_default(bp: &BranchPoint) -> Option<usize> {
+    let wanted = bp.default_option.as_deref()?;
+    bp.options.iter().position(|o| {
+        o.key
+            .as_deref()
+            .is_some_and(|key| key.eq_ignore_ascii_case(wanted))
+    })
+}
+
+/// After answers' keys change, points `default-option` at the key the
+/// answer at `named` — the one it named before — has now, dropping it if
+/// that answer has none. A `default-option` that named no answer is left
+/// for validation to report.
+fn repoint_default(bp: &mut BranchPoint, named: Option<usize>) {
+    if let Some(i) = named {
+        bp.default_option = bp.options.get(i).and_then(|o| o.key.clone());
+    }
+}
+
 fn retarget_answer(
     graph: &mut Graph,
     id: &str,
@@ -589,50 +883,252 @@ fn retarget_answer(
     Ok(())
 }
 
+fn set_answer_key(
+    graph: &mut Graph,
+    id: &str,
+    index: usize,
+    key: Option<&str>,
+) -> Result<(), AuthoringError> {
+    check_reserved_key(key)?;
+    let bp = branch_point_mut(&mut graph.nodes, id)?;
+    check_key_unused(bp, key, Some(index))?;
+    let named = named_default(bp);
+    let opt = bp
+        .options
+        .get_mut(index)
+        .ok_or_else(|| AuthoringError::UnknownAnswer(index, id.to_owned()))?;
+    opt.key = key.map(str::to_owned);
+    repoint_default(bp, named);
+    Ok(())
+}
+
+fn set_choice_prompt(
+    graph: &mut Graph,
+    id: &str,
+    prompt: Option<&str>,
+) -> Result<(), AuthoringError> {
+    let bp = branch_point_mut(&mut graph.nodes, id)?;
+    bp.prompt = prompt.filter(|p| !p.trim().is_empty()).map(str::to_owned);
+    Ok(())
+}
+
+fn edit_answer(
+    graph: &mut Graph,
+    id: &str,
+    index: usize,
+    label: &str,
+    key: Option<&str>,
+    description: Option<&str>,
+) -> Result<(), AuthoringError> {
+    if label.trim().is_empty() {
+        return Err(AuthoringError::EmptyAnswerLabel);
+    }
+    check_reserved_key(key)?;
+    let bp = branch_point_mut(&mut graph.nodes, id)?;
+    check_key_unused(bp, key, Some(index))?;
+    let named = named_default(bp);
+    let opt = bp
+        .options
+        .get_mut(index)
+        .ok_or_else(|| AuthoringError::UnknownAnswer(index, id.to_owned()))?;
+    label.clone_into(&mut opt.label);
+    opt.key = key.map(str::to_owned);
+    opt.description = description
+        .filter(|d| !d.trim().is_empty())
+        .map(str::to_owned);
+    repoint_default(bp, named);
+    Ok(())
+}
+
+/// Gives every branch point's answers unique shortcut keys: `1`–`9`, then
+/// `a`–`z` without the presenter's reserved letters, in option order. With
+/// `preserve`, an answer keeps a key that is set, not reserved, and not
+/// already used by an earlier answer; the others get the first keys still
+/// free. Answers past the last available key are left without one.
+/// Returns how many answers' keys changed.
+pub fn reindex_branch_keys(graph: &mut Graph, preserve: bool) -> usize {
+    graph
+        .nodes
+        .iter_mut()
+        .filter_map(|node| match &mut node.traversal {
+            Some(TraversalSpec::Rules(Traversal {
+                branch_point: Some(bp),
+                ..
+            })) => Some(assign_answer_keys(bp, preserve)),
+            _ => None,
+        })
+        .sum()
+}
+
+/// [`reindex_branch_keys`] for one branch point.
+fn assign_answer_keys(bp: &mut BranchPoint, preserve: bool) -> usize {
+    let usable = |key: &str| {
+        key.chars()
+            .next()
+            .is_some_and(|c| !c.is_whitespace() && check_reserved_key(Some(key)).is_ok())
+    };
+    let mut taken: Vec<String> = Vec::new();
+    let mut kept = vec![false; bp.options.len()];
+    if preserve {
+        for (i, opt) in bp.options.iter().enumerate() {
+            if let Some(key) = opt.key.as_deref()
+                && usable(key)
+                && !taken.iter().any(|t| t.eq_ignore_ascii_case(key))
+            {
+                taken.push(key.to_owned());
+                kept[i] = true;
+            }
+        }
+    }
+    let mut fresh =
+        answer_key_candidates().filter(|k| !taken.iter().any(|t| t.eq_ignore_ascii_case(k)));
+    let named = named_default(bp);
+    let mut changed = 0;
+    for (opt, kept) in bp.options.iter_mut().zip(kept) {
+        if kept {
+            continue;
+        }
+        let key = fresh.next();
+        if opt.key != key {
+            opt.key = key;
+            changed += 1;
+        }
+    }
+    repoint_default(bp, named);
+    changed
+}
+
+/// The shortcut keys no answer on `bp` uses yet, in the order
+/// [`reindex_branch_keys`] hands them out.
+pub fn free_answer_keys(bp: &BranchPoint) -> impl Iterator<Item = String> + '_ {
+    answer_key_candidates().filter(|k| check_key_unused(bp, Some(k), None).is_ok())
+}
+
+/// `1`–`9`, then `a`–`z` without the presenter's reserved letters.
+fn answer_key_candidates() -> impl Iterator<Item = String> {
+    ('1'..='9')
+        .chain('a'..='z')
+        .map(String::from)
+        .filter(|k| check_reserved_key(Some(k)).is_ok())
+}
+
+fn check_reserved_key(key: Option<&str>) -> Result<(), AuthoringError> {
+    if let Some(k) = key
+        && let Some(c) = k.chars().next()
+        && crate::validation::RESERVED_PRESENTER_KEYS.contains(&c)
+    {
+        return Err(AuthoringError::ReservedBranchKey(c));
+    }
+    Ok(())
+}
+
+/// Refuses `key` when another answer than `except` already uses it —
+/// matched case-insensitively, the way the presenter matches keys.
+fn check_key_unused(
+    bp: &BranchPoint,
+    key: Option<&str>,
+    except: Option<usize>,
+) -> Result<(), AuthoringError> {
+    let Some(key) = key else {
+        return Ok(());
+    };
+    let taken = bp.options.iter().enumerate().any(|(i, opt)| {
+        Some(i) != except
+            && opt
+                .key
+                .as_deref()
+                .is_some_and(|k| k.eq_ignore_ascii_case(key))
+    });
+    if taken {
+        return Err(AuthoringError::DuplicateAnswerKey(key.to_owned()));
+    }
+    Ok(())
+}
+
 // ─── Block ops ──────────────────────────────────────────────────────────────
 
 fn placeholder(kind: BlockKind) -> ContentBlock {
     match kind {
         BlockKind::Heading => ContentBlock::Heading {
             reveal: None,
+            id: None,
+            visible_if: None,
             level: 2,
             text: "New heading".to_owned(),
         },
         BlockKind::Text => ContentBlock::Text {
             reveal: None,
+            id: None,
+            visible_if: None,
             body: "New text".to_owned(),
         },
         BlockKind::Code => ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: None,
             source: String::new(),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         },
         BlockKind::List => ContentBlock::List {
             reveal: None,
+            id: None,
+            visible_if: None,
             ordered: None,
             items: vec!["New item".to_owned()],
+            incremental: false,
         },
         BlockKind::Image => ContentBlock::Image {
             reveal: None,
+            id: None,
+            visible_if: None,
             src: String::new(),
             alt: None,
+            decorative: false,
             caption: None,
             width: None,
             height: None,
         },
-        BlockKind::Divider => ContentBlock::Divider { reveal: None },
+        BlockKind::Divider => ContentBlock::Divider {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            style: None,
+            label: None,
+        },
         BlockKind::Container => ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             children: Vec::new(),
             layout: Some(ContainerLayout::Stack),
+            columns: None,
         },
         BlockKind::AsciiArt => ContentBlock::AsciiArt {
             reveal: None,
+            id: None,
+            visible_if: None,
             art: String::new(),
             alt: None,
         },
+        BlockKind::Video => ContentBlock::Video {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            src: String::new(),
+            poster: None,
+            caption: None,
+        },
+        BlockKind::Columns => ContentBlock::Columns {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            columns: vec![Vec::new(), Vec::new()],
+            ratios: None,
+        },
     }
 }
 
@@ -709,8 +1205,47 @@ fn edit_block(
         .get_mut(index)
         .ok_or_else(|| AuthoringError::UnknownBlock(node.to_owned()))?;
     let preserved_reveal = existing.reveal();
+    let preserved_id = existing.id().map(str::to_owned);
+    let preserved_visible_if = existing.visible_if().map(str::to_owned);
     let mut replacement = content;
     set_reveal(&mut replacement, preserved_reveal);
+    set_id(&mut replacement, preserved_id);
+    set_visible_if(&mut replacement, preserved_visible_if);
+    // The code form has no runnable toggle or wrap choice, nor the list
+    // form an incremental one: an edit keeps them.
+    if let (
+        ContentBlock::Code {
+            runnable: was_runnable,
+            wrap: was_wrap,
+            ..
+        },
+        ContentBlock::Code { runnable, wrap, .. },
+    ) = (&*existing, &mut replacement)
+    {
+        *runnable |= *was_runnable;
+        if wrap.is_none() {
+            *wrap = *was_wrap;
+        }
+    }
+    if let (
+        ContentBlock::List {
+            incremental: true, ..
+        },
+        ContentBlock::List { incremental, .. },
+    ) = (&*existing, &mut replacement)
+    {
+        *incremental = true;
+    }
+    // Nor has the picture form a decorative toggle.
+    if let (
+        ContentBlock::Image {
+            decorative: true, ..
+        },
+        ContentBlock::Image { decorative, .. },
+    ) = (&*existing, &mut replacement)
+    {
+        *decorative = true;
+    }
     *existing = replacement;
     Ok(())
 }
@@ -741,12 +1276,35 @@ fn set_reveal(block: &mut ContentBlock, value: Option<u32>) {
         | ContentBlock::Code { reveal, .. }
         | ContentBlock::List { reveal, .. }
         | ContentBlock::Image { reveal, .. }
-        | ContentBlock::Divider { reveal }
+        | ContentBlock::Video { reveal, .. }
+        | ContentBlock::Embed { reveal, .. }
+        | ContentBlock::Divider { reveal, .. }
         | ContentBlock::AsciiArt { reveal, .. }
-        | ContentBlock::Container { reveal, .. } => *reveal = value,
+        | ContentBlock::Container { reveal, .. }
+        | ContentBlock::Columns { reveal, .. } => *reveal = value,
+    }
+}
+
+fn set_id(block: &mut ContentBlock, value: Option<String>) {
+    match block {
+        ContentBlock::Heading { id, .. }
+        | ContentBlock::Text { id, .. }
+        | ContentBlock::Code { id, .. }
+        | ContentBlock::List { id, .. }
+        | ContentBlock::Image { id, .. }
+        | ContentBlock::Video { id, .. }
+        | ContentBlock::Embed { id, .. }
+        | ContentBlock::Divider { id, .. }
+        | ContentBlock::AsciiArt { id, .. }
+        | ContentBlock::Container { id, .. }
+        | ContentBlock::Columns { id, .. } => *id = value,
     }
 }
 
+fn set_visible_if(block: &mut ContentBlock, value: Option<String>) {
+    *block.visible_if_mut() = value;
+}
+
 fn collect_positive_reveals(content: &[ContentBlock], out: &mut Vec<u32>) {
     for block in content {
         if let Some(v) = block.reveal()
@@ -754,13 +1312,13 @@ fn collect_positive_reveals(content: &[ContentBlock], out: &mut Vec<u32>) {
         {
             out.push(v);
         }
-        if let ContentBlock::Container { children, .. } = block {
+        for children in block.child_blocks() {
             collect_positive_reveals(children, out);
         }
     }
 }
 
-fn remap_reveals(content: &mut [ContentBlock], mapping: &std::collections::HashMap<u32, u32>) {
+fn remap_reveals(content: &mut [ContentBlock], mapping: &HashMap<u32, u32>) {
     for block in content {
         if let Some(v) = block.reveal()
             && v > 0
@@ -768,8 +1326,14 @@ fn remap_reveals(content: &mut [ContentBlock], mapping: &std::collections::HashM
         {
             set_reveal(block, Some(mapped));
         }
-        if let ContentBlock::Container { children, .. } = block {
-            remap_reveals(children, mapping);
+        match block {
+            ContentBlock::Container { children, .. } => remap_reveals(children, mapping),
+            ContentBlock::Columns { columns, .. } => {
+                for column in columns {
+                    remap_reveals(column, mapping);
+                }
+            }
+            _ => {}
         }
     }
 }
@@ -791,19 +1355,98 @@ fn set_reveal_step(
     set_reveal(block, step);
 
     let idx = node_index(&graph.nodes, node)?;
+    renumber_reveals(&mut graph.nodes[idx].content);
+    Ok(())
+}
+
+/// Maps the distinct positive reveal values in `content` onto `1..=n`, in
+/// order, so the step sequence never has a gap.
+fn renumber_reveals(content: &mut [ContentBlock]) {
     let mut distinct = Vec::new();
-    collect_positive_reveals(&graph.nodes[idx].content, &mut distinct);
+    collect_positive_reveals(content, &mut distinct);
     distinct.sort_unstable();
     distinct.dedup();
-    let mapping: std::collections::HashMap<u32, u32> = distinct
+    let mapping: HashMap<u32, u32> = distinct
         .iter()
         .enumerate()
         .map(|(i, &v)| (v, u32::try_from(i + 1).unwrap_or(u32::MAX)))
         .collect();
-    remap_reveals(&mut graph.nodes[idx].content, &mapping);
+    remap_reveals(content, &mapping);
+}
+
+fn replace_content(
+    graph: &mut Graph,
+    node: &str,
+    content: &[ContentBlock],
+) -> Result<(), AuthoringError> {
+    check_blocks(content)
+        .map_err(|reason| AuthoringError::InvalidBlock(node.to_owned(), reason))?;
+    let existing = node_content_mut(&mut graph.nodes, node)?;
+    *existing = content.to_vec();
+    renumber_reveals(existing);
     Ok(())
 }
 
+/// The per-block constraints the schema states but the serde model can't
+/// enforce, recursively through containers and columns. Returns the first problem
+/// found, in plain language.
+fn check_blocks(blocks: &[ContentBlock]) -> Result<(), String> {
+    for block in blocks {
+        match block {
+            ContentBlock::Heading { level, .. } if !(1..=6).contains(level) => {
+                return Err(format!("heading level {level} is outside 1–6"));
+            }
+            ContentBlock::Heading { .. } => {}
+            _ => {
+                for children in block.child_blocks() {
+                    check_blocks(children)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// ─── Block conversions ──────────────────────────────────────────────────────
+
+/// A text block's lines as a bulleted list: one item per line, each
+/// trimmed, blank lines dropped. Apply it with [`Op::EditBlock`], which
+/// keeps the original block's `reveal` and `id`.
+#[must_use]
+pub fn text_to_list(body: &str) -> ContentBlock {
+    ContentBlock::List {
+        reveal: None,
+        id: None,
+        visible_if: None,
+        ordered: None,
+        items: body
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect(),
+        incremental: false,
+    }
+}
+
+/// A list's items as one text block, a line each — the inverse of
+/// [`text_to_list`], minus the blank lines it dropped. Items are plain
+/// strings, so there is nothing nested to flatten.
+#[must_use]
+pub fn list_to_text(items: &[String]) -> ContentBlock {
+    ContentBlock::Text {
+        reveal: None,
+        id: None,
+        visible_if: None,
+        body: items
+            .iter()
+            .map(|item| item.trim())
+            .filter(|item| !item.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
 // ─── Outline ordering ───────────────────────────────────────────────────────
 
 /// One row of the editor's outline: a slide's id, its 1-based display
@@ -882,7 +1525,7 @@ pub fn outline_order(graph: &Graph) -> Vec<OutlineRow> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fireside_core::{ContentBlock as CB, Graph, Node};
+    use fireside_core::{CodeWrap, ContentBlock as CB, Graph, Node};
 
     fn node(id: &str) -> Node {
         Node {
@@ -892,6 +1535,12 @@ mod tests {
             transition: None,
             speaker_notes: None,
             traversal: None,
+            section: None,
+            duration: None,
+            hidden: false,
+            terminal: false,
+            background: None,
+            accent: None,
             content: Vec::new(),
         }
     }
@@ -936,32 +1585,105 @@ mod tests {
         assert_eq!(slug("Welcome", &existing), "welcome-3");
     }
 
-    // ── AddSlide ──
+    #[test]
+    fn slug_strategy_derives_from_the_title_and_dedupes() {
+        let mut g = graph_of(vec![node("a")]);
+        let id = generate_node_id(&g, IdStrategy::Slug, "Intro to Rust");
+        assert_eq!(id, "intro-to-rust");
+        g.nodes.push(node(&id));
+        assert_eq!(
+            generate_node_id(&g, IdStrategy::Slug, "Intro to Rust"),
+            "intro-to-rust-2"
+        );
+    }
 
     #[test]
-    fn add_slide_wires_as_next_when_predecessor_was_terminal() {
+    fn sequential_strategy_numbers_by_position_and_dedupes() {
+        let g = graph_of(vec![node("a"), node("slide-3")]);
+        assert_eq!(
+            generate_node_id(&g, IdStrategy::Sequential, "x"),
+            "slide-3-2"
+        );
         let g = graph_of(vec![node("a")]);
+        assert_eq!(generate_node_id(&g, IdStrategy::Sequential, "x"), "slide-2");
+    }
+
+    #[test]
+    fn short_strategy_is_eight_hex_digits_and_unique() {
+        let mut g = graph_of(vec![node("a")]);
+        let first = generate_node_id(&g, IdStrategy::Short, "Intro");
+        assert_eq!(first.len(), 8);
+        assert!(first.chars().all(|c| c.is_ascii_hexdigit()), "{first}");
+        g.nodes.push(node(&first));
+        assert_ne!(generate_node_id(&g, IdStrategy::Short, "Intro"), first);
+    }
+
+    #[test]
+    fn duplicate_seeds_its_id_from_the_first_heading_without_a_title() {
+        let mut a = node("a");
+        a.title = None;
+        a.content.push(CB::Heading {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            level: 1,
+            text: "Why Rust".into(),
+        });
         let g2 = apply(
-            &g,
-            &Op::AddSlide {
-                after: "a".into(),
-                title: "New One".into(),
+            &graph_of(vec![a]),
+            &Op::DuplicateSlide {
+                id: "a".into(),
+                ids: IdStrategy::Slug,
             },
         )
         .unwrap();
-        assert_eq!(g2.nodes.len(), 2);
-        assert_eq!(g2.nodes[0].next_target(), Some("new-one"));
+        assert_eq!(g2.nodes[1].id, "why-rust");
     }
 
     #[test]
-    fn add_slide_leaves_new_node_unwired_when_predecessor_already_has_next() {
-        let g = graph_of(vec![linked("a", "b"), node("b")]);
+    fn retitle_keeps_the_id_under_non_slug_strategies() {
+        let g = graph_of(vec![node("a")]);
         let g2 = apply(
             &g,
-            &Op::AddSlide {
-                after: "a".into(),
-                title: "C".into(),
-            },
+            &Op::RetitleSlide {
+                id: "a".into(),
+                title: "Brand New".into(),
+                ids: IdStrategy::Sequential,
+            },
+        )
+        .unwrap();
+        assert_eq!(g2.nodes[0].id, "a");
+        assert_eq!(g2.nodes[0].title.as_deref(), Some("Brand New"));
+    }
+
+    // ── AddSlide ──
+
+    #[test]
+    fn add_slide_wires_as_next_when_predecessor_was_terminal() {
+        let g = graph_of(vec![node("a")]);
+        let g2 = apply(
+            &g,
+            &Op::AddSlide {
+                after: "a".into(),
+                title: "New One".into(),
+                ids: IdStrategy::Slug,
+            },
+        )
+        .unwrap();
+        assert_eq!(g2.nodes.len(), 2);
+        assert_eq!(g2.nodes[0].next_target(), Some("new-one"));
+    }
+
+    #[test]
+    fn add_slide_leaves_new_node_unwired_when_predecessor_already_has_next() {
+        let g = graph_of(vec![linked("a", "b"), node("b")]);
+        let g2 = apply(
+            &g,
+            &Op::AddSlide {
+                after: "a".into(),
+                title: "C".into(),
+                ids: IdStrategy::Slug,
+            },
         )
         .unwrap();
         assert_eq!(g2.nodes[0].next_target(), Some("b"));
@@ -976,7 +1698,8 @@ mod tests {
                 &g,
                 &Op::AddSlide {
                     after: "zzz".into(),
-                    title: "X".into()
+                    title: "X".into(),
+                    ids: IdStrategy::Slug,
                 }
             ),
             Err(AuthoringError::UnknownSlide("zzz".into()))
@@ -1007,18 +1730,22 @@ mod tests {
             next: None,
             branch_point: Some(BranchPoint {
                 prompt: None,
+                timeout: None,
+                default_option: None,
                 options: vec![
                     BranchOption {
                         label: "B".into(),
                         key: None,
                         target: "b".into(),
                         description: None,
+                        icon: None,
                     },
                     BranchOption {
                         label: "C".into(),
                         key: None,
                         target: "c".into(),
                         description: None,
+                        icon: None,
                     },
                 ],
             }),
@@ -1048,11 +1775,14 @@ mod tests {
             next: None,
             branch_point: Some(BranchPoint {
                 prompt: None,
+                timeout: None,
+                default_option: None,
                 options: vec![BranchOption {
                     label: "B".into(),
                     key: None,
                     target: "b".into(),
                     description: None,
+                    icon: None,
                 }],
             }),
         }));
@@ -1062,6 +1792,7 @@ mod tests {
             &Op::RetitleSlide {
                 id: "b".into(),
                 title: "Brand New".into(),
+                ids: IdStrategy::Slug,
             },
         )
         .unwrap();
@@ -1083,12 +1814,115 @@ mod tests {
             &Op::RetitleSlide {
                 id: "a".into(),
                 title: "A".into(),
+                ids: IdStrategy::Slug,
             },
         )
         .unwrap();
         assert!(g2.node("a").is_some());
     }
 
+    // ── SetSlideId ──
+
+    #[test]
+    fn set_slide_id_moves_every_reference_and_renaming_back_undoes_it() {
+        let mut a = node("a");
+        a.traversal = Some(TraversalSpec::Rules(Traversal {
+            next: None,
+            branch_point: Some(BranchPoint {
+                prompt: None,
+                timeout: None,
+                default_option: None,
+                options: vec![BranchOption {
+                    label: "B".into(),
+                    key: None,
+                    target: "b".into(),
+                    description: None,
+                    icon: None,
+                }],
+            }),
+        }));
+        let g = graph_of(vec![a, linked("c", "b"), node("b")]);
+        let rename = |g: &Graph, id: &str, new_id: &str| {
+            apply(
+                g,
+                &Op::SetSlideId {
+                    id: id.into(),
+                    new_id: new_id.into(),
+                },
+            )
+        };
+        let g2 = rename(&g, "b", "finale").unwrap();
+        assert!(g2.node("b").is_none());
+        assert_eq!(g2.node("finale").unwrap().title.as_deref(), Some("b"));
+        assert_eq!(
+            g2.node("a").unwrap().branch_point().unwrap().options[0].target,
+            "finale"
+        );
+        assert_eq!(g2.node("c").unwrap().next_target(), Some("finale"));
+        assert_eq!(rename(&g2, "finale", "b").unwrap(), g);
+    }
+
+    #[test]
+    fn renaming_a_branch_point_carries_its_conditions_along() {
+        let g = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"ask","traversal":{"branch-point":{"options":[
+                    {"label":"Deep","key":"d","target":"b"}
+                ]}},"content":[]},
+                {"id":"b","content":[{"kind":"container","children":[
+                    {"kind":"text","body":"deep","visible-if":"ask == \"d\""}
+                ]}]}
+            ]}"#,
+        )
+        .unwrap();
+        let condition = |g: &Graph| match &g.node("b").unwrap().content[0] {
+            ContentBlock::Container { children, .. } => children[0].visible_if().map(str::to_owned),
+            other => panic!("expected the container, got {other:?}"),
+        };
+        for op in [
+            Op::SetSlideId {
+                id: "ask".into(),
+                new_id: "choose".into(),
+            },
+            Op::RetitleSlide {
+                id: "ask".into(),
+                title: "Choose".into(),
+                ids: IdStrategy::Slug,
+            },
+        ] {
+            let g2 = apply(&g, &op).unwrap();
+            assert_eq!(
+                condition(&g2).as_deref(),
+                Some(r#"choose == "d""#),
+                "{op:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn set_slide_id_refuses_a_taken_or_malformed_id() {
+        let g = graph_of(vec![linked("a", "b"), node("b")]);
+        let rename = |new_id: &str| {
+            apply(
+                &g,
+                &Op::SetSlideId {
+                    id: "a".into(),
+                    new_id: new_id.into(),
+                },
+            )
+        };
+        assert_eq!(rename("b"), Err(AuthoringError::DuplicateId("b".into())));
+        assert!(matches!(
+            rename("two words"),
+            Err(AuthoringError::InvalidId(IdError::Whitespace(_)))
+        ));
+        assert!(matches!(
+            rename(""),
+            Err(AuthoringError::InvalidId(IdError::Empty))
+        ));
+        assert_eq!(rename("a"), Ok(g.clone()));
+    }
+
     // ── ReorderSlide ──
 
     #[test]
@@ -1114,18 +1948,22 @@ mod tests {
             next: None,
             branch_point: Some(BranchPoint {
                 prompt: None,
+                timeout: None,
+                default_option: None,
                 options: vec![
                     BranchOption {
                         label: "B".into(),
                         key: None,
                         target: "b".into(),
                         description: None,
+                        icon: None,
                     },
                     BranchOption {
                         label: "C".into(),
                         key: None,
                         target: "c".into(),
                         description: None,
+                        icon: None,
                     },
                 ],
             }),
@@ -1143,6 +1981,102 @@ mod tests {
         );
     }
 
+    // ── SplitSlide ──
+
+    fn text(body: &str, reveal: Option<u32>) -> CB {
+        CB::Text {
+            reveal,
+            id: None,
+            visible_if: None,
+            body: body.into(),
+        }
+    }
+
+    fn split(id: &str, at: usize, new_id: Option<&str>) -> Op {
+        Op::SplitSlide {
+            id: id.into(),
+            at,
+            new_id: new_id.map(str::to_owned),
+            ids: IdStrategy::Slug,
+        }
+    }
+
+    #[test]
+    fn split_moves_the_tail_into_a_slide_linked_between_the_halves() {
+        let mut a = linked("a", "b");
+        a.section = Some("Intro".into());
+        a.speaker_notes = Some("stays".into());
+        a.content = vec![
+            text("one", None),
+            text("two", Some(2)),
+            text("three", Some(5)),
+            text("four", Some(7)),
+        ];
+        let g = graph_of(vec![a, node("b")]);
+        let g2 = apply(&g, &split("a", 2, None)).unwrap();
+
+        let ids: Vec<&str> = g2.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["a", "a-2", "b"]);
+        let (first, second) = (&g2.nodes[0], &g2.nodes[1]);
+        assert_eq!(first.next_target(), Some("a-2"));
+        assert_eq!(second.next_target(), Some("b"));
+        assert_eq!(first.content, [text("one", None), text("two", Some(1))]);
+        assert_eq!(
+            second.content,
+            [text("three", Some(1)), text("four", Some(2))]
+        );
+        assert_eq!(second.section.as_deref(), Some("Intro"));
+        assert_eq!(second.speaker_notes, None, "notes stay with the original");
+    }
+
+    #[test]
+    fn split_hands_a_choice_to_the_second_half_under_the_id_given() {
+        let mut q = node("q");
+        q.content = vec![text("setup", None), text("question", None)];
+        q.traversal = Some(TraversalSpec::Rules(Traversal {
+            next: None,
+            branch_point: Some(BranchPoint {
+                prompt: Some("Which way?".into()),
+                timeout: None,
+                default_option: None,
+                options: vec![BranchOption {
+                    label: "B".into(),
+                    key: None,
+                    target: "b".into(),
+                    description: None,
+                    icon: None,
+                }],
+            }),
+        }));
+        let g = graph_of(vec![q, node("b")]);
+        let g2 = apply(&g, &split("q", 1, Some("the-choice"))).unwrap();
+        assert_eq!(g2.nodes[0].next_target(), Some("the-choice"));
+        assert!(g2.nodes[0].branch_point().is_none());
+        assert_eq!(g2.nodes[1].id, "the-choice");
+        assert_eq!(g2.nodes[1].branch_point(), g.nodes[0].branch_point());
+    }
+
+    #[test]
+    fn split_refuses_an_empty_half_a_taken_id_and_an_unknown_slide() {
+        let mut a = node("a");
+        a.content = vec![text("one", None), text("two", None)];
+        let g = graph_of(vec![a, node("b")]);
+        for at in [0, 2, 9] {
+            assert_eq!(
+                apply(&g, &split("a", at, None)),
+                Err(AuthoringError::CannotSplitAt(at, "a".into()))
+            );
+        }
+        assert_eq!(
+            apply(&g, &split("a", 1, Some("b"))),
+            Err(AuthoringError::DuplicateId("b".into()))
+        );
+        assert_eq!(
+            apply(&g, &split("zz", 1, None)),
+            Err(AuthoringError::UnknownSlide("zz".into()))
+        );
+    }
+
     // ── Choice ops ──
 
     #[test]
@@ -1204,6 +2138,170 @@ mod tests {
         );
     }
 
+    #[test]
+    fn answer_keys_stay_unique_on_add_and_edit() {
+        let g = graph_of(vec![node("a"), node("b"), node("c")]);
+        let g = apply(
+            &g,
+            &Op::TurnIntoChoice {
+                id: "a".into(),
+                prompt: None,
+                first_label: "B".into(),
+                first_target: "b".into(),
+            },
+        )
+        .unwrap();
+        let g = apply(
+            &g,
+            &Op::SetAnswerKey {
+                id: "a".into(),
+                index: 0,
+                key: Some("x".into()),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            apply(
+                &g,
+                &Op::AddAnswer {
+                    id: "a".into(),
+                    label: "C".into(),
+                    key: Some("X".into()),
+                    target: "c".into()
+                }
+            ),
+            Err(AuthoringError::DuplicateAnswerKey("X".into()))
+        );
+        // Re-saving an answer with its own key is not a clash.
+        assert!(
+            apply(
+                &g,
+                &Op::EditAnswer {
+                    id: "a".into(),
+                    index: 0,
+                    label: "Bee".into(),
+                    key: Some("x".into()),
+                    description: None,
+                }
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn the_default_answer_follows_its_key_and_goes_when_the_answer_does() {
+        let option = |key: &str, target: &str| BranchOption {
+            label: target.to_uppercase(),
+            key: Some(key.into()),
+            target: target.into(),
+            description: None,
+            icon: None,
+        };
+        let mut q = node("q");
+        q.traversal = Some(TraversalSpec::Rules(Traversal {
+            next: None,
+            branch_point: Some(BranchPoint {
+                prompt: None,
+                timeout: None,
+                default_option: Some("y".into()),
+                options: vec![option("x", "b"), option("y", "c")],
+            }),
+        }));
+        let g = graph_of(vec![q, node("b"), node("c")]);
+        let default = |g: &Graph| {
+            g.nodes[0]
+                .branch_point()
+                .and_then(|bp| bp.default_option.clone())
+        };
+
+        let rekeyed = apply(
+            &g,
+            &Op::SetAnswerKey {
+                id: "q".into(),
+                index: 1,
+                key: Some("z".into()),
+            },
+        )
+        .unwrap();
+        assert_eq!(default(&rekeyed).as_deref(), Some("z"));
+        let other = apply(
+            &g,
+            &Op::RemoveAnswer {
+                id: "q".into(),
+                index: 0,
+            },
+        )
+        .unwrap();
+        assert_eq!(default(&other).as_deref(), Some("y"));
+        let removed = apply(
+            &g,
+            &Op::RemoveAnswer {
+                id: "q".into(),
+                index: 1,
+            },
+        )
+        .unwrap();
+        assert_eq!(default(&removed), None);
+        let deleted = apply(&g, &Op::DeleteSlide { id: "c".into() }).unwrap();
+        assert_eq!(default(&deleted), None);
+    }
+
+    #[test]
+    fn edit_answer_and_prompt_update_the_branch_point() {
+        let g = graph_of(vec![node("a"), node("b")]);
+        let g = apply(
+            &g,
+            &Op::TurnIntoChoice {
+                id: "a".into(),
+                prompt: Some("Old?".into()),
+                first_label: "B".into(),
+                first_target: "b".into(),
+            },
+        )
+        .unwrap();
+        let g = apply(
+            &g,
+            &Op::EditAnswer {
+                id: "a".into(),
+                index: 0,
+                label: "Go to B".into(),
+                key: Some("b".into()),
+                description: Some("The long way round".into()),
+            },
+        )
+        .unwrap();
+        let g = apply(
+            &g,
+            &Op::SetChoicePrompt {
+                id: "a".into(),
+                prompt: Some("  ".into()),
+            },
+        )
+        .unwrap();
+        let bp = g.node("a").unwrap().branch_point().unwrap();
+        assert_eq!(bp.prompt, None, "a blank prompt clears it");
+        assert_eq!(bp.options[0].label, "Go to B");
+        assert_eq!(bp.options[0].key.as_deref(), Some("b"));
+        assert_eq!(
+            bp.options[0].description.as_deref(),
+            Some("The long way round")
+        );
+        assert_eq!(bp.options[0].target, "b");
+        assert_eq!(
+            apply(
+                &g,
+                &Op::EditAnswer {
+                    id: "a".into(),
+                    index: 0,
+                    label: " ".into(),
+                    key: None,
+                    description: None,
+                }
+            ),
+            Err(AuthoringError::EmptyAnswerLabel)
+        );
+    }
+
     #[test]
     fn remove_last_answer_refuses() {
         let g = graph_of(vec![node("a"), node("b")]);
@@ -1229,6 +2327,151 @@ mod tests {
         );
     }
 
+    fn keyed_branch(keys: &[Option<&str>]) -> Graph {
+        let mut g = graph_of(vec![node("a"), node("b")]);
+        let options = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| BranchOption {
+                label: format!("Option {i}"),
+                key: key.map(str::to_owned),
+                target: "b".into(),
+                description: None,
+                icon: None,
+            })
+            .collect();
+        g.nodes[0].traversal = Some(TraversalSpec::Rules(Traversal {
+            branch_point: Some(BranchPoint {
+                prompt: None,
+                timeout: None,
+                default_option: None,
+                options,
+            }),
+            next: None,
+        }));
+        g
+    }
+
+    fn keys_of(g: &Graph) -> Vec<Option<&str>> {
+        let bp = g.node("a").unwrap().branch_point().unwrap();
+        bp.options.iter().map(|o| o.key.as_deref()).collect()
+    }
+
+    #[test]
+    fn reindexing_gives_blank_and_duplicate_keys_unique_ones_in_order() {
+        let original = keyed_branch(&[None, Some("x"), Some("X"), Some("q"), Some(" ")]);
+        let mut g = original.clone();
+        assert_eq!(reindex_branch_keys(&mut g, false), 5);
+        assert_eq!(
+            keys_of(&g),
+            [Some("1"), Some("2"), Some("3"), Some("4"), Some("5")]
+        );
+        assert_eq!(reindex_branch_keys(&mut g, false), 0, "deterministic");
+
+        let mut g = original.clone();
+        assert_eq!(reindex_branch_keys(&mut g, true), 4);
+        assert_eq!(
+            keys_of(&g),
+            [Some("1"), Some("x"), Some("2"), Some("3"), Some("4")]
+        );
+
+        // Keys run out of digits into the unreserved letters.
+        let mut g = keyed_branch(&[None; 12]);
+        reindex_branch_keys(&mut g, false);
+        assert_eq!(
+            &keys_of(&g)[8..],
+            [Some("9"), Some("a"), Some("b"), Some("c")]
+        );
+        let mut g = keyed_branch(&[None; 14]);
+        reindex_branch_keys(&mut g, false);
+        assert_eq!(&keys_of(&g)[12..], [Some("d"), Some("i")]);
+    }
+
+    #[test]
+    fn free_answer_keys_skip_every_key_an_answer_uses() {
+        let g = keyed_branch(&[Some("1"), Some("3"), Some("A")]);
+        let bp = g.node("a").unwrap().branch_point().unwrap();
+        let free: Vec<String> = free_answer_keys(bp).collect();
+        assert_eq!(free[..2], ["2", "4"]);
+        assert_eq!(free[7], "b", "\"A\" takes \"a\" too");
+    }
+
+    #[test]
+    fn assigning_answer_keys_leaves_the_original_untouched_and_refuses_a_no_op() {
+        let original = keyed_branch(&[Some("x"), None]);
+        let op = Op::AssignAnswerKeys {
+            id: "a".into(),
+            preserve: false,
+        };
+        let g = apply(&original, &op).unwrap();
+        assert_eq!(keys_of(&g), [Some("1"), Some("2")]);
+        assert_eq!(keys_of(&original), [Some("x"), None]);
+        assert_eq!(
+            apply(&g, &op),
+            Err(AuthoringError::KeysAlreadyAssigned("a".into()))
+        );
+        assert_eq!(
+            apply(
+                &original,
+                &Op::AssignAnswerKeys {
+                    id: "b".into(),
+                    preserve: true
+                }
+            ),
+            Err(AuthoringError::NotABranchPoint("b".into()))
+        );
+    }
+
+    #[test]
+    fn set_answer_key_sets_and_clears_the_key() {
+        let g = graph_of(vec![node("a"), node("b")]);
+        let g = apply(
+            &g,
+            &Op::TurnIntoChoice {
+                id: "a".into(),
+                prompt: None,
+                first_label: "B".into(),
+                first_target: "b".into(),
+            },
+        )
+        .unwrap();
+        let keyed = apply(
+            &g,
+            &Op::SetAnswerKey {
+                id: "a".into(),
+                index: 0,
+                key: Some("1".into()),
+            },
+        )
+        .unwrap();
+        let bp = keyed.node("a").unwrap().branch_point().unwrap();
+        assert_eq!(bp.options[0].key.as_deref(), Some("1"));
+
+        let cleared = apply(
+            &keyed,
+            &Op::SetAnswerKey {
+                id: "a".into(),
+                index: 0,
+                key: None,
+            },
+        )
+        .unwrap();
+        let bp = cleared.node("a").unwrap().branch_point().unwrap();
+        assert_eq!(bp.options[0].key, None);
+
+        assert_eq!(
+            apply(
+                &g,
+                &Op::SetAnswerKey {
+                    id: "a".into(),
+                    index: 0,
+                    key: Some("q".into()),
+                },
+            ),
+            Err(AuthoringError::ReservedBranchKey('q'))
+        );
+    }
+
     // ── Block ops ──
 
     #[test]
@@ -1252,6 +2495,8 @@ mod tests {
         let mut a = node("a");
         a.content.push(CB::Text {
             reveal: Some(1),
+            id: None,
+            visible_if: None,
             body: "old".into(),
         });
         let g = graph_of(vec![a]);
@@ -1262,6 +2507,8 @@ mod tests {
                 path: vec![0],
                 content: CB::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "new".into(),
                 },
             },
@@ -1270,15 +2517,166 @@ mod tests {
         assert_eq!(g2.node("a").unwrap().content[0].reveal(), Some(1));
     }
 
+    #[test]
+    fn replace_text_leaves_the_original_untouched_and_refuses_a_no_op() {
+        let mut a = node("a");
+        a.content.push(CB::Text {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            body: "Acme and acme".into(),
+        });
+        let g = graph_of(vec![a]);
+        let op = |find: &str| Op::ReplaceText {
+            find: find.into(),
+            replace: "Globex".into(),
+            case_sensitive: false,
+        };
+        let g2 = apply(&g, &op("ACME")).expect("matches");
+        assert_eq!(g2.nodes[0].plain_text(), "Globex and Globex");
+        assert_eq!(g.nodes[0].plain_text(), "Acme and acme");
+        assert_eq!(
+            apply(&g, &op("Initech")),
+            Err(AuthoringError::NoMatches("Initech".into()))
+        );
+    }
+
+    #[test]
+    fn edit_block_preserves_anchor() {
+        let mut a = node("a");
+        a.content.push(CB::Text {
+            reveal: None,
+            id: Some("intro".into()),
+            visible_if: None,
+            body: "old".into(),
+        });
+        let g = graph_of(vec![a]);
+        let g2 = apply(
+            &g,
+            &Op::EditBlock {
+                node: "a".into(),
+                path: vec![0],
+                content: CB::Text {
+                    reveal: None,
+                    id: None,
+                    visible_if: None,
+                    body: "new".into(),
+                },
+            },
+        )
+        .unwrap();
+        assert_eq!(g2.node("a").unwrap().content[0].id(), Some("intro"));
+    }
+
+    #[test]
+    fn edit_block_keeps_a_code_blocks_runnable_flag_and_wrap() {
+        let code = |source: &str, runnable, wrap| CB::Code {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            language: Some("sh".into()),
+            source: source.into(),
+            highlight_lines: None,
+            show_line_numbers: None,
+            runnable,
+            wrap,
+        };
+        let mut a = node("a");
+        a.content.push(code("ls", true, Some(CodeWrap::Word)));
+        let g = graph_of(vec![a]);
+        let g2 = apply(
+            &g,
+            &Op::EditBlock {
+                node: "a".into(),
+                path: vec![0],
+                content: code("ls -la", false, None),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            g2.node("a").unwrap().content[0],
+            code("ls -la", true, Some(CodeWrap::Word))
+        );
+    }
+
+    #[test]
+    fn edit_block_keeps_an_image_decorative() {
+        let image = |src: &str, decorative| CB::Image {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            src: src.into(),
+            alt: None,
+            decorative,
+            caption: None,
+            width: None,
+            height: None,
+        };
+        let mut a = node("a");
+        a.content.push(image("rule.png", true));
+        let g = graph_of(vec![a]);
+        let g2 = apply(
+            &g,
+            &Op::EditBlock {
+                node: "a".into(),
+                path: vec![0],
+                content: image("rule-wide.png", false),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            g2.node("a").unwrap().content[0],
+            image("rule-wide.png", true)
+        );
+    }
+
+    #[test]
+    fn text_and_list_conversions_round_trip() {
+        let CB::List { items, ordered, .. } = text_to_list("Plan\n  Build  \nShip") else {
+            panic!("a list");
+        };
+        assert_eq!(items, ["Plan", "Build", "Ship"]);
+        assert_eq!(ordered, None);
+        assert_eq!(
+            list_to_text(&items),
+            CB::Text {
+                reveal: None,
+                id: None,
+                visible_if: None,
+                body: "Plan\nBuild\nShip".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn text_to_list_drops_blank_lines() {
+        let CB::List { items, .. } = text_to_list("\none\n\n   \ntwo\n") else {
+            panic!("a list");
+        };
+        assert_eq!(items, ["one", "two"]);
+        let CB::List { items, .. } = text_to_list("  \n") else {
+            panic!("a list");
+        };
+        assert!(items.is_empty());
+        let CB::Text { body, .. } = list_to_text(&["a".into(), " ".into(), "b".into()]) else {
+            panic!("text");
+        };
+        assert_eq!(body, "a\nb");
+    }
+
     #[test]
     fn move_block_reorders_siblings() {
         let mut a = node("a");
         a.content.push(CB::Text {
             reveal: None,
+            id: None,
+            visible_if: None,
             body: "1".into(),
         });
         a.content.push(CB::Text {
             reveal: None,
+            id: None,
+            visible_if: None,
             body: "2".into(),
         });
         let g = graph_of(vec![a]);
@@ -1297,15 +2695,90 @@ mod tests {
         assert_eq!(body, "1");
     }
 
+    #[test]
+    fn replace_content_swaps_the_whole_slide_and_renumbers_reveals() {
+        let g = graph_of(vec![node("a")]);
+        let g2 = apply(
+            &g,
+            &Op::ReplaceContent {
+                node: "a".into(),
+                content: vec![
+                    CB::Heading {
+                        reveal: None,
+                        id: None,
+                        visible_if: None,
+                        level: 1,
+                        text: "Title".into(),
+                    },
+                    CB::Text {
+                        reveal: Some(4),
+                        id: None,
+                        visible_if: None,
+                        body: "later".into(),
+                    },
+                ],
+            },
+        )
+        .unwrap();
+        let node = g2.node("a").unwrap();
+        assert_eq!(node.content.len(), 2);
+        assert_eq!(node.reveal_levels(), vec![1]);
+    }
+
+    #[test]
+    fn replace_content_rejects_an_invalid_heading_level_and_changes_nothing() {
+        let mut a = node("a");
+        a.content.push(CB::Text {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            body: "keep".into(),
+        });
+        let g = graph_of(vec![a]);
+        let before = g.clone();
+        let result = apply(
+            &g,
+            &Op::ReplaceContent {
+                node: "a".into(),
+                content: vec![CB::Container {
+                    reveal: None,
+                    id: None,
+                    visible_if: None,
+                    layout: None,
+                    columns: None,
+                    children: vec![CB::Heading {
+                        reveal: None,
+                        id: None,
+                        visible_if: None,
+                        level: 9,
+                        text: "Too deep".into(),
+                    }],
+                }],
+            },
+        );
+        assert_eq!(
+            result,
+            Err(AuthoringError::InvalidBlock(
+                "a".into(),
+                "heading level 9 is outside 1–6".into()
+            ))
+        );
+        assert_eq!(g, before);
+    }
+
     #[test]
     fn set_reveal_step_keeps_steps_consecutive() {
         let mut a = node("a");
         a.content.push(CB::Text {
             reveal: Some(1),
+            id: None,
+            visible_if: None,
             body: "1".into(),
         });
         a.content.push(CB::Text {
             reveal: Some(3),
+            id: None,
+            visible_if: None,
             body: "2".into(),
         });
         let g = graph_of(vec![a]);
@@ -1327,7 +2800,13 @@ mod tests {
     #[test]
     fn delete_block_removes_it() {
         let mut a = node("a");
-        a.content.push(CB::Divider { reveal: None });
+        a.content.push(CB::Divider {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            style: None,
+            label: None,
+        });
         let g = graph_of(vec![a]);
         let g2 = apply(
             &g,
@@ -1345,8 +2824,11 @@ mod tests {
         let mut a = node("a");
         a.content.push(CB::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             children: vec![],
             layout: None,
+            columns: None,
         });
         let g = graph_of(vec![a]);
         let g2 = apply(
@@ -1374,18 +2856,22 @@ mod tests {
             next: None,
             branch_point: Some(BranchPoint {
                 prompt: None,
+                timeout: None,
+                default_option: None,
                 options: vec![
                     BranchOption {
                         label: "B".into(),
                         key: None,
                         target: "b".into(),
                         description: None,
+                        icon: None,
                     },
                     BranchOption {
                         label: "C".into(),
                         key: None,
                         target: "c".into(),
                         description: None,
+                        icon: None,
                     },
                 ],
             }),
@@ -1440,6 +2926,10 @@ mod tests {
                 idx: usize,
                 before_idx: Option<usize>,
             },
+            SetId {
+                idx: usize,
+                new_id: String,
+            },
         }
 
         pub(super) fn arbitrary_linear_graph(n: usize) -> Graph {
@@ -1460,6 +2950,8 @@ mod tests {
                 (1..n).prop_map(|idx| SmallOp::Delete { idx }),
                 (0..n, proptest::option::of(0..n))
                     .prop_map(|(idx, before_idx)| SmallOp::Reorder { idx, before_idx }),
+                (0..n, "n[0-9]|[a-z ]{0,4}")
+                    .prop_map(|(idx, new_id)| SmallOp::SetId { idx, new_id }),
             ]
         }
 
@@ -1499,7 +2991,7 @@ mod tests {
             #[test]
             fn retitle_never_dangles_a_reference(title in "[a-zA-Z ]{0,12}") {
                 let g = arbitrary_linear_graph(4);
-                if let Ok(g2) = apply(&g, &Op::RetitleSlide { id: "n2".into(), title }) {
+                if let Ok(g2) = apply(&g, &Op::RetitleSlide { ids: IdStrategy::Slug, id: "n2".into(), title }) {
                     prop_assert!(no_dangling_reference(&g2));
                     prop_assert!(no_duplicate_id(&g2));
                 }
@@ -1512,13 +3004,14 @@ mod tests {
                     let ids: Vec<String> = g.nodes.iter().map(|n| n.id.clone()).collect();
                     let translated = match op {
                         SmallOp::Retitle { idx, title } => {
-                            ids.get(idx).map(|id| Op::RetitleSlide { id: id.clone(), title })
+                            ids.get(idx).map(|id| Op::RetitleSlide { ids: IdStrategy::Slug, id: id.clone(), title })
                         }
                         SmallOp::Delete { idx } => ids.get(idx).map(|id| Op::DeleteSlide { id: id.clone() }),
                         SmallOp::Reorder { idx, before_idx } => ids.get(idx).map(|id| Op::ReorderSlide {
                             id: id.clone(),
                             before: before_idx.and_then(|bi| ids.get(bi).cloned()),
                         }),
+                        SmallOp::SetId { idx, new_id } => ids.get(idx).map(|id| Op::SetSlideId { id: id.clone(), new_id }),
                     };
                     if let Some(op) = translated
                         && let Ok(next) = apply(&g, &op)
@@ -1548,13 +3041,14 @@ mod tests {
                     let ids: Vec<String> = g.nodes.iter().map(|n| n.id.clone()).collect();
                     let translated = match op {
                         SmallOp::Retitle { idx, title } => {
-                            ids.get(idx).map(|id| Op::RetitleSlide { id: id.clone(), title })
+                            ids.get(idx).map(|id| Op::RetitleSlide { ids: IdStrategy::Slug, id: id.clone(), title })
                         }
                         SmallOp::Delete { idx } => ids.get(idx).map(|id| Op::DeleteSlide { id: id.clone() }),
                         SmallOp::Reorder { idx, before_idx } => ids.get(idx).map(|id| Op::ReorderSlide {
                             id: id.clone(),
                             before: before_idx.and_then(|bi| ids.get(bi).cloned()),
                         }),
+                        SmallOp::SetId { idx, new_id } => ids.get(idx).map(|id| Op::SetSlideId { id: id.clone(), new_id }),
                     };
                     if let Some(op) = translated
                         && let Ok(next) = apply(&g, &op)
@@ -0,0 +1,149 @@
+//! What changed between two versions of a deck, counted slide by slide —
+//! "2 slides changed, 1 added" rather than "you have unsaved changes".
+//!
+//! Slides are matched by id, so a slide whose id changed counts as one
+//! removed and one added. Moving a slide, or editing the deck's own
+//! title or defaults, changes no slide and so counts for nothing here.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use fireside_core::Graph;
+
+/// How many slides one version of a deck added, removed, and modified
+/// relative to another.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangeSummary {
+    /// Slides only the newer version has.
+    pub added: usize,
+    /// Slides only the older version has.
+    pub removed: usize,
+    /// Slides both have, but not alike.
+    pub modified: usize,
+}
+
+impl ChangeSummary {
+    /// Whether no slide was added, removed, or modified.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for ChangeSummary {
+    /// `"2 slides changed, 1 added"`: the non-zero counts, modified
+    /// first, the first naming what's counted. An empty summary reads
+    /// `"no slides changed"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<(usize, &str)> = [
+            (self.modified, "changed"),
+            (self.added, "added"),
+            (self.removed, "removed"),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .collect();
+        let Some(&(first, verb)) = parts.first() else {
+            return f.write_str("no slides changed");
+        };
+        let noun = if first == 1 { "slide" } else { "slides" };
+        write!(f, "{first} {noun} {verb}")?;
+        for (n, verb) in &parts[1..] {
+            write!(f, ", {n} {verb}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How `after` differs from `before`, slide by slide — see the module
+/// docs for how slides are matched.
+#[must_use]
+pub fn diff_graphs(before: &Graph, after: &Graph) -> ChangeSummary {
+    let old: HashMap<&str, _> = before.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let mut summary = ChangeSummary::default();
+    let mut kept = 0;
+    for node in &after.nodes {
+        match old.get(node.id.as_str()) {
+            Some(was) => {
+                kept += 1;
+                if *was != node {
+                    summary.modified += 1;
+                }
+            }
+            None => summary.added += 1,
+        }
+    }
+    summary.removed = old.len().saturating_sub(kept);
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authoring::{IdStrategy, Op, apply};
+    use fireside_core::ContentBlock;
+
+    fn deck() -> Graph {
+        Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[{"kind":"text","body":"one"}]},
+                {"id":"b","content":[{"kind":"text","body":"two"}]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn adding_a_slide_and_editing_a_block_is_one_added_one_modified() {
+        let before = deck();
+        let added = apply(
+            &before,
+            &Op::AddSlide {
+                after: "b".into(),
+                title: "Three".into(),
+                ids: IdStrategy::default(),
+            },
+        )
+        .unwrap();
+        // The new slide follows "b", so "b" already changed once: its
+        // next now leads there. Editing it again still counts once.
+        let mut edited = added.nodes[1].content[0].clone();
+        if let ContentBlock::Text { body, .. } = &mut edited {
+            *body = "dos".into();
+        }
+        let after = apply(
+            &added,
+            &Op::EditBlock {
+                node: "b".into(),
+                path: vec![0],
+                content: edited,
+            },
+        )
+        .unwrap();
+        let summary = diff_graphs(&before, &after);
+        assert_eq!(
+            summary,
+            ChangeSummary {
+                added: 1,
+                removed: 0,
+                modified: 1,
+            }
+        );
+        assert_eq!(summary.to_string(), "1 slide changed, 1 added");
+    }
+
+    #[test]
+    fn a_renamed_slide_is_removed_and_added_and_a_move_is_nothing() {
+        let before = deck();
+        let mut renamed = before.clone();
+        renamed.nodes[1].id = "c".into();
+        let summary = diff_graphs(&before, &renamed);
+        assert_eq!((summary.added, summary.removed), (1, 1));
+        assert_eq!(summary.to_string(), "1 slide added, 1 removed");
+
+        let mut moved = before.clone();
+        moved.nodes.swap(0, 1);
+        assert!(diff_graphs(&before, &moved).is_empty());
+        assert_eq!(ChangeSummary::default().to_string(), "no slides changed");
+    }
+}
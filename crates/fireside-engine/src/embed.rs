@@ -0,0 +1,225 @@
+//! `embed` blocks: text pulled from another file when a deck loads. This
+//! module parses their line ranges and fills in what each one resolved to;
+//! reading the files is left to the caller, who hands over a reader.
+
+use fireside_core::{ContentBlock, Graph};
+
+use crate::EngineError;
+
+/// Extensions whose text shows as prose rather than code. Everything else,
+/// and a file with no extension, is code in the language its extension
+/// names.
+const PROSE_EXTENSIONS: [&str; 3] = ["md", "markdown", "txt"];
+
+/// Reads an embed's `range`: `"12"` for one line, `"12-20"` for a run,
+/// both 1-based and inclusive. `None` if it's neither, or if the run ends
+/// before it starts.
+#[must_use]
+pub fn parse_range(range: &str) -> Option<(usize, usize)> {
+    let line = |s: &str| s.trim().parse::<usize>().ok().filter(|&n| n >= 1);
+    let (first, last) = match range.split_once('-') {
+        Some((first, last)) => (line(first)?, line(last)?),
+        None => {
+            let only = line(range)?;
+            (only, only)
+        }
+    };
+    (first <= last).then_some((first, last))
+}
+
+/// Resolves every `embed` block in the deck, at any container depth:
+/// `read` is given each block's `source` as written and returns the file's
+/// text, which is cut to the block's `range` and kept as its `resolved`
+/// block — prose for Markdown and plain-text files, code otherwise.
+///
+/// # Errors
+///
+/// Stops at the first embed whose file `read` can't supply, whose range
+/// isn't one [`parse_range`] reads, or whose range runs past the end of
+/// the file.
+pub fn resolve_embeds<F>(graph: &mut Graph, mut read: F) -> Result<(), EngineError>
+where
+    F: FnMut(&str) -> std::io::Result<String>,
+{
+    for node in &mut graph.nodes {
+        resolve_blocks(&node.id, &mut node.content, &mut read)?;
+    }
+    Ok(())
+}
+
+fn resolve_blocks<F>(
+    node: &str,
+    blocks: &mut [ContentBlock],
+    read: &mut F,
+) -> Result<(), EngineError>
+where
+    F: FnMut(&str) -> std::io::Result<String>,
+{
+    for block in blocks {
+        match block {
+            ContentBlock::Embed {
+                source,
+                range,
+                resolved,
+                ..
+            } => {
+                let text = read(source).map_err(|err| EngineError::EmbedUnreadable {
+                    node: node.to_owned(),
+                    file: source.clone(),
+                    reason: err.to_string(),
+                })?;
+                let text = match range {
+                    Some(range) => lines_in(node, source, range, &text)?,
+                    None => text,
+                };
+                *resolved = Some(Box::new(embedded_block(source, text)));
+            }
+            ContentBlock::Container { children, .. } => resolve_blocks(node, children, read)?,
+            ContentBlock::Columns { columns, .. } => {
+                for column in columns {
+                    resolve_blocks(node, column, read)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The lines of `text` that `range` names, joined back together.
+fn lines_in(node: &str, source: &str, range: &str, text: &str) -> Result<String, EngineError> {
+    let Some((first, last)) = parse_range(range) else {
+        return Err(EngineError::BadEmbedRange {
+            node: node.to_owned(),
+            range: range.to_owned(),
+        });
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    if last > lines.len() {
+        return Err(EngineError::EmbedPastEnd {
+            node: node.to_owned(),
+            file: source.to_owned(),
+            range: range.to_owned(),
+            lines: lines.len(),
+        });
+    }
+    Ok(lines[first - 1..last].join("\n"))
+}
+
+/// What an embed of `source` shows: its text as a text block for prose
+/// files, or as a code block in the language its extension names.
+fn embedded_block(source: &str, text: String) -> ContentBlock {
+    let extension = std::path::Path::new(source)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension {
+        Some(ext) if !PROSE_EXTENSIONS.contains(&ext.as_str()) => ContentBlock::Code {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            language: Some(ext),
+            source: text.trim_end_matches('\n').to_owned(),
+            highlight_lines: None,
+            show_line_numbers: None,
+            runnable: false,
+            wrap: None,
+        },
+        _ => ContentBlock::Text {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            body: text.trim_end_matches('\n').to_owned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNIPPET: &str = "fn main() {\n    println!(\"hi\");\n}\n";
+
+    fn deck(embed: &str) -> Graph {
+        Graph::from_json(&format!(
+            r#"{{"nodes":[{{"id":"a","content":[{{"kind":"container","children":[{embed}]}}]}}]}}"#
+        ))
+        .expect("fixture parses")
+    }
+
+    fn resolved(graph: &Graph) -> &ContentBlock {
+        let ContentBlock::Container { children, .. } = &graph.nodes[0].content[0] else {
+            panic!("a container");
+        };
+        let ContentBlock::Embed {
+            resolved: Some(block),
+            ..
+        } = &children[0]
+        else {
+            panic!("a resolved embed: {children:?}");
+        };
+        block
+    }
+
+    fn read_snippet(source: &str) -> std::io::Result<String> {
+        match source {
+            "main.rs" => Ok(SNIPPET.to_owned()),
+            "notes.md" => Ok("Shared *words*.\n".to_owned()),
+            _ => Err(std::io::ErrorKind::NotFound.into()),
+        }
+    }
+
+    #[test]
+    fn a_whole_file_embeds_as_code_in_its_extensions_language() {
+        let mut graph = deck(r#"{"kind":"embed","source":"main.rs"}"#);
+        resolve_embeds(&mut graph, read_snippet).expect("resolves");
+        let ContentBlock::Code {
+            language, source, ..
+        } = resolved(&graph)
+        else {
+            panic!("code");
+        };
+        assert_eq!(language.as_deref(), Some("rs"));
+        assert_eq!(source, SNIPPET.trim_end());
+
+        let mut graph = deck(r#"{"kind":"embed","source":"notes.md"}"#);
+        resolve_embeds(&mut graph, read_snippet).expect("resolves");
+        assert!(
+            matches!(resolved(&graph), ContentBlock::Text { body, .. } if body == "Shared *words*.")
+        );
+    }
+
+    #[test]
+    fn a_range_takes_just_those_lines() {
+        let mut graph = deck(r#"{"kind":"embed","source":"main.rs","range":"2-3"}"#);
+        resolve_embeds(&mut graph, read_snippet).expect("resolves");
+        assert_eq!(resolved(&graph).plain_text(), "    println!(\"hi\");\n}");
+
+        let mut graph = deck(r#"{"kind":"embed","source":"main.rs","range":"2-9"}"#);
+        let err = resolve_embeds(&mut graph, read_snippet).expect_err("past the end");
+        assert!(
+            matches!(err, EngineError::EmbedPastEnd { lines: 3, .. }),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn a_missing_file_names_the_slide_and_the_source() {
+        let mut graph = deck(r#"{"kind":"embed","source":"gone.rs"}"#);
+        let err = resolve_embeds(&mut graph, read_snippet).expect_err("missing");
+        let message = err.to_string();
+        assert!(
+            message.contains("\"a\"") && message.contains("gone.rs"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn ranges_are_one_line_or_a_forward_run() {
+        assert_eq!(parse_range("12"), Some((12, 12)));
+        assert_eq!(parse_range("3-7"), Some((3, 7)));
+        for bad in ["", "0", "7-3", "a-b", "3-", "-3", "1-2-3"] {
+            assert_eq!(parse_range(bad), None, "{bad:?}");
+        }
+    }
+}
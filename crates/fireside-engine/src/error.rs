@@ -2,10 +2,81 @@
 
 use thiserror::Error;
 
-/// Errors produced when constructing a presentation session.
+/// Errors produced when constructing, restoring, or navigating a
+/// presentation session, when merging decks, when loading one strictly, or
+/// when resolving its embeds.
 #[derive(Debug, Error)]
 pub enum EngineError {
+    /// [`crate::Session::goto_by_id`] was given an id no node has.
+    #[error("no slide with id \"{0}\"")]
+    UnknownNode(String),
     /// A session needs at least one node to present.
     #[error("graph has no nodes")]
     EmptyGraph,
+    /// A path code from [`crate::Session::encode_path`] that this version
+    /// can't read: an unknown version prefix or a broken escape.
+    #[error("\"{0}\" is not a path code this version of fireside understands")]
+    UnreadablePath(String),
+    /// A readable path code none of whose slides exist in this deck.
+    #[error("none of the slides in that path are in this deck")]
+    PathNotInDeck,
+    /// [`crate::merge::append`] was asked to link into the appended deck
+    /// from a last slide that already leads somewhere.
+    #[error("\"{0}\" already leads somewhere, so it can't also lead into the appended deck")]
+    AlreadyLinked(String),
+    /// [`crate::range::node_range`] or [`crate::range::resolve`] was given
+    /// a slide that is neither a slide's id nor its position.
+    #[error("there's no slide \"{bound}\" — give a slide's id, or a number from 1 to {len}")]
+    NoSuchSlide {
+        /// The end as given.
+        bound: String,
+        /// How many slides the deck has.
+        len: usize,
+    },
+    /// [`crate::range::node_range`] was given a start after its end.
+    #[error("\"{from}\" comes after \"{to}\" — a range runs forward through the deck")]
+    BackwardRange {
+        /// The start as given.
+        from: String,
+        /// The end as given.
+        to: String,
+    },
+    /// [`crate::embed::resolve_embeds`] couldn't read an embed's file.
+    #[error("slide \"{node}\" embeds \"{file}\", which can't be read: {reason}")]
+    EmbedUnreadable {
+        /// The slide the embed is on.
+        node: String,
+        /// The embed's `source` as written.
+        file: String,
+        /// Why reading it failed.
+        reason: String,
+    },
+    /// An embed's `range` is neither one line nor a forward run of them.
+    #[error(
+        "slide \"{node}\" embeds lines \"{range}\" — write one line, like \"12\", or a run, like \"12-20\""
+    )]
+    BadEmbedRange {
+        /// The slide the embed is on.
+        node: String,
+        /// The range as written.
+        range: String,
+    },
+    /// An embed's `range` runs past the end of its file.
+    #[error("slide \"{node}\" embeds lines {range} of \"{file}\", which has only {lines}")]
+    EmbedPastEnd {
+        /// The slide the embed is on.
+        node: String,
+        /// The embed's `source` as written.
+        file: String,
+        /// The range as written.
+        range: String,
+        /// How many lines the file has.
+        lines: usize,
+    },
+    /// [`crate::load::load_graph`] was given text that isn't a deck.
+    #[error(transparent)]
+    Parse(#[from] fireside_core::CoreError),
+    /// [`crate::load::load_graph`], denying unknown fields, found one.
+    #[error("{0}")]
+    UnknownField(crate::load::UnknownField),
 }
@@ -0,0 +1,473 @@
+//! The `visible-if` condition language: a block shows only while its
+//! condition holds over the choices made so far.
+//!
+//! Each branch point sets one variable, named by its node's id, to the key
+//! of the option taken there — or the option's number, when it has no key.
+//! A condition compares variables to values and combines the comparisons:
+//!
+//! | Form           | Holds when                                  |
+//! | -------------- | ------------------------------------------- |
+//! | `ask == "d"`   | the choice at `ask` took the option `d`     |
+//! | `ask != "d"`   | it took another option, or none yet         |
+//! | `ask`          | any choice was made at `ask`                |
+//! | `!a`           | `a` doesn't hold                            |
+//! | `a && b`       | both hold; `&&` binds tighter than `\|\|`   |
+//! | `a \|\| b`     | either holds                                |
+//! | `(a)`          | `a`, grouped                                |
+//!
+//! Values are quoted with `"` or `'`, or written bare when they're a
+//! single word (`ask == d`). A variable no choice has set yet — including
+//! one naming no branch point at all — equals no value.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use fireside_core::{ContentBlock, Graph, NodeId};
+use thiserror::Error;
+
+/// A condition that didn't parse: what was wrong, and where.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message} at column {column}")]
+pub struct ExprError {
+    /// What the parser expected or found.
+    pub message: String,
+    /// Where, counting characters from 1.
+    pub column: usize,
+}
+
+/// A parsed `visible-if` condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// `name == value`.
+    Eq(String, String),
+    /// `name != value`.
+    Ne(String, String),
+    /// A bare `name`: some choice set it.
+    Set(String),
+    /// `!expr`.
+    Not(Box<Expr>),
+    /// `left && right`.
+    And(Box<Expr>, Box<Expr>),
+    /// `left || right`.
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Parses `text` as a condition.
+    ///
+    /// # Errors
+    ///
+    /// [`ExprError`] naming the first thing that doesn't fit the grammar
+    /// in the module docs, and its column.
+    pub fn parse(text: &str) -> Result<Self, ExprError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            pos: 0,
+            end: text.chars().count() + 1,
+        };
+        let expr = parser.or()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some((token, column)) => Err(ExprError {
+                message: format!("unexpected {}", token.describe()),
+                column,
+            }),
+        }
+    }
+
+    /// Whether the condition holds, given each set variable's value.
+    #[must_use]
+    pub fn eval(&self, vars: &HashMap<String, String>) -> bool {
+        match self {
+            Self::Eq(name, value) => vars.get(name).is_some_and(|v| v == value),
+            Self::Ne(name, value) => vars.get(name).is_none_or(|v| v != value),
+            Self::Set(name) => vars.contains_key(name),
+            Self::Not(inner) => !inner.eval(vars),
+            Self::And(left, right) => left.eval(vars) && right.eval(vars),
+            Self::Or(left, right) => left.eval(vars) || right.eval(vars),
+        }
+    }
+
+    /// Every variable the condition reads, left to right, repeats kept.
+    #[must_use]
+    pub fn variables(&self) -> Vec<&str> {
+        match self {
+            Self::Eq(name, _) | Self::Ne(name, _) | Self::Set(name) => vec![name],
+            Self::Not(inner) => inner.variables(),
+            Self::And(left, right) | Self::Or(left, right) => {
+                let mut names = left.variables();
+                names.extend(right.variables());
+                names
+            }
+        }
+    }
+}
+
+/// `blocks` less those whose `visible-if` doesn't hold over `vars`, at
+/// any depth. Borrowed as-is when no block has a condition. A condition
+/// that doesn't parse leaves its block shown — validation reports it.
+#[must_use]
+pub fn shown_blocks<'a>(
+    blocks: &'a [ContentBlock],
+    vars: &HashMap<String, String>,
+) -> Cow<'a, [ContentBlock]> {
+    if !has_conditions(blocks) {
+        return Cow::Borrowed(blocks);
+    }
+    let mut shown = blocks.to_vec();
+    prune(&mut shown, vars);
+    Cow::Owned(shown)
+}
+
+/// Renames the variables `mapping` names in every `visible-if` on
+/// `graph`, at any depth — the conditions' half of
+/// [`Graph::rewrite_references`], for every command that renames a branch
+/// point.
+pub fn rename_variables(graph: &mut Graph, mapping: &HashMap<NodeId, NodeId>) {
+    for node in &mut graph.nodes {
+        rename_in_blocks(&mut node.content, mapping);
+    }
+}
+
+fn rename_in_blocks(blocks: &mut [ContentBlock], mapping: &HashMap<NodeId, NodeId>) {
+    for block in blocks {
+        if let Some(condition) = block.visible_if_mut() {
+            let renamed = match rename_in_condition(condition, mapping) {
+                Cow::Owned(renamed) => Some(renamed),
+                Cow::Borrowed(_) => None,
+            };
+            if let Some(renamed) = renamed {
+                *condition = renamed;
+            }
+        }
+        match block {
+            ContentBlock::Container { children, .. } => rename_in_blocks(children, mapping),
+            ContentBlock::Columns { columns, .. } => {
+                for column in columns {
+                    rename_in_blocks(column, mapping);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `text` with every variable `mapping` names renamed, and the rest —
+/// spacing, quotes, the values compared against — kept as written.
+/// Borrowed as-is when nothing was renamed, or the condition doesn't
+/// parse.
+#[must_use]
+pub fn rename_in_condition<'a>(text: &'a str, mapping: &HashMap<NodeId, NodeId>) -> Cow<'a, str> {
+    if Expr::parse(text).is_err() {
+        return Cow::Borrowed(text);
+    }
+    let Ok(tokens) = tokenize(text) else {
+        return Cow::Borrowed(text);
+    };
+    let chars: Vec<char> = text.chars().collect();
+    let mut renamed = String::new();
+    let mut copied = 0;
+    let mut is_value = false;
+    for (token, column) in &tokens {
+        let follows_comparison =
+            std::mem::replace(&mut is_value, matches!(token, Token::EqEq | Token::NotEq));
+        let Token::Word(name) = token else {
+            continue;
+        };
+        let Some(to) = mapping.get(name).filter(|_| !follows_comparison) else {
+            continue;
+        };
+        let start = column - 1;
+        renamed.extend(&chars[copied..start]);
+        renamed.push_str(to);
+        copied = start + name.chars().count();
+    }
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+    renamed.extend(&chars[copied..]);
+    Cow::Owned(renamed)
+}
+
+fn has_conditions(blocks: &[ContentBlock]) -> bool {
+    blocks.iter().any(|block| {
+        block.visible_if().is_some() || block.child_blocks().into_iter().any(has_conditions)
+    })
+}
+
+fn prune(blocks: &mut Vec<ContentBlock>, vars: &HashMap<String, String>) {
+    blocks.retain(|block| match block.visible_if().map(Expr::parse) {
+        Some(Ok(condition)) => condition.eval(vars),
+        None | Some(Err(_)) => true,
+    });
+    for block in blocks {
+        match block {
+            ContentBlock::Container { children, .. } => prune(children, vars),
+            ContentBlock::Columns { columns, .. } => {
+                for column in columns {
+                    prune(column, vars);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    EqEq,
+    NotEq,
+    Bang,
+    AndAnd,
+    OrOr,
+    Open,
+    Close,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => format!("\"{word}\""),
+            Self::Quoted(value) => format!("the string \"{value}\""),
+            Self::EqEq => "\"==\"".to_owned(),
+            Self::NotEq => "\"!=\"".to_owned(),
+            Self::Bang => "\"!\"".to_owned(),
+            Self::AndAnd => "\"&&\"".to_owned(),
+            Self::OrOr => "\"||\"".to_owned(),
+            Self::Open => "\"(\"".to_owned(),
+            Self::Close => "\")\"".to_owned(),
+        }
+    }
+}
+
+/// Whether `c` can be part of a bare word: a node id or a key.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// `text` as tokens, each with the column it starts at.
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, ExprError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while let Some(&c) = chars.get(i) {
+        let column = i + 1;
+        let next = chars.get(i + 1).copied();
+        let token = match (c, next) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('=', Some('=')) => Token::EqEq,
+            ('!', Some('=')) => Token::NotEq,
+            ('&', Some('&')) => Token::AndAnd,
+            ('|', Some('|')) => Token::OrOr,
+            ('!', _) => Token::Bang,
+            ('(', _) => Token::Open,
+            (')', _) => Token::Close,
+            ('"' | '\'', _) => {
+                let close =
+                    chars[i + 1..]
+                        .iter()
+                        .position(|&d| d == c)
+                        .ok_or_else(|| ExprError {
+                            message: "a string that never closes".to_owned(),
+                            column,
+                        })?;
+                let value: String = chars[i + 1..i + 1 + close].iter().collect();
+                i += close + 2;
+                tokens.push((Token::Quoted(value), column));
+                continue;
+            }
+            (c, _) if is_word_char(c) => {
+                let len = chars[i..].iter().take_while(|&&d| is_word_char(d)).count();
+                let word: String = chars[i..i + len].iter().collect();
+                i += len;
+                tokens.push((Token::Word(word), column));
+                continue;
+            }
+            (c, _) => {
+                return Err(ExprError {
+                    message: format!("unexpected \"{c}\""),
+                    column,
+                });
+            }
+        };
+        i += if matches!(token, Token::Bang | Token::Open | Token::Close) {
+            1
+        } else {
+            2
+        };
+        tokens.push((token, column));
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent over the tokens, loosest-binding operator first.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    /// The column just past the text, where "ended too soon" points.
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<(&Token, usize)> {
+        self.tokens.get(self.pos).map(|(t, c)| (t, *c))
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.peek().is_some_and(|(t, _)| t == token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// An error for the token at hand, or for the text ending there.
+    fn expected(&self, what: &str) -> ExprError {
+        match self.peek() {
+            Some((token, column)) => ExprError {
+                message: format!("expected {what}, found {}", token.describe()),
+                column,
+            },
+            None => ExprError {
+                message: format!("expected {what}, but the condition ends"),
+                column: self.end,
+            },
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, ExprError> {
+        let mut left = self.and()?;
+        while self.eat(&Token::OrOr) {
+            left = Expr::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr, ExprError> {
+        let mut left = self.unary()?;
+        while self.eat(&Token::AndAnd) {
+            left = Expr::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        if self.eat(&Token::Bang) {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        if self.eat(&Token::Open) {
+            let inner = self.or()?;
+            if !self.eat(&Token::Close) {
+                return Err(self.expected("\")\""));
+            }
+            return Ok(inner);
+        }
+        let Some((Token::Word(name), _)) = self.peek() else {
+            return Err(self.expected("a choice's id"));
+        };
+        let name = name.clone();
+        self.pos += 1;
+        let negated = if self.eat(&Token::EqEq) {
+            false
+        } else if self.eat(&Token::NotEq) {
+            true
+        } else {
+            return Ok(Expr::Set(name));
+        };
+        let value = match self.peek() {
+            Some((Token::Word(value) | Token::Quoted(value), _)) => value.clone(),
+            _ => return Err(self.expected("a value")),
+        };
+        self.pos += 1;
+        Ok(if negated {
+            Expr::Ne(name, value)
+        } else {
+            Expr::Eq(name, value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holds(text: &str, vars: &[(&str, &str)]) -> bool {
+        let vars = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect();
+        Expr::parse(text).expect("parses").eval(&vars)
+    }
+
+    #[test]
+    fn equals_and_not_equals_compare_the_choice_taken() {
+        let taken = [("ask", "d")];
+        assert!(holds(r#"ask == "d""#, &taken));
+        assert!(holds("ask == 'd'", &taken));
+        assert!(holds("ask == d", &taken), "a bare word is a value too");
+        assert!(!holds(r#"ask == "m""#, &taken));
+        assert!(holds(r#"ask != "m""#, &taken));
+        assert!(!holds(r#"ask != "d""#, &taken));
+    }
+
+    #[test]
+    fn an_unset_variable_equals_nothing() {
+        assert!(!holds(r#"ask == "d""#, &[]));
+        assert!(holds(r#"ask != "d""#, &[]));
+        assert!(!holds("ask", &[]));
+        assert!(holds("!ask", &[]));
+        assert!(!holds(r#"typo == "d""#, &[("ask", "d")]));
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or_and_parentheses_group() {
+        let vars = [("a", "1"), ("b", "2")];
+        assert!(holds("a == 1 || a == 9 && b == 9", &vars));
+        assert!(!holds("(a == 1 || a == 9) && b == 9", &vars));
+        assert!(holds("a && !(b == 3)", &vars));
+        assert_eq!(
+            Expr::parse("a == 1 || b && c").expect("parses").variables(),
+            ["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn renaming_a_variable_keeps_values_and_spacing() {
+        let mapping = HashMap::from([("ask".to_owned(), "ask-2".to_owned())]);
+        let rename = |text| rename_in_condition(text, &mapping);
+        assert_eq!(
+            rename(r#"ask == "d" ||  (!ask && other != ask)"#),
+            r#"ask-2 == "d" ||  (!ask-2 && other != ask)"#,
+            "a value that happens to match is a value, not a variable"
+        );
+        assert!(matches!(rename("asked == d"), Cow::Borrowed(_)));
+        assert!(
+            matches!(rename("ask =="), Cow::Borrowed(_)),
+            "a broken condition is left for validation to report"
+        );
+    }
+
+    #[test]
+    fn parse_errors_name_the_column() {
+        for (text, message, column) in [
+            ("ask ==", "expected a value, but the condition ends", 7),
+            ("ask = d", "unexpected \"=\"", 5),
+            ("(ask", "expected \")\", but the condition ends", 5),
+            ("ask == 'd", "a string that never closes", 8),
+            ("ask d", "unexpected \"d\"", 5),
+            ("== d", "expected a choice's id, found \"==\"", 1),
+        ] {
+            let err = Expr::parse(text).expect_err(text);
+            assert_eq!(
+                (err.message.as_str(), err.column),
+                (message, column),
+                "{text}"
+            );
+        }
+    }
+}
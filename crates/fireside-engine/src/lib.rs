@@ -7,14 +7,37 @@
 //! - [`session`]: the §3 traversal state machine. Every operation returns
 //!   an [`Outcome`] so frontends can give feedback for every action.
 //!
+//! Alongside them, [`search`] ranks slides against a typed query, so every
+//! frontend that finds a slide by name agrees on the order, [`diff`]
+//! counts the slides one version of a deck changed, [`expr`] decides
+//! which blocks a `visible-if` condition shows, [`merge`]
+//! appends one deck to another, [`range`] picks out a stretch of one,
+//! [`repair`] fixes the structural breaks that have one obvious fix,
+//! [`embed`] fills in the text `embed` blocks pull from other files, and
+//! [`load`] reads a deck's text and can refuse properties the protocol
+//! doesn't define.
+//!
 //! No file I/O, no rendering, no terminal — callers load text, this crate
 //! gives them a validated, navigable presentation.
 
 pub mod authoring;
+pub mod diff;
+pub mod embed;
 pub mod error;
+pub mod expr;
+pub mod load;
+pub mod merge;
+pub mod range;
+pub mod repair;
+pub mod search;
 pub mod session;
 pub mod validation;
 
 pub use error::EngineError;
-pub use session::{Outcome, Session};
-pub use validation::{Diagnostic, RESERVED_PRESENTER_KEYS, Severity, has_errors, validate};
+pub use session::{
+    BranchCoverage, BranchTally, EndBehavior, OptionTally, Outcome, Session, Step, TraversalEvent,
+};
+pub use validation::{
+    DECK_WIDE_RULES, Diagnostic, RESERVED_PRESENTER_KEYS, ReverseRefs, Severity, has_errors,
+    needs_alt_text, validate, validate_heading_hierarchy, validate_incremental,
+};
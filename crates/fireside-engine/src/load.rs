@@ -0,0 +1,80 @@
+//! Loading deck text, optionally refusing properties the protocol doesn't
+//! define.
+//!
+//! The data model ignores unknown fields so a deck written for a newer
+//! Fireside still opens, which also means a typo like `"transiton"` is
+//! silently dropped. With [`LoadOptions::deny_unknown_fields`] the text is
+//! also run through [`unknown_fields`], and the first property it finds
+//! fails the load.
+
+use fireside_core::Graph;
+pub use fireside_core::strict::{UnknownField, unknown_fields};
+
+use crate::error::EngineError;
+
+/// How strictly [`load_graph`] reads a deck.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Fail on any property the protocol doesn't define instead of
+    /// ignoring it. Off by default.
+    pub deny_unknown_fields: bool,
+}
+
+/// Parses `text` as a JSON deck.
+///
+/// # Errors
+///
+/// [`EngineError::Parse`] when `text` isn't a deck at all, and — with
+/// [`LoadOptions::deny_unknown_fields`] — [`EngineError::UnknownField`]
+/// for the first property the protocol doesn't define.
+pub fn load_graph(text: &str, options: LoadOptions) -> Result<Graph, EngineError> {
+    let graph = Graph::from_json(text)?;
+    if options.deny_unknown_fields
+        && let Some(unknown) = unknown_fields(text)?.into_iter().next()
+    {
+        return Err(EngineError::UnknownField(unknown));
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRICT: LoadOptions = LoadOptions {
+        deny_unknown_fields: true,
+    };
+
+    #[test]
+    fn lenient_loading_ignores_what_strict_loading_refuses() {
+        let text = r#"{"nodes":[
+            {"id":"intro","content":[]},
+            {"id":"main","transiton":"fade","content":[]}
+        ]}"#;
+        let graph = load_graph(text, LoadOptions::default()).unwrap();
+        assert_eq!(graph.nodes.len(), 2);
+
+        let err = load_graph(text, STRICT).unwrap_err();
+        let EngineError::UnknownField(unknown) = &err else {
+            panic!("{err}");
+        };
+        assert_eq!(unknown.field, "transiton");
+        assert_eq!(unknown.path, "nodes[1].transiton");
+        assert_eq!(unknown.node.as_deref(), Some("main"));
+        assert_eq!(unknown.suggestion, Some("transition"));
+        assert_eq!(
+            err.to_string(),
+            "unknown field \"transiton\" at nodes[1].transiton (slide \"main\") — did you mean \"transition\"?"
+        );
+    }
+
+    #[test]
+    fn text_that_isnt_a_deck_is_a_parse_error_either_way() {
+        for options in [LoadOptions::default(), STRICT] {
+            assert!(matches!(
+                load_graph("{", options),
+                Err(EngineError::Parse(_))
+            ));
+        }
+    }
+}
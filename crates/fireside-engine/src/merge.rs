@@ -0,0 +1,228 @@
+//! Appending one deck to another — a Q&A deck after a talk, a shared
+//! closing section after every workshop.
+//!
+//! The appended deck's nodes go after the first deck's, keeping their
+//! order. Any whose id the first deck already uses is renamed with the
+//! same `-2`, `-3` suffixing new slides get, and every traversal target
+//! and `visible-if` condition inside the appended deck follows the rename,
+//! so the merged graph never gains a dangling or crossed reference.
+
+use std::collections::{HashMap, HashSet};
+
+use fireside_core::{Graph, NodeDefaults, TraversalSpec};
+
+use crate::authoring::dedupe;
+use crate::error::EngineError;
+
+/// A node id the appended deck had to give up, and the one it got instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    /// The id in the appended deck.
+    pub from: String,
+    /// The id in the merged graph.
+    pub to: String,
+}
+
+/// Appends `other`'s nodes to `graph`, renaming any that collide and
+/// patching `other`'s traversal and conditions to match. With `link`, `graph`'s last node
+/// gets a `next` into `other`'s first, so presenting flows straight on.
+///
+/// `graph` keeps its own metadata. Where `other`'s `defaults` would have
+/// shown a node differently, the node carries the difference itself.
+///
+/// # Errors
+///
+/// [`EngineError::EmptyGraph`] when `other` has no nodes, and
+/// [`EngineError::AlreadyLinked`] when `link` is set but `graph`'s last
+/// node already leads somewhere. Either way `graph` is left unchanged.
+pub fn append(graph: &mut Graph, mut other: Graph, link: bool) -> Result<Vec<Rename>, EngineError> {
+    if other.nodes.is_empty() {
+        return Err(EngineError::EmptyGraph);
+    }
+    if link
+        && let Some(last) = graph.nodes.last()
+        && last.traversal.is_some()
+    {
+        return Err(EngineError::AlreadyLinked(last.id.clone()));
+    }
+
+    let ours: HashSet<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+    let mut taken: Vec<String> = graph
+        .nodes
+        .iter()
+        .chain(&other.nodes)
+        .map(|n| n.id.clone())
+        .collect();
+    let mut renames = Vec::new();
+    for node in &other.nodes {
+        if ours.contains(node.id.as_str()) {
+            let to = dedupe(&node.id, &taken);
+            taken.push(to.clone());
+            renames.push(Rename {
+                from: node.id.clone(),
+                to,
+            });
+        }
+    }
+    let mapping: HashMap<String, String> = renames
+        .iter()
+        .map(|r| (r.from.clone(), r.to.clone()))
+        .collect();
+    other.rewrite_references(&mapping);
+    crate::expr::rename_variables(&mut other, &mapping);
+
+    let theirs = other.defaults.unwrap_or(EMPTY_DEFAULTS);
+    let mine = graph.defaults.unwrap_or(EMPTY_DEFAULTS);
+    let first = graph.nodes.len();
+    for mut node in other.nodes {
+        if let Some(to) = mapping.get(&node.id) {
+            node.id.clone_from(to);
+        }
+        let view_mode = node.view_mode.or(theirs.view_mode).unwrap_or_default();
+        if node.view_mode.or(mine.view_mode).unwrap_or_default() != view_mode {
+            node.view_mode = Some(view_mode);
+        }
+        let transition = node.transition.or(theirs.transition).unwrap_or_default();
+        if node.transition.or(mine.transition).unwrap_or_default() != transition {
+            node.transition = Some(transition);
+        }
+        graph.nodes.push(node);
+    }
+
+    if link && first > 0 {
+        let target = graph.nodes[first].id.clone();
+        graph.nodes[first - 1].traversal = Some(TraversalSpec::Target(target));
+    }
+    Ok(renames)
+}
+
+const EMPTY_DEFAULTS: NodeDefaults = NodeDefaults {
+    view_mode: None,
+    transition: None,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{has_errors, validate};
+    use fireside_core::{Transition, ViewMode};
+
+    fn graph(json: &str) -> Graph {
+        Graph::from_json(json).unwrap()
+    }
+
+    #[test]
+    fn colliding_ids_are_suffixed_and_every_reference_follows_them() {
+        let mut talk = graph(
+            r#"{"title":"Talk","nodes":[
+                {"id":"intro","traversal":"end","content":[]},
+                {"id":"end","content":[]}
+            ]}"#,
+        );
+        let qa = graph(
+            r#"{"title":"Q&A","nodes":[
+                {"id":"intro","traversal":{"branch-point":{"options":[
+                    {"label":"Ask","target":"ask"},
+                    {"label":"Done","target":"end"}
+                ]}},"content":[]},
+                {"id":"ask","traversal":{"next":"intro"},"content":[]},
+                {"id":"end","content":[]},
+                {"id":"end-2","traversal":"end","content":[]}
+            ]}"#,
+        );
+        let renames = append(&mut talk, qa, false).unwrap();
+        assert_eq!(
+            renames,
+            [
+                Rename {
+                    from: "intro".into(),
+                    to: "intro-2".into()
+                },
+                Rename {
+                    from: "end".into(),
+                    to: "end-3".into()
+                },
+            ]
+        );
+        let ids: Vec<_> = talk.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["intro", "end", "intro-2", "ask", "end-3", "end-2"]);
+        assert_eq!(talk.title.as_deref(), Some("Talk"));
+        assert_eq!(talk.node("intro").unwrap().next_target(), Some("end"));
+        let options = &talk
+            .node("intro-2")
+            .unwrap()
+            .branch_point()
+            .unwrap()
+            .options;
+        assert_eq!(options[0].target, "ask");
+        assert_eq!(options[1].target, "end-3");
+        assert_eq!(talk.node("ask").unwrap().next_target(), Some("intro-2"));
+        assert_eq!(talk.node("end-2").unwrap().next_target(), Some("end-3"));
+        assert!(!has_errors(&validate(&talk)));
+    }
+
+    #[test]
+    fn conditions_follow_their_own_decks_renamed_choice() {
+        let deck = r#"{"nodes":[
+            {"id":"ask","traversal":{"branch-point":{"options":[
+                {"label":"Yes","key":"y","target":"after"}
+            ]}},"content":[]},
+            {"id":"after","content":[
+                {"kind":"text","body":"yes","visible-if":"ask == \"y\""}
+            ]}
+        ]}"#;
+        let mut talk = graph(deck);
+        append(&mut talk, graph(deck), false).unwrap();
+        let condition = |id: &str| talk.node(id).unwrap().content[0].visible_if();
+        assert_eq!(condition("after"), Some(r#"ask == "y""#));
+        assert_eq!(condition("after-2"), Some(r#"ask-2 == "y""#));
+        assert!(!has_errors(&validate(&talk)));
+    }
+
+    #[test]
+    fn link_leads_the_last_slide_into_the_appended_deck() {
+        let mut talk = graph(r#"{"nodes":[{"id":"a","content":[]}]}"#);
+        let qa = graph(r#"{"nodes":[{"id":"a","content":[]}]}"#);
+        append(&mut talk, qa, true).unwrap();
+        assert_eq!(talk.nodes[0].next_target(), Some("a-2"));
+        assert!(validate(&talk).is_empty(), "{:?}", validate(&talk));
+    }
+
+    #[test]
+    fn link_refuses_a_last_slide_that_already_leads_somewhere() {
+        let mut talk = graph(r#"{"nodes":[{"id":"a","traversal":"a","content":[]}]}"#);
+        let before = talk.clone();
+        let qa = graph(r#"{"nodes":[{"id":"b","content":[]}]}"#);
+        let err = append(&mut talk, qa, true).unwrap_err();
+        assert!(matches!(err, EngineError::AlreadyLinked(id) if id == "a"));
+        assert_eq!(talk, before);
+
+        let empty = Graph {
+            nodes: Vec::new(),
+            ..before.clone()
+        };
+        assert!(matches!(
+            append(&mut talk, empty, false),
+            Err(EngineError::EmptyGraph)
+        ));
+    }
+
+    #[test]
+    fn the_appended_decks_defaults_move_onto_the_nodes_they_changed() {
+        let mut talk =
+            graph(r#"{"defaults":{"transition":"fade"},"nodes":[{"id":"a","content":[]}]}"#);
+        let qa = graph(
+            r#"{"defaults":{"view-mode":"fullscreen"},"nodes":[
+                {"id":"b","content":[]},
+                {"id":"c","view-mode":"default","transition":"fade","content":[]}
+            ]}"#,
+        );
+        append(&mut talk, qa, false).unwrap();
+        let b = talk.node("b").unwrap();
+        assert_eq!(b.view_mode, Some(ViewMode::Fullscreen));
+        assert_eq!(b.transition, Some(Transition::None));
+        let c = talk.node("c").unwrap();
+        assert_eq!(c.view_mode, Some(ViewMode::Default));
+        assert_eq!(c.transition, Some(Transition::Fade));
+    }
+}
@@ -0,0 +1,120 @@
+//! A stretch of a deck picked by its ends — `--from intro --to 7` — for
+//! sharing just part of a talk.
+//!
+//! Either end is a slide's id or its 1-based position in the deck, the
+//! numbering the presenter's goto prompt and the map use. An id wins when
+//! a slide's id is itself a number, so the slide `"2"` is always that
+//! slide, wherever it sits.
+
+use std::ops::Range;
+
+use fireside_core::Graph;
+
+use crate::error::EngineError;
+
+/// The indices into [`Graph::nodes`] from `from` to `to`, both ends
+/// included, in deck order. A missing end runs to that end of the deck.
+///
+/// # Errors
+///
+/// [`EngineError::NoSuchSlide`] when an end is neither an id in `graph`
+/// nor a position in it, and [`EngineError::BackwardRange`] when `from`
+/// comes after `to`.
+pub fn node_range(
+    graph: &Graph,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<Range<usize>, EngineError> {
+    let start = from.map_or(Ok(0), |bound| resolve(graph, bound))?;
+    let end = match to {
+        Some(bound) => resolve(graph, bound)?,
+        None => graph.nodes.len().saturating_sub(1),
+    };
+    if start > end {
+        return Err(EngineError::BackwardRange {
+            from: from.unwrap_or_default().to_owned(),
+            to: to.unwrap_or_default().to_owned(),
+        });
+    }
+    Ok(start..end + 1)
+}
+
+/// The index `bound` names: a slide's id, else its 1-based position.
+///
+/// # Errors
+///
+/// [`EngineError::NoSuchSlide`] when `bound` is neither.
+pub fn resolve(graph: &Graph, bound: &str) -> Result<usize, EngineError> {
+    if let Some(index) = graph.nodes.iter().position(|n| n.id == bound) {
+        return Ok(index);
+    }
+    bound
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=graph.nodes.len()).contains(n))
+        .map(|n| n - 1)
+        .ok_or_else(|| EngineError::NoSuchSlide {
+            bound: bound.to_owned(),
+            len: graph.nodes.len(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck() -> Graph {
+        Graph::from_json(
+            r#"{"nodes":[
+                {"id":"intro","content":[]},
+                {"id":"why","content":[]},
+                {"id":"3","content":[]},
+                {"id":"how","content":[]},
+                {"id":"end","content":[]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ends_are_ids_or_positions_and_both_are_included() {
+        let graph = deck();
+        assert_eq!(node_range(&graph, Some("why"), Some("how")).unwrap(), 1..4);
+        assert_eq!(node_range(&graph, Some("2"), Some("4")).unwrap(), 1..4);
+        assert_eq!(node_range(&graph, Some("how"), None).unwrap(), 3..5);
+        assert_eq!(node_range(&graph, None, Some("intro")).unwrap(), 0..1);
+        assert_eq!(node_range(&graph, None, None).unwrap(), 0..5);
+    }
+
+    #[test]
+    fn an_id_that_is_a_number_names_its_own_slide() {
+        assert_eq!(node_range(&deck(), Some("3"), Some("3")).unwrap(), 2..3);
+        assert_eq!(node_range(&deck(), Some("5"), None).unwrap(), 4..5);
+    }
+
+    #[test]
+    fn a_range_must_run_forward_over_slides_that_exist() {
+        let graph = deck();
+        assert!(matches!(
+            node_range(&graph, Some("how"), Some("why")),
+            Err(EngineError::BackwardRange { from, to }) if from == "how" && to == "why"
+        ));
+        for bound in ["0", "6", "nope"] {
+            assert!(matches!(
+                node_range(&graph, Some(bound), None),
+                Err(EngineError::NoSuchSlide { len: 5, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn a_single_slide_resolves_by_id_or_number() {
+        let graph = deck();
+        assert_eq!(resolve(&graph, "how").unwrap(), 3);
+        assert_eq!(resolve(&graph, "2").unwrap(), 1);
+        assert!(matches!(
+            resolve(&graph, "nope"),
+            Err(EngineError::NoSuchSlide { bound, len: 5 }) if bound == "nope"
+        ));
+    }
+}
@@ -0,0 +1,305 @@
+//! Mechanical repairs for a deck that's broken in ways with one obvious
+//! fix — the kind a hand-merge or a half-finished edit leaves behind.
+//!
+//! [`repair`] works through duplicate ids, links to slides that don't
+//! exist, and empty blocks left at the end of a slide. Nodes without ids
+//! can't reach it — the typed parse refuses them — so the caller reading
+//! the text gives them one first and reports it as
+//! [`Repair::AssignedId`]. Every change comes back as a [`Repair`], in
+//! the order it was made, so nothing is fixed without saying so.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use fireside_core::{ContentBlock, Graph, TraversalSpec};
+
+use crate::authoring::dedupe;
+
+/// One change made to a broken deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repair {
+    /// The node at `index` had no usable id and was given one.
+    AssignedId {
+        /// The node's position in the deck, from 0.
+        index: usize,
+        /// The id it was given.
+        id: String,
+    },
+    /// The node at `index` repeated an earlier node's id and was renamed.
+    /// Links to `from`, and `visible-if` conditions reading it, still
+    /// lead to the first node that has it.
+    RenamedDuplicate {
+        /// The node's position in the deck, from 0.
+        index: usize,
+        /// The id it shared.
+        from: String,
+        /// The id it has now.
+        to: String,
+    },
+    /// A `next` link led to a slide that doesn't exist and was removed.
+    DroppedNext {
+        /// The node the link left from.
+        node: String,
+        /// The id it named.
+        target: String,
+    },
+    /// A branch option led to a slide that doesn't exist and was removed.
+    DroppedOption {
+        /// The node the option was on.
+        node: String,
+        /// The option's label.
+        label: String,
+        /// The id it named.
+        target: String,
+    },
+    /// Every option on a choice was dropped, so the choice went too.
+    DroppedChoice {
+        /// The node the choice was on.
+        node: String,
+    },
+    /// Empty blocks at the end of a slide were removed.
+    DroppedEmptyBlocks {
+        /// The node they were on.
+        node: String,
+        /// How many there were.
+        count: usize,
+    },
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AssignedId { index, id } => {
+                write!(f, "gave slide {} the id \"{id}\"", index + 1)
+            }
+            Self::RenamedDuplicate { index, from, to } => write!(
+                f,
+                "renamed slide {} from \"{from}\" to \"{to}\" — links to \"{from}\" still go to the first",
+                index + 1
+            ),
+            Self::DroppedNext { node, target } => write!(
+                f,
+                "removed \"{node}\"'s next link to \"{target}\", which doesn't exist"
+            ),
+            Self::DroppedOption {
+                node,
+                label,
+                target,
+            } => write!(
+                f,
+                "dropped \"{label}\" from \"{node}\" — it led to \"{target}\", which doesn't exist"
+            ),
+            Self::DroppedChoice { node } => {
+                write!(
+                    f,
+                    "removed the choice from \"{node}\" — it had no answers left"
+                )
+            }
+            Self::DroppedEmptyBlocks { node, count } => {
+                let noun = if *count == 1 { "block" } else { "blocks" };
+                write!(f, "dropped {count} empty {noun} from the end of \"{node}\"")
+            }
+        }
+    }
+}
+
+/// Repairs `graph` in place and reports each change, in this order:
+/// duplicate ids are suffixed `-2`, `-3`, … on every node after the
+/// first to use them; `next` links and branch options naming a slide
+/// that doesn't exist are removed, along with a choice left without
+/// options; and empty blocks (see [`is_empty_block`]) at the end of a
+/// slide are dropped. A graph with none of these problems is untouched.
+pub fn repair(graph: &mut Graph) -> Vec<Repair> {
+    let mut repairs = Vec::new();
+    rename_duplicates(graph, &mut repairs);
+    drop_dangling_links(graph, &mut repairs);
+    drop_trailing_empty_blocks(graph, &mut repairs);
+    repairs
+}
+
+fn rename_duplicates(graph: &mut Graph, repairs: &mut Vec<Repair>) {
+    let mut taken: Vec<String> = graph.nodes.iter().map(|n| n.id.clone()).collect();
+    let mut seen = HashSet::new();
+    for (index, node) in graph.nodes.iter_mut().enumerate() {
+        if seen.insert(node.id.clone()) {
+            continue;
+        }
+        let to = dedupe(&node.id, &taken);
+        taken.push(to.clone());
+        seen.insert(to.clone());
+        let from = std::mem::replace(&mut node.id, to.clone());
+        repairs.push(Repair::RenamedDuplicate { index, from, to });
+    }
+}
+
+fn drop_dangling_links(graph: &mut Graph, repairs: &mut Vec<Repair>) {
+    let ids: HashSet<String> = graph.nodes.iter().map(|n| n.id.clone()).collect();
+    for node in &mut graph.nodes {
+        let id = node.id.clone();
+        let Some(traversal) = &mut node.traversal else {
+            continue;
+        };
+        match traversal {
+            TraversalSpec::Target(target) => {
+                if !ids.contains(target) {
+                    repairs.push(Repair::DroppedNext {
+                        node: id,
+                        target: target.clone(),
+                    });
+                    node.traversal = None;
+                }
+            }
+            TraversalSpec::Rules(rules) => {
+                if let Some(target) = rules.next.take_if(|t| !ids.contains(t)) {
+                    repairs.push(Repair::DroppedNext {
+                        node: id.clone(),
+                        target,
+                    });
+                }
+                if let Some(bp) = &mut rules.branch_point {
+                    let had_options = !bp.options.is_empty();
+                    bp.options.retain(|option| {
+                        let keep = ids.contains(&option.target);
+                        if !keep {
+                            repairs.push(Repair::DroppedOption {
+                                node: id.clone(),
+                                label: option.label.clone(),
+                                target: option.target.clone(),
+                            });
+                        }
+                        keep
+                    });
+                    if had_options && bp.options.is_empty() {
+                        rules.branch_point = None;
+                        repairs.push(Repair::DroppedChoice { node: id });
+                    }
+                }
+                if rules.next.is_none() && rules.branch_point.is_none() {
+                    node.traversal = None;
+                }
+            }
+        }
+    }
+}
+
+fn drop_trailing_empty_blocks(graph: &mut Graph, repairs: &mut Vec<Repair>) {
+    for node in &mut graph.nodes {
+        let keep = node
+            .content
+            .iter()
+            .rposition(|b| !is_empty_block(b))
+            .map_or(0, |i| i + 1);
+        let count = node.content.len() - keep;
+        if count > 0 {
+            node.content.truncate(keep);
+            repairs.push(Repair::DroppedEmptyBlocks {
+                node: node.id.clone(),
+                count,
+            });
+        }
+    }
+}
+
+/// True for a block with nothing to show. `ascii-art` is left to
+/// validation's own `ascii-art-empty` rule.
+#[must_use]
+pub fn is_empty_block(block: &ContentBlock) -> bool {
+    match block {
+        ContentBlock::Heading { text, .. } => text.trim().is_empty(),
+        ContentBlock::Text { body, .. } => body.trim().is_empty(),
+        ContentBlock::Code { source, .. } => source.trim().is_empty(),
+        ContentBlock::List { items, .. } => items.iter().all(|i| i.trim().is_empty()),
+        ContentBlock::Image { src, .. } | ContentBlock::Video { src, .. } => src.trim().is_empty(),
+        ContentBlock::Embed { source, .. } => source.trim().is_empty(),
+        ContentBlock::Container { children, .. } => children.is_empty(),
+        ContentBlock::Columns { columns, .. } => columns.iter().all(Vec::is_empty),
+        ContentBlock::Divider { .. } | ContentBlock::AsciiArt { .. } => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{has_errors, validate};
+
+    #[test]
+    fn later_duplicates_are_suffixed_and_links_keep_the_first() {
+        let mut graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"intro","traversal":"end","content":[]},
+                {"id":"end","traversal":"intro","content":[]},
+                {"id":"end","content":[]},
+                {"id":"end-2","traversal":"end","content":[]}
+            ]}"#,
+        )
+        .unwrap();
+        let repairs = repair(&mut graph);
+        assert_eq!(
+            repairs,
+            [Repair::RenamedDuplicate {
+                index: 2,
+                from: "end".into(),
+                to: "end-3".into()
+            }]
+        );
+        let ids: Vec<_> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["intro", "end", "end-3", "end-2"]);
+        assert_eq!(graph.nodes[0].next_target(), Some("end"));
+        assert_eq!(graph.nodes[3].next_target(), Some("end"));
+        assert!(!has_errors(&validate(&graph)));
+    }
+
+    #[test]
+    fn options_to_missing_slides_are_dropped_and_an_emptied_choice_goes_too() {
+        let mut graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"ask","traversal":{"branch-point":{"options":[
+                    {"label":"Deep","target":"deep"},
+                    {"label":"Gone","target":"ghost"}
+                ]}},"content":[]},
+                {"id":"deep","traversal":{"branch-point":{"options":[
+                    {"label":"Lost","target":"nowhere"}
+                ]}},"content":[]}
+            ]}"#,
+        )
+        .unwrap();
+        let repairs = repair(&mut graph);
+        assert_eq!(
+            repairs,
+            [
+                Repair::DroppedOption {
+                    node: "ask".into(),
+                    label: "Gone".into(),
+                    target: "ghost".into()
+                },
+                Repair::DroppedOption {
+                    node: "deep".into(),
+                    label: "Lost".into(),
+                    target: "nowhere".into()
+                },
+                Repair::DroppedChoice {
+                    node: "deep".into()
+                },
+            ]
+        );
+        let options = &graph.nodes[0].branch_point().unwrap().options;
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].target, "deep");
+        assert!(graph.nodes[1].traversal.is_none());
+        assert!(!has_errors(&validate(&graph)));
+    }
+
+    #[test]
+    fn a_sound_deck_needs_no_repairs() {
+        let mut graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"next":"b"},"content":[{"kind":"divider"}]},
+                {"id":"b","content":[]}
+            ]}"#,
+        )
+        .unwrap();
+        let before = graph.clone();
+        assert!(repair(&mut graph).is_empty());
+        assert_eq!(graph, before);
+    }
+}
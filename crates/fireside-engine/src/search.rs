@@ -0,0 +1,196 @@
+//! Fuzzy matching of a typed query against node ids and slide text — one
+//! scorer, so every frontend that finds slides by name ranks them the same
+//! way.
+//!
+//! Matching is case-insensitive and tiered: an exact match beats a prefix,
+//! a prefix beats every query word starting a word of the candidate, that
+//! beats a plain substring, and a scattered subsequence (`bdg` in
+//! `budget`) is the last resort. Within a tier, a shorter candidate ranks
+//! higher — it is closer to what was typed.
+
+use fireside_core::{ContentBlock, Graph, Node};
+
+/// How well a query matches. Higher is better; only ordering is
+/// meaningful, not the number itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Score(pub u32);
+
+const EXACT: u32 = 5_000;
+const PREFIX: u32 = 4_000;
+const WORD_PREFIXES: u32 = 3_000;
+const SUBSTRING: u32 = 2_000;
+const SUBSEQUENCE: u32 = 1_000;
+/// Candidates longer than this all take the same length penalty.
+const MAX_PENALTY: u32 = 500;
+
+/// Whether every character of `needle` appears in `haystack`, in order but
+/// not necessarily adjacent, ignoring case. An empty needle is in
+/// everything.
+#[must_use]
+pub fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut rest = haystack.chars().flat_map(char::to_lowercase);
+    needle
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|c| rest.any(|h| h == c))
+}
+
+/// `text` lowercased and split into its alphanumeric words:
+/// `"Q&A — wrap-up"` is `["q", "a", "wrap", "up"]`.
+#[must_use]
+pub fn search_tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// How well `query` matches `candidate`, or `None` when it doesn't match at
+/// all — not even as a subsequence. An empty query matches nothing.
+#[must_use]
+pub fn score_match(query: &str, candidate: &str) -> Option<Score> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    let text = candidate.to_lowercase();
+    let tier = if text == query {
+        EXACT
+    } else if text.starts_with(&query) {
+        PREFIX
+    } else if word_prefixes(&query, &text) {
+        WORD_PREFIXES
+    } else if text.contains(&query) {
+        SUBSTRING
+    } else if is_subsequence(&query, &text) {
+        SUBSEQUENCE
+    } else {
+        return None;
+    };
+    let penalty = u32::try_from(text.chars().count())
+        .unwrap_or(MAX_PENALTY)
+        .min(MAX_PENALTY);
+    Some(Score(tier + MAX_PENALTY - penalty))
+}
+
+/// Every word of `query` starts some word of `text`: `"dem set"` against
+/// `"Demo: setup"`.
+fn word_prefixes(query: &str, text: &str) -> bool {
+    let words = search_tokens(text);
+    let wanted = search_tokens(query);
+    !wanted.is_empty()
+        && wanted
+            .iter()
+            .all(|w| words.iter().any(|word| word.starts_with(w.as_str())))
+}
+
+/// The nodes matching `query` by id or by text — the title, or failing
+/// that the first heading — best first, as `(node index, score)`. A node
+/// matching on both adds the two scores, so it outranks one matching only
+/// one way; ties keep deck order.
+#[must_use]
+pub fn fuzzy_find_nodes(graph: &Graph, query: &str) -> Vec<(usize, Score)> {
+    let mut found: Vec<(usize, Score)> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, node)| {
+            let by_id = score_match(query, &node.id);
+            let by_text = display_text(node).and_then(|text| score_match(query, text));
+            let total = match (by_id, by_text) {
+                (None, None) => return None,
+                (Some(a), None) | (None, Some(a)) => a.0,
+                (Some(a), Some(b)) => a.0 + b.0,
+            };
+            Some((index, Score(total)))
+        })
+        .collect();
+    found.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    found
+}
+
+/// What a slide is called on screen: its title, or its first heading at
+/// any container depth.
+fn display_text(node: &Node) -> Option<&str> {
+    node.title
+        .as_deref()
+        .or_else(|| first_heading(&node.content))
+}
+
+fn first_heading(blocks: &[ContentBlock]) -> Option<&str> {
+    blocks.iter().find_map(|block| match block {
+        ContentBlock::Heading { text, .. } => Some(text.as_str()),
+        _ => block.child_blocks().into_iter().find_map(first_heading),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_ignores_case_and_gaps() {
+        assert!(is_subsequence("bdg", "Budget"));
+        assert!(is_subsequence("", "anything"));
+        assert!(!is_subsequence("gdb", "budget"));
+    }
+
+    #[test]
+    fn tokens_are_lowercase_alphanumeric_words() {
+        assert_eq!(
+            search_tokens("Q&A — Wrap-up 2"),
+            ["q", "a", "wrap", "up", "2"]
+        );
+    }
+
+    #[test]
+    fn tiers_rank_exact_then_prefix_then_words_then_substring_then_subsequence() {
+        let score = |candidate| score_match("demo set", candidate);
+        let exact = score("Demo Set").expect("exact");
+        let prefix = score("demo setup").expect("prefix");
+        let words = score("The demo: setting up").expect("word prefixes");
+        let substring = score("xdemo settles").expect("substring");
+        let subsequence = score("de-mo s-e-t").expect("subsequence");
+        assert!(exact > prefix);
+        assert!(prefix > words);
+        assert!(words > substring);
+        assert!(substring > subsequence);
+        assert_eq!(score("nothing like it"), None);
+        assert_eq!(score_match("  ", "anything"), None);
+    }
+
+    #[test]
+    fn a_shorter_candidate_wins_within_a_tier() {
+        let short = score_match("int", "intro").expect("prefix");
+        let long = score_match("int", "introduction-to-the-topic").expect("prefix");
+        assert!(short > long);
+    }
+
+    #[test]
+    fn nodes_rank_by_id_and_title_with_subsequence_last() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"intro","title":"Welcome","content":[]},
+                {"id":"b-2","content":[{"kind":"container","children":[
+                    {"kind":"heading","level":1,"text":"Budget review"}
+                ]}]},
+                {"id":"budget","title":"Budget","content":[]},
+                {"id":"wrap","title":"Big design goals","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let order: Vec<usize> = fuzzy_find_nodes(&graph, "budget")
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        // Matching on id and title beats a heading alone.
+        assert_eq!(order, [2, 1]);
+
+        let scattered: Vec<usize> = fuzzy_find_nodes(&graph, "bdg")
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(scattered, [2, 1, 3]);
+        assert!(fuzzy_find_nodes(&graph, "").is_empty());
+    }
+}
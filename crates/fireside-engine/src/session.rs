@@ -9,15 +9,41 @@
 //!
 //! History invariants (spec §3) upheld by construction:
 //! 1. `choose` and `goto` push exactly one entry on success.
-//! 2. A `next` that moves pushes exactly one entry.
-//! 3. `back` pops one entry and pushes none.
+//! 2. A `next` that moves forward pushes exactly one entry — hidden nodes
+//!    it steps over are never pushed. That includes
+//!    [`EndBehavior::Restart`]'s return to the entry node. Under
+//!    [`EndBehavior::Bounce`], a `next` that turns around at the end of a
+//!    path, and every `next` after it until the entry node, moves as
+//!    `back` does instead (3).
+//! 3. `back` pops one entry (plus any hidden entries it steps over) and
+//!    pushes none. The one exception is [`EndBehavior::Restart`]'s `back`
+//!    on the entry node with nothing behind it: that goes to the deck's
+//!    last node and leaves history empty.
 //! 4. Failed operations never mutate history.
+//!
+//! What happens at the ends of the deck is the session's [`EndBehavior`]
+//! ([`Session::set_end_behavior`]), [`EndBehavior::Stop`] by default. With
+//! [`EndBehavior::Restart`], `next` at the end of a path goes to the entry
+//! node as a `goto` would, and `back` at the entry node with nothing behind
+//! it goes to the deck's last node as a `back` would, without pushing
+//! history. With [`EndBehavior::Bounce`], `next` at the end of a path turns
+//! around and walks the history back, as `back` would, until the entry
+//! node turns it forward again.
+//!
+//! An embedder that wants to follow along — analytics, logging, a second
+//! display — can [`Session::set_observer`] to hear a [`TraversalEvent`]
+//! after every move. [`Session::branch_coverage`] says which of the deck's
+//! branch options the session has taken, for a rehearsal that should try
+//! them all.
 
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 
-use fireside_core::{BranchPoint, Graph, Node, NodeDefaults, NodeId};
+use fireside_core::{BranchPoint, ContentBlock, Edge, Graph, Node, NodeDefaults, NodeId};
 
 use crate::error::EngineError;
+use crate::expr;
 
 /// The result of a traversal operation, for UI feedback.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -29,6 +55,10 @@ pub enum Outcome {
     /// to real navigation (transitions, branch-selection reset) MUST NOT
     /// fire for this outcome.
     Revealed,
+    /// `back` took a reveal step back: the content the last step showed
+    /// is hidden again. Like [`Outcome::Revealed`], the current node did
+    /// NOT change.
+    Concealed,
     /// `next` is blocked: the current node has a branch point awaiting a
     /// choice.
     BlockedByBranch,
@@ -43,8 +73,177 @@ pub enum Outcome {
     UnknownNode(NodeId),
 }
 
+/// One edge of a route [`Session::path_to`] finds: the edge taken and the
+/// node it lands on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The edge followed — a `next`, or a branch option with its key.
+    pub edge: Edge,
+    /// For a branch edge, the option's position, as [`Session::choose`]
+    /// takes it; `None` for a `next` edge.
+    pub option: Option<usize>,
+    /// Index of the node the step lands on.
+    pub to: usize,
+}
+
+/// What [`Session::next`] and [`Session::back`] do at the ends of the deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EndBehavior {
+    /// Both stop: `next` reports [`Outcome::EndOfPath`] and `back`
+    /// [`Outcome::HistoryEmpty`].
+    #[default]
+    Stop,
+    /// Wrap around: `next` at the end of a path returns to the entry node,
+    /// and `back` on the entry node goes to the deck's last node.
+    Restart,
+    /// Reverse direction: `next` at the end of a path steps back through
+    /// history until the entry node, then forward again.
+    Bounce,
+    /// Stay on the last node, like [`EndBehavior::Stop`], but as a loop's
+    /// end rather than a path's — an unattended presenter waits there
+    /// instead of starting over.
+    Hold,
+}
+
+/// A move the session made, as heard by its observer
+/// ([`Session::set_observer`]). `from` and `to` are positions in
+/// `graph.nodes`, like [`Session::current_index`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraversalEvent {
+    /// [`Session::next`] moved — forward along an edge, or wherever the
+    /// [`EndBehavior`] sent it.
+    Advanced {
+        /// Where it moved from.
+        from: usize,
+        /// Where it landed.
+        to: usize,
+    },
+    /// [`Session::back`] moved to an earlier node.
+    WentBack {
+        /// Where it moved from.
+        from: usize,
+        /// Where it landed.
+        to: usize,
+    },
+    /// [`Session::goto`], [`Session::goto_by_id`], or
+    /// [`Session::decode_path`] moved.
+    Jumped {
+        /// Where it moved from.
+        from: usize,
+        /// Where it landed.
+        to: usize,
+    },
+    /// [`Session::choose`] took an option.
+    ChoseBranch {
+        /// The branch point.
+        from: usize,
+        /// Where the option landed.
+        to: usize,
+        /// The option's key, or its 1-based number when it has none —
+        /// the value [`Session::variables`] records for the choice.
+        key: String,
+    },
+}
+
+/// Which of the deck's branch options a session has taken, from
+/// [`Session::branch_coverage`]. Displays as `visited 3 of 5 branch
+/// options`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchCoverage {
+    /// Every branch point in the deck, in deck order.
+    pub branches: Vec<BranchTally>,
+}
+
+/// One branch point's options, and which of them were taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchTally {
+    /// The branch point's node id.
+    pub node: NodeId,
+    /// Its options, in order.
+    pub options: Vec<OptionTally>,
+}
+
+/// One branch option, and whether it was taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionTally {
+    /// The option's key, or its 1-based number when it has none.
+    pub key: String,
+    /// The option's label.
+    pub label: String,
+    /// Whether the session took it at least once.
+    pub taken: bool,
+}
+
+impl BranchTally {
+    /// How many of the options were taken.
+    #[must_use]
+    pub fn taken(&self) -> usize {
+        self.options.iter().filter(|option| option.taken).count()
+    }
+
+    /// The share of options taken, as a whole percentage rounded down; a
+    /// branch point with no options counts as fully covered.
+    #[must_use]
+    pub fn percent(&self) -> usize {
+        if self.options.is_empty() {
+            100
+        } else {
+            self.taken() * 100 / self.options.len()
+        }
+    }
+}
+
+impl BranchCoverage {
+    /// Options taken, across every branch point.
+    #[must_use]
+    pub fn taken(&self) -> usize {
+        self.branches.iter().map(BranchTally::taken).sum()
+    }
+
+    /// Options the deck offers, across every branch point.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.branches
+            .iter()
+            .map(|branch| branch.options.len())
+            .sum()
+    }
+
+    /// The tally for the branch point `node_id`, if it is one.
+    #[must_use]
+    pub fn branch(&self, node_id: &str) -> Option<&BranchTally> {
+        self.branches.iter().find(|branch| branch.node == node_id)
+    }
+
+    /// Every option never taken, with its branch point's id, in deck
+    /// order.
+    pub fn untaken(&self) -> impl Iterator<Item = (&str, &OptionTally)> {
+        self.branches.iter().flat_map(|branch| {
+            branch
+                .options
+                .iter()
+                .filter(|option| !option.taken)
+                .map(|option| (branch.node.as_str(), option))
+        })
+    }
+}
+
+impl fmt::Display for BranchCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "visited {} of {} branch option{}",
+            self.taken(),
+            self.total(),
+            if self.total() == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// What [`Session::set_observer`] was given.
+type ObserverFn = Box<dyn FnMut(TraversalEvent) + Send>;
+
 /// A live presentation over an immutable graph.
-#[derive(Debug)]
 pub struct Session {
     graph: Graph,
     /// Index of the current node in `graph.nodes`.
@@ -55,10 +254,41 @@ pub struct Session {
     index: HashMap<NodeId, usize>,
     /// Every node ID the presenter has seen this session.
     visited: HashSet<NodeId>,
+    /// Branch-point node ID → the option last chosen there, so a presenter
+    /// who goes back to a choice can see which way they went before.
+    choices: HashMap<NodeId, usize>,
+    /// Every (branch-point id, option key) chosen this session, for
+    /// [`Session::branch_coverage`]. Keys are as [`Session::variables`]
+    /// records them.
+    taken: HashSet<(NodeId, String)>,
     /// The reveal threshold reached at the current node. Reset to `0` on
     /// every node entry (see `move_to` and `back`) — reveal progress is
     /// not history-aware.
     reveal_level: u32,
+    /// What `next` and `back` do at the ends of the deck.
+    end: EndBehavior,
+    /// Whether a [`EndBehavior::Bounce`] has turned `next` around, so it
+    /// walks back towards the entry node.
+    reversed: bool,
+    /// Called after every move.
+    observer: Option<ObserverFn>,
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("graph", &self.graph)
+            .field("current", &self.current)
+            .field("history", &self.history)
+            .field("visited", &self.visited)
+            .field("choices", &self.choices)
+            .field("taken", &self.taken)
+            .field("reveal_level", &self.reveal_level)
+            .field("end", &self.end)
+            .field("reversed", &self.reversed)
+            .field("observer", &self.observer.is_some())
+            .finish_non_exhaustive()
+    }
 }
 
 impl Session {
@@ -85,10 +315,50 @@ impl Session {
             history: Vec::new(),
             index,
             visited,
+            choices: HashMap::new(),
+            taken: HashSet::new(),
             reveal_level: 0,
+            end: EndBehavior::Stop,
+            reversed: false,
+            observer: None,
         })
     }
 
+    /// Calls `observer` with a [`TraversalEvent`] after every move from
+    /// here on, replacing any earlier observer. Reveal steps aren't moves
+    /// and aren't reported, nor are operations that fail.
+    ///
+    /// Observers must not panic: the observer is called directly, so a
+    /// panic unwinds out of the move that reported it.
+    pub fn set_observer(&mut self, observer: Box<dyn FnMut(TraversalEvent) + Send>) {
+        self.observer = Some(observer);
+    }
+
+    /// Stops reporting moves.
+    pub fn clear_observer(&mut self) {
+        self.observer = None;
+    }
+
+    /// Tells the observer, if there is one, about `event`.
+    fn notify(&mut self, event: TraversalEvent) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer(event);
+        }
+    }
+
+    /// Sets what `next` and `back` do at the ends of the deck, facing
+    /// forward again if a bounce had turned around.
+    pub fn set_end_behavior(&mut self, end: EndBehavior) {
+        self.end = end;
+        self.reversed = false;
+    }
+
+    /// What `next` and `back` do at the ends of the deck.
+    #[must_use]
+    pub fn end_behavior(&self) -> EndBehavior {
+        self.end
+    }
+
     /// The graph being presented.
     #[must_use]
     pub fn graph(&self) -> &Graph {
@@ -101,6 +371,12 @@ impl Session {
         &self.graph.nodes[self.current]
     }
 
+    /// The current node's position in the graph's `nodes` array.
+    #[must_use]
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
     /// The graph's node defaults, if any.
     #[must_use]
     pub fn defaults(&self) -> Option<&NodeDefaults> {
@@ -131,6 +407,47 @@ impl Session {
         &self.visited
     }
 
+    /// The option last chosen at the branch point `node_id` this session,
+    /// by its position in the options array — `None` when no choice has
+    /// been made there yet. `back` keeps it; a new session starts empty.
+    #[must_use]
+    pub fn last_choice(&self, node_id: &str) -> Option<usize> {
+        self.choices.get(node_id).copied()
+    }
+
+    /// Which of the deck's branch options this session has chosen, at
+    /// each branch point in deck order. Only [`Session::choose`] counts:
+    /// a `goto` straight to an option's target takes no option.
+    #[must_use]
+    pub fn branch_coverage(&self) -> BranchCoverage {
+        let branches = self
+            .graph
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                let bp = node.branch_point()?;
+                let options = bp
+                    .options
+                    .iter()
+                    .enumerate()
+                    .map(|(i, option)| {
+                        let key = option.key.clone().unwrap_or_else(|| (i + 1).to_string());
+                        OptionTally {
+                            taken: self.taken.contains(&(node.id.clone(), key.clone())),
+                            key,
+                            label: option.label.clone(),
+                        }
+                    })
+                    .collect();
+                Some(BranchTally {
+                    node: node.id.clone(),
+                    options,
+                })
+            })
+            .collect();
+        BranchCoverage { branches }
+    }
+
     /// The reveal threshold currently reached at the current node. A
     /// block is visible when its own `reveal` value (or `0` if absent)
     /// is `<=` this.
@@ -139,19 +456,57 @@ impl Session {
         self.reveal_level
     }
 
+    /// What the choices made so far set, for `visible-if` conditions: each
+    /// branch point's id → the key of the option last taken there, or that
+    /// option's number when it has no key. See [`crate::expr`].
+    #[must_use]
+    pub fn variables(&self) -> HashMap<String, String> {
+        self.choices
+            .iter()
+            .filter_map(|(id, &option)| {
+                let bp = self.graph.nodes[*self.index.get(id)?].branch_point()?;
+                let value = bp.options.get(option)?.key.clone();
+                Some((
+                    id.clone(),
+                    value.unwrap_or_else(|| (option + 1).to_string()),
+                ))
+            })
+            .collect()
+    }
+
+    /// The current node's blocks, less any whose `visible-if` doesn't hold
+    /// over [`Session::variables`] — what a frontend should show.
+    #[must_use]
+    pub fn visible_content(&self) -> Cow<'_, [ContentBlock]> {
+        expr::shown_blocks(&self.current().content, &self.variables())
+    }
+
+    /// The current node's reveal steps among the blocks it shows: a step
+    /// only hidden blocks use is no step at all.
+    fn reveal_levels(&self) -> Vec<u32> {
+        match self.visible_content() {
+            Cow::Borrowed(_) => self.current().reveal_levels(),
+            Cow::Owned(content) => Node {
+                content,
+                ..self.current().clone()
+            }
+            .reveal_levels(),
+        }
+    }
+
     /// Whether the current node has reveal steps not yet reached — while
     /// true, `next()` will reveal rather than navigate, and branch
     /// selection MUST be unavailable.
     #[must_use]
     pub fn has_pending_reveal(&self) -> bool {
-        self.reveal_level < self.current().reveal_levels().last().copied().unwrap_or(0)
+        self.reveal_level < self.reveal_levels().last().copied().unwrap_or(0)
     }
 
     /// `(revealed, total)` distinct reveal steps for the current node.
     /// `None` when the node uses no reveal marks at all.
     #[must_use]
     pub fn reveal_progress(&self) -> Option<(usize, usize)> {
-        let levels = self.current().reveal_levels();
+        let levels = self.reveal_levels();
         if levels.is_empty() {
             return None;
         }
@@ -167,12 +522,35 @@ impl Session {
     /// traversal-target check happens on this call. Only once every
     /// reveal step is exhausted does `next()` fall through to its
     /// pre-reveal behavior: blocked at a branch point, or reporting the
-    /// end of the path at a terminal node.
+    /// end of the path at a terminal node — unless the [`EndBehavior`]
+    /// restarts or bounces there.
+    ///
+    /// A bounce that has turned around skips reveals and branch points: it
+    /// steps back, as [`Session::back`] would, until nothing is behind it.
     // The spec names this operation `next()`; matching it beats Iterator
     // naming hygiene, and Session is not an iterator.
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Outcome {
-        let levels = self.current().reveal_levels();
+        let from = self.current;
+        let outcome = self.next_step();
+        if outcome == Outcome::Moved {
+            self.notify(TraversalEvent::Advanced {
+                from,
+                to: self.current,
+            });
+        }
+        outcome
+    }
+
+    /// [`Session::next`], unobserved.
+    fn next_step(&mut self) -> Outcome {
+        if self.reversed {
+            match self.back_node() {
+                Outcome::Moved => return Outcome::Moved,
+                _ => self.reversed = false,
+            }
+        }
+        let levels = self.reveal_levels();
         if let Some(&next_level) = levels.iter().find(|&&l| l > self.reveal_level) {
             self.reveal_level = next_level;
             return Outcome::Revealed;
@@ -183,12 +561,124 @@ impl Session {
         match self.current().next_target() {
             Some(target) => {
                 let id = target.to_owned();
-                self.move_to(&id)
+                self.advance_to(&id)
             }
+            None if self.end == EndBehavior::Restart && self.current != 0 => {
+                let entry = self.graph.nodes[0].id.clone();
+                self.move_to(&entry)
+            }
+            None if self.end == EndBehavior::Bounce => match self.back_node() {
+                Outcome::Moved => {
+                    self.reversed = true;
+                    Outcome::Moved
+                }
+                _ => Outcome::EndOfPath,
+            },
             None => Outcome::EndOfPath,
         }
     }
 
+    /// Where [`Session::next`] would move once the current node's reveal
+    /// steps are used up, without moving: the index of the node it lands
+    /// on, stepping over hidden nodes and honouring the [`EndBehavior`].
+    /// `None` at the end of a path, and at a branch point, where a choice
+    /// is required — see [`Session::peek_branch_options`].
+    #[must_use]
+    pub fn peek_next(&self) -> Option<usize> {
+        if self.reversed
+            && let Some(previous) = self.previous_visible()
+        {
+            return Some(previous);
+        }
+        if self.current().branch_point().is_some() {
+            return None;
+        }
+        match self.current().next_target() {
+            Some(target) => self.landing(target).ok(),
+            None if self.end == EndBehavior::Restart && self.current != 0 => Some(0),
+            None if self.end == EndBehavior::Bounce => self.previous_visible(),
+            None => None,
+        }
+    }
+
+    /// Where each of the current branch point's options would land if
+    /// chosen, in option order — `None` for one that leads nowhere (an
+    /// unknown target, or only hidden nodes). Empty when the current node
+    /// is not a branch point.
+    #[must_use]
+    pub fn peek_branch_options(&self) -> Vec<Option<usize>> {
+        self.branch_point().map_or_else(Vec::new, |bp| {
+            bp.options
+                .iter()
+                .map(|option| self.landing(&option.target).ok())
+                .collect()
+        })
+    }
+
+    /// Where [`Session::back`] would move, without moving: the nearest
+    /// visible node in history, or — restarting from the entry node — the
+    /// deck's last node. `None` when `back` would stay put.
+    #[must_use]
+    pub fn peek_back(&self) -> Option<usize> {
+        let last = self.graph.nodes.len() - 1;
+        self.previous_visible().or((self.end == EndBehavior::Restart
+            && self.current == 0
+            && last != 0)
+            .then_some(last))
+    }
+
+    /// The nearest visible node in history.
+    fn previous_visible(&self) -> Option<usize> {
+        self.history.iter().rev().find_map(|id| {
+            self.index
+                .get(id)
+                .copied()
+                .filter(|&i| !self.graph.nodes[i].hidden)
+        })
+    }
+
+    /// The shortest route from the current node to the node at `target`,
+    /// following `next` and branch-option edges, one [`Step`] per edge —
+    /// what a guided jump walks through instead of teleporting. Empty when
+    /// `target` is the current node; `None` when no route reaches it.
+    ///
+    /// The route follows the deck's edges as written: hidden nodes on it
+    /// are steps like any other, and the [`EndBehavior`] adds no edges.
+    #[must_use]
+    pub fn path_to(&self, target: usize) -> Option<Vec<Step>> {
+        if target >= self.graph.nodes.len() {
+            return None;
+        }
+        // How each reached node was first entered: (from, step).
+        let mut entered: HashMap<usize, (usize, Step)> = HashMap::new();
+        let mut queue = VecDeque::from([self.current]);
+        while let Some(from) = queue.pop_front() {
+            if from == target {
+                break;
+            }
+            for (position, edge) in self.graph.outgoing_edges(from).into_iter().enumerate() {
+                let Some(&to) = self.index.get(edge.target()) else {
+                    continue;
+                };
+                if to == self.current || entered.contains_key(&to) {
+                    continue;
+                }
+                let option = matches!(edge, Edge::Branch { .. }).then_some(position);
+                entered.insert(to, (from, Step { edge, option, to }));
+                queue.push_back(to);
+            }
+        }
+        let mut steps = Vec::new();
+        let mut at = target;
+        while at != self.current {
+            let (from, step) = entered.remove(&at)?;
+            steps.push(step);
+            at = from;
+        }
+        steps.reverse();
+        Some(steps)
+    }
+
     /// Select a branch option by its position in the options array.
     ///
     /// MUST NOT succeed while the current node has reveal steps not yet
@@ -208,37 +698,224 @@ impl Session {
             return Outcome::InvalidChoice;
         };
         let id = opt.target.clone();
-        self.move_to(&id)
+        let key = opt.key.clone().unwrap_or_else(|| (option + 1).to_string());
+        let from = self.current;
+        let outcome = self.advance_to(&id);
+        if outcome == Outcome::Moved {
+            self.choices
+                .insert(self.graph.nodes[from].id.clone(), option);
+            self.taken
+                .insert((self.graph.nodes[from].id.clone(), key.clone()));
+            self.notify(TraversalEvent::ChoseBranch {
+                from,
+                to: self.current,
+                key,
+            });
+        }
+        outcome
     }
 
     /// Jump directly to a node by ID. As an explicit command, `goto`
     /// bypasses branch-point gating.
     pub fn goto(&mut self, target: &str) -> Outcome {
-        self.move_to(target)
+        let from = self.current;
+        let outcome = self.move_to(target);
+        if outcome == Outcome::Moved {
+            self.notify(TraversalEvent::Jumped {
+                from,
+                to: self.current,
+            });
+        }
+        outcome
+    }
+
+    /// [`Session::goto`] for callers that want the landing position back:
+    /// jumps to `id`, recording the move in history like any goto, and
+    /// returns the new current index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError::UnknownNode`] carrying `id` when no node has
+    /// it; the session is left exactly as it was.
+    pub fn goto_by_id(&mut self, id: &str) -> Result<usize, EngineError> {
+        match self.goto(id) {
+            Outcome::Moved => Ok(self.current),
+            _ => Err(EngineError::UnknownNode(id.to_owned())),
+        }
     }
 
-    /// Return to the previous node in the history stack.
+    /// Return to the previous node in the history stack, stepping over
+    /// any hidden node (one an explicit `goto` reached) on the way. With
+    /// [`EndBehavior::Restart`], `back` on the entry node with nothing
+    /// behind it goes to the deck's last node instead.
+    ///
+    /// A node partway through its reveal steps takes one step back first,
+    /// and stops: reveals undo in reverse before `back` leaves the node.
     pub fn back(&mut self) -> Outcome {
-        let Some(id) = self.history.last() else {
-            return Outcome::HistoryEmpty;
+        let from = self.current;
+        let outcome = self.back_step();
+        if outcome == Outcome::Moved {
+            self.notify(TraversalEvent::WentBack {
+                from,
+                to: self.current,
+            });
+        }
+        outcome
+    }
+
+    /// [`Session::back`], unobserved.
+    fn back_step(&mut self) -> Outcome {
+        if self.reveal_level > 0 {
+            let levels = self.reveal_levels();
+            self.reveal_level = levels
+                .iter()
+                .rev()
+                .find(|&&l| l < self.reveal_level)
+                .copied()
+                .unwrap_or(0);
+            return Outcome::Concealed;
+        }
+        self.back_node()
+    }
+
+    /// [`Session::back`] once the current node's reveals are undone: the
+    /// previous node, whatever its reveal progress.
+    fn back_node(&mut self) -> Outcome {
+        // History entries were valid when pushed and the graph is
+        // immutable for the life of the session, so every lookup hits.
+        let Some(depth) = self.history.iter().rposition(|id| {
+            self.index
+                .get(id)
+                .is_some_and(|&i| !self.graph.nodes[i].hidden)
+        }) else {
+            return self.wrap_to_last();
         };
-        // Always present: history entries were valid when pushed and the
-        // graph is immutable for the life of the session.
-        let Some(&idx) = self.index.get(id) else {
+        let Some(&idx) = self.index.get(&self.history[depth]) else {
             return Outcome::HistoryEmpty;
         };
-        self.history.pop();
+        self.history.truncate(depth);
         self.current = idx;
         self.reveal_level = 0;
         Outcome::Moved
     }
 
-    /// Navigate to `target`, pushing the current node onto history.
-    /// Fails without mutating anything when the target is unknown.
+    /// The path walked so far — every history entry then the current node
+    /// — as a compact, URL-safe code that [`Session::decode_path`] turns
+    /// back into the same position and history. The code is `1.` (the
+    /// format version) followed by the node ids, `.`-separated, with any
+    /// byte outside `A–Z a–z 0–9 - _ ~` percent-escaped. Ids rather than
+    /// positions keep a code meaningful after the deck is edited.
+    #[must_use]
+    pub fn encode_path(&self) -> String {
+        let mut out = String::from(PATH_VERSION);
+        for id in self
+            .history
+            .iter()
+            .chain(std::iter::once(&self.current().id))
+        {
+            out.push('.');
+            escape_path_id(id, &mut out);
+        }
+        out
+    }
+
+    /// Restores a path from [`Session::encode_path`]: the last id that
+    /// exists in this deck becomes the current node and the ones before it
+    /// the history, exactly as if the presenter had walked them. Ids that
+    /// no longer exist are skipped and returned, so a caller can warn
+    /// about them. Reveal progress starts fresh.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError::UnreadablePath`] for a code in an unknown
+    /// format, and [`EngineError::PathNotInDeck`] when none of its ids
+    /// exist — both leave the session untouched.
+    pub fn decode_path(&mut self, code: &str) -> Result<Vec<NodeId>, EngineError> {
+        let unreadable = || EngineError::UnreadablePath(code.to_owned());
+        let mut parts = code.split('.');
+        if parts.next() != Some(PATH_VERSION) {
+            return Err(unreadable());
+        }
+        let ids = parts
+            .map(unescape_path_id)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(unreadable)?;
+        let (found, skipped): (Vec<_>, Vec<_>) =
+            ids.into_iter().partition(|id| self.index.contains_key(id));
+        let Some((last, walked)) = found.split_last() else {
+            return Err(EngineError::PathNotInDeck);
+        };
+        let Some(&current) = self.index.get(last) else {
+            return Err(EngineError::PathNotInDeck);
+        };
+        self.visited.extend(found.iter().cloned());
+        self.history = walked.to_vec();
+        let from = self.current;
+        self.current = current;
+        self.reveal_level = 0;
+        self.notify(TraversalEvent::Jumped { from, to: current });
+        Ok(skipped)
+    }
+
+    /// `next`/`choose` toward `target`: a hidden node is stepped over by
+    /// following its own `next` edge, as many times as it takes to reach a
+    /// visible node. A hidden node with nowhere to continue (terminal, a
+    /// branch point, or a loop of hidden nodes) makes this the end of the
+    /// path. Unknown targets still surface as [`Outcome::UnknownNode`].
+    fn advance_to(&mut self, target: &str) -> Outcome {
+        match self.landing(target) {
+            Ok(index) => {
+                let id = self.graph.nodes[index].id.clone();
+                self.move_to(&id)
+            }
+            Err(outcome) => outcome,
+        }
+    }
+
+    /// The node a `next`/`choose` toward `target` lands on, as
+    /// [`Session::advance_to`] describes, or the outcome that stops it.
+    fn landing(&self, target: &str) -> Result<usize, Outcome> {
+        let mut id = target;
+        for _ in 0..=self.graph.nodes.len() {
+            let Some(&index) = self.index.get(id) else {
+                return Err(Outcome::UnknownNode(id.to_owned()));
+            };
+            let node = &self.graph.nodes[index];
+            if !node.hidden {
+                return Ok(index);
+            }
+            match node.next_target() {
+                Some(next) if node.branch_point().is_none() => id = next,
+                _ => return Err(Outcome::EndOfPath),
+            }
+        }
+        // A loop of hidden nodes.
+        Err(Outcome::EndOfPath)
+    }
+
+    /// `back` with nothing behind it: the deck's last node when restarting
+    /// from the entry node, like a `back` that pushes no history —
+    /// otherwise [`Outcome::HistoryEmpty`].
+    fn wrap_to_last(&mut self) -> Outcome {
+        let last = self.graph.nodes.len() - 1;
+        if self.end != EndBehavior::Restart || self.current != 0 || last == 0 {
+            return Outcome::HistoryEmpty;
+        }
+        self.history.clear();
+        self.current = last;
+        self.visited.insert(self.graph.nodes[last].id.clone());
+        self.reveal_level = 0;
+        Outcome::Moved
+    }
+
+    /// Navigate to `target`, pushing the current node onto history and
+    /// facing forward again. Fails without mutating anything when the
+    /// target is unknown.
     fn move_to(&mut self, target: &str) -> Outcome {
         let Some(&idx) = self.index.get(target) else {
             return Outcome::UnknownNode(target.to_owned());
         };
+        self.reversed = false;
         self.history.push(self.current().id.clone());
         self.current = idx;
         self.visited.insert(self.graph.nodes[idx].id.clone());
@@ -247,6 +924,35 @@ impl Session {
     }
 }
 
+/// The version prefix every path code starts with.
+const PATH_VERSION: &str = "1";
+
+fn escape_path_id(id: &str, out: &mut String) {
+    for byte in id.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'~') {
+            out.push(char::from(byte));
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+}
+
+fn unescape_path_id(segment: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok().filter(|id| !id.is_empty())
+}
+
 #[cfg(test)]
 mod proptest_support {
     //! Test-only generators for session-invariant property tests, per
@@ -267,6 +973,8 @@ mod proptest_support {
         BranchOption, BranchPoint, ContentBlock, Graph, Node, Traversal, TraversalSpec,
     };
 
+    use super::EndBehavior;
+
     /// One step of a generated navigation sequence. `Choose` carries an
     /// option *index* (matching `Session::choose`'s actual signature, a
     /// `usize` position in the branch point's `options` array — not a key
@@ -339,7 +1047,7 @@ mod proptest_support {
             2 => vec(arbitrary_branch_option(ids.clone()), 1..4).prop_map(|options| Some(
                 TraversalSpec::Rules(Traversal {
                     next: None,
-                    branch_point: Some(BranchPoint { prompt: None, options }),
+                    branch_point: Some(BranchPoint { prompt: None, options, timeout: None, default_option: None }),
                 })
             )),
         ];
@@ -350,6 +1058,12 @@ mod proptest_support {
             transition: None,
             speaker_notes: None,
             traversal,
+            section: None,
+            duration: None,
+            hidden: false,
+            terminal: false,
+            background: None,
+            accent: None,
             content: Vec::new(),
         })
     }
@@ -360,9 +1074,21 @@ mod proptest_support {
             key: None,
             target,
             description: None,
+            icon: None,
         })
     }
 
+    /// Any of the session's end behaviors, so every property holds at the
+    /// ends of a path however they're handled.
+    pub(super) fn arbitrary_end_behavior() -> impl Strategy<Value = EndBehavior> {
+        prop_oneof![
+            Just(EndBehavior::Stop),
+            Just(EndBehavior::Restart),
+            Just(EndBehavior::Bounce),
+            Just(EndBehavior::Hold),
+        ]
+    }
+
     /// An arbitrary valid `(Graph, Vec<SessionOp>)` pair: 1-8 nodes, 0-30
     /// operations drawn against that graph's actual (and occasionally
     /// fictitious) node ids.
@@ -381,7 +1107,13 @@ mod proptest_support {
     /// content, not its edges.
     fn arbitrary_reveal_node(id: String, ids: Vec<String>) -> impl Strategy<Value = Node> {
         let content = vec(
-            option::of(0u32..4).prop_map(|reveal| ContentBlock::Divider { reveal }),
+            option::of(0u32..4).prop_map(|reveal| ContentBlock::Divider {
+                reveal,
+                id: None,
+                visible_if: None,
+                style: None,
+                label: None,
+            }),
             0..3,
         );
         (arbitrary_node(id, ids), content).prop_map(|(mut node, content)| {
@@ -452,6 +1184,153 @@ mod tests {
         assert_eq!(s.history(), ["intro", "features"]);
     }
 
+    #[test]
+    fn path_to_follows_next_edges_to_a_later_node() {
+        let s = hello_session();
+        let path = s.path_to(1).expect("features is reachable");
+        assert_eq!(
+            path,
+            [Step {
+                edge: Edge::Next {
+                    target: "features".to_owned()
+                },
+                option: None,
+                to: 1,
+            }]
+        );
+        assert_eq!(s.path_to(0), Some(Vec::new()));
+    }
+
+    #[test]
+    fn path_to_names_the_branch_option_a_route_needs() {
+        let mut s = hello_session();
+        let path = s.path_to(5).expect("thanks is reachable");
+        let landings: Vec<usize> = path.iter().map(|step| step.to).collect();
+        // Straight through "Finish", not via either demo.
+        assert_eq!(landings, [1, 2, 5]);
+        assert_eq!(path[2].option, Some(2));
+        assert!(matches!(
+            &path[2].edge,
+            Edge::Branch { key: Some(key), .. } if key == "c"
+        ));
+
+        s.goto("thanks");
+        assert_eq!(s.path_to(0), None, "a terminal node reaches nothing");
+        assert_eq!(s.path_to(99), None);
+    }
+
+    fn linear_session(end: EndBehavior) -> Session {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[]},
+                {"id":"b","traversal":"c","content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let mut s = Session::new(graph).expect("non-empty");
+        s.set_end_behavior(end);
+        s
+    }
+
+    #[test]
+    fn without_wrap_both_ends_clamp() {
+        let mut s = linear_session(EndBehavior::Stop);
+        assert_eq!(s.back(), Outcome::HistoryEmpty);
+        assert_eq!(s.current().id, "a");
+        s.next();
+        s.next();
+        assert_eq!(s.next(), Outcome::EndOfPath);
+        assert_eq!(s.current().id, "c");
+    }
+
+    #[test]
+    fn with_wrap_next_at_the_end_returns_to_the_entry() {
+        let mut s = linear_session(EndBehavior::Restart);
+        s.next();
+        s.next();
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "a");
+        assert_eq!(s.history(), ["a", "b", "c"], "a wrap is one more step");
+    }
+
+    #[test]
+    fn with_wrap_back_on_the_entry_goes_to_the_last_node() {
+        let mut s = linear_session(EndBehavior::Restart);
+        assert_eq!(s.back(), Outcome::Moved);
+        assert_eq!(s.current().id, "c");
+        assert!(s.history().is_empty(), "back pushes no history");
+        // Nothing behind c, and c isn't the entry: no further wrap.
+        assert_eq!(s.back(), Outcome::HistoryEmpty);
+    }
+
+    #[test]
+    fn with_wrap_a_single_node_deck_stays_put() {
+        let graph = Graph::from_json(r#"{"nodes":[{"id":"only","content":[]}]}"#).expect("parse");
+        let mut s = Session::new(graph).expect("non-empty");
+        s.set_end_behavior(EndBehavior::Restart);
+        assert_eq!(s.next(), Outcome::EndOfPath);
+        assert_eq!(s.back(), Outcome::HistoryEmpty);
+        assert_eq!(s.current().id, "only");
+        assert!(s.history().is_empty());
+    }
+
+    #[test]
+    fn hold_stays_on_the_last_node_and_the_first() {
+        let mut s = linear_session(EndBehavior::Hold);
+        assert_eq!(s.back(), Outcome::HistoryEmpty);
+        assert_eq!(s.current().id, "a");
+        s.next();
+        s.next();
+        assert_eq!(s.next(), Outcome::EndOfPath);
+        assert_eq!(s.peek_next(), None);
+        assert_eq!(s.current().id, "c");
+    }
+
+    #[test]
+    fn bounce_turns_around_at_the_end_and_again_at_the_entry() {
+        let mut s = linear_session(EndBehavior::Bounce);
+        s.next();
+        s.next();
+        assert_eq!(s.peek_next(), Some(1));
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "b");
+        assert_eq!(s.peek_next(), Some(0), "still walking back");
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "a");
+        assert!(s.history().is_empty(), "walking back pops history");
+        assert_eq!(s.peek_next(), Some(1));
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "b", "the entry turns it forward again");
+        // `back` on the entry itself has nothing to bounce off.
+        s.back();
+        assert_eq!(s.back(), Outcome::HistoryEmpty);
+    }
+
+    #[test]
+    fn bounce_on_a_single_node_deck_reports_the_end_instead_of_spinning() {
+        let graph = Graph::from_json(r#"{"nodes":[{"id":"only","content":[]}]}"#).expect("parse");
+        let mut s = Session::new(graph).expect("non-empty");
+        s.set_end_behavior(EndBehavior::Bounce);
+        assert_eq!(s.peek_next(), None);
+        assert_eq!(s.next(), Outcome::EndOfPath);
+        assert_eq!(s.next(), Outcome::EndOfPath);
+        assert_eq!(s.back(), Outcome::HistoryEmpty);
+        assert_eq!(s.current().id, "only");
+    }
+
+    #[test]
+    fn a_goto_mid_bounce_faces_forward_again() {
+        let mut s = linear_session(EndBehavior::Bounce);
+        s.next();
+        s.next();
+        s.next();
+        assert_eq!(s.current().id, "b", "bounced off c");
+        assert_eq!(s.goto("a"), Outcome::Moved);
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "b", "forward from a, not back");
+    }
+
     #[test]
     fn next_is_blocked_at_branch_point_without_mutating() {
         let mut s = hello_session();
@@ -473,6 +1352,138 @@ mod tests {
         assert_eq!(s.history(), ["intro", "features", "choose"]);
     }
 
+    #[test]
+    fn the_last_choice_at_a_branch_point_survives_going_back() {
+        let mut s = hello_session();
+        s.next();
+        s.next(); // at "choose"
+        assert_eq!(s.last_choice("choose"), None);
+        assert_eq!(s.choose(1), Outcome::Moved);
+        s.back();
+        assert_eq!(s.current().id, "choose");
+        assert_eq!(s.last_choice("choose"), Some(1));
+        assert_eq!(s.choose(99), Outcome::InvalidChoice);
+        assert_eq!(s.last_choice("choose"), Some(1), "a failed choice keeps it");
+    }
+
+    #[test]
+    fn choosing_one_of_two_options_covers_half_of_that_branch() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"ask","content":[],"traversal":{"branch-point":{"options":[
+                    {"label":"Deep","key":"d","target":"deep"},
+                    {"label":"Skim","target":"wrap"}
+                ]}}},
+                {"id":"deep","traversal":"wrap","content":[]},
+                {"id":"wrap","content":[]}
+            ]}"#,
+        );
+        let coverage = s.branch_coverage();
+        assert_eq!((coverage.taken(), coverage.total()), (0, 2));
+
+        assert_eq!(s.choose(0), Outcome::Moved);
+        s.back();
+        assert_eq!(s.choose(0), Outcome::Moved, "twice still counts once");
+        let coverage = s.branch_coverage();
+        assert_eq!(coverage.branch("ask").map(BranchTally::percent), Some(50));
+        assert_eq!(coverage.to_string(), "visited 1 of 2 branch options");
+        let untaken: Vec<_> = coverage
+            .untaken()
+            .map(|(node, option)| (node, option.key.as_str(), option.label.as_str()))
+            .collect();
+        assert_eq!(untaken, [("ask", "2", "Skim")]);
+
+        s.back();
+        assert_eq!(s.choose(1), Outcome::Moved);
+        assert_eq!(
+            s.branch_coverage().branch("ask").map(BranchTally::percent),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn a_choice_sets_its_variable_and_hides_blocks_that_need_another() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"ask","traversal":{"branch-point":{"options":[
+                    {"label":"Deep","key":"d","target":"end"},
+                    {"label":"Skim","target":"end"}]}},"content":[]},
+                {"id":"end","content":[
+                    {"kind":"text","body":"always"},
+                    {"kind":"text","body":"deep","visible-if":"ask == d","reveal":1},
+                    {"kind":"text","body":"skim","visible-if":"ask == 2"}
+                ]}
+            ]}"#,
+        );
+        assert!(s.variables().is_empty());
+        assert_eq!(s.choose(1), Outcome::Moved);
+        assert_eq!(s.variables().get("ask").map(String::as_str), Some("2"));
+        let shown: Vec<_> = s
+            .visible_content()
+            .iter()
+            .map(ContentBlock::plain_text)
+            .collect();
+        assert_eq!(shown, ["always", "skim"]);
+        assert!(
+            !s.has_pending_reveal(),
+            "a step only a hidden block uses isn't one"
+        );
+
+        s.back();
+        assert_eq!(s.choose(0), Outcome::Moved);
+        assert_eq!(s.variables().get("ask").map(String::as_str), Some("d"));
+        assert_eq!(s.visible_content().len(), 2);
+        assert!(s.has_pending_reveal());
+    }
+
+    #[test]
+    fn the_observer_hears_each_move_in_order() {
+        use std::sync::{Arc, Mutex};
+
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"start","traversal":"ask","content":[
+                    {"kind":"text","body":"later","reveal":1}]},
+                {"id":"ask","traversal":{"branch-point":{"options":[
+                    {"label":"Deep","key":"d","target":"end"},
+                    {"label":"Skim","target":"end"}]}},"content":[]},
+                {"id":"end","content":[]}
+            ]}"#,
+        );
+        let heard = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&heard);
+        s.set_observer(Box::new(move |event| sink.lock().unwrap().push(event)));
+
+        assert_eq!(s.next(), Outcome::Revealed);
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.next(), Outcome::BlockedByBranch);
+        assert_eq!(s.choose(1), Outcome::Moved);
+        assert_eq!(s.back(), Outcome::Moved);
+        assert_eq!(s.choose(0), Outcome::Moved);
+        assert_eq!(s.goto("start"), Outcome::Moved);
+        assert_eq!(s.goto("nowhere"), Outcome::UnknownNode("nowhere".into()));
+
+        use TraversalEvent::{Advanced, ChoseBranch, Jumped, WentBack};
+        assert_eq!(
+            *heard.lock().unwrap(),
+            [
+                Advanced { from: 0, to: 1 },
+                ChoseBranch {
+                    from: 1,
+                    to: 2,
+                    key: "2".into()
+                },
+                WentBack { from: 2, to: 1 },
+                ChoseBranch {
+                    from: 1,
+                    to: 2,
+                    key: "d".into()
+                },
+                Jumped { from: 2, to: 0 },
+            ]
+        );
+    }
+
     #[test]
     fn choose_is_invalid_outside_branch_point() {
         let mut s = hello_session();
@@ -506,6 +1517,26 @@ mod tests {
         assert!(s.history().is_empty());
     }
 
+    #[test]
+    fn goto_by_id_returns_the_new_index_and_records_history() {
+        let mut s = hello_session();
+        let idx = s.goto_by_id("thanks").expect("thanks exists");
+        assert_eq!(s.graph().nodes[idx].id, "thanks");
+        assert_eq!(s.current_index(), idx);
+        assert_eq!(s.history(), ["intro"]);
+    }
+
+    #[test]
+    fn goto_by_id_with_an_unknown_id_names_it_and_moves_nothing() {
+        let mut s = hello_session();
+        s.next();
+        let before = s.current_index();
+        let err = s.goto_by_id("nope").expect_err("no such node");
+        assert!(matches!(&err, EngineError::UnknownNode(id) if id == "nope"));
+        assert_eq!(s.current_index(), before);
+        assert_eq!(s.history(), ["intro"]);
+    }
+
     #[test]
     fn back_pops_one_entry_and_pushes_none() {
         let mut s = hello_session();
@@ -536,6 +1567,191 @@ mod tests {
         assert_eq!(s.current().id, "features");
     }
 
+    #[test]
+    fn next_skips_a_hidden_middle_node_and_back_returns_past_it() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":"draft","content":[]},
+                {"id":"draft","hidden":true,"traversal":"c","content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        );
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "c");
+        assert_eq!(s.history(), ["a"], "the skipped node is never pushed");
+        assert!(!s.visited().contains("draft"));
+        assert_eq!(s.back(), Outcome::Moved);
+        assert_eq!(s.current().id, "a");
+    }
+
+    #[test]
+    fn goto_still_reaches_a_hidden_node_and_back_steps_over_it() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":"c","content":[]},
+                {"id":"draft","hidden":true,"traversal":"c","content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        );
+        assert_eq!(s.goto("draft"), Outcome::Moved);
+        assert_eq!(s.current().id, "draft");
+        assert_eq!(s.next(), Outcome::Moved);
+        assert_eq!(s.current().id, "c");
+        assert_eq!(s.back(), Outcome::Moved);
+        assert_eq!(s.current().id, "a", "back steps over the hidden entry");
+    }
+
+    #[test]
+    fn a_hidden_node_with_nowhere_to_go_ends_the_path() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":"draft","content":[]},
+                {"id":"draft","hidden":true,"content":[]}
+            ]}"#,
+        );
+        assert_eq!(s.next(), Outcome::EndOfPath);
+        assert_eq!(s.current().id, "a");
+        assert!(s.history().is_empty());
+    }
+
+    #[test]
+    fn choose_steps_over_a_hidden_target_too() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"x","target":"draft"}
+                ]}},"content":[]},
+                {"id":"draft","hidden":true,"traversal":"c","content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        );
+        assert_eq!(s.choose(0), Outcome::Moved);
+        assert_eq!(s.current().id, "c");
+    }
+
+    #[test]
+    fn peeking_along_a_sequence_moves_nothing() {
+        let mut s = linear_session(EndBehavior::Stop);
+        assert_eq!(s.peek_next(), Some(1));
+        assert_eq!(s.peek_back(), None);
+        assert_eq!((s.current_index(), s.history().len()), (0, 0));
+        s.next();
+        assert_eq!((s.peek_back(), s.peek_next()), (Some(0), Some(2)));
+        s.next();
+        assert_eq!(s.peek_next(), None, "the end of the path");
+
+        let mut wrapping = linear_session(EndBehavior::Restart);
+        assert_eq!(wrapping.peek_back(), Some(2));
+        wrapping.next();
+        wrapping.next();
+        assert_eq!(wrapping.peek_next(), Some(0));
+    }
+
+    #[test]
+    fn peek_next_follows_an_explicit_next_over_hidden_nodes() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":"draft","content":[]},
+                {"id":"c","content":[]},
+                {"id":"draft","hidden":true,"traversal":"c","content":[]}
+            ]}"#,
+        );
+        assert_eq!(s.peek_next(), Some(1));
+        s.next();
+        assert_eq!(s.current_index(), 1, "next lands where peek said");
+        assert_eq!(s.peek_back(), Some(0));
+    }
+
+    #[test]
+    fn peek_next_needs_a_choice_at_a_branch_point() {
+        let s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"B","target":"b"},
+                    {"label":"Draft","target":"draft"}
+                ]}},"content":[]},
+                {"id":"b","content":[]},
+                {"id":"draft","hidden":true,"content":[]}
+            ]}"#,
+        );
+        assert_eq!(s.peek_next(), None);
+        assert_eq!(s.peek_branch_options(), [Some(1), None]);
+        assert!(
+            linear_session(EndBehavior::Stop)
+                .peek_branch_options()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn a_path_code_round_trips_position_and_history() {
+        let mut s = hello_session();
+        s.next(); // features
+        s.next(); // choose
+        s.choose(1); // layout-demo
+        let code = s.encode_path();
+        assert_eq!(code, "1.intro.features.choose.layout-demo");
+
+        let mut replay = hello_session();
+        assert_eq!(
+            replay.decode_path(&code).expect("decodes"),
+            Vec::<String>::new()
+        );
+        assert_eq!(replay.current().id, "layout-demo");
+        assert_eq!(replay.history(), s.history());
+        assert_eq!(replay.visited(), s.visited());
+    }
+
+    #[test]
+    fn path_codes_escape_ids_that_are_not_url_safe() {
+        // A parsed deck's ids are letters, digits, `-` and `_` — but the
+        // letters needn't be ASCII.
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a_b","traversal":"café-2","content":[]},
+                {"id":"café-2","content":[]}
+            ]}"#,
+        );
+        s.next();
+        let code = s.encode_path();
+        assert_eq!(code, "1.a_b.caf%C3%A9-2");
+        let mut replay =
+            session_from(r#"{"nodes":[{"id":"a_b","content":[]},{"id":"café-2","content":[]}]}"#);
+        replay.decode_path(&code).expect("decodes");
+        assert_eq!(replay.current().id, "café-2");
+        assert_eq!(replay.history(), ["a_b"]);
+    }
+
+    #[test]
+    fn decoding_skips_ids_the_deck_no_longer_has() {
+        let mut s = hello_session();
+        let skipped = s
+            .decode_path("1.intro.gone.features")
+            .expect("still decodes");
+        assert_eq!(skipped, ["gone"]);
+        assert_eq!(s.current().id, "features");
+        assert_eq!(s.history(), ["intro"]);
+    }
+
+    #[test]
+    fn unreadable_or_foreign_paths_leave_the_session_alone() {
+        let mut s = hello_session();
+        assert!(matches!(
+            s.decode_path("2.intro"),
+            Err(EngineError::UnreadablePath(_))
+        ));
+        assert!(matches!(
+            s.decode_path("1.bad%zz"),
+            Err(EngineError::UnreadablePath(_))
+        ));
+        assert!(matches!(
+            s.decode_path("1.nowhere"),
+            Err(EngineError::PathNotInDeck)
+        ));
+        assert_eq!(s.current().id, "intro");
+        assert!(s.history().is_empty());
+    }
+
     #[test]
     fn visited_tracks_every_node_seen() {
         let mut s = hello_session();
@@ -640,6 +1856,30 @@ mod tests {
         assert_eq!(s.reveal_progress(), Some((0, 1)));
     }
 
+    #[test]
+    fn back_takes_reveal_steps_back_before_leaving_the_node() {
+        let mut s = session_from(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[]},
+                {"id":"b","content":[
+                    {"kind":"text","body":"x","reveal":1},
+                    {"kind":"text","body":"y","reveal":4}
+                ]}
+            ]}"#,
+        );
+        s.next();
+        s.next();
+        s.next();
+        assert_eq!(s.reveal_level(), 4);
+        assert_eq!(s.back(), Outcome::Concealed);
+        assert_eq!(s.reveal_level(), 1);
+        assert_eq!(s.back(), Outcome::Concealed);
+        assert_eq!(s.reveal_level(), 0);
+        assert_eq!(s.current().id, "b");
+        assert_eq!(s.back(), Outcome::Moved);
+        assert_eq!(s.current().id, "a");
+    }
+
     #[test]
     fn reveal_progress_is_none_for_ordinary_nodes() {
         let s = hello_session();
@@ -680,14 +1920,19 @@ mod tests {
         /// deliberately included in the generated sequence and asserted
         /// to leave both `history()` and `current()` untouched, per the
         /// "failed operations never mutate history" invariant documented
-        /// at the top of this module.
+        /// at the top of this module. Runs under every [`EndBehavior`]: a
+        /// moving `next` pushes one entry, except that a bounce may pop
+        /// one instead, and a restarting `back` from the entry node
+        /// starts the path over at the last node.
         #[test]
         fn session_history_and_visited_stay_truthful(
-            (graph, ops) in proptest_support::arbitrary_graph_and_ops()
+            (graph, ops) in proptest_support::arbitrary_graph_and_ops(),
+            end in proptest_support::arbitrary_end_behavior(),
         ) {
             let node_ids: std::collections::HashSet<String> =
                 graph.nodes.iter().map(|n| n.id.clone()).collect();
             let mut session = Session::new(graph).expect("generator always produces >=1 node");
+            session.set_end_behavior(end);
 
             // `path` mirrors the full sequence of nodes entered, including
             // the starting entry node — `history()` is always `path` minus
@@ -704,19 +1949,24 @@ mod tests {
                     proptest_support::SessionOp::Goto(ref target) => {
                         session.goto(target) == Outcome::Moved
                     }
-                    proptest_support::SessionOp::Back => {
-                        let was_back = session.back() == Outcome::Moved;
-                        if was_back {
-                            path.pop();
-                        }
-                        was_back
-                    }
+                    proptest_support::SessionOp::Back => session.back() == Outcome::Moved,
                 };
 
-                // `Back`'s path bookkeeping already happened above (it
-                // pops rather than pushes); every other op that moved
-                // pushes the new current node.
-                if moved && !matches!(op, proptest_support::SessionOp::Back) {
+                // A `back`, or a bouncing `next`, pops; a restarting `back`
+                // with nothing behind it starts over; every other op that
+                // moved pushes the new current node.
+                let popped = session.history().len() < before_history.len();
+                if moved && matches!(op, proptest_support::SessionOp::Next) && popped {
+                    proptest::prop_assert_eq!(end, EndBehavior::Bounce, "only a bounce walks next back");
+                    path.pop();
+                } else if moved && matches!(op, proptest_support::SessionOp::Back) {
+                    if before_history.is_empty() {
+                        proptest::prop_assert_eq!(end, EndBehavior::Restart, "only a restart goes back from nothing");
+                        path = vec![session.current().id.clone()];
+                    } else {
+                        path.pop();
+                    }
+                } else if moved {
                     path.push(session.current().id.clone());
                 }
 
@@ -758,20 +2008,28 @@ mod tests {
         /// immediate `back()` returns to that exact node id — `back`
         /// inverts a moving `next`, though not necessarily its reveal
         /// progress, since re-entering any node always resets
-        /// `reveal_level` to `0` by design.
+        /// `reveal_level` to `0` by design. Runs under every
+        /// [`EndBehavior`]; a bounce that has turned around steps back
+        /// past reveals, so (2) and (3) only judge a `next` facing forward.
         #[test]
         fn reveal_state_stays_valid_and_next_back_are_consistent(
-            (graph, ops) in proptest_support::arbitrary_reveal_graph_and_ops()
+            (graph, ops) in proptest_support::arbitrary_reveal_graph_and_ops(),
+            end in proptest_support::arbitrary_end_behavior(),
         ) {
             let mut session = Session::new(graph).expect("generator always produces >=1 node");
+            session.set_end_behavior(end);
 
             for op in ops {
                 let pending_before = session.has_pending_reveal();
                 let id_before = session.current().id.clone();
+                let depth_before = session.history().len();
 
                 if matches!(op, proptest_support::SessionOp::Next) {
+                    let reversed = session.reversed;
                     let outcome = session.next();
-                    if pending_before {
+                    if reversed {
+                        // Walking back, as `back` would.
+                    } else if pending_before {
                         proptest::prop_assert_eq!(
                             outcome,
                             Outcome::Revealed,
@@ -782,7 +2040,7 @@ mod tests {
                             &id_before,
                             "a revealing next() must not change the current node"
                         );
-                    } else if outcome == Outcome::Moved {
+                    } else if outcome == Outcome::Moved && session.history().len() > depth_before {
                         proptest::prop_assert_eq!(
                             session.back(),
                             Outcome::Moved,
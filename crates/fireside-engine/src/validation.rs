@@ -7,8 +7,14 @@
 
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
+use std::time::Duration;
 
-use fireside_core::{ContentBlock, Graph, Node, TraversalSpec};
+use fireside_core::{
+    ContentBlock, Graph, Node, NodeId, TraversalSpec, format_duration, parse_hex_color,
+};
+
+use crate::embed::parse_range;
+use crate::expr::Expr;
 
 /// The presenter's global single-key commands (`fireside-tui`'s
 /// `App::on_present_key`: quit, help, map, quick-edit, notes, timer, next/
@@ -19,8 +25,24 @@ use fireside_core::{ContentBlock, Graph, Node, TraversalSpec};
 /// this list; `protocol/validate.mjs` keeps a hand-mirrored copy, checked
 /// against this list's behavior via the shared fixture corpus (see
 /// `protocol/fixtures/valid/reserved-branch-key.json`).
-pub const RESERVED_PRESENTER_KEYS: [char; 12] =
-    ['e', 'f', 'g', 'h', 'j', 'k', 'm', 'n', 'p', 'q', 's', 't'];
+pub const RESERVED_PRESENTER_KEYS: [char; 13] = [
+    ':', 'e', 'f', 'g', 'h', 'j', 'k', 'm', 'n', 'p', 'q', 's', 't',
+];
+
+/// Rules that can only be judged over the whole deck — duplicate ids,
+/// section runs, reachability from the entry node — or that judge a node
+/// by slides it doesn't point at: the choice a `visible-if` names, and the
+/// hidden slides past a branch option's target. [`validate_incremental`]
+/// never reports them, so a caller merging its findings into an earlier
+/// [`validate`] keeps these as they were until the next full pass.
+pub const DECK_WIDE_RULES: [&str; 6] = [
+    "unique-node-ids",
+    "section-not-contiguous",
+    "unreachable-node",
+    "unmarked-dead-end",
+    "unknown-visible-if-choice",
+    "hidden-branch-target",
+];
 
 /// How serious a diagnostic is.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -102,23 +124,125 @@ fn edges(node: &Node) -> Vec<Edge<'_>> {
 #[must_use]
 pub fn validate(graph: &Graph) -> Vec<Diagnostic> {
     let ids: HashSet<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+    let by_id: HashMap<&str, &Node> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
 
     let mut diags = Vec::new();
     check_unique_node_ids(graph, &mut diags);
-    check_valid_targets(graph, &ids, &mut diags);
-    check_next_branch_point_conflict(graph, &mut diags);
-    check_branch_options(graph, &mut diags);
-    check_reserved_branch_keys(graph, &mut diags);
-    check_container_nesting_depth(graph, &mut diags);
-    check_empty_traversal(graph, &mut diags);
-    check_reveal_masked_by_container(graph, &mut diags);
-    check_ascii_art_too_wide(graph, &mut diags);
-    check_ascii_art_empty(graph, &mut diags);
-    check_malformed_link_urls(graph, &mut diags);
-    check_reachability(graph, &ids, &mut diags);
-    check_self_loops(graph, &mut diags);
-    check_trivial_cycles(graph, &mut diags);
-    check_dead_end_branches(graph, &mut diags);
+    check_valid_targets(&graph.nodes, &ids, &mut diags);
+    check_next_branch_point_conflict(&graph.nodes, &mut diags);
+    check_branch_options(&graph.nodes, &mut diags);
+    check_default_options(&graph.nodes, &mut diags);
+    check_reserved_branch_keys(&graph.nodes, &mut diags);
+    check_branch_description_length(&graph.nodes, &mut diags);
+    check_hidden_branch_targets(&graph.nodes, &by_id, &mut diags);
+    check_container_nesting_depth(&graph.nodes, &mut diags);
+    check_empty_traversal(&graph.nodes, &mut diags);
+    check_reveal_masked_by_container(&graph.nodes, &mut diags);
+    check_ascii_art_too_wide(&graph.nodes, &mut diags);
+    check_ascii_art_empty(&graph.nodes, &mut diags);
+    check_empty_columns(&graph.nodes, &mut diags);
+    check_duplicate_block_anchors(&graph.nodes, &mut diags);
+    check_visible_if(&graph.nodes, &by_id, &mut diags);
+    check_section_contiguity(graph, &mut diags);
+    check_duration_range(&graph.nodes, &mut diags);
+    check_node_colors(&graph.nodes, &mut diags);
+    check_heading_hierarchy(&graph.nodes, &mut diags);
+    check_malformed_link_urls(&graph.nodes, &mut diags);
+    check_missing_alt_text(&graph.nodes, &mut diags);
+    check_embed_ranges(&graph.nodes, &mut diags);
+    let reachable = reachable_ids(graph, &ids);
+    check_reachability(graph, &reachable, &mut diags);
+    check_unmarked_dead_ends(graph, &reachable, &mut diags);
+    check_self_loops(&graph.nodes, &mut diags);
+    check_trivial_cycles(&graph.nodes, &by_id, &mut diags);
+    check_dead_end_branches(&graph.nodes, &by_id, &mut diags);
+
+    diags.sort_by_key(|d| std::cmp::Reverse(d.severity));
+    diags
+}
+
+/// Node id → the ids of the nodes whose `next` or branch options point at
+/// it, in deck order — what [`validate_incremental`] follows to find the
+/// nodes an edit can affect besides the edited one. Build it once, and
+/// again after any edit that adds, removes, renames, or rewires a node.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReverseRefs(HashMap<NodeId, Vec<NodeId>>);
+
+impl ReverseRefs {
+    /// Indexes every edge in `graph`.
+    #[must_use]
+    pub fn new(graph: &Graph) -> Self {
+        let mut map: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for node in &graph.nodes {
+            for edge in edges(node) {
+                let referrers = map.entry(edge.target.to_owned()).or_default();
+                if !referrers.contains(&node.id) {
+                    referrers.push(node.id.clone());
+                }
+            }
+        }
+        Self(map)
+    }
+
+    /// The nodes pointing at `id`; empty when none do.
+    #[must_use]
+    pub fn referrers(&self, id: &str) -> &[NodeId] {
+        self.0.get(id).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// [`validate`] narrowed to one edited node: every rule that judges a node
+/// on its own or by what it points at, run over `graph.nodes[changed]` and
+/// the nodes `refs` says point at it — a dangling `next`, say, or a choice
+/// that now leads to a dead end. [`DECK_WIDE_RULES`] wait for the next full
+/// pass. Errors first; empty when `changed` is out of range.
+///
+/// Rewiring the edited node's own `next` or options doesn't change who
+/// points at it, so `refs` can predate that edit; it must be rebuilt once
+/// any node is added, removed, renamed, or another node rewired.
+#[must_use]
+pub fn validate_incremental(graph: &Graph, changed: usize, refs: &ReverseRefs) -> Vec<Diagnostic> {
+    let Some(edited) = graph.nodes.get(changed) else {
+        return Vec::new();
+    };
+    let ids: HashSet<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+    let by_id: HashMap<&str, &Node> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let mut scope = vec![edited.clone()];
+    for id in refs.referrers(&edited.id) {
+        if let Some(&node) = by_id.get(id.as_str())
+            && node.id != edited.id
+        {
+            scope.push(node.clone());
+        }
+    }
+
+    let mut diags = Vec::new();
+    check_valid_targets(&scope, &ids, &mut diags);
+    check_next_branch_point_conflict(&scope, &mut diags);
+    check_branch_options(&scope, &mut diags);
+    check_default_options(&scope, &mut diags);
+    check_reserved_branch_keys(&scope, &mut diags);
+    check_branch_description_length(&scope, &mut diags);
+    check_container_nesting_depth(&scope, &mut diags);
+    check_empty_traversal(&scope, &mut diags);
+    check_reveal_masked_by_container(&scope, &mut diags);
+    check_ascii_art_too_wide(&scope, &mut diags);
+    check_ascii_art_empty(&scope, &mut diags);
+    check_empty_columns(&scope, &mut diags);
+    check_duplicate_block_anchors(&scope, &mut diags);
+    check_visible_if(&scope, &by_id, &mut diags);
+    check_duration_range(&scope, &mut diags);
+    check_node_colors(&scope, &mut diags);
+    check_heading_hierarchy(&scope, &mut diags);
+    check_malformed_link_urls(&scope, &mut diags);
+    check_missing_alt_text(&scope, &mut diags);
+    check_embed_ranges(&scope, &mut diags);
+    check_self_loops(&scope, &mut diags);
+    check_trivial_cycles(&scope, &by_id, &mut diags);
+    check_dead_end_branches(&scope, &by_id, &mut diags);
+    // `check_visible_if` reports unreadable conditions, which are the
+    // node's own, alongside unknown choice names, which aren't.
+    diags.retain(|d| !DECK_WIDE_RULES.contains(&d.rule));
 
     diags.sort_by_key(|d| std::cmp::Reverse(d.severity));
     diags
@@ -153,8 +277,8 @@ fn check_unique_node_ids(graph: &Graph, diags: &mut Vec<Diagnostic>) {
 }
 
 /// ERROR: every next/branch target must exist (required checks 2 and 3).
-fn check_valid_targets(graph: &Graph, ids: &HashSet<&str>, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_valid_targets(nodes: &[Node], ids: &HashSet<&str>, diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         for edge in edges(node) {
             if !ids.contains(edge.target) {
                 let via = edge
@@ -176,14 +300,14 @@ fn check_valid_targets(graph: &Graph, ids: &HashSet<&str>, diags: &mut Vec<Diagn
 }
 
 /// ERROR: `next` and `branch-point` are mutually exclusive (required check 5).
-fn check_next_branch_point_conflict(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_next_branch_point_conflict(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         if node.next_target().is_some() && node.branch_point().is_some() {
             diags.push(Diagnostic::new(
                 Severity::Error,
                 "next-branch-point-conflict",
                 format!(
-                    "\"{}\" has both \"next\" and \"branch-point\" — keep the branch point and wire each option's return instead",
+                    "\"{}\" has both \"next\" and \"branch-point\" — the branch point wins and \"next\" is never followed; keep the branch point and wire each option's return instead",
                     node.id
                 ),
                 Some(&node.id),
@@ -194,8 +318,38 @@ fn check_next_branch_point_conflict(graph: &Graph, diags: &mut Vec<Diagnostic>)
 
 /// ERROR: branch points need at least one option, and option keys must be
 /// unique within a branch point (required check 4).
-fn check_branch_options(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+/// ERROR: a branch point's `default-option` names a key none of its
+/// options has, so its timeout would take the first option instead of
+/// the one meant.
+fn check_default_options(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        let Some(bp) = node.branch_point() else {
+            continue;
+        };
+        let Some(wanted) = bp.default_option.as_deref() else {
+            continue;
+        };
+        let named = bp.options.iter().any(|o| {
+            o.key
+                .as_deref()
+                .is_some_and(|key| key.eq_ignore_ascii_case(wanted))
+        });
+        if !named {
+            diags.push(Diagnostic::new(
+                Severity::Error,
+                "valid-default-option",
+                format!(
+                    "\"{}\" makes \"{wanted}\" its default option, but none of its options has that key",
+                    node.id
+                ),
+                Some(&node.id),
+            ));
+        }
+    }
+}
+
+fn check_branch_options(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         let Some(bp) = node.branch_point() else {
             continue;
         };
@@ -236,8 +390,8 @@ fn check_branch_options(graph: &Graph, diags: &mut Vec<Diagnostic>) {
 /// reserved global single-key commands — the option can never be selected
 /// by keyboard, because the global action always wins (see
 /// `RESERVED_PRESENTER_KEYS`).
-fn check_reserved_branch_keys(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_reserved_branch_keys(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         let Some(bp) = node.branch_point() else {
             continue;
         };
@@ -264,11 +418,95 @@ fn check_reserved_branch_keys(graph: &Graph, diags: &mut Vec<Diagnostic>) {
     }
 }
 
+/// Longest branch option `description`, in characters, that doesn't draw a
+/// `long-branch-description` warning — about four lines of the menu at the
+/// card's width.
+const MAX_BRANCH_DESCRIPTION: usize = 240;
+
+/// WARNING: a branch option's `description` runs past
+/// [`MAX_BRANCH_DESCRIPTION`] characters, pushing the options after it
+/// down and off a small screen.
+fn check_branch_description_length(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        let Some(bp) = node.branch_point() else {
+            continue;
+        };
+        for opt in &bp.options {
+            let Some(len) = opt.description.as_deref().map(|d| d.chars().count()) else {
+                continue;
+            };
+            if len > MAX_BRANCH_DESCRIPTION {
+                diags.push(Diagnostic::new(
+                    Severity::Warning,
+                    "long-branch-description",
+                    format!(
+                        "\"{}\" describes \"{}\" in {len} characters — past {MAX_BRANCH_DESCRIPTION} the menu crowds out the other options; keep it to a sentence or two",
+                        node.id, opt.label
+                    ),
+                    Some(&node.id),
+                ));
+            }
+        }
+    }
+}
+
+/// WARNING: a branch option leads to a hidden node that presenting can't
+/// step past — the hidden node (or every hidden node its `next` chain runs
+/// through) ends in no edge, a branch point, or a loop, so choosing the
+/// option goes nowhere. A hidden target that continues on to a visible
+/// node is fine: `Session::choose` steps over it.
+fn check_hidden_branch_targets(
+    nodes: &[Node],
+    by_id: &HashMap<&str, &Node>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    for node in nodes {
+        let Some(bp) = node.branch_point() else {
+            continue;
+        };
+        for opt in &bp.options {
+            if by_id
+                .get(opt.target.as_str())
+                .is_some_and(|target| target.hidden && !continues_past_hidden(by_id, target))
+            {
+                diags.push(Diagnostic::new(
+                    Severity::Warning,
+                    "hidden-branch-target",
+                    format!(
+                        "\"{}\" offers \"{}\", but it leads to the hidden node \"{}\" with nowhere visible to go next — presenting skips hidden nodes, so this choice is a dead end",
+                        node.id, opt.label, opt.target
+                    ),
+                    Some(&node.id),
+                ));
+            }
+        }
+    }
+}
+
+/// Whether following `next` edges from the hidden node `start` reaches a
+/// visible node — the same walk `Session::next`/`choose` take.
+fn continues_past_hidden(by_id: &HashMap<&str, &Node>, start: &Node) -> bool {
+    let mut node = start;
+    for _ in 0..by_id.len() {
+        if !node.hidden {
+            return true;
+        }
+        if node.branch_point().is_some() {
+            return false;
+        }
+        let Some(next) = node.next_target().and_then(|id| by_id.get(id)) else {
+            return false;
+        };
+        node = next;
+    }
+    !node.hidden
+}
+
 /// WARNING: a present-but-vacuous `Traversal` object (`{}`) behaves like an
 /// absent field — terminal — but is more likely an authoring mistake than
 /// a deliberately omitted field.
-fn check_empty_traversal(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_empty_traversal(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         let Some(TraversalSpec::Rules(t)) = node.traversal.as_ref() else {
             continue;
         };
@@ -295,8 +533,8 @@ const MAX_CONTAINER_NESTING_DEPTH: u32 = 8;
 
 /// ERROR: a node's content nests `Container` blocks deeper than
 /// [`MAX_CONTAINER_NESTING_DEPTH`] (ADR-010).
-fn check_container_nesting_depth(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_container_nesting_depth(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         let depth = node.content.iter().map(container_depth).max().unwrap_or(0);
         if depth > MAX_CONTAINER_NESTING_DEPTH {
             diags.push(Diagnostic::new(
@@ -313,34 +551,38 @@ fn check_container_nesting_depth(graph: &Graph, diags: &mut Vec<Diagnostic>) {
 }
 
 /// `0` for a non-container leaf; `1 + max(child depth)` for a `Container`
-/// (data-model.md's formula, `specs/008-protocol-workflow-hardening/`).
+/// (data-model.md's formula, `specs/008-protocol-workflow-hardening/`) or
+/// a `Columns` block, which nests the same way.
 fn container_depth(block: &ContentBlock) -> u32 {
-    let ContentBlock::Container { children, .. } = block else {
+    let groups = block.child_blocks();
+    if groups.is_empty() {
         return 0;
-    };
-    1 + children.iter().map(container_depth).max().unwrap_or(0)
+    }
+    1 + groups
+        .into_iter()
+        .flatten()
+        .map(container_depth)
+        .max()
+        .unwrap_or(0)
 }
 
 /// WARNING: a child block's own `reveal` value is lower than its
 /// enclosing container's — the child can never actually appear before the
 /// container does, so the lower number is misleading rather than
 /// functional.
-fn check_reveal_masked_by_container(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_reveal_masked_by_container(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         walk_reveal_masking(&node.content, &node.id, diags);
     }
 }
 
 fn walk_reveal_masking(blocks: &[ContentBlock], node_id: &str, diags: &mut Vec<Diagnostic>) {
     for block in blocks {
-        let ContentBlock::Container {
-            children, reveal, ..
-        } = block
-        else {
-            continue;
-        };
-        let container_level = reveal.unwrap_or(0);
-        for child in children {
+        let container_level = block.reveal().unwrap_or(0);
+        for children in block.child_blocks() {
+            walk_reveal_masking(children, node_id, diags);
+        }
+        for child in block.child_blocks().into_iter().flatten() {
             let child_level = child.reveal().unwrap_or(0);
             if child_level < container_level {
                 diags.push(Diagnostic::new(
@@ -353,7 +595,6 @@ fn walk_reveal_masking(blocks: &[ContentBlock], node_id: &str, diags: &mut Vec<D
                 ));
             }
         }
-        walk_reveal_masking(children, node_id, diags);
     }
 }
 
@@ -371,8 +612,8 @@ const MAX_ASCII_ART_WIDTH: usize = 76;
 
 /// WARNING: an `AsciiArt` block's widest line exceeds
 /// [`MAX_ASCII_ART_WIDTH`].
-fn check_ascii_art_too_wide(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_ascii_art_too_wide(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         walk_ascii_art(&node.content, &node.id, diags, |art, node_id, diags| {
             let widest = art
                 .lines()
@@ -395,8 +636,8 @@ fn check_ascii_art_too_wide(graph: &Graph, diags: &mut Vec<Diagnostic>) {
 }
 
 /// WARNING: an `AsciiArt` block's `art` is empty or whitespace-only.
-fn check_ascii_art_empty(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_ascii_art_empty(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         walk_ascii_art(&node.content, &node.id, diags, |art, node_id, diags| {
             if art.trim().is_empty() {
                 diags.push(Diagnostic::new(
@@ -410,7 +651,262 @@ fn check_ascii_art_empty(graph: &Graph, diags: &mut Vec<Diagnostic>) {
     }
 }
 
-/// Walks `blocks` recursively (through `Container` children, like
+/// WARNING: a `Columns` block has a column with nothing in it — an empty
+/// slot that only pushes the other columns aside.
+fn check_empty_columns(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    fn walk(blocks: &[ContentBlock], node_id: &str, diags: &mut Vec<Diagnostic>) {
+        for block in blocks {
+            if let ContentBlock::Columns { columns, .. } = block {
+                for (i, column) in columns.iter().enumerate() {
+                    if column.is_empty() {
+                        diags.push(Diagnostic::new(
+                            Severity::Warning,
+                            "empty-column",
+                            format!(
+                                "\"{node_id}\" has a columns block whose column {} is empty — add content to it or remove the column",
+                                i + 1
+                            ),
+                            Some(node_id),
+                        ));
+                    }
+                }
+            }
+            for children in block.child_blocks() {
+                walk(children, node_id, diags);
+            }
+        }
+    }
+    for node in nodes {
+        walk(&node.content, &node.id, diags);
+    }
+}
+
+/// ERROR: two blocks in the same node (at any container depth) share an
+/// anchor `id`. Anchors only need to be unique within their node, but
+/// there they MUST be: a repeat means a deep link can only ever reach the
+/// first one (required check 9).
+fn check_duplicate_block_anchors(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        collect_duplicate_anchors(&node.content, &mut seen, &mut reported);
+        let mut repeats: Vec<_> = reported.into_iter().collect();
+        repeats.sort_unstable();
+        for anchor in repeats {
+            diags.push(Diagnostic::new(
+                Severity::Error,
+                "duplicate-block-anchor",
+                format!(
+                    "\"{}\" has more than one block with the anchor \"{anchor}\" — rename one so links reach the block you meant",
+                    node.id
+                ),
+                Some(&node.id),
+            ));
+        }
+    }
+}
+
+/// ERROR: a block's `visible-if` doesn't parse, so nothing can say when
+/// the block shows (`valid-visible-if`). WARNING: it reads a variable no
+/// branch point sets, so that part of the condition never sees a choice
+/// (`unknown-visible-if-choice`). Blocks are located by their position,
+/// nested ones dotted after their parent's: `block 2.1`.
+fn check_visible_if(nodes: &[Node], by_id: &HashMap<&str, &Node>, diags: &mut Vec<Diagnostic>) {
+    fn walk<'a>(
+        blocks: impl IntoIterator<Item = &'a ContentBlock>,
+        prefix: &str,
+        node_id: &str,
+        by_id: &HashMap<&str, &Node>,
+        diags: &mut Vec<Diagnostic>,
+    ) {
+        for (i, block) in blocks.into_iter().enumerate() {
+            let at = format!("{prefix}{}", i + 1);
+            match block.visible_if().map(Expr::parse) {
+                Some(Err(err)) => diags.push(Diagnostic::new(
+                    Severity::Error,
+                    "valid-visible-if",
+                    format!("\"{node_id}\" block {at} has a visible-if that can't be read: {err}"),
+                    Some(node_id),
+                )),
+                Some(Ok(condition)) => {
+                    let mut seen = HashSet::new();
+                    for name in condition.variables() {
+                        let sets_it = by_id
+                            .get(name)
+                            .is_some_and(|node| node.branch_point().is_some());
+                        if sets_it || !seen.insert(name) {
+                            continue;
+                        }
+                        diags.push(Diagnostic::new(
+                            Severity::Warning,
+                            "unknown-visible-if-choice",
+                            format!(
+                                "\"{node_id}\" block {at} shows depending on the choice at \"{name}\", but no slide \"{name}\" asks for one — check the id"
+                            ),
+                            Some(node_id),
+                        ));
+                    }
+                }
+                None => {}
+            }
+            let children = block.child_blocks().into_iter().flatten();
+            walk(children, &format!("{at}."), node_id, by_id, diags);
+        }
+    }
+    for node in nodes {
+        walk(&node.content, "", &node.id, by_id, diags);
+    }
+}
+
+/// WARNING: a section's nodes are split by nodes outside it. Section
+/// navigation jumps to a section's first node, so the stray run after the
+/// gap can only be reached slide by slide. Reported once per section, at
+/// the first node that resumes it.
+fn check_section_contiguity(graph: &Graph, diags: &mut Vec<Diagnostic>) {
+    for (name, members) in graph.sections() {
+        let Some(pair) = members.windows(2).find(|pair| pair[1] != pair[0] + 1) else {
+            continue;
+        };
+        let resumed = &graph.nodes[pair[1]].id;
+        diags.push(Diagnostic::new(
+            Severity::Warning,
+            "section-not-contiguous",
+            format!(
+                "\"{resumed}\" is in section \"{name}\", but other slides come between it and the rest of that section — move the section's slides together",
+            ),
+            Some(resumed),
+        ));
+    }
+}
+
+/// Longest `duration` that doesn't draw a `duration-out-of-range` warning.
+/// Past an hour on one slide, the likelier story is minutes typed where
+/// seconds were meant.
+const MAX_NODE_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// WARNING: a node's `duration` is zero, so auto-advance leaves it the
+/// moment it appears, or longer than [`MAX_NODE_DURATION`].
+fn check_duration_range(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        let Some(duration) = node.duration else {
+            continue;
+        };
+        let message = if duration.is_zero() {
+            format!(
+                "\"{}\" has a duration of 0:00, so auto-advance leaves it at once — give it a length or remove the duration",
+                node.id
+            )
+        } else if duration > MAX_NODE_DURATION {
+            format!(
+                "\"{}\" has a duration of {} — over an hour on one slide; check the units (90 is seconds, 90m is minutes)",
+                node.id,
+                format_duration(duration)
+            )
+        } else {
+            continue;
+        };
+        diags.push(Diagnostic::new(
+            Severity::Warning,
+            "duration-out-of-range",
+            message,
+            Some(&node.id),
+        ));
+    }
+}
+
+/// WARNING: a node's `background` or `accent` isn't a hex color, so the
+/// presenter ignores it and keeps the theme's.
+fn check_node_colors(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        for (field, value) in [("background", &node.background), ("accent", &node.accent)] {
+            let Some(value) = value else {
+                continue;
+            };
+            if parse_hex_color(value).is_some() {
+                continue;
+            }
+            diags.push(Diagnostic::new(
+                Severity::Warning,
+                "invalid-node-color",
+                format!(
+                    "\"{}\" has {field} \"{value}\", which isn't a hex color like \"#1e1e2e\" — the theme's color is used instead",
+                    node.id
+                ),
+                Some(&node.id),
+            ));
+        }
+    }
+}
+
+fn collect_duplicate_anchors<'a>(
+    blocks: &'a [ContentBlock],
+    seen: &mut HashSet<&'a str>,
+    repeats: &mut HashSet<&'a str>,
+) {
+    for block in blocks {
+        if let Some(anchor) = block.id()
+            && !seen.insert(anchor)
+        {
+            repeats.insert(anchor);
+        }
+        for children in block.child_blocks() {
+            collect_duplicate_anchors(children, seen, repeats);
+        }
+    }
+}
+
+/// WARNING: a node's headings skip a level on the way down (an h1 followed
+/// by an h3 with no h2 between). Screen readers and outlines navigate by
+/// heading level, so a skip reads as a missing section.
+fn check_heading_hierarchy(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        diags.extend(validate_heading_hierarchy(node));
+    }
+}
+
+/// The `heading-level-skip` warnings for one node: headings are compared
+/// with the previous heading only, in document order and at any container
+/// depth, so other blocks between them don't matter. Stepping back up any
+/// number of levels, repeating a level (several h1s), and a first heading
+/// that isn't an h1 are all fine — only a jump of more than one level
+/// deeper is flagged.
+#[must_use]
+pub fn validate_heading_hierarchy(node: &Node) -> Vec<Diagnostic> {
+    let mut headings = Vec::new();
+    collect_headings(&node.content, &mut headings);
+    headings
+        .windows(2)
+        .filter(|pair| pair[1].0 > pair[0].0 + 1)
+        .map(|pair| {
+            let ((from, _), (to, text)) = (pair[0], pair[1]);
+            Diagnostic::new(
+                Severity::Warning,
+                "heading-level-skip",
+                format!(
+                    "\"{}\" jumps from a level-{from} heading to a level-{to} one (\"{text}\") — make it level {} or add the missing heading between",
+                    node.id,
+                    from + 1
+                ),
+                Some(&node.id),
+            )
+        })
+        .collect()
+}
+
+fn collect_headings<'a>(blocks: &'a [ContentBlock], out: &mut Vec<(u8, &'a str)>) {
+    for block in blocks {
+        match block {
+            ContentBlock::Heading { level, text, .. } => out.push((*level, text)),
+            _ => {
+                for children in block.child_blocks() {
+                    collect_headings(children, out);
+                }
+            }
+        }
+    }
+}
+
+/// Walks `blocks` recursively (through containers and columns, like
 /// `walk_reveal_masking`/`walk_link_urls`), calling `check` on every
 /// `AsciiArt` block's `art` string.
 fn walk_ascii_art(
@@ -422,10 +918,11 @@ fn walk_ascii_art(
     for block in blocks {
         match block {
             ContentBlock::AsciiArt { art, .. } => check(art, node_id, diags),
-            ContentBlock::Container { children, .. } => {
-                walk_ascii_art(children, node_id, diags, check);
+            _ => {
+                for children in block.child_blocks() {
+                    walk_ascii_art(children, node_id, diags, check);
+                }
             }
-            _ => {}
         }
     }
 }
@@ -434,8 +931,8 @@ fn walk_ascii_art(
 /// well-formed URL (contracts/link-syntax.md) — a malformed link must not
 /// block presenting, so this is a warning, not an error, matching every
 /// other content-quality rule in this validator.
-fn check_malformed_link_urls(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_malformed_link_urls(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         walk_link_urls(&node.content, &node.id, diags);
     }
 }
@@ -450,8 +947,11 @@ fn walk_link_urls(blocks: &[ContentBlock], node_id: &str, diags: &mut Vec<Diagno
                     check_text_links(item, node_id, diags);
                 }
             }
-            ContentBlock::Container { children, .. } => walk_link_urls(children, node_id, diags),
-            _ => {}
+            _ => {
+                for children in block.child_blocks() {
+                    walk_link_urls(children, node_id, diags);
+                }
+            }
         }
     }
 }
@@ -471,6 +971,81 @@ fn check_text_links(text: &str, node_id: &str, diags: &mut Vec<Diagnostic>) {
     }
 }
 
+/// INFO: an image with no alt text, or only whitespace, that isn't marked
+/// `decorative` — a screen reader or a text-only export has nothing to say
+/// for it. `fireside validate --require-alt` raises this to an error for
+/// decks that must be accessible.
+fn check_missing_alt_text(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
+        walk_missing_alt(&node.content, &node.id, diags);
+    }
+}
+
+fn walk_missing_alt(blocks: &[ContentBlock], node_id: &str, diags: &mut Vec<Diagnostic>) {
+    for block in blocks {
+        if let ContentBlock::Image { src, .. } = block
+            && needs_alt_text(block)
+        {
+            diags.push(Diagnostic::new(
+                Severity::Info,
+                "missing-alt-text",
+                format!(
+                    "image \"{src}\" on \"{node_id}\" has no alt text — describe it in \"alt\", or mark it \"decorative\": true if it carries no information"
+                ),
+                Some(node_id),
+            ));
+        }
+        for children in block.child_blocks() {
+            walk_missing_alt(children, node_id, diags);
+        }
+    }
+}
+
+/// ERROR: an embed's `range` must be one line or a forward run of them —
+/// otherwise there's no telling which lines to take.
+fn check_embed_ranges(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    fn walk(blocks: &[ContentBlock], node_id: &str, diags: &mut Vec<Diagnostic>) {
+        for block in blocks {
+            if let ContentBlock::Embed {
+                source,
+                range: Some(range),
+                ..
+            } = block
+                && parse_range(range).is_none()
+            {
+                diags.push(Diagnostic::new(
+                    Severity::Error,
+                    "invalid-embed-range",
+                    format!(
+                        "the embed of \"{source}\" on \"{node_id}\" asks for lines \"{range}\" — write one line, like \"12\", or a run, like \"12-20\""
+                    ),
+                    Some(node_id),
+                ));
+            }
+            for children in block.child_blocks() {
+                walk(children, node_id, diags);
+            }
+        }
+    }
+    for node in nodes {
+        walk(&node.content, &node.id, diags);
+    }
+}
+
+/// Whether `block` is an image with nothing to stand in for it: no `alt`,
+/// or only whitespace, and not marked `decorative`.
+#[must_use]
+pub fn needs_alt_text(block: &ContentBlock) -> bool {
+    matches!(
+        block,
+        ContentBlock::Image {
+            alt,
+            decorative: false,
+            ..
+        } if alt.as_deref().is_none_or(|alt| alt.trim().is_empty())
+    )
+}
+
 /// Extracts every link destination found in `text`'s `[label](url)` syntax
 /// — a minimal, independent mirror of `fireside-tui`'s inline-Markdown
 /// parser (`fireside-engine` cannot depend on `fireside-tui` per the crate
@@ -512,12 +1087,32 @@ fn is_well_formed_url(url: &str) -> bool {
 }
 
 /// WARNING: nodes should be reachable from the entry point (recommended 1).
-fn check_reachability(graph: &Graph, ids: &HashSet<&str>, diags: &mut Vec<Diagnostic>) {
+fn check_reachability(graph: &Graph, reachable: &HashSet<&str>, diags: &mut Vec<Diagnostic>) {
     let Some(entry) = graph.entry() else {
         return;
     };
-    let by_id: HashMap<&str, &Node> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    for node in &graph.nodes {
+        if !reachable.contains(node.id.as_str()) {
+            diags.push(Diagnostic::new(
+                Severity::Warning,
+                "unreachable-node",
+                format!(
+                    "\"{}\" can never be reached from the start (\"{}\") — link to it or remove it",
+                    node.id, entry.id
+                ),
+                Some(&node.id),
+            ));
+        }
+    }
+}
+
+/// The ids of every node a walk from the entry node can reach.
+fn reachable_ids<'a>(graph: &'a Graph, ids: &HashSet<&str>) -> HashSet<&'a str> {
     let mut reachable: HashSet<&str> = HashSet::new();
+    let Some(entry) = graph.entry() else {
+        return reachable;
+    };
+    let by_id: HashMap<&str, &Node> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
     let mut queue: VecDeque<&str> = VecDeque::from([entry.id.as_str()]);
 
     while let Some(id) = queue.pop_front() {
@@ -531,25 +1126,41 @@ fn check_reachability(graph: &Graph, ids: &HashSet<&str>, diags: &mut Vec<Diagno
             }
         }
     }
+    reachable
+}
 
-    for node in &graph.nodes {
-        if !reachable.contains(node.id.as_str()) {
-            diags.push(Diagnostic::new(
-                Severity::Warning,
-                "unreachable-node",
-                format!(
-                    "\"{}\" can never be reached from the start (\"{}\") — link to it or remove it",
-                    node.id, entry.id
-                ),
-                Some(&node.id),
-            ));
+/// WARNING: a reachable node with no way forward that isn't the deck's
+/// last node and isn't marked `terminal` — usually a `next` that was
+/// never written rather than an ending. Hidden backup slides, which the
+/// presenter leaves by going back, and empty traversal objects, which
+/// `empty-traversal` already reports, are left alone.
+fn check_unmarked_dead_ends(graph: &Graph, reachable: &HashSet<&str>, diags: &mut Vec<Diagnostic>) {
+    let last = graph.nodes.len().saturating_sub(1);
+    for index in graph.leaf_nodes() {
+        let node = &graph.nodes[index];
+        if index == last
+            || node.terminal
+            || node.hidden
+            || node.traversal.is_some()
+            || !reachable.contains(node.id.as_str())
+        {
+            continue;
         }
+        diags.push(Diagnostic::new(
+            Severity::Warning,
+            "unmarked-dead-end",
+            format!(
+                "\"{}\" stops the path, but it isn't the last slide — give it a \"next\", or mark it \"terminal\": true if the talk can end here",
+                node.id
+            ),
+            Some(&node.id),
+        ));
     }
 }
 
 /// WARNING: a node pointing at itself is usually an accident (recommended 2).
-fn check_self_loops(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    for node in &graph.nodes {
+fn check_self_loops(nodes: &[Node], diags: &mut Vec<Diagnostic>) {
+    for node in nodes {
         for edge in edges(node) {
             if edge.target == node.id {
                 diags.push(Diagnostic::new(
@@ -564,11 +1175,10 @@ fn check_self_loops(graph: &Graph, diags: &mut Vec<Diagnostic>) {
 }
 
 /// WARNING: two-node cycles (A → B → A) are likely accidental (recommended 4).
-fn check_trivial_cycles(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    let by_id: HashMap<&str, &Node> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+fn check_trivial_cycles(nodes: &[Node], by_id: &HashMap<&str, &Node>, diags: &mut Vec<Diagnostic>) {
     let mut reported: HashSet<(String, String)> = HashSet::new();
 
-    for node in &graph.nodes {
+    for node in nodes {
         for edge in edges(node) {
             if edge.target == node.id {
                 continue; // self-loops have their own rule
@@ -597,10 +1207,12 @@ fn check_trivial_cycles(graph: &Graph, diags: &mut Vec<Diagnostic>) {
 
 /// INFO: branch options leading to terminal nodes. Terminal nodes are a
 /// legitimate ending pattern (recommended 5).
-fn check_dead_end_branches(graph: &Graph, diags: &mut Vec<Diagnostic>) {
-    let by_id: HashMap<&str, &Node> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
-
-    for node in &graph.nodes {
+fn check_dead_end_branches(
+    nodes: &[Node],
+    by_id: &HashMap<&str, &Node>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    for node in nodes {
         let Some(bp) = node.branch_point() else {
             continue;
         };
@@ -658,6 +1270,7 @@ mod proptest_support {
             key,
             target,
             description: None,
+            icon: None,
         })
     }
 
@@ -670,13 +1283,13 @@ mod proptest_support {
                 branch_point: None,
             }))),
             2 => vec(arbitrary_branch_option(), 0..3).prop_map(|options| Some(
-                TraversalSpec::Rules(Traversal { next: None, branch_point: Some(BranchPoint { prompt: None, options }) })
+                TraversalSpec::Rules(Traversal { next: None, branch_point: Some(BranchPoint { prompt: None, options, timeout: None, default_option: None }) })
             )),
             // Deliberately invalid: both `next` and `branch-point` set.
             1 => (arbitrary_id(), vec(arbitrary_branch_option(), 0..3)).prop_map(|(next, options)| Some(
                 TraversalSpec::Rules(Traversal {
                     next: Some(next),
-                    branch_point: Some(BranchPoint { prompt: None, options }),
+                    branch_point: Some(BranchPoint { prompt: None, options, timeout: None, default_option: None }),
                 })
             )),
         ]
@@ -687,9 +1300,17 @@ mod proptest_support {
         prop_oneof![
             reveal.clone().prop_map(|reveal| ContentBlock::Text {
                 reveal,
+                id: None,
+                visible_if: None,
                 body: "text with a [link](not really a url)".to_owned(),
             }),
-            reveal.prop_map(|reveal| ContentBlock::Divider { reveal }),
+            reveal.prop_map(|reveal| ContentBlock::Divider {
+                reveal,
+                id: None,
+                visible_if: None,
+                style: None,
+                label: None,
+            }),
         ]
     }
 
@@ -701,8 +1322,11 @@ mod proptest_support {
             (option::of(0u32..4), vec(inner, 0..3)).prop_map(|(reveal, children)| {
                 ContentBlock::Container {
                     reveal,
+                    id: None,
+                    visible_if: None,
                     children,
                     layout: None,
+                    columns: None,
                 }
             })
         })
@@ -721,6 +1345,12 @@ mod proptest_support {
                 transition: None,
                 speaker_notes: None,
                 traversal,
+                section: None,
+                duration: None,
+                hidden: false,
+                terminal: false,
+                background: None,
+                accent: None,
                 content,
             })
     }
@@ -754,6 +1384,128 @@ mod tests {
         diags.iter().map(|d| d.rule).collect()
     }
 
+    #[test]
+    fn editing_a_next_to_a_missing_target_is_caught_incrementally() {
+        let mut graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[]},
+                {"id":"b","traversal":"c","content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        )
+        .expect("fixture parses");
+        let refs = ReverseRefs::new(&graph);
+        assert!(validate_incremental(&graph, 1, &refs).is_empty());
+
+        graph.nodes[1].traversal = Some(TraversalSpec::Target("nowhere".into()));
+        let diags = validate_incremental(&graph, 1, &refs);
+        assert_eq!(rules(&diags), ["valid-traversal-target"]);
+        assert_eq!(diags[0].node.as_deref(), Some("b"));
+        assert_eq!(diags[0].severity, Severity::Error);
+        assert!(validate_incremental(&graph, 9, &refs).is_empty());
+    }
+
+    #[test]
+    fn an_incremental_pass_rechecks_the_nodes_pointing_at_the_edit() {
+        let mut graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"ask","content":[],"traversal":{"branch-point":{"options":[
+                    {"label":"Go","target":"end"}]}}},
+                {"id":"end","traversal":"ask","content":[]},
+                {"id":"orphan","content":[]}
+            ]}"#,
+        )
+        .expect("fixture parses");
+        let refs = ReverseRefs::new(&graph);
+        assert_eq!(refs.referrers("end"), ["ask"]);
+        assert!(refs.referrers("orphan").is_empty());
+
+        graph.nodes[1].traversal = None;
+        let diags = validate_incremental(&graph, 1, &refs);
+        assert_eq!(rules(&diags), ["dead-end-branch"]);
+        assert_eq!(diags[0].node.as_deref(), Some("ask"));
+        let full = validate(&graph);
+        assert!(full.iter().any(|d| d.rule == "unreachable-node"));
+        assert!(
+            diags.iter().all(|d| full.contains(d)),
+            "every incremental finding is one the full pass makes too"
+        );
+    }
+
+    /// `before` with the findings on the edited node and its referrers
+    /// swapped for an incremental pass's, the way the editor merges them —
+    /// then, like the full pass it's compared with, minus
+    /// [`DECK_WIDE_RULES`], sorted.
+    fn merged_without_deck_wide(
+        before: &[Diagnostic],
+        graph: &Graph,
+        changed: usize,
+        refs: &ReverseRefs,
+    ) -> Vec<Diagnostic> {
+        let id = &graph.nodes[changed].id;
+        let referrers = refs.referrers(id);
+        let mut merged: Vec<_> = before
+            .iter()
+            .filter(|d| {
+                d.node
+                    .as_deref()
+                    .is_none_or(|n| n != id && !referrers.iter().any(|r| r == n))
+            })
+            .cloned()
+            .chain(validate_incremental(graph, changed, refs))
+            .collect();
+        merged.retain(|d| !DECK_WIDE_RULES.contains(&d.rule));
+        merged.sort_by(|a, b| a.message.cmp(&b.message));
+        merged
+    }
+
+    fn full_without_deck_wide(graph: &Graph) -> Vec<Diagnostic> {
+        let mut full = validate(graph);
+        full.retain(|d| !DECK_WIDE_RULES.contains(&d.rule));
+        full.sort_by(|a, b| a.message.cmp(&b.message));
+        full
+    }
+
+    #[test]
+    fn merging_an_incremental_pass_agrees_with_a_full_one_on_node_rules() {
+        // Dropping "ask"'s choice leaves "wrap"'s condition naming
+        // nothing, and ending "deeper" strands the choice at "a" two
+        // hidden slides back. Neither "wrap" nor "a" points at the slide
+        // edited, so neither is in the incremental pass's reach.
+        for (json, edited) in [
+            (
+                r#"{"nodes":[
+                    {"id":"ask","content":[],"traversal":{"branch-point":{"options":[
+                        {"label":"Go","key":"d","target":"wrap"}]}}},
+                    {"id":"wrap","content":[
+                        {"kind":"text","body":"Deep","visible-if":"ask == d"}]}
+                ]}"#,
+                0,
+            ),
+            (
+                r#"{"nodes":[
+                    {"id":"a","content":[],"traversal":{"branch-point":{"options":[
+                        {"label":"Backup","target":"backup"},{"label":"On","target":"c"}]}}},
+                    {"id":"backup","hidden":true,"traversal":"deeper","content":[]},
+                    {"id":"deeper","hidden":true,"traversal":"c","content":[]},
+                    {"id":"c","content":[]}
+                ]}"#,
+                2,
+            ),
+        ] {
+            let mut graph = Graph::from_json(json).expect("fixture parses");
+            let before = validate(&graph);
+            let refs = ReverseRefs::new(&graph);
+            graph.nodes[edited].traversal = None;
+            assert_eq!(
+                merged_without_deck_wide(&before, &graph, edited, &refs),
+                full_without_deck_wide(&graph),
+                "editing {}",
+                graph.nodes[edited].id
+            );
+        }
+    }
+
     #[test]
     fn canonical_example_has_no_errors_or_warnings() {
         let diags = diags_for(HELLO);
@@ -785,6 +1537,20 @@ mod tests {
         assert_eq!(targets.len(), 2);
     }
 
+    #[test]
+    fn an_interleaved_section_is_a_warning() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","section":"Intro","traversal":"b","content":[]},
+                {"id":"b","section":"Demo","traversal":"c","content":[]},
+                {"id":"c","section":"Intro","content":[]}
+            ]}"#,
+        );
+        assert_eq!(rules(&diags), ["section-not-contiguous"]);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].node.as_deref(), Some("c"));
+    }
+
     #[test]
     fn next_and_branch_point_together_is_an_error() {
         let diags = diags_for(
@@ -793,7 +1559,12 @@ mod tests {
                 {"id":"b","content":[]}
             ]}"#,
         );
-        assert!(rules(&diags).contains(&"next-branch-point-conflict"));
+        let conflict = diags
+            .iter()
+            .find(|d| d.rule == "next-branch-point-conflict")
+            .expect("conflict reported");
+        assert_eq!(conflict.severity, Severity::Error);
+        assert!(conflict.message.contains("branch point wins"));
     }
 
     #[test]
@@ -971,6 +1742,146 @@ mod tests {
         assert!(!has_errors(&diags));
     }
 
+    #[test]
+    fn empty_column_warns_and_checks_reach_inside_columns() {
+        let diags = diags_for(
+            r#"{"nodes":[{"id":"a","content":[{"kind":"columns","columns":[
+                [],
+                [{"kind":"ascii-art","art":" "}]
+            ]}]}]}"#,
+        );
+        let hits: Vec<_> = diags.iter().filter(|d| d.rule == "empty-column").collect();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].message.contains("column 1"), "{}", hits[0].message);
+        assert!(rules(&diags).contains(&"ascii-art-empty"));
+        assert!(!has_errors(&diags));
+    }
+
+    #[test]
+    fn zero_and_hour_plus_durations_warn() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","duration":0,"traversal":"b","content":[]},
+                {"id":"b","duration":"1h","traversal":"c","content":[]},
+                {"id":"c","duration":"60:01","content":[]}
+            ]}"#,
+        );
+        let hits: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "duration-out-of-range")
+            .collect();
+        assert_eq!(hits.len(), 2, "{diags:?}");
+        assert_eq!(hits[0].node.as_deref(), Some("a"));
+        assert_eq!(hits[1].node.as_deref(), Some("c"));
+        assert!(hits[1].message.contains("60:01"), "{}", hits[1].message);
+        assert!(!has_errors(&diags));
+    }
+
+    #[test]
+    fn only_an_overlong_branch_description_warns() {
+        let json = format!(
+            r#"{{"nodes":[
+                {{"id":"a","traversal":{{"branch-point":{{"options":[
+                    {{"label":"Short","target":"b","description":"One line."}},
+                    {{"label":"Long","target":"b","description":"{}"}}
+                ]}}}},"content":[]}},
+                {{"id":"b","content":[]}}
+            ]}}"#,
+            "word ".repeat(60)
+        );
+        let diags = diags_for(&json);
+        let hits: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "long-branch-description")
+            .collect();
+        assert_eq!(hits.len(), 1, "{diags:?}");
+        assert!(hits[0].message.contains("\"Long\""), "{}", hits[0].message);
+        assert!(!has_errors(&diags));
+    }
+
+    #[test]
+    fn images_without_alt_text_are_flagged_unless_decorative() {
+        let diags = diags_for(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"image","src":"none.png"},
+                {"kind":"image","src":"blank.png","alt":"  "},
+                {"kind":"image","src":"described.png","alt":"A campfire"},
+                {"kind":"image","src":"flourish.png","alt":"","decorative":true},
+                {"kind":"container","children":[{"kind":"image","src":"nested.png"}]}
+            ]}]}"#,
+        );
+        let flagged: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "missing-alt-text")
+            .map(|d| d.message.split('"').nth(1).unwrap_or_default())
+            .collect();
+        assert_eq!(flagged, ["none.png", "blank.png", "nested.png"]);
+        assert!(diags.iter().all(|d| d.severity == Severity::Info));
+    }
+
+    #[test]
+    fn embed_ranges_must_be_a_line_or_a_forward_run() {
+        let diags = diags_for(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"embed","source":"whole.rs"},
+                {"kind":"embed","source":"run.rs","range":"3-9"},
+                {"kind":"embed","source":"backward.rs","range":"9-3"},
+                {"kind":"container","children":[
+                    {"kind":"embed","source":"words.rs","range":"top"}
+                ]}
+            ]}]}"#,
+        );
+        let flagged: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "invalid-embed-range")
+            .map(|d| d.message.split('"').nth(1).unwrap_or_default())
+            .collect();
+        assert_eq!(flagged, ["backward.rs", "words.rs"]);
+        assert!(has_errors(&diags));
+    }
+
+    #[test]
+    fn a_default_option_must_name_an_options_key() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","content":[],"traversal":{"branch-point":{
+                    "timeout":"10s","default-option":"Y","options":[
+                    {"label":"X","key":"x","target":"c"},{"label":"Y","key":"y","target":"c"}]}}},
+                {"id":"b","content":[],"traversal":{"branch-point":{
+                    "default-option":"z","options":[{"label":"X","key":"x","target":"c"}]}}},
+                {"id":"c","content":[]}
+            ]}"#,
+        );
+        let flagged: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "valid-default-option")
+            .map(|d| (d.node.as_deref(), d.severity))
+            .collect();
+        assert_eq!(flagged, [(Some("b"), Severity::Error)]);
+    }
+
+    #[test]
+    fn node_colors_that_are_not_hex_warn() {
+        let diags = diags_for(
+            r##"{"nodes":[
+                {"id":"a","background":"#101010","accent":"#fa0","content":[]},
+                {"id":"b","background":"navy","accent":"#12345","content":[]}
+            ]}"##,
+        );
+        let flagged: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "invalid-node-color")
+            .map(|d| (d.node.as_deref(), d.severity))
+            .collect();
+        assert_eq!(
+            flagged,
+            [
+                (Some("b"), Severity::Warning),
+                (Some("b"), Severity::Warning)
+            ]
+        );
+    }
+
     #[test]
     fn ascii_art_within_limits_produces_no_warning() {
         let diags = diags_for(
@@ -980,6 +1891,146 @@ mod tests {
         assert!(!rules(&diags).contains(&"ascii-art-empty"));
     }
 
+    #[test]
+    fn branch_to_a_hidden_dead_end_warns() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"Draft","target":"draft"},
+                    {"label":"Live","target":"b"}
+                ]}},"content":[]},
+                {"id":"draft","hidden":true,"content":[]},
+                {"id":"b","content":[]}
+            ]}"#,
+        );
+        let hits: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "hidden-branch-target")
+            .collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].node.as_deref(), Some("a"));
+        assert!(hits[0].message.contains("\"draft\""));
+    }
+
+    #[test]
+    fn branch_to_a_hidden_node_that_continues_is_fine() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"Draft","target":"draft"}
+                ]}},"content":[]},
+                {"id":"draft","hidden":true,"traversal":"b","content":[]},
+                {"id":"b","content":[]}
+            ]}"#,
+        );
+        assert!(!diags.iter().any(|d| d.rule == "hidden-branch-target"));
+    }
+
+    #[test]
+    fn duplicate_block_anchor_warns_once_per_anchor() {
+        let diags = diags_for(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"text","body":"x","id":"dup"},
+                {"kind":"container","children":[
+                    {"kind":"text","body":"y","id":"dup"},
+                    {"kind":"divider","id":"dup"}
+                ]}
+            ]}]}"#,
+        );
+        let hits: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "duplicate-block-anchor")
+            .collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].node.as_deref(), Some("a"));
+        assert_eq!(hits[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn visible_if_errors_say_which_block_and_warn_on_unknown_choices() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"ask","traversal":{"branch-point":{"options":[
+                    {"label":"Deep","key":"d","target":"a"}]}},"content":[]},
+                {"id":"a","content":[
+                    {"kind":"text","body":"ok","visible-if":"ask == d"},
+                    {"kind":"container","children":[
+                        {"kind":"divider"},
+                        {"kind":"text","body":"x","visible-if":"ask =="}
+                    ]},
+                    {"kind":"text","body":"y","visible-if":"typo == d || typo"}
+                ]}
+            ]}"#,
+        );
+        let broken: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "valid-visible-if")
+            .collect();
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].severity, Severity::Error);
+        assert!(
+            broken[0].message.contains("\"a\" block 2.2")
+                && broken[0].message.contains("at column 7"),
+            "{}",
+            broken[0].message
+        );
+        let unknown: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "unknown-visible-if-choice")
+            .collect();
+        assert_eq!(unknown.len(), 1, "once per variable");
+        assert!(unknown[0].message.contains("\"typo\""));
+    }
+
+    #[test]
+    fn heading_level_skip_warns_on_jumps_deeper_than_one_level() {
+        let diags = diags_for(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"heading","level":1,"text":"Title"},
+                {"kind":"text","body":"between headings doesn't count"},
+                {"kind":"container","children":[
+                    {"kind":"heading","level":3,"text":"Too deep"}
+                ]}
+            ]}]}"#,
+        );
+        let hits: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "heading-level-skip")
+            .collect();
+        assert_eq!(hits.len(), 1);
+        assert!(
+            hits[0].message.contains("\"Too deep\""),
+            "{}",
+            hits[0].message
+        );
+        assert_eq!(hits[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn heading_level_skip_allows_repeats_step_ups_and_a_deep_first_heading() {
+        let diags = diags_for(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"heading","level":2,"text":"Starts at h2"},
+                {"kind":"heading","level":3,"text":"One down"},
+                {"kind":"heading","level":1,"text":"Back to the top"},
+                {"kind":"heading","level":1,"text":"Another h1"},
+                {"kind":"heading","level":2,"text":"One down again"}
+            ]}]}"#,
+        );
+        assert!(!rules(&diags).contains(&"heading-level-skip"));
+    }
+
+    #[test]
+    fn same_anchor_in_different_nodes_is_fine() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[{"kind":"text","body":"x","id":"top"}]},
+                {"id":"b","content":[{"kind":"text","body":"y","id":"top"}]}
+            ]}"#,
+        );
+        assert!(!rules(&diags).contains(&"duplicate-block-anchor"));
+    }
+
     #[test]
     fn malformed_link_url_warns() {
         let diags = diags_for(
@@ -1035,6 +2086,29 @@ mod tests {
         assert!(!has_errors(&diags));
     }
 
+    #[test]
+    fn a_branch_target_that_stops_mid_deck_warns_but_the_last_node_does_not() {
+        let diags = diags_for(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"short","target":"short"},
+                    {"label":"long","target":"long"},
+                    {"label":"end","target":"end"}
+                ]}},"content":[]},
+                {"id":"short","content":[]},
+                {"id":"long","terminal":true,"content":[]},
+                {"id":"end","content":[]}
+            ]}"#,
+        );
+        let dead_ends: Vec<_> = diags
+            .iter()
+            .filter(|d| d.rule == "unmarked-dead-end")
+            .collect();
+        assert_eq!(dead_ends.len(), 1);
+        assert_eq!(dead_ends[0].node.as_deref(), Some("short"));
+        assert_eq!(dead_ends[0].severity, Severity::Warning);
+    }
+
     #[test]
     fn self_loops_and_trivial_cycles_warn_distinctly() {
         let diags = diags_for(
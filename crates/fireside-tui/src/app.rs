@@ -6,17 +6,22 @@
 //! flash message — the presenter is never left wondering whether a key
 //! "worked".
 
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use crossterm::event::{
     Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
 };
-use fireside_core::{ContentBlock, Graph, Node, Transition, ViewMode};
-use fireside_engine::{Outcome, Session, Severity, validate};
+use fireside_core::{ContentBlock, Graph, Node, OutlineKind, Transition, ViewMode};
+use fireside_engine::{EndBehavior, Outcome, Session, Severity, search, validate};
 use ratatui::layout::Rect;
 
 use crate::editor::forms::{EditableField, EditableKind};
+use crate::rehearsal::Rehearsal;
+use crate::remote::RemoteCommand;
 use crate::render;
+use crate::side_timer::SideTimer;
+use crate::theme::{Theme, Tokens};
 
 /// How long feedback messages stay on screen.
 const FLASH_DURATION: Duration = Duration::from_millis(3000);
@@ -24,6 +29,16 @@ const FLASH_DURATION: Duration = Duration::from_millis(3000);
 /// How long a slide's fade-in lasts: one dim beat, then full brightness.
 const FADE_DURATION: Duration = Duration::from_millis(90);
 
+/// How often a fading slide is redrawn, so the brighten lands on time.
+const FADE_FRAME: Duration = Duration::from_millis(30);
+
+/// How long the side countdown runs when `--countdown` doesn't say.
+pub const DEFAULT_COUNTDOWN: Duration = Duration::from_secs(10 * 60);
+
+/// How many stops the ending's route trace shows when `--route-stops`
+/// doesn't say.
+pub const DEFAULT_ROUTE_STOPS: usize = 8;
+
 /// P2-3: once the unknown-key flash has shown, further unrecognized keys
 /// within this window are silently ignored rather than re-triggering it —
 /// a presenter mashing keys while lost gets the message once, not a flash
@@ -42,6 +57,27 @@ pub enum Msg {
     /// The write-back sink's response to a quick-edit save: success, or a
     /// human-readable message about why it could not be saved.
     SaveResult(Result<(), String>),
+    /// Where a copied code block ended up, or a human-readable message
+    /// about why it couldn't be copied anywhere.
+    CopyResult(Result<Copied, String>),
+    /// The current slide's auto-advance dwell ran out at this instant — the
+    /// event loop sends it when [`App::auto_advance_due`] says so.
+    AutoAdvance(Instant),
+    /// The current slide's timed choice ran out at this instant — the
+    /// event loop sends it when [`App::choice_timeout_due`] says so.
+    ChoiceTimeout(Instant),
+    /// A command from the `--control` socket, already parsed.
+    Remote(RemoteCommand),
+}
+
+/// Where a copied code block went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Copied {
+    /// The system clipboard.
+    Clipboard,
+    /// No clipboard could be reached (headless, SSH), so the snippet was
+    /// written to this file instead.
+    File(PathBuf),
 }
 
 /// Which screen the presenter is looking at.
@@ -65,6 +101,163 @@ pub enum Screen {
         /// Index into `fields` of the block currently being typed into.
         focused: usize,
     },
+    /// The goto prompt in the footer: a 1-based slide number, or the start
+    /// of a node id.
+    Goto {
+        /// What has been typed so far.
+        buffer: String,
+        /// Which of several id-prefix matches is highlighted — the one
+        /// Enter lands on.
+        cycle: usize,
+    },
+    /// The picker shown when `Y` finds more than one code block on the
+    /// current slide.
+    Copy {
+        /// Index into [`code_snippets`] of the highlighted block.
+        selected: usize,
+    },
+    /// The jump list opened by `H`: recently visited slides, most recent
+    /// first, Enter going back to one.
+    Jumps {
+        /// Index into [`jump_list`] of the highlighted entry.
+        selected: usize,
+    },
+    /// The table of contents opened by `O`: the deck's sections, slides
+    /// and headings from [`Graph::outline`], Enter going to one.
+    Outline {
+        /// Index into the outline of the highlighted entry.
+        selected: usize,
+    },
+}
+
+/// The candidates the goto prompt cycles through: the nodes whose id starts
+/// with `buffer`, in deck order, or — when no id does — every node
+/// [`search::fuzzy_find_nodes`] matches by id or title, best first. A
+/// numeric buffer is a slide number, not a search, and matches nothing
+/// here.
+#[must_use]
+pub(crate) fn goto_matches(graph: &Graph, buffer: &str) -> Vec<usize> {
+    if buffer.is_empty() || buffer.chars().all(|c| c.is_ascii_digit()) {
+        return Vec::new();
+    }
+    let prefixed: Vec<usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.id.starts_with(buffer))
+        .map(|(i, _)| i)
+        .collect();
+    if !prefixed.is_empty() {
+        return prefixed;
+    }
+    search::fuzzy_find_nodes(graph, buffer)
+        .into_iter()
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// A code block on the current slide that `Y` can copy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Snippet<'a> {
+    pub(crate) language: Option<&'a str>,
+    pub(crate) source: &'a str,
+    pub(crate) runnable: bool,
+}
+
+/// The most entries [`jump_list`] returns.
+pub(crate) const JUMP_LIST_CAP: usize = 50;
+
+/// One row of the jump list: a slide visited earlier on this path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct JumpEntry {
+    /// The slide's position in the deck.
+    pub(crate) index: usize,
+    /// Whether the presenter arrived there by taking a branch option.
+    pub(crate) via_branch: bool,
+}
+
+/// Whether moving from slide `prev_index` to `new_index` arrives at one
+/// that asks for a choice — the cue `--notify-on-branch` rings for. Staying
+/// put, whether revealing a step or moving between options, isn't arriving.
+#[must_use]
+pub fn should_notify_branch_arrival(graph: &Graph, prev_index: usize, new_index: usize) -> bool {
+    prev_index != new_index
+        && graph
+            .nodes
+            .get(new_index)
+            .is_some_and(|node| node.branch_point().is_some())
+}
+
+/// The slides behind the current one in `session`'s history, most recent
+/// first, with runs of the same slide shown once and the current slide
+/// left out — at most [`JUMP_LIST_CAP`] of them.
+#[must_use]
+pub(crate) fn jump_list(session: &Session) -> Vec<JumpEntry> {
+    let graph = session.graph();
+    let current = &session.current().id;
+    let path: Vec<&str> = session
+        .history()
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(current.as_str()))
+        .collect();
+    let mut out = Vec::new();
+    let mut last = current.as_str();
+    for k in (0..path.len() - 1).rev() {
+        let id = path[k];
+        if id == last {
+            continue;
+        }
+        last = id;
+        let Some(index) = graph.nodes.iter().position(|n| n.id == id) else {
+            continue;
+        };
+        let via_branch = k > 0
+            && graph
+                .node(path[k - 1])
+                .and_then(Node::branch_point)
+                .is_some_and(|bp| bp.options.iter().any(|o| o.target == id));
+        out.push(JumpEntry { index, via_branch });
+        if out.len() == JUMP_LIST_CAP {
+            break;
+        }
+    }
+    out
+}
+
+/// Every code block in `blocks` — a slide's shown content — the audience
+/// can see at `reveal_level`, in document order, including those nested
+/// inside `Container` children.
+#[must_use]
+pub(crate) fn code_snippets(blocks: &[ContentBlock], reveal_level: u32) -> Vec<Snippet<'_>> {
+    let mut out = Vec::new();
+    collect_snippets(blocks, reveal_level, &mut out);
+    out
+}
+
+fn collect_snippets<'a>(blocks: &'a [ContentBlock], reveal_level: u32, out: &mut Vec<Snippet<'a>>) {
+    for block in blocks {
+        if block.reveal().unwrap_or(0) > reveal_level {
+            continue;
+        }
+        match block {
+            ContentBlock::Code {
+                language,
+                source,
+                runnable,
+                ..
+            } => out.push(Snippet {
+                language: language.as_deref(),
+                source,
+                runnable: *runnable,
+            }),
+            _ => {
+                for children in block.child_blocks() {
+                    collect_snippets(children, reveal_level, out);
+                }
+            }
+        }
+    }
 }
 
 /// Every heading/text/list block on `node`, in document order, including
@@ -140,6 +333,86 @@ pub enum FlashKind {
     Error,
 }
 
+/// What a blanked screen is filled with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlankKind {
+    /// The terminal's own background — `b`.
+    Black,
+    /// The theme's text color — `w`.
+    White,
+}
+
+/// Unattended playback (the `--auto` launch flag): the deck moves on by
+/// itself, each slide staying up for its own `duration` or `dwell`, and
+/// starts over from the first slide once a path ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoAdvance {
+    /// How long a slide without a `duration` stays up.
+    pub dwell: Duration,
+    /// What happens on a slide that asks for a choice.
+    pub at_branch: AutoBranch,
+}
+
+/// What auto-advance does when it reaches a branch point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoBranch {
+    /// Stays put until someone chooses.
+    #[default]
+    Pause,
+    /// Takes the first option once the slide's dwell is up.
+    First,
+}
+
+/// How the header shows progress through the deck — cycled with `P`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressStyle {
+    /// `3/12 seen`.
+    #[default]
+    Count,
+    /// A filled bar of slides seen, with the count beside it.
+    Bar,
+    /// One dot per slide seen or not (`●●○○○`), scaled down for long decks.
+    Dots,
+    /// A bar for the stretch of path since the last branch choice.
+    Path,
+}
+
+impl ProgressStyle {
+    /// The style `P` switches to from this one.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Count => Self::Bar,
+            Self::Bar => Self::Dots,
+            Self::Dots => Self::Path,
+            Self::Path => Self::Count,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Bar => "bar",
+            Self::Dots => "dots",
+            Self::Path => "this path",
+        }
+    }
+}
+
+/// Who a frame is drawn for — toggled with `A` on a single terminal. A
+/// frontend driving a second screen draws [`ViewAudience::Audience`] there
+/// and [`ViewAudience::Presenter`] on its own, from the same [`App`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewAudience {
+    /// Everything: speaker notes, what comes next, the timers, the footer's
+    /// keys and messages, and every overlay.
+    #[default]
+    Presenter,
+    /// The slide alone, as the room should see it: no notes, timers,
+    /// footer, or overlays, whatever the presenter has switched on.
+    Audience,
+}
+
 /// A transient feedback message shown in the footer.
 #[derive(Debug, Clone)]
 pub struct Flash {
@@ -160,12 +433,54 @@ pub struct App {
     view_override: Option<ViewMode>,
     show_notes: bool,
     show_timer: bool,
+    view_audience: ViewAudience,
+    progress: ProgressStyle,
     started: Instant,
+    /// Time on each slide, banked at every slide change.
+    rehearsal: Rehearsal,
+    /// The side countdown `T` starts, created on first use; `None` until
+    /// then, so the footer shows no readout nobody asked for.
+    side_timer: Option<SideTimer>,
+    /// How long a fresh side countdown runs.
+    countdown: Duration,
+    /// The widest the content column may get (`--max-width`), centered
+    /// when the screen is wider.
+    content_max_width: Option<u16>,
+    /// The most stops the ending's route trace shows before the middle of
+    /// the walk collapses to `…`.
+    route_stops: usize,
+    /// The visible block `o` spotlit on this slide, if any: the rest dim
+    /// until Esc or the next slide.
+    spotlight: Option<usize>,
+    /// Unattended playback, when `--auto` asked for it.
+    auto: Option<AutoAdvance>,
+    /// When the current auto-advance dwell began: the last move, reveal,
+    /// or keypress.
+    auto_since: Instant,
+    /// When the countdown on a choice with a `timeout` began: the moment
+    /// its last reveal step showed. `None` anywhere else.
+    choice_since: Option<Instant>,
     flash: Option<Flash>,
     fade_started: Option<Instant>,
+    /// Off with `--no-animations`: no fades, no blinking, and no redraw
+    /// on a timer except to clear a flash or end the countdown.
+    animations: bool,
+    /// The bundled theme's tokens, before any node's own colors.
+    tokens: Tokens,
+    /// Set by `--notify-on-branch`: arriving at a choice rings the bell.
+    notify_on_branch: bool,
+    /// A bell due on the next tick, for a choice just arrived at.
+    pending_bell: bool,
+    /// Set by `--confirm-branch-choice`: an option's number, key, or a
+    /// click only selects it, and Enter takes it.
+    confirm_branch_choice: bool,
+    /// Set by `b`/`w`: the whole screen is one solid fill until the next
+    /// keypress, which only clears it.
+    blank: Option<BlankKind>,
     viewport: (u16, u16),
     quit: bool,
     pending_save: Option<Graph>,
+    pending_copy: Option<String>,
     unknown_key_flash_at: Option<Instant>,
     sink_available: bool,
     /// Set the instant an Esc is pressed in the quick-edit modal with
@@ -178,13 +493,17 @@ pub struct App {
     /// write, which would otherwise replace the "Saved" flash with
     /// "Reloaded" before the presenter ever saw it.
     awaiting_self_reload: bool,
+    /// Set by every message: the frame on screen may no longer match the
+    /// state. Consumed by the event loop's `take_needs_redraw`.
+    needs_redraw: bool,
 }
 
 impl App {
     /// Create the app over a live session.
     #[must_use]
     pub fn new(session: Session) -> Self {
-        Self {
+        let rehearsal = Rehearsal::new(session.current_index(), Instant::now());
+        let mut app = Self {
             session,
             screen: Screen::Present,
             branch_selected: 0,
@@ -192,17 +511,38 @@ impl App {
             view_override: None,
             show_notes: false,
             show_timer: false,
+            view_audience: ViewAudience::default(),
+            progress: ProgressStyle::default(),
             started: Instant::now(),
+            rehearsal,
+            side_timer: None,
+            countdown: DEFAULT_COUNTDOWN,
+            auto: None,
+            auto_since: Instant::now(),
+            choice_since: None,
             flash: None,
             fade_started: None,
+            animations: true,
+            tokens: Tokens::default(),
+            notify_on_branch: false,
+            pending_bell: false,
+            confirm_branch_choice: false,
+            blank: None,
             viewport: (80, 24),
+            content_max_width: None,
+            route_stops: DEFAULT_ROUTE_STOPS,
+            spotlight: None,
             quit: false,
             pending_save: None,
+            pending_copy: None,
             unknown_key_flash_at: None,
             sink_available: true,
             edit_discard_confirm_at: None,
             awaiting_self_reload: false,
-        }
+            needs_redraw: true,
+        };
+        app.start_choice_countdown();
+        app
     }
 
     /// Marks the presentation as having no write-back sink (e.g. the
@@ -225,6 +565,193 @@ impl App {
         self
     }
 
+    /// Starts with the header's progress readout in `style` (the
+    /// `--progress` launch flag) rather than the plain count.
+    #[must_use]
+    pub(crate) fn with_progress(mut self, style: ProgressStyle) -> Self {
+        self.progress = style;
+        self
+    }
+
+    /// Sets how long the side countdown runs (the `--countdown` launch
+    /// flag) in place of [`DEFAULT_COUNTDOWN`].
+    #[must_use]
+    pub(crate) fn with_countdown(mut self, countdown: Duration) -> Self {
+        self.countdown = countdown;
+        self
+    }
+
+    /// Caps the content column at `width` columns (the `--max-width`
+    /// launch flag), so an ultra-wide terminal keeps a readable measure.
+    #[must_use]
+    pub(crate) fn with_content_max_width(mut self, width: Option<u16>) -> Self {
+        self.content_max_width = width;
+        self
+    }
+
+    /// Shows at most `stops` stops in the ending's route trace (the
+    /// `--route-stops` launch flag) in place of [`DEFAULT_ROUTE_STOPS`].
+    #[must_use]
+    pub(crate) fn with_route_stops(mut self, stops: usize) -> Self {
+        self.route_stops = stops;
+        self
+    }
+
+    /// Presents without motion (the `--no-animations` launch flag), for a
+    /// slow SSH link or a terminal recording: every slide's transition is
+    /// taken as `none` — the deck itself is untouched — the countdown's
+    /// overrun stops blinking, and the clocks only redraw along with
+    /// something else, so a still slide sends nothing at all.
+    #[must_use]
+    pub(crate) fn without_animations(mut self) -> Self {
+        self.animations = false;
+        self
+    }
+
+    /// Whether anything on screen may move by itself — see
+    /// [`App::without_animations`].
+    #[must_use]
+    pub(crate) fn animations(&self) -> bool {
+        self.animations
+    }
+
+    /// Draws with `theme`'s tokens (the `--theme` launch flag).
+    #[must_use]
+    pub(crate) fn with_theme(mut self, theme: Theme) -> Self {
+        self.tokens = Tokens::for_theme(theme);
+        self
+    }
+
+    /// Rings the terminal bell on arriving at a slide that asks for a
+    /// choice (the `--notify-on-branch` launch flag).
+    #[must_use]
+    pub(crate) fn with_notify_on_branch(mut self) -> Self {
+        self.notify_on_branch = true;
+        self
+    }
+
+    /// Makes picking an option by its number, key, or a click select it
+    /// rather than take it, so a stray keypress can't choose (the
+    /// `--confirm-branch-choice` launch flag).
+    #[must_use]
+    pub(crate) fn with_confirm_branch_choice(mut self) -> Self {
+        self.confirm_branch_choice = true;
+        self
+    }
+
+    /// The bundled theme's tokens, before the current node's own colors
+    /// are laid over them.
+    #[must_use]
+    pub fn tokens(&self) -> &Tokens {
+        &self.tokens
+    }
+
+    /// Plays the deck unattended (the `--auto` launch flag).
+    #[must_use]
+    pub(crate) fn with_auto_advance(mut self, auto: AutoAdvance) -> Self {
+        self.auto = Some(auto);
+        self.auto_since = Instant::now();
+        self
+    }
+
+    /// Whether auto-advance should take its next step at `now`: it is on,
+    /// the slide itself is showing (no overlay, prompt, or blank screen),
+    /// it isn't waiting on a choice, and the slide's dwell has run out.
+    /// A slide with reveal steps splits its dwell evenly across them.
+    #[must_use]
+    pub fn auto_advance_due(&self, now: Instant) -> bool {
+        let Some(auto) = self.auto else {
+            return false;
+        };
+        if self.screen != Screen::Present || self.blank.is_some() {
+            return false;
+        }
+        if self.at_choice() && auto.at_branch == AutoBranch::Pause {
+            return false;
+        }
+        let dwell = self.session.current().duration.unwrap_or(auto.dwell);
+        let steps = self.session.reveal_progress().map_or(0, |(_, n)| n) as u32 + 1;
+        now.saturating_duration_since(self.auto_since) >= dwell / steps
+    }
+
+    /// Whether the current slide is waiting on a choice, every reveal step
+    /// shown.
+    fn at_choice(&self) -> bool {
+        self.session.branch_point().is_some() && !self.session.has_pending_reveal()
+    }
+
+    /// Starts the countdown if the slide is now a choice with a `timeout`,
+    /// and drops any countdown otherwise.
+    fn start_choice_countdown(&mut self) {
+        let timed = self
+            .session
+            .branch_point()
+            .is_some_and(|bp| bp.timeout.is_some());
+        self.choice_since = (timed && self.at_choice()).then(Instant::now);
+    }
+
+    /// How long is left at `now` to make the current slide's timed choice,
+    /// or `None` when it isn't one.
+    #[must_use]
+    pub fn choice_left(&self, now: Instant) -> Option<Duration> {
+        let since = self.choice_since.filter(|_| self.at_choice())?;
+        let timeout = self.session.branch_point()?.timeout?;
+        Some(timeout.saturating_sub(now.saturating_duration_since(since)))
+    }
+
+    /// Whether the current slide's timed choice should be made for the
+    /// presenter at `now`: the slide itself is showing (no overlay,
+    /// prompt, or blank screen) and its countdown has run out.
+    #[must_use]
+    pub fn choice_timeout_due(&self, now: Instant) -> bool {
+        self.screen == Screen::Present
+            && self.blank.is_none()
+            && self.choice_left(now).is_some_and(|left| left.is_zero())
+    }
+
+    /// Takes the choice's `default-option`, or its first option, once the
+    /// countdown is out — a stale message, after the presenter chose or
+    /// moved away, does nothing.
+    fn on_choice_timeout(&mut self, now: Instant) {
+        if !self.choice_timeout_due(now) {
+            return;
+        }
+        let Some(bp) = self.session.branch_point() else {
+            return;
+        };
+        let index = bp.default_index();
+        let label = bp.options.get(index).map(|o| o.label.clone());
+        self.auto_since = now;
+        let outcome = self.session.choose(index);
+        self.apply(&outcome);
+        if let (Outcome::Moved, Some(label)) = (outcome, label) {
+            self.set_flash(&format!("Time's up — took \"{label}\""), FlashKind::Info);
+        }
+    }
+
+    /// One auto-advance step: the next reveal or slide, the first option
+    /// at a choice when `--auto-branch first` allows it, and back to the
+    /// first slide once the path ends — unless the session has its own
+    /// [`EndBehavior`], which `next` already follows, so an end it reports
+    /// is one to wait at.
+    fn on_auto_advance(&mut self, now: Instant) {
+        self.auto_since = now;
+        let outcome = if self.at_choice() {
+            self.session.choose(0)
+        } else {
+            self.session.next()
+        };
+        let outcome = match outcome {
+            Outcome::EndOfPath if self.session.end_behavior() != EndBehavior::Stop => return,
+            Outcome::EndOfPath => {
+                let entry = self.session.graph().nodes[0].id.clone();
+                self.session.goto(&entry)
+            }
+            outcome => outcome,
+        };
+        self.apply(&outcome);
+    }
+
     /// Whether a quick-edit save has anywhere to go. `false` for the demo
     /// deck and any other sink-less presentation.
     #[must_use]
@@ -252,6 +779,19 @@ impl App {
         self.pending_save.take()
     }
 
+    /// Whether a bell is due for a choice just arrived at, clearing it —
+    /// the event loop rings it once, however many frames the choice stays up.
+    pub(crate) fn take_pending_bell(&mut self) -> bool {
+        std::mem::take(&mut self.pending_bell)
+    }
+
+    /// Takes the code block source queued by `Y`, if any — the event loop
+    /// puts it on the clipboard and reports back with [`Msg::CopyResult`].
+    #[must_use]
+    pub(crate) fn take_pending_copy(&mut self) -> Option<String> {
+        self.pending_copy.take()
+    }
+
     /// Index of the highlighted branch option.
     #[must_use]
     pub fn branch_selected(&self) -> usize {
@@ -276,18 +816,66 @@ impl App {
         self.show_timer
     }
 
+    /// Who this terminal's frames are drawn for.
+    #[must_use]
+    pub fn view_audience(&self) -> ViewAudience {
+        self.view_audience
+    }
+
+    /// The side countdown, once `T` has started it.
+    #[must_use]
+    pub fn side_timer(&self) -> Option<&SideTimer> {
+        self.side_timer.as_ref()
+    }
+
+    /// The widest the content column may get, when capped.
+    #[must_use]
+    pub fn content_max_width(&self) -> Option<u16> {
+        self.content_max_width
+    }
+
+    /// The most stops the ending's route trace shows.
+    #[must_use]
+    pub fn route_stops(&self) -> usize {
+        self.route_stops
+    }
+
+    /// The visible block in the spotlight, counted from the top.
+    #[must_use]
+    pub fn spotlight(&self) -> Option<usize> {
+        self.spotlight
+    }
+
+    /// How the header shows progress.
+    #[must_use]
+    pub fn progress_style(&self) -> ProgressStyle {
+        self.progress
+    }
+
     /// Time since the presentation started.
     #[must_use]
     pub fn elapsed(&self) -> Duration {
         self.started.elapsed()
     }
 
+    /// Time spent on each slide so far.
+    #[must_use]
+    pub fn rehearsal(&self) -> &Rehearsal {
+        &self.rehearsal
+    }
+
     /// The active flash message, if it has not expired.
     #[must_use]
     pub fn flash(&self) -> Option<&Flash> {
         self.flash.as_ref().filter(|f| f.expires > Instant::now())
     }
 
+    /// The fill covering the whole screen, if the presenter blanked it.
+    #[must_use]
+    pub fn blank(&self) -> Option<BlankKind> {
+        self.blank
+    }
+
     /// Whether the event loop should exit.
     #[must_use]
     pub fn should_quit(&self) -> bool {
@@ -299,8 +887,10 @@ impl App {
     /// so the brighten lands on time.
     #[must_use]
     pub fn fading(&self) -> bool {
-        self.fade_started
-            .is_some_and(|started| started.elapsed() < FADE_DURATION)
+        self.blank.is_none()
+            && self
+                .fade_started
+                .is_some_and(|started| started.elapsed() < FADE_DURATION)
     }
 
     /// The view mode in effect: the presenter's runtime toggle wins over the
@@ -315,8 +905,66 @@ impl App {
         })
     }
 
+    /// Whether anything changed since the last frame was drawn, clearing
+    /// the flag. Every message sets it — `update` is the sole mutation
+    /// point, so nothing changes the state without it.
+    pub(crate) fn take_needs_redraw(&mut self) -> bool {
+        std::mem::take(&mut self.needs_redraw)
+    }
+
+    /// How soon the screen changes with no input at all: the next frame
+    /// of a fade, a flash clearing, or the next second of a running clock.
+    /// `None` when the frame on screen stays right until something
+    /// happens, so the event loop can skip redrawing an idle slide.
+    /// A timed choice ticks like a clock. Without animations only a flash
+    /// clearing and a countdown — either kind — reaching zero count.
+    #[must_use]
+    pub(crate) fn next_tick(&self, now: Instant) -> Option<Duration> {
+        if self.fading() {
+            return Some(FADE_FRAME);
+        }
+        let flash = self
+            .flash
+            .as_ref()
+            .map(|flash| flash.expires.saturating_duration_since(now))
+            .filter(|left| !left.is_zero());
+        let choice_left = self.choice_left(now).filter(|left| !left.is_zero());
+        if !self.animations {
+            let runs_out = self
+                .side_timer
+                .as_ref()
+                .filter(|timer| timer.is_running())
+                .map(|timer| timer.remaining(now))
+                .filter(|left| !left.is_zero());
+            return [flash, runs_out, choice_left].into_iter().flatten().min();
+        }
+        let to_next_second = |elapsed: Duration| {
+            Duration::from_secs(1) - Duration::from_nanos(elapsed.subsec_nanos().into())
+        };
+        let clock = self
+            .show_timer
+            .then(|| to_next_second(now.saturating_duration_since(self.started)));
+        let countdown = self
+            .side_timer
+            .as_ref()
+            .filter(|timer| timer.is_running())
+            .map(|timer| to_next_second(timer.elapsed(now)));
+        // The choice's count shows whole seconds rounded up, so it changes
+        // as `left` crosses each whole second.
+        let choice = choice_left.map(|left| match left.subsec_nanos() {
+            0 => Duration::from_secs(1),
+            nanos => Duration::from_nanos(nanos.into()),
+        });
+        [flash, clock, countdown, choice]
+            .into_iter()
+            .flatten()
+            .min()
+    }
+
     /// Apply one message. The sole mutation point.
     pub fn update(&mut self, msg: Msg) {
+        self.needs_redraw = true;
+        let before = self.session.current_index();
         match msg {
             Msg::Terminal(Event::Resize(w, h)) => self.viewport = (w, h),
             Msg::Terminal(Event::Key(key)) if key.kind == KeyEventKind::Press => {
@@ -326,6 +974,77 @@ impl App {
             Msg::Terminal(_) => {}
             Msg::Reload(result) => self.on_reload(result),
             Msg::SaveResult(result) => self.on_save_result(result),
+            Msg::CopyResult(result) => match result {
+                Ok(Copied::Clipboard) => self.set_flash("Copied to the clipboard", FlashKind::Info),
+                Ok(Copied::File(path)) => self.set_flash(
+                    &format!(
+                        "No clipboard here — saved the snippet to {}",
+                        path.display()
+                    ),
+                    FlashKind::Info,
+                ),
+                Err(message) => self.set_flash(&message, FlashKind::Error),
+            },
+            Msg::AutoAdvance(now) => self.on_auto_advance(now),
+            Msg::ChoiceTimeout(now) => self.on_choice_timeout(now),
+            Msg::Remote(command) => self.on_remote(command),
+        }
+        self.rehearsal
+            .record_node_change(self.session.current_index(), Instant::now());
+        if self.notify_on_branch
+            && should_notify_branch_arrival(
+                self.session.graph(),
+                before,
+                self.session.current_index(),
+            )
+        {
+            self.pending_bell = true;
+        }
+    }
+
+    /// A remote-control command acts on the slide the way its key would,
+    /// from whichever overlay is up — except the quick-edit modal, which a
+    /// clicker must never yank away mid-edit. Like a key, it brings a
+    /// blanked screen back, but then still acts: the remote can't see
+    /// that the screen was blank.
+    fn on_remote(&mut self, command: RemoteCommand) {
+        if matches!(self.screen, Screen::Edit { .. }) {
+            self.set_flash(
+                &format!("Remote \"{command}\" ignored while editing"),
+                FlashKind::Info,
+            );
+            return;
+        }
+        self.auto_since = Instant::now();
+        self.blank = None;
+        self.screen = Screen::Present;
+        match command {
+            RemoteCommand::Next => {
+                let outcome = self.session.next();
+                self.apply(&outcome);
+            }
+            RemoteCommand::Back => {
+                let outcome = self.session.back();
+                self.apply(&outcome);
+            }
+            RemoteCommand::Goto(n) => {
+                let count = self.session.graph().nodes.len();
+                if (1..=count).contains(&n) {
+                    self.jump_to(n - 1);
+                } else {
+                    self.set_flash(
+                        &format!("There is no slide {n} — the deck has {count}"),
+                        FlashKind::Error,
+                    );
+                }
+            }
+            RemoteCommand::Choose(key) => match self.option_for_key(key) {
+                Some(idx) if self.at_choice() => {
+                    let outcome = self.session.choose(idx);
+                    self.apply(&outcome);
+                }
+                _ => self.set_flash(&format!("No choice on key '{key}'"), FlashKind::Error),
+            },
         }
     }
 
@@ -381,6 +1100,7 @@ impl App {
             );
             return;
         };
+        session.set_end_behavior(self.session.end_behavior());
         let survived = session.graph().node(&here).is_some();
         if survived && session.current().id != here {
             let _ = session.goto(&here);
@@ -408,6 +1128,14 @@ impl App {
             self.quit = true;
             return;
         }
+        // Someone is at the keyboard: give them a whole dwell before the
+        // deck moves on by itself.
+        self.auto_since = Instant::now();
+        // Any key brings a blanked screen back, and does nothing else — the
+        // deck never moves behind the presenter's back.
+        if self.blank.take().is_some() {
+            return;
+        }
         match &self.screen {
             Screen::Help => self.screen = Screen::Present,
             Screen::Map { selected } => {
@@ -416,6 +1144,19 @@ impl App {
             }
             Screen::Present => self.on_present_key(key.code),
             Screen::Edit { .. } => self.on_edit_key(key),
+            Screen::Goto { .. } => self.on_goto_key(key.code),
+            Screen::Copy { selected } => {
+                let selected = *selected;
+                self.on_copy_key(key.code, selected);
+            }
+            Screen::Jumps { selected } => {
+                let selected = *selected;
+                self.on_jumps_key(key.code, selected);
+            }
+            Screen::Outline { selected } => {
+                let selected = *selected;
+                self.on_outline_key(key.code, selected);
+            }
         }
     }
 
@@ -426,6 +1167,9 @@ impl App {
     /// both invite it, and click support already trains a presenter to
     /// expect the mouse to work. Every other button/kind is ignored.
     fn on_mouse(&mut self, event: MouseEvent) {
+        if self.blank.is_some() {
+            return;
+        }
         match event.kind {
             MouseEventKind::Down(MouseButton::Left) => {
                 self.on_click(event.column, event.row);
@@ -501,8 +1245,7 @@ impl App {
                 if self.session.branch_point().is_some() && !self.session.has_pending_reveal() =>
             {
                 if let Some(idx) = render::branch_option_hit(self, frame_area, col, row) {
-                    let outcome = self.session.choose(idx);
-                    self.apply(&outcome);
+                    self.pick_option(idx);
                 }
             }
             _ => {}
@@ -535,6 +1278,84 @@ impl App {
         }
     }
 
+    /// Keys in the goto prompt. A numeric buffer jumps to that 1-based
+    /// slide. Anything else is an id prefix, or failing that a fuzzy search
+    /// of ids and titles (see [`goto_matches`]): a single match jumps and
+    /// closes the prompt, while several matches keep it open so repeated
+    /// Enter walks through them (Tab moves the highlight without jumping).
+    /// A buffer that matches nothing says so in the prompt itself.
+    fn on_goto_key(&mut self, code: KeyCode) {
+        let Screen::Goto { buffer, cycle } = &mut self.screen else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.screen = Screen::Present,
+            KeyCode::Backspace => {
+                buffer.pop();
+                *cycle = 0;
+            }
+            KeyCode::Char(c) => {
+                buffer.push(c);
+                *cycle = 0;
+            }
+            KeyCode::Tab => {
+                let count = goto_matches(self.session.graph(), buffer).len();
+                if count > 1 {
+                    *cycle = (*cycle + 1) % count;
+                }
+            }
+            KeyCode::Enter => {
+                let (buffer, cycle) = (buffer.clone(), *cycle);
+                self.confirm_goto(&buffer, cycle);
+            }
+            _ => {}
+        }
+    }
+
+    fn confirm_goto(&mut self, buffer: &str, cycle: usize) {
+        let nodes = &self.session.graph().nodes;
+        if buffer.is_empty() {
+            self.screen = Screen::Present;
+            return;
+        }
+        if let Ok(number) = buffer.parse::<usize>() {
+            if (1..=nodes.len()).contains(&number) {
+                self.screen = Screen::Present;
+                self.jump_to(number - 1);
+            }
+            return;
+        }
+        let matches = goto_matches(self.session.graph(), buffer);
+        match matches.len() {
+            0 => {}
+            1 => {
+                self.screen = Screen::Present;
+                self.jump_to(matches[0]);
+            }
+            count => {
+                // Already standing on the highlighted match (the previous
+                // Enter landed here): move on to the next one.
+                let mut pick = cycle % count;
+                if nodes[matches[pick]].id == self.session.current().id {
+                    pick = (pick + 1) % count;
+                }
+                self.jump_to(matches[pick]);
+                self.screen = Screen::Goto {
+                    buffer: buffer.to_owned(),
+                    cycle: pick,
+                };
+            }
+        }
+    }
+
+    fn jump_to(&mut self, index: usize) {
+        let id = self.session.graph().nodes[index].id.clone();
+        if id != self.session.current().id {
+            let outcome = self.session.goto(&id);
+            self.apply(&outcome);
+        }
+    }
+
     fn on_present_key(&mut self, code: KeyCode) {
         let pending_reveal = self.session.has_pending_reveal();
         // While a node has reveal steps not yet shown, the branch menu is
@@ -578,11 +1399,88 @@ impl App {
                 }
             }
             KeyCode::Char('t') => self.show_timer = !self.show_timer,
+            // Not reserved, like `R` below.
+            KeyCode::Char('A') if !(at_branch && self.option_for_key('A').is_some()) => {
+                self.view_audience = match self.view_audience {
+                    ViewAudience::Presenter => ViewAudience::Audience,
+                    ViewAudience::Audience => ViewAudience::Presenter,
+                };
+                if self.view_audience == ViewAudience::Presenter {
+                    self.set_flash("Presenter view", FlashKind::Info);
+                }
+            }
+            KeyCode::Char('P') => {
+                self.progress = self.progress.next();
+                let label = self.progress.label();
+                self.set_flash(&format!("Progress: {label}"), FlashKind::Info);
+            }
+            KeyCode::Char('T') => self.toggle_side_timer(),
+            // Not reserved, like `b`/`w` below.
+            KeyCode::Char('R') if !(at_branch && self.option_for_key('R').is_some()) => {
+                if let Some(timer) = &mut self.side_timer {
+                    timer.reset();
+                    self.set_flash("Countdown reset", FlashKind::Info);
+                }
+            }
+            // Not reserved either.
+            KeyCode::Char('o') if !(at_branch && self.option_for_key('o').is_some()) => {
+                self.cycle_spotlight();
+            }
+            KeyCode::Esc if self.spotlight.is_some() => self.spotlight = None,
             KeyCode::Char('e') => self.open_edit(),
+            KeyCode::Char('Y') => self.open_copy(),
+            KeyCode::Char('H') => {
+                if jump_list(&self.session).is_empty() {
+                    self.set_flash("No earlier slides to jump back to yet", FlashKind::Info);
+                } else {
+                    self.screen = Screen::Jumps { selected: 0 };
+                }
+            }
+            KeyCode::Char('O') => {
+                let current = self.session.current_index();
+                let selected = self
+                    .session
+                    .graph()
+                    .outline()
+                    .iter()
+                    .position(|e| e.kind == OutlineKind::Node && e.index == current)
+                    .unwrap_or(0);
+                self.screen = Screen::Outline { selected };
+            }
+            // Not reserved: an option keyed `b` or `w` on this branch point
+            // keeps its key, and blanking is one keypress away everywhere else.
+            KeyCode::Char(c @ ('b' | 'w')) if !(at_branch && self.option_for_key(c).is_some()) => {
+                self.blank = Some(if c == 'b' {
+                    BlankKind::Black
+                } else {
+                    BlankKind::White
+                });
+            }
+            KeyCode::Home => self.jump_to(0),
+            KeyCode::End => self.jump_to(self.session.graph().nodes.len() - 1),
+            // Not reserved, like `b`/`w` above.
+            KeyCode::Char(c @ ('[' | ']')) if !(at_branch && self.option_for_key(c).is_some()) => {
+                self.jump_section(c == ']');
+            }
+            // Not reserved either.
+            KeyCode::Char(c @ ('{' | '}')) if !(at_branch && self.option_for_key(c).is_some()) => {
+                self.jump_branch_point(c == '}');
+            }
+            KeyCode::Char(':') => {
+                self.screen = Screen::Goto {
+                    buffer: String::new(),
+                    cycle: 0,
+                };
+            }
             _ if at_branch => self.on_branch_key(code),
             _ if pending_reveal => self.on_reveal_pending_key(code),
             _ => self.on_flow_key(code),
         }
+        // Overlays are the presenter's: opening one from the audience view
+        // brings that view back rather than leaving the presenter blind.
+        if !matches!(self.screen, Screen::Present) {
+            self.view_audience = ViewAudience::Presenter;
+        }
     }
 
     /// Keys on a node with reveal steps still pending. Only the explicit
@@ -603,6 +1501,185 @@ impl App {
         }
     }
 
+    /// `]` / `[`: jumps to the first slide of the next or previous section,
+    /// or says why it can't.
+    fn jump_section(&mut self, forward: bool) {
+        let graph = self.session.graph();
+        let here = self.session.current_index();
+        let target = if forward {
+            graph.next_section_start(here)
+        } else {
+            graph.previous_section_start(here)
+        };
+        match target {
+            Some(index) => self.jump_to(index),
+            None if graph.nodes.iter().all(|n| n.section.is_none()) => {
+                self.set_flash("This deck has no sections", FlashKind::Info);
+            }
+            None if forward => self.set_flash("This is the last section", FlashKind::Info),
+            None => self.set_flash("This is the first section", FlashKind::Info),
+        }
+    }
+
+    /// `}` / `{`: jumps to the nearest choice ahead on the current path, or
+    /// the nearest one behind it, or says there isn't one.
+    fn jump_branch_point(&mut self, forward: bool) {
+        let graph = self.session.graph();
+        let here = self.session.current_index();
+        let target = if forward {
+            graph.next_branch_point_from(here)
+        } else {
+            graph.previous_branch_point_from(here)
+        };
+        match target {
+            Some(index) => self.jump_to(index),
+            None if forward => self.set_flash("No choice ahead on this path", FlashKind::Info),
+            None => self.set_flash("No choice before this on the path", FlashKind::Info),
+        }
+    }
+
+    /// `o`: moves the spotlight to the next visible block, wrapping past
+    /// the last back to the first.
+    fn cycle_spotlight(&mut self) {
+        let level = self.session.reveal_level();
+        let count = self
+            .session
+            .visible_content()
+            .iter()
+            .filter(|b| b.reveal().unwrap_or(0) <= level)
+            .count();
+        if count == 0 {
+            self.set_flash("Nothing on this slide to spotlight", FlashKind::Info);
+            return;
+        }
+        let next = self.spotlight.map_or(0, |i| (i + 1) % count);
+        self.spotlight = Some(next);
+        self.set_flash(
+            &format!("Spotlight {}/{count} — Esc clears", next + 1),
+            FlashKind::Info,
+        );
+    }
+
+    /// `T`: starts the side countdown (creating it the first time), or
+    /// pauses and resumes it.
+    fn toggle_side_timer(&mut self) {
+        let countdown = self.countdown;
+        let timer = self
+            .side_timer
+            .get_or_insert_with(|| SideTimer::new(countdown));
+        timer.toggle(Instant::now());
+        let text = if timer.is_running() {
+            "Countdown running — T pauses, R resets"
+        } else {
+            "Countdown paused"
+        };
+        self.set_flash(text, FlashKind::Info);
+    }
+
+    /// `Y`: copies the slide's only visible code block straight away, or
+    /// opens the picker when there are several — on the first runnable one,
+    /// since that's the block the author meant to be pasted.
+    fn open_copy(&mut self) {
+        let content = self.session.visible_content();
+        let snippets = code_snippets(&content, self.session.reveal_level());
+        match snippets.as_slice() {
+            [] => self.set_flash("This slide has no code to copy", FlashKind::Info),
+            [only] => self.pending_copy = Some(only.source.to_owned()),
+            _ => {
+                let selected = snippets.iter().position(|s| s.runnable).unwrap_or(0);
+                self.screen = Screen::Copy { selected };
+            }
+        }
+    }
+
+    /// Keys while the copy picker is open: move, Enter or a number to copy,
+    /// Esc to leave without copying.
+    fn on_copy_key(&mut self, code: KeyCode, selected: usize) {
+        let count =
+            code_snippets(&self.session.visible_content(), self.session.reveal_level()).len();
+        let pick = match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.screen = Screen::Copy {
+                    selected: selected.saturating_sub(1),
+                };
+                return;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.screen = Screen::Copy {
+                    selected: (selected + 1).min(count.saturating_sub(1)),
+                };
+                return;
+            }
+            KeyCode::Enter => selected,
+            KeyCode::Char(c @ '1'..='9') => (c as usize) - ('1' as usize),
+            KeyCode::Esc | KeyCode::Char('q' | 'Y') => {
+                self.screen = Screen::Present;
+                return;
+            }
+            _ => return,
+        };
+        let content = self.session.visible_content();
+        let snippets = code_snippets(&content, self.session.reveal_level());
+        if let Some(snippet) = snippets.get(pick) {
+            self.pending_copy = Some(snippet.source.to_owned());
+            self.screen = Screen::Present;
+        }
+    }
+
+    /// Keys while the jump list is open: move, Enter to go back to the
+    /// highlighted slide (a goto, so `back` returns here), Esc to leave.
+    fn on_jumps_key(&mut self, code: KeyCode, selected: usize) {
+        let entries = jump_list(&self.session);
+        let selected = match code {
+            KeyCode::Up | KeyCode::Char('k') => selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                (selected + 1).min(entries.len().saturating_sub(1))
+            }
+            KeyCode::Home => 0,
+            KeyCode::End => entries.len().saturating_sub(1),
+            KeyCode::Enter => {
+                if let Some(entry) = entries.get(selected) {
+                    self.jump_to(entry.index);
+                }
+                self.screen = Screen::Present;
+                return;
+            }
+            KeyCode::Esc | KeyCode::Char('q' | 'H') => {
+                self.screen = Screen::Present;
+                return;
+            }
+            _ => return,
+        };
+        self.screen = Screen::Jumps { selected };
+    }
+
+    /// Keys while the table of contents is open: move, Enter to go to the
+    /// highlighted line's slide (a goto, so `back` returns here), Esc to
+    /// leave.
+    fn on_outline_key(&mut self, code: KeyCode, selected: usize) {
+        let outline = self.session.graph().outline();
+        let last = outline.len().saturating_sub(1);
+        let selected = match code {
+            KeyCode::Up | KeyCode::Char('k') => selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => (selected + 1).min(last),
+            KeyCode::Home => 0,
+            KeyCode::End => last,
+            KeyCode::Enter => {
+                if let Some(entry) = outline.get(selected) {
+                    self.jump_to(entry.index);
+                }
+                self.screen = Screen::Present;
+                return;
+            }
+            KeyCode::Esc | KeyCode::Char('q' | 'O') => {
+                self.screen = Screen::Present;
+                return;
+            }
+            _ => return,
+        };
+        self.screen = Screen::Outline { selected };
+    }
+
     /// Opens the quick-edit modal on the current node's heading/text/list
     /// blocks, or flashes that there is nothing to edit (ADR-005/ADR-016
     /// scope: content-only, current node only).
@@ -711,10 +1788,14 @@ impl App {
         match code {
             KeyCode::Up | KeyCode::Char('k') if count > 0 => {
                 self.branch_selected = (self.branch_selected + count - 1) % count;
+                self.keep_choice_in_view();
             }
             KeyCode::Down | KeyCode::Char('j') if count > 0 => {
                 self.branch_selected = (self.branch_selected + 1) % count;
+                self.keep_choice_in_view();
             }
+            KeyCode::PageDown if self.scroll < self.max_scroll() => self.scroll_page(true),
+            KeyCode::PageUp if self.scroll > 0 => self.scroll_page(false),
             KeyCode::Enter => {
                 let outcome = self.session.choose(self.branch_selected);
                 self.apply(&outcome);
@@ -722,8 +1803,7 @@ impl App {
             KeyCode::Char(c @ '1'..='9') => {
                 let idx = (c as usize) - ('1' as usize);
                 if idx < count {
-                    let outcome = self.session.choose(idx);
-                    self.apply(&outcome);
+                    self.pick_option(idx);
                 } else {
                     self.set_flash(&format!("There are only {count} choices"), FlashKind::Error);
                 }
@@ -736,20 +1816,43 @@ impl App {
                 let outcome = self.session.back();
                 self.apply(&outcome);
             }
-            KeyCode::Char(c) if c.is_alphanumeric() => match self.option_for_key(c) {
-                Some(idx) => {
-                    let outcome = self.session.choose(idx);
-                    self.apply(&outcome);
-                }
+            // Keys are free-form, so punctuation can name an option too.
+            KeyCode::Char(c) if !c.is_whitespace() => match self.option_for_key(c) {
+                Some(idx) => self.pick_option(idx),
                 None => self.set_flash(&format!("No choice on key '{c}'"), FlashKind::Error),
             },
             _ => {}
         }
     }
 
+    /// Takes option `idx` — or, under `--confirm-branch-choice`, only
+    /// selects it, leaving Enter to take it.
+    fn pick_option(&mut self, idx: usize) {
+        if !self.confirm_branch_choice {
+            let outcome = self.session.choose(idx);
+            self.apply(&outcome);
+            return;
+        }
+        self.branch_selected = idx;
+        self.keep_choice_in_view();
+        let label = self
+            .session
+            .branch_point()
+            .and_then(|bp| bp.options.get(idx))
+            .map(|o| o.label.clone());
+        if let Some(label) = label {
+            self.set_flash(&format!("Enter takes \"{label}\""), FlashKind::Info);
+        }
+    }
+
     /// Keys on an ordinary (non-branch) node.
     fn on_flow_key(&mut self, code: KeyCode) {
         match code {
+            // A slide taller than the body pages through its content
+            // before PageDown/PageUp move on, so a clicker reaches every
+            // line; Space, →, ← and the rest still move straight away.
+            KeyCode::PageDown if self.scroll < self.max_scroll() => self.scroll_page(true),
+            KeyCode::PageUp if self.scroll > 0 => self.scroll_page(false),
             KeyCode::Char(' ' | 'n') | KeyCode::Right | KeyCode::Enter | KeyCode::PageDown => {
                 let outcome = self.session.next();
                 self.apply(&outcome);
@@ -794,21 +1897,29 @@ impl App {
         match outcome {
             Outcome::Moved => {
                 self.scroll = 0;
-                self.branch_selected = 0;
-                self.flash = None;
-                let fades = self
+                // Back at a choice already made, the menu starts on it.
+                self.branch_selected = self
                     .session
-                    .current()
-                    .resolved_transition(self.session.defaults())
-                    == Transition::Fade;
+                    .last_choice(&self.session.current().id)
+                    .unwrap_or(0);
+                self.spotlight = None;
+                self.flash = None;
+                let fades = self.animations
+                    && self
+                        .session
+                        .current()
+                        .resolved_transition(self.session.defaults())
+                        == Transition::Fade;
                 self.fade_started = fades.then(Instant::now);
+                self.start_choice_countdown();
             }
-            Outcome::Revealed => {
+            Outcome::Revealed | Outcome::Concealed => {
                 // The current node did not change — no fade, no
                 // branch-selection reset, just clear any stale flash and
                 // keep newly revealed content in view.
                 self.scroll = 0;
                 self.flash = None;
+                self.start_choice_countdown();
             }
             Outcome::BlockedByBranch => {
                 self.set_flash(
@@ -845,6 +1956,33 @@ impl App {
         let (w, h) = self.viewport;
         render::max_scroll(self, w, h)
     }
+
+    /// Scroll the current node by one body's height less a line, so the
+    /// last line of one page is the first of the next.
+    fn scroll_page(&mut self, down: bool) {
+        let (w, h) = self.viewport;
+        let step = render::scroll_page(self, w, h).saturating_sub(1).max(1);
+        self.scroll = if down {
+            self.scroll.saturating_add(step).min(self.max_scroll())
+        } else {
+            self.scroll.saturating_sub(step)
+        };
+    }
+
+    /// Scroll just far enough that the highlighted branch option is on
+    /// screen — a long slide's menu can start below the fold.
+    fn keep_choice_in_view(&mut self) {
+        let (w, h) = self.viewport;
+        let Some(line) = render::selected_option_line(self, w, h) else {
+            return;
+        };
+        let page = render::scroll_page(self, w, h).max(1);
+        if line < self.scroll {
+            self.scroll = line;
+        } else if line >= self.scroll.saturating_add(page) {
+            self.scroll = (line + 1).saturating_sub(page).min(self.max_scroll());
+        }
+    }
 }
 
 #[cfg(test)]
@@ -885,4 +2023,513 @@ mod tests {
         assert_eq!(fields[1].kind, EditableKind::Text);
         assert_eq!(fields[1].buffer, vec!["Nested text".to_owned()]);
     }
+
+    #[test]
+    fn a_still_slide_needs_no_redraw_until_a_message_arrives() {
+        let graph = Graph::from_json(FIXTURE).expect("fixture parses");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        assert!(app.take_needs_redraw(), "the first frame");
+        assert!(!app.take_needs_redraw());
+        assert_eq!(app.next_tick(Instant::now()), None, "idle: nothing moves");
+
+        app.update(Msg::Terminal(Event::Resize(100, 30)));
+        assert!(app.take_needs_redraw());
+    }
+
+    #[test]
+    fn a_flash_or_a_running_clock_schedules_the_next_frame() {
+        let graph = Graph::from_json(FIXTURE).expect("fixture parses");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        app.set_flash("Saved", FlashKind::Info);
+        let now = Instant::now();
+        let tick = app.next_tick(now).expect("the flash clears on its own");
+        assert!(tick <= FLASH_DURATION);
+
+        app.flash = None;
+        app.show_timer = true;
+        let tick = app.next_tick(now).expect("the clock ticks");
+        assert!(tick <= SECOND);
+    }
+
+    #[test]
+    fn without_animations_a_fade_never_starts_and_only_flashes_and_the_countdown_tick() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[]},
+                {"id":"b","transition":"fade","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let mut app = App::new(Session::new(graph).expect("non-empty")).without_animations();
+        app.show_timer = true;
+        app.update(Msg::Terminal(Event::Key(KeyCode::Right.into())));
+        assert_eq!(app.session().current().id, "b");
+        assert_eq!(
+            app.fade_started, None,
+            "the fade transition is taken as none"
+        );
+        assert!(!app.fading());
+        let now = Instant::now();
+        assert_eq!(app.next_tick(now), None, "the clock alone doesn't redraw");
+        assert_eq!(
+            app.session().graph().nodes[1].transition,
+            Some(Transition::Fade),
+            "the deck keeps its fade"
+        );
+
+        app.set_flash("Saved", FlashKind::Info);
+        let now = Instant::now();
+        assert!(app.next_tick(now).is_some_and(|t| t <= FLASH_DURATION));
+        app.flash = None;
+        let mut timer = SideTimer::new(Duration::from_secs(90));
+        timer.start(now);
+        app.side_timer = Some(timer);
+        let tick = app.next_tick(now).expect("the countdown's end is due");
+        assert!(tick > SECOND, "one wake at zero, not one a second");
+    }
+
+    #[test]
+    fn o_cycles_the_spotlight_through_the_blocks_and_wraps() {
+        let graph = Graph::from_json(FIXTURE).expect("fixture parses");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        let press =
+            |app: &mut App, code: KeyCode| app.update(Msg::Terminal(Event::Key(code.into())));
+        assert_eq!(app.spotlight(), None);
+        for expected in [0, 1, 2, 0] {
+            press(&mut app, KeyCode::Char('o'));
+            assert_eq!(app.spotlight(), Some(expected));
+        }
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.spotlight(), None);
+    }
+
+    #[test]
+    fn moving_between_slides_times_each_one_it_lands_on() {
+        let session = Session::new(
+            Graph::from_json(
+                r#"{"nodes":[
+                    {"id":"a","traversal":"b","content":[]},
+                    {"id":"b","traversal":"c","content":[]},
+                    {"id":"c","content":[]}
+                ]}"#,
+            )
+            .expect("parse"),
+        )
+        .expect("non-empty");
+        let mut app = App::new(session);
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Left);
+        let ids: Vec<_> = app
+            .rehearsal()
+            .report(app.session().graph(), Instant::now())
+            .into_iter()
+            .map(|time| time.id)
+            .collect();
+        assert_eq!(ids, ["a", "b"], "c was never shown");
+    }
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn auto_app(json: &str, at_branch: AutoBranch) -> App {
+        let session = Session::new(Graph::from_json(json).expect("parse")).expect("non-empty");
+        App::new(session).with_auto_advance(AutoAdvance {
+            dwell: 10 * SECOND,
+            at_branch,
+        })
+    }
+
+    #[test]
+    fn auto_advance_waits_out_each_slides_dwell_then_starts_over() {
+        let mut app = auto_app(
+            r#"{"nodes":[
+                {"id":"a","duration":4,"traversal":"b","content":[]},
+                {"id":"b","content":[]}
+            ]}"#,
+            AutoBranch::Pause,
+        );
+        let start = app.auto_since;
+        assert!(!app.auto_advance_due(start + 3 * SECOND));
+        assert!(app.auto_advance_due(start + 4 * SECOND), "a's own duration");
+        app.update(Msg::AutoAdvance(start + 4 * SECOND));
+        assert_eq!(app.session().current().id, "b");
+
+        assert!(!app.auto_advance_due(start + 13 * SECOND));
+        assert!(app.auto_advance_due(start + 14 * SECOND), "the fallback");
+        app.update(Msg::AutoAdvance(start + 14 * SECOND));
+        assert_eq!(app.session().current().id, "a", "the end loops");
+    }
+
+    #[test]
+    fn auto_advance_follows_the_sessions_own_end_behavior() {
+        let json = r#"{"nodes":[
+            {"id":"a","traversal":"b","content":[]},
+            {"id":"b","traversal":"c","content":[]},
+            {"id":"c","content":[]}
+        ]}"#;
+        let walk = |end| {
+            let mut app = auto_app(json, AutoBranch::Pause);
+            let mut session = Session::new(app.session().graph().clone()).expect("non-empty");
+            session.set_end_behavior(end);
+            app.session = session;
+            let start = app.auto_since;
+            (1..=4)
+                .map(|tick| {
+                    app.update(Msg::AutoAdvance(start + tick * 10 * SECOND));
+                    app.session().current().id.clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(walk(EndBehavior::Hold), ["b", "c", "c", "c"]);
+        assert_eq!(walk(EndBehavior::Bounce), ["b", "c", "b", "a"]);
+        assert_eq!(walk(EndBehavior::Restart), ["b", "c", "a", "b"]);
+    }
+
+    #[test]
+    fn reveal_steps_share_their_slides_dwell() {
+        let mut app = auto_app(
+            r#"{"nodes":[{"id":"a","content":[
+                {"kind":"text","body":"first"},
+                {"kind":"text","reveal":1,"body":"second"}
+            ]}]}"#,
+            AutoBranch::Pause,
+        );
+        let start = app.auto_since;
+        assert!(app.auto_advance_due(start + 5 * SECOND));
+        app.update(Msg::AutoAdvance(start + 5 * SECOND));
+        assert_eq!(app.session().reveal_level(), 1);
+    }
+
+    #[test]
+    fn a_keypress_restarts_the_dwell() {
+        let mut app = auto_app(r#"{"nodes":[{"id":"a","content":[]}]}"#, AutoBranch::Pause);
+        app.auto_since = Instant::now()
+            .checked_sub(60 * SECOND)
+            .expect("a minute ago");
+        assert!(app.auto_advance_due(Instant::now()));
+        app.update(Msg::Terminal(Event::Key(KeyEvent::new(
+            KeyCode::Down,
+            KeyModifiers::NONE,
+        ))));
+        assert!(!app.auto_advance_due(Instant::now()));
+    }
+
+    #[test]
+    fn a_choice_pauses_auto_advance_unless_it_takes_the_first_option() {
+        let deck = r#"{"nodes":[
+            {"id":"a","traversal":{"branch-point":{"options":[
+                {"label":"B","target":"b"},
+                {"label":"C","target":"c"}
+            ]}},"content":[]},
+            {"id":"b","content":[]},
+            {"id":"c","content":[]}
+        ]}"#;
+        let paused = auto_app(deck, AutoBranch::Pause);
+        assert!(!paused.auto_advance_due(paused.auto_since + 3600 * SECOND));
+
+        let mut first = auto_app(deck, AutoBranch::First);
+        let due = first.auto_since + 10 * SECOND;
+        assert!(first.auto_advance_due(due));
+        first.update(Msg::AutoAdvance(due));
+        assert_eq!(first.session().current().id, "b");
+    }
+
+    #[test]
+    fn without_auto_nothing_is_ever_due() {
+        let app =
+            App::new(Session::new(Graph::from_json(FIXTURE).expect("parse")).expect("non-empty"));
+        assert!(!app.auto_advance_due(Instant::now() + 3600 * SECOND));
+    }
+
+    const TIMED_CHOICE: &str = r#"{"nodes":[
+        {"id":"a","traversal":{"branch-point":{"timeout":"10s","default-option":"Y",
+            "options":[
+                {"label":"Left","key":"x","target":"b"},
+                {"label":"Right","key":"y","target":"c"}
+            ]}},"content":[]},
+        {"id":"b","content":[]},
+        {"id":"c","content":[]}
+    ]}"#;
+
+    #[test]
+    fn a_timed_choice_takes_its_default_once_the_countdown_runs_out() {
+        let graph = Graph::from_json(TIMED_CHOICE).expect("parse");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        let since = app.choice_since.expect("the countdown starts on arrival");
+        assert_eq!(app.choice_left(since + 4 * SECOND), Some(6 * SECOND));
+        assert!(!app.choice_timeout_due(since + 9 * SECOND));
+        app.update(Msg::ChoiceTimeout(since + 9 * SECOND));
+        assert_eq!(app.session().current().id, "a", "not yet");
+
+        app.update(Msg::ChoiceTimeout(since + 10 * SECOND));
+        assert_eq!(
+            app.session().current().id,
+            "c",
+            "the default, not the first"
+        );
+        assert!(app.flash().is_some_and(|f| f.text.contains("Right")));
+        assert_eq!(app.choice_left(since + 10 * SECOND), None);
+
+        press(&mut app, KeyCode::Left);
+        assert!(app.choice_since.is_some(), "coming back starts it over");
+    }
+
+    #[test]
+    fn choosing_by_hand_cancels_the_countdown() {
+        let graph = Graph::from_json(TIMED_CHOICE).expect("parse");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        let since = app.choice_since.expect("the countdown starts on arrival");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.session().current().id, "b");
+        assert_eq!(app.choice_since, None);
+        app.update(Msg::ChoiceTimeout(since + 60 * SECOND));
+        assert_eq!(
+            app.session().current().id,
+            "b",
+            "a stale timeout does nothing"
+        );
+    }
+
+    #[test]
+    fn only_arriving_at_a_choice_is_a_branch_arrival() {
+        let graph = Graph::from_json(TIMED_CHOICE).expect("parse");
+        assert!(should_notify_branch_arrival(&graph, 1, 0), "arriving");
+        assert!(!should_notify_branch_arrival(&graph, 0, 0), "staying");
+        assert!(!should_notify_branch_arrival(&graph, 0, 1), "leaving");
+        assert!(!should_notify_branch_arrival(&graph, 0, 9), "no such slide");
+    }
+
+    #[test]
+    fn notify_on_branch_rings_once_per_arrival() {
+        let graph = Graph::from_json(TIMED_CHOICE).expect("parse");
+        let mut app = App::new(Session::new(graph).expect("non-empty")).with_notify_on_branch();
+        press(&mut app, KeyCode::Down);
+        assert!(!app.take_pending_bell(), "moving among options");
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.take_pending_bell(), "leaving the choice");
+        press(&mut app, KeyCode::Left);
+        assert!(app.take_pending_bell(), "back at the choice");
+        press(&mut app, KeyCode::Up);
+        assert!(!app.take_pending_bell(), "rung once, not every frame");
+
+        let graph = Graph::from_json(TIMED_CHOICE).expect("parse");
+        let mut quiet = App::new(Session::new(graph).expect("non-empty"));
+        press(&mut quiet, KeyCode::Enter);
+        press(&mut quiet, KeyCode::Left);
+        assert!(!quiet.take_pending_bell(), "off unless asked for");
+    }
+
+    #[test]
+    fn confirm_branch_choice_selects_on_a_number_and_takes_on_enter() {
+        let graph = Graph::from_json(TIMED_CHOICE).expect("parse");
+        let mut app =
+            App::new(Session::new(graph).expect("non-empty")).with_confirm_branch_choice();
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.branch_selected(), 1);
+        assert_eq!(app.session().current().id, "a", "not taken yet");
+        assert!(app.flash().is_some_and(|f| f.text.contains("Right")));
+
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.branch_selected(), 0, "an option's key selects too");
+        assert_eq!(app.session().current().id, "a");
+
+        press(&mut app, KeyCode::Char('2'));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.session().current().id, "c");
+    }
+
+    #[test]
+    fn remote_commands_drive_the_slide_like_their_keys() {
+        let mut app = App::new(
+            Session::new(
+                Graph::from_json(
+                    r#"{"nodes":[
+                        {"id":"a","traversal":{"branch-point":{"options":[
+                            {"label":"B","key":"b","target":"b"},
+                            {"label":"C","key":"c","target":"c"}
+                        ]}},"content":[]},
+                        {"id":"b","content":[]},
+                        {"id":"c","content":[]}
+                    ]}"#,
+                )
+                .expect("parse"),
+            )
+            .expect("non-empty"),
+        );
+        app.update(Msg::Remote(RemoteCommand::Choose('c')));
+        assert_eq!(app.session().current().id, "c");
+        app.update(Msg::Remote(RemoteCommand::Back));
+        assert_eq!(app.session().current().id, "a");
+        app.update(Msg::Remote(RemoteCommand::Goto(2)));
+        assert_eq!(app.session().current().id, "b");
+
+        app.update(Msg::Remote(RemoteCommand::Goto(9)));
+        assert_eq!(app.session().current().id, "b");
+        assert!(app.flash().is_some_and(|f| f.kind == FlashKind::Error));
+        app.update(Msg::Remote(RemoteCommand::Choose('c')));
+        assert_eq!(app.session().current().id, "b", "no choice on this slide");
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.update(Msg::Terminal(Event::Key(KeyEvent::new(
+            code,
+            KeyModifiers::NONE,
+        ))));
+    }
+
+    #[test]
+    fn the_jump_list_goes_back_to_a_recent_slide_as_a_new_move() {
+        let mut app = App::new(
+            Session::new(
+                Graph::from_json(
+                    r#"{"nodes":[
+                        {"id":"a","traversal":"b","content":[]},
+                        {"id":"b","traversal":{"branch-point":{"options":[
+                            {"label":"C","key":"c","target":"c"}
+                        ]}},"content":[]},
+                        {"id":"c","traversal":"d","content":[]},
+                        {"id":"d","content":[]}
+                    ]}"#,
+                )
+                .expect("parse"),
+            )
+            .expect("non-empty"),
+        );
+        press(&mut app, KeyCode::Char('H'));
+        assert_eq!(*app.screen(), Screen::Present, "nothing behind the start");
+
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Char('c'));
+        press(&mut app, KeyCode::Right);
+        app.jump_to(2);
+        app.jump_to(3);
+        // History a, b, c, d, c — the list drops the current d and shows
+        // the run-free path behind it, most recent first.
+        assert_eq!(
+            jump_list(&app.session),
+            [
+                JumpEntry {
+                    index: 2,
+                    via_branch: false
+                },
+                JumpEntry {
+                    index: 3,
+                    via_branch: false
+                },
+                JumpEntry {
+                    index: 2,
+                    via_branch: true
+                },
+                JumpEntry {
+                    index: 1,
+                    via_branch: false
+                },
+                JumpEntry {
+                    index: 0,
+                    via_branch: false
+                },
+            ]
+        );
+
+        press(&mut app, KeyCode::Char('H'));
+        assert_eq!(*app.screen(), Screen::Jumps { selected: 0 });
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(*app.screen(), Screen::Present);
+        assert_eq!(app.session().current().id, "b");
+        assert_eq!(
+            app.session().history().last().map(String::as_str),
+            Some("d")
+        );
+        assert_eq!(jump_list(&app.session)[0].index, 3);
+    }
+
+    #[test]
+    fn the_contents_open_on_the_current_slide_and_go_to_the_one_chosen() {
+        let mut app = App::new(
+            Session::new(
+                Graph::from_json(
+                    r#"{"nodes":[
+                        {"id":"a","section":"One","traversal":"c","content":[]},
+                        {"id":"b","content":[]},
+                        {"id":"c","section":"Two","traversal":"b","content":[
+                            {"kind":"heading","level":1,"text":"Cee"},
+                            {"kind":"heading","level":2,"text":"Detail"}]}
+                    ]}"#,
+                )
+                .expect("parse"),
+            )
+            .expect("non-empty"),
+        );
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Char('O'));
+        // One, a, Two, Cee, Detail, b — c is on screen.
+        assert_eq!(*app.screen(), Screen::Outline { selected: 3 });
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.session().current().id, "c");
+
+        press(&mut app, KeyCode::Char('O'));
+        press(&mut app, KeyCode::End);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(*app.screen(), Screen::Present);
+        assert_eq!(app.session().current().id, "b");
+        assert_eq!(
+            app.session().history().last().map(String::as_str),
+            Some("c"),
+            "a goto, so back returns"
+        );
+    }
+
+    #[test]
+    fn braces_jump_to_the_nearest_choice_either_way_along_the_path() {
+        let nodes: Vec<String> = (0..9)
+            .map(|i| match i {
+                3 | 7 => format!(
+                    r#"{{"id":"n{i}","traversal":{{"branch-point":{{"options":[
+                        {{"label":"On","target":"n{}"}}
+                    ]}}}},"content":[]}}"#,
+                    i + 1
+                ),
+                8 => r#"{"id":"n8","content":[]}"#.to_owned(),
+                _ => format!(r#"{{"id":"n{i}","traversal":"n{}","content":[]}}"#, i + 1),
+            })
+            .collect();
+        let graph =
+            Graph::from_json(&format!(r#"{{"nodes":[{}]}}"#, nodes.join(","))).expect("parse");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        app.jump_to(4);
+
+        press(&mut app, KeyCode::Char('}'));
+        assert_eq!(app.session().current_index(), 7);
+        app.jump_to(4);
+        press(&mut app, KeyCode::Char('{'));
+        assert_eq!(app.session().current_index(), 3);
+
+        press(&mut app, KeyCode::Char('{'));
+        assert_eq!(app.session().current_index(), 3, "nothing before the first");
+        assert!(
+            app.flash()
+                .is_some_and(|f| f.text.contains("No choice before"))
+        );
+    }
+
+    #[test]
+    fn a_branch_option_keyed_with_a_brace_wins_over_the_choice_jump() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":{"branch-point":{"options":[
+                    {"label":"Skip","key":"}","target":"c"}
+                ]}},"content":[]},
+                {"id":"b","traversal":{"branch-point":{"options":[
+                    {"label":"On","target":"c"}
+                ]}},"content":[]},
+                {"id":"c","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let mut app = App::new(Session::new(graph).expect("non-empty"));
+        press(&mut app, KeyCode::Char('}'));
+        assert_eq!(app.session().current().id, "c", "the option, not choice b");
+    }
 }
@@ -95,6 +95,19 @@ impl EditableField {
         self.cursor.1 += 1;
     }
 
+    /// Inserts a whole chunk at the cursor, as a paste does: each line
+    /// break (`\n`, `\r\n` or a lone `\r`) starts a new row, the way
+    /// [`EditableField::newline`] would.
+    pub(crate) fn insert_str(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.newline();
+            }
+            line.chars().for_each(|c| self.insert_char(c));
+        }
+    }
+
     pub(crate) fn newline(&mut self) {
         let (row, col) = self.cursor;
         let idx = self.byte_offset(row, col);
@@ -232,9 +245,9 @@ pub(crate) struct ChildSummary {
 }
 
 /// The form open for one block, keyed by the node and block it edits. Every
-/// variant maps to exactly one of the eight authoring-facing block kinds
-/// (`Divider` has no fields, so it has no form — selecting one offers
-/// no `[ Edit ]` action at all).
+/// variant maps to exactly one of the authoring-facing block kinds
+/// (`Divider` has no form — its style and label are set in the deck file —
+/// so selecting one offers no `[ Edit ]` action at all).
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum FormState {
     Heading {
@@ -273,6 +286,15 @@ pub(crate) enum FormState {
         alt: EditableField,
         focus: TextArtFocus,
     },
+    /// Only the source is editable; the poster and caption ride along
+    /// untouched so `[ Done ]` never drops them.
+    Video {
+        node: String,
+        path: BlockPath,
+        src: EditableField,
+        poster: Option<String>,
+        caption: Option<String>,
+    },
     Container {
         node: String,
         path: BlockPath,
@@ -317,6 +339,7 @@ impl FormState {
             | Self::List { node, .. }
             | Self::Picture { node, .. }
             | Self::TextArt { node, .. }
+            | Self::Video { node, .. }
             | Self::Container { node, .. }
             | Self::AddPalette { node, .. } => node,
             Self::Prompt { .. } | Self::SlidePicker { .. } => "",
@@ -331,6 +354,7 @@ impl FormState {
             | Self::List { path, .. }
             | Self::Picture { path, .. }
             | Self::TextArt { path, .. }
+            | Self::Video { path, .. }
             | Self::Container { path, .. }
             | Self::AddPalette { path, .. } => path,
             Self::Prompt { .. } | Self::SlidePicker { .. } => {
@@ -381,15 +405,21 @@ impl FormState {
     }
 
     /// Whether this form's `[ Done ]` chip applies a direct effect
-    /// (`NewSlide`/`DeckTitle`/`Notes`) rather than a `[ Choose target → ]`
+    /// (`NewSlide`/`DeckDetails`/`Notes`, and editing an existing choice's
+    /// prompt or answer) rather than a `[ Choose target → ]`
     /// hand-off (`ChoicePrompt`/`NewAnswer`) — spec 013 US3, T051/T052.
     pub(crate) fn prompt_commits_directly(&self) -> bool {
         matches!(
             self,
             Self::Prompt {
                 kind: PromptKind::NewSlide { .. }
-                    | PromptKind::DeckTitle
-                    | PromptKind::Notes { .. },
+                    | PromptKind::DeckDetails
+                    | PromptKind::Notes { .. }
+                    | PromptKind::Duration { .. }
+                    | PromptKind::SlideId { .. }
+                    | PromptKind::EditChoicePrompt { .. }
+                    | PromptKind::EditAnswer { .. }
+                    | PromptKind::ReplaceText,
                 ..
             }
         )
@@ -407,12 +437,16 @@ impl FormState {
                 };
                 Some(ContentBlock::Heading {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     level,
                     text: field.text(),
                 })
             }
             Self::Text { field, .. } => Some(ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: field.text(),
             }),
             Self::Code {
@@ -421,10 +455,14 @@ impl FormState {
                 let lang = language.text();
                 Some(ContentBlock::Code {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     language: (!lang.trim().is_empty()).then_some(lang),
                     source: source.text(),
                     highlight_lines: None,
                     show_line_numbers: None,
+                    runnable: false,
+                    wrap: None,
                 })
             }
             Self::List { field, .. } => {
@@ -439,16 +477,22 @@ impl FormState {
                     .collect();
                 Some(ContentBlock::List {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     ordered: Some(ordered),
                     items,
+                    incremental: false,
                 })
             }
             Self::Picture { src, alt, .. } => {
                 let alt_text = alt.text();
                 Some(ContentBlock::Image {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     src: src.text(),
                     alt: (!alt_text.trim().is_empty()).then_some(alt_text),
+                    decorative: false,
                     caption: None,
                     width: None,
                     height: None,
@@ -458,10 +502,25 @@ impl FormState {
                 let alt_text = alt.text();
                 Some(ContentBlock::AsciiArt {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     art: art.text(),
                     alt: (!alt_text.trim().is_empty()).then_some(alt_text),
                 })
             }
+            Self::Video {
+                src,
+                poster,
+                caption,
+                ..
+            } => Some(ContentBlock::Video {
+                reveal: None,
+                id: None,
+                visible_if: None,
+                src: src.text(),
+                poster: poster.clone(),
+                caption: caption.clone(),
+            }),
             Self::Container { .. }
             | Self::AddPalette { .. }
             | Self::Prompt { .. }
@@ -496,7 +555,10 @@ pub(crate) fn kind_label(block: &ContentBlock) -> &'static str {
         ContentBlock::Image { .. } => "picture",
         ContentBlock::Divider { .. } => "divider",
         ContentBlock::Container { .. } => "layout",
+        ContentBlock::Columns { .. } => "columns",
         ContentBlock::AsciiArt { .. } => "text art",
+        ContentBlock::Video { .. } => "video",
+        ContentBlock::Embed { .. } => "embed",
     }
 }
 
@@ -507,6 +569,8 @@ fn child_summary(block: &ContentBlock) -> ChildSummary {
         ContentBlock::Code { source, .. } => source.lines().next().unwrap_or_default().to_owned(),
         ContentBlock::List { items, .. } => items.first().cloned().unwrap_or_default(),
         ContentBlock::Image { alt, src, .. } => alt.clone().unwrap_or_else(|| src.clone()),
+        ContentBlock::Video { src, .. } => src.clone(),
+        ContentBlock::Embed { source, .. } => source.clone(),
         ContentBlock::Divider { .. } => String::new(),
         ContentBlock::Container { children, .. } => {
             format!(
@@ -515,6 +579,7 @@ fn child_summary(block: &ContentBlock) -> ChildSummary {
                 if children.len() == 1 { "" } else { "s" }
             )
         }
+        ContentBlock::Columns { columns, .. } => format!("{} columns", columns.len()),
         ContentBlock::AsciiArt { alt, .. } => alt.clone().unwrap_or_default(),
     };
     let label = if snippet.trim().is_empty() {
@@ -526,7 +591,9 @@ fn child_summary(block: &ContentBlock) -> ChildSummary {
 }
 
 /// Opens the form for `block` at `path` on `node`, or `None` for a
-/// `Divider`, which has nothing to edit (spec 013 T027-T033).
+/// `Divider` (spec 013 T027-T033) and for `Columns` and `Embed`, whose
+/// style, label, columns, and source are edited in the deck file for now.
+/// An embed's canvas shows what it resolved to either way.
 #[must_use]
 pub(crate) fn open(node: &str, path: BlockPath, block: &ContentBlock) -> Option<FormState> {
     let node = node.to_owned();
@@ -579,6 +646,18 @@ pub(crate) fn open(node: &str, path: BlockPath, block: &ContentBlock) -> Option<
             node,
             path,
         }),
+        ContentBlock::Video {
+            src,
+            poster,
+            caption,
+            ..
+        } => Some(FormState::Video {
+            src: EditableField::single_line(path.clone(), src),
+            poster: poster.clone(),
+            caption: caption.clone(),
+            node,
+            path,
+        }),
         ContentBlock::Container {
             children, layout, ..
         } => Some(FormState::Container {
@@ -587,7 +666,9 @@ pub(crate) fn open(node: &str, path: BlockPath, block: &ContentBlock) -> Option<
             node,
             path,
         }),
-        ContentBlock::Divider { .. } => None,
+        ContentBlock::Divider { .. }
+        | ContentBlock::Columns { .. }
+        | ContentBlock::Embed { .. } => None,
     }
 }
 
@@ -603,6 +684,8 @@ mod tests {
     fn heading_form_round_trips_edited_text() {
         let block = ContentBlock::Heading {
             reveal: Some(2),
+            id: None,
+            visible_if: None,
             level: 2,
             text: "Old title".to_owned(),
         };
@@ -618,6 +701,8 @@ mod tests {
             content,
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 2,
                 text: "New title".to_owned(),
             }
@@ -626,7 +711,13 @@ mod tests {
 
     #[test]
     fn divider_has_no_form() {
-        let block = ContentBlock::Divider { reveal: None };
+        let block = ContentBlock::Divider {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            style: None,
+            label: None,
+        };
         assert!(open("a", path(&[0]), &block).is_none());
     }
 
@@ -634,8 +725,11 @@ mod tests {
     fn list_form_drops_blank_lines_on_commit() {
         let block = ContentBlock::List {
             reveal: None,
+            id: None,
+            visible_if: None,
             ordered: Some(true),
             items: vec!["one".to_owned(), "two".to_owned()],
+            incremental: false,
         };
         let Some(mut form) = open("a", path(&[0]), &block) else {
             panic!("list has a form");
@@ -661,10 +755,14 @@ mod tests {
     fn code_form_treats_blank_language_as_absent() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: Some("rust".to_owned()),
             source: "fn main() {}".to_owned(),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         let Some(mut form) = open("a", path(&[0]), &block) else {
             panic!("code has a form");
@@ -684,6 +782,8 @@ mod tests {
     fn text_art_over_max_width_cannot_commit() {
         let block = ContentBlock::AsciiArt {
             reveal: None,
+            id: None,
+            visible_if: None,
             art: "short".to_owned(),
             alt: None,
         };
@@ -706,13 +806,24 @@ mod tests {
     fn container_form_reports_its_children_and_no_staged_content() {
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Columns),
+            columns: None,
             children: vec![
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "left".to_owned(),
                 },
-                ContentBlock::Divider { reveal: None },
+                ContentBlock::Divider {
+                    reveal: None,
+                    id: None,
+                    visible_if: None,
+                    style: None,
+                    label: None,
+                },
             ],
         };
         let Some(form) = open("a", path(&[0]), &block) else {
@@ -733,6 +844,8 @@ mod tests {
     fn nested_block_reports_its_container_as_parent() {
         let block = ContentBlock::Text {
             reveal: None,
+            id: None,
+            visible_if: None,
             body: "nested".to_owned(),
         };
         let form = open("a", path(&[0, 1]), &block).expect("text has a form");
@@ -746,9 +859,14 @@ mod tests {
     fn block_at_recurses_into_containers() {
         let blocks = vec![ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: None,
+            columns: None,
             children: vec![ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "inner".to_owned(),
             }],
         }];
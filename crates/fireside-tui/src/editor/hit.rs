@@ -11,10 +11,15 @@
 //! `render::editor` reuses this module's pure layout functions for its own
 //! drawing, so the two can never drift apart either.
 
+use std::borrow::Cow;
+
 use ratatui::layout::{Constraint, Layout, Rect};
 
-use fireside_core::{BranchOption, ContainerLayout, ContentBlock, Graph, Node};
+use fireside_core::{
+    BranchOption, ContainerLayout, ContentBlock, Graph, Node, Transition, ViewMode, format_duration,
+};
 use fireside_engine::authoring::{BlockKind, BlockPath, OutlineRow, outline_order};
+use fireside_engine::needs_alt_text;
 
 use crate::render::blocks::ChildGeometry;
 use crate::render::content::{NodeLines, SlideView, content_inner, node_lines};
@@ -46,8 +51,14 @@ pub(crate) enum ToolbarAction {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum BlockAction {
     Edit,
+    /// Opens a picture's form at its alt field — offered only while the
+    /// image has no alt text and isn't marked decorative.
+    DescribeImage,
     AddBelow,
     Reveal,
+    /// Moves this block and everything after it onto a new slide — shown
+    /// only for a top-level block with at least one block above it.
+    SplitHere,
     Delete,
 }
 
@@ -65,7 +76,16 @@ pub(crate) enum SlideAction {
     /// when ≥ 2 answers exist; `[ Turn back into a normal slide ]` is
     /// the path to zero, per `AuthoringError::LastAnswer`.
     RemoveAnswer,
+    /// Opens the branch point's prompt for editing — branch slides only.
+    EditPrompt,
+    /// Gives every answer without a usable key the next free one
+    /// (`Op::AssignAnswerKeys`, preserving keys already set).
+    AssignKeys,
     Notes,
+    /// Opens the slide's auto-advance `duration` for editing.
+    Duration,
+    /// Opens the slide's id for editing.
+    Id,
 }
 
 /// A click on the flash message's action link, if it has one (spec 013 US3
@@ -124,11 +144,43 @@ impl PickerTarget {
 /// effect or hands off to [`FormState::SlidePicker`](super::forms::FormState::SlidePicker).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum PromptKind {
-    NewSlide { after: String },
-    DeckTitle,
-    Notes { node: String },
-    ChoicePrompt { node: String },
-    NewAnswer { node: String },
+    NewSlide {
+        after: String,
+    },
+    /// The deck's own metadata — title, author, date, and description —
+    /// one field each; an emptied field removes that property.
+    DeckDetails,
+    Notes {
+        node: String,
+    },
+    /// A slide's auto-advance duration, in any form `parse_duration`
+    /// reads; left empty, the slide has none.
+    Duration {
+        node: String,
+    },
+    /// A slide's id; renaming it moves every `next` and branch target
+    /// that pointed at the old one.
+    SlideId {
+        node: String,
+    },
+    ChoicePrompt {
+        node: String,
+    },
+    NewAnswer {
+        node: String,
+    },
+    /// An existing branch point's prompt, edited in place.
+    EditChoicePrompt {
+        node: String,
+    },
+    /// An existing answer's label, key, and description; its target is
+    /// changed from the same form's `[ Change target → ]` chip.
+    EditAnswer {
+        node: String,
+        index: usize,
+    },
+    /// Find-and-replace across the whole deck.
+    ReplaceText,
 }
 
 /// One chip inside the currently open form (spec 013, US1-US2). `Done`
@@ -146,8 +198,8 @@ pub(crate) enum FormChipKind {
     CycleLayout,
     PaletteCard(BlockKind),
     /// The `[ Choose target → ]` chip on `PromptKind::ChoicePrompt`/
-    /// `NewAnswer` (spec 013 US3, T051/T052): hands off to
-    /// `FormState::SlidePicker`.
+    /// `NewAnswer` (spec 013 US3, T051/T052), or `EditAnswer`'s
+    /// `[ Change target → ]`: hands off to `FormState::SlidePicker`.
     ChooseTarget,
     /// One row of an open `FormState::SlidePicker`, by index into its
     /// `rows` (T051).
@@ -326,7 +378,7 @@ pub(crate) const QUIT_PROMPT_CHIPS: [(QuitAction, &str); 3] = [
     (QuitAction::KeepEditing, "[ Keep editing ]"),
 ];
 
-const QUIT_PROMPT_SIZE: (u16, u16) = (46, 4);
+const QUIT_PROMPT_SIZE: (u16, u16) = (46, 5);
 
 /// The quit-prompt modal's centered rect — same sizing convention
 /// `render::overlay_rect` already uses for the help overlay.
@@ -422,9 +474,10 @@ fn chip_row_rects(inner: Rect, y: u16, labels: &[&str]) -> Vec<Rect> {
     out
 }
 
-/// One line of the outline pane: a slide row, the "not linked yet" divider
-/// (shown once, before the first unreachable row), or the permanent
-/// "+ new slide" row. Built once by [`outline_lines`] and shared by
+/// One line of the outline pane: a slide row, a section header (before a
+/// row whose `section` differs from the row above's), the "not linked yet"
+/// divider (shown once, before the first unreachable row), or the
+/// permanent "+ new slide" row. Built once by [`outline_lines`] and shared by
 /// `render::editor::outline` (which turns it into styled `Line`s) and this
 /// module's `outline_hit` (which turns a click's row into a target) — the
 /// same "one pure layout, two consumers" convention as everywhere else
@@ -432,6 +485,7 @@ fn chip_row_rects(inner: Rect, y: u16, labels: &[&str]) -> Vec<Rect> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum OutlineLine {
     Row(OutlineRow),
+    Section(String),
     Divider,
     NewSlide,
 }
@@ -441,11 +495,19 @@ pub(crate) fn outline_lines(graph: &Graph) -> Vec<OutlineLine> {
     let rows = outline_order(graph);
     let mut out = Vec::with_capacity(rows.len() + 2);
     let mut divider_shown = false;
+    let mut section: Option<&str> = None;
     for row in rows {
         if !row.reachable && !divider_shown {
             out.push(OutlineLine::Divider);
             divider_shown = true;
         }
+        let row_section = graph.node(&row.node_id).and_then(|n| n.section.as_deref());
+        if row_section != section {
+            if let Some(name) = row_section {
+                out.push(OutlineLine::Section(name.to_owned()));
+            }
+            section = row_section;
+        }
         out.push(OutlineLine::Row(row));
     }
     out.push(OutlineLine::NewSlide);
@@ -522,7 +584,10 @@ fn container_child_extents(
     let mut out = Vec::new();
     for (ci, &(start, _end)) in extents.iter().enumerate() {
         let Some(ContentBlock::Container {
-            children, layout, ..
+            children,
+            layout,
+            columns,
+            ..
         }) = content.get(ci)
         else {
             continue;
@@ -530,6 +595,7 @@ fn container_child_extents(
         let geoms = blocks::container_child_geometry(
             children,
             layout.unwrap_or_default(),
+            *columns,
             width,
             tokens,
             reveal_level,
@@ -599,7 +665,7 @@ fn outline_hit(app: &EditorApp, outline: Rect, col: u16, row: u16) -> Option<Tar
     let idx = outline_scroll_offset(app, outline) + (row - outline.y) as usize;
     match outline_lines(app.working_graph()).get(idx)? {
         OutlineLine::Row(r) => Some(Target::OutlineRow(r.node_id.clone())),
-        OutlineLine::Divider => None,
+        OutlineLine::Section(_) | OutlineLine::Divider => None,
         OutlineLine::NewSlide => Some(Target::OutlineNewSlide),
     }
 }
@@ -623,16 +689,21 @@ pub(crate) fn canvas_layout(app: &EditorApp, canvas: Rect) -> Option<CanvasLayou
     let node = selected_node(app)?;
     let tokens = Tokens::default();
     let view_mode = node.resolved_view_mode(app.working_graph().defaults.as_ref());
-    let surf: Surface = surface(view_mode, canvas);
+    let surf: Surface = surface(view_mode, canvas, None);
     let view = SlideView {
         node,
+        content: Cow::Borrowed(&node.content),
         reveal_level: u32::MAX,
         has_pending_reveal: false,
         branch_selected: 0,
         fading: false,
         scroll: app.scroll(),
         view_mode,
-        history_titles: Vec::new(),
+        max_width: None,
+        route: Vec::new(),
+        spotlight: None,
+        last_choice: None,
+        choice_left: None,
     };
     let NodeLines { lines, .. } = node_lines(&view, surf.width, &tokens);
     let total = lines.len() as u16;
@@ -824,11 +895,15 @@ fn resolve_sibling_slot(
 /// over the hint line exactly the way a flash message would — visible
 /// only while something is selected, gone the moment it isn't.
 /// `[ ✎ Edit ]` only appears when the block has a form (a `Divider` has
-/// nothing to edit); `[ + Add below ]` and `[ Delete ]` are always
-/// available for any selected block.
+/// nothing to edit), and `[ ⚠ Add alt text ]` only on a picture nothing
+/// describes; `[ Split here ]` only on a top-level block with another
+/// above it, so both halves keep a block; `[ + Add below ]` and
+/// `[ Delete ]` are always available for any selected block.
 pub(crate) const BLOCK_EDIT_CHIP: &str = " [ \u{270e} Edit ]";
 pub(crate) const BLOCK_ADD_BELOW_CHIP: &str = " [ + Add below ]";
+pub(crate) const BLOCK_SPLIT_CHIP: &str = " [ Split here ]";
 pub(crate) const BLOCK_DELETE_CHIP: &str = " [ Delete ]";
+pub(crate) const BLOCK_ALT_CHIP: &str = " [ \u{26a0} Add alt text ]";
 
 /// The selected block's current reveal step, for the `[ Reveal: … ▾ ]`
 /// chip's label (spec 013 US3, T053) — "none" when unset or `0`.
@@ -880,12 +955,43 @@ pub(crate) fn selected_block_chips(app: &EditorApp) -> Vec<(BlockAction, String)
     if selection_has_form(app) {
         chips.push((BlockAction::Edit, BLOCK_EDIT_CHIP.to_owned()));
     }
+    if forms::block_at(&node.content, path).is_some_and(needs_alt_text) {
+        chips.push((BlockAction::DescribeImage, BLOCK_ALT_CHIP.to_owned()));
+    }
     chips.push((BlockAction::AddBelow, BLOCK_ADD_BELOW_CHIP.to_owned()));
     chips.push((BlockAction::Reveal, reveal_chip_label(node, path)));
+    if let [at] = path[..]
+        && at > 0
+    {
+        chips.push((BlockAction::SplitHere, BLOCK_SPLIT_CHIP.to_owned()));
+    }
     chips.push((BlockAction::Delete, BLOCK_DELETE_CHIP.to_owned()));
     chips
 }
 
+/// The selected slide's view mode and transition in words, each marked
+/// "(default)" when the slide doesn't set it and inherits the deck's
+/// `defaults` or the built-in value — so the hint line shows what the
+/// presenter will do without implying the slide says so itself.
+#[must_use]
+pub(crate) fn slide_settings(graph: &Graph, node: &Node) -> String {
+    let defaults = graph.defaults.as_ref();
+    let view = match node.resolved_view_mode(defaults) {
+        ViewMode::Default => "standard view",
+        ViewMode::Fullscreen => "fullscreen",
+    };
+    let transition = match node.resolved_transition(defaults) {
+        Transition::None => "no transition",
+        Transition::Fade => "fade in",
+    };
+    let mark = |explicit: bool| if explicit { "" } else { " (default)" };
+    format!(
+        "{view}{} \u{b7} {transition}{}",
+        mark(node.view_mode.is_some()),
+        mark(node.transition.is_some())
+    )
+}
+
 /// The selected slide's contextual chips (spec 013 US3, T049/T052/T054) —
 /// the hint line's equivalent of [`selected_block_chips`] when
 /// `Selection::Slide` (not `Block`) is active.
@@ -898,11 +1004,14 @@ pub(crate) fn selected_slide_chips(app: &EditorApp) -> Vec<(SlideAction, String)
         return Vec::new();
     };
     let mut chips = vec![
+        (SlideAction::Id, format!(" [ Id {} ]", node.id)),
         (SlideAction::Duplicate, " [ Duplicate ]".to_owned()),
         (SlideAction::Delete, " [ Delete ]".to_owned()),
     ];
     if let Some(bp) = node.branch_point() {
         chips.push((SlideAction::AddAnswer, " [ + Add answer ]".to_owned()));
+        chips.push((SlideAction::EditPrompt, " [ Edit prompt ]".to_owned()));
+        chips.push((SlideAction::AssignKeys, " [ Assign keys ]".to_owned()));
         if bp.options.len() > 1 {
             chips.push((
                 SlideAction::RemoveAnswer,
@@ -929,6 +1038,11 @@ pub(crate) fn selected_slide_chips(app: &EditorApp) -> Vec<(SlideAction, String)
         " [ Notes ]"
     };
     chips.push((SlideAction::Notes, notes_label.to_owned()));
+    let duration_label = match node.duration {
+        Some(duration) => format!(" [ Duration {} ]", format_duration(duration)),
+        None => " [ Duration ]".to_owned(),
+    };
+    chips.push((SlideAction::Duration, duration_label));
     chips
 }
 
@@ -1018,13 +1132,21 @@ fn form_title(form: &FormState) -> &'static str {
             ..
         } => " New slide ",
         FormState::Prompt {
-            kind: PromptKind::DeckTitle,
+            kind: PromptKind::DeckDetails,
             ..
-        } => " Rename the deck ",
+        } => " Deck details ",
         FormState::Prompt {
             kind: PromptKind::Notes { .. },
             ..
         } => " Speaker notes ",
+        FormState::Prompt {
+            kind: PromptKind::Duration { .. },
+            ..
+        } => " Auto-advance duration ",
+        FormState::Prompt {
+            kind: PromptKind::SlideId { .. },
+            ..
+        } => " Slide id ",
         FormState::Prompt {
             kind: PromptKind::ChoicePrompt { .. },
             ..
@@ -1033,9 +1155,22 @@ fn form_title(form: &FormState) -> &'static str {
             kind: PromptKind::NewAnswer { .. },
             ..
         } => " Add an answer ",
+        FormState::Prompt {
+            kind: PromptKind::EditChoicePrompt { .. },
+            ..
+        } => " Edit the prompt ",
+        FormState::Prompt {
+            kind: PromptKind::EditAnswer { .. },
+            ..
+        } => " Edit answer ",
+        FormState::Prompt {
+            kind: PromptKind::ReplaceText,
+            ..
+        } => " Find and replace ",
         FormState::SlidePicker { .. } => " Choose a slide ",
         FormState::Picture { .. } => " Edit picture ",
         FormState::TextArt { .. } => " Edit text art ",
+        FormState::Video { .. } => " Edit video ",
         FormState::Container { .. } => " Edit layout ",
         FormState::AddPalette { .. } => " Add a block ",
     }
@@ -1044,10 +1179,24 @@ fn form_title(form: &FormState) -> &'static str {
 fn prompt_field_labels(kind: &PromptKind) -> Vec<&'static str> {
     match kind {
         PromptKind::NewSlide { .. } => vec!["Title"],
-        PromptKind::DeckTitle => vec!["Deck title"],
+        PromptKind::DeckDetails => vec![
+            "Deck title",
+            "Author",
+            "Date (2026-03-14, say)",
+            "Description",
+        ],
         PromptKind::Notes { .. } => vec!["Speaker notes"],
+        PromptKind::Duration { .. } => vec!["Duration (90s, 1m30s, or 1:30; empty for none)"],
+        PromptKind::SlideId { .. } => vec!["Id (letters, digits, - and _)"],
         PromptKind::ChoicePrompt { .. } => vec!["Prompt (optional)", "First answer's label"],
         PromptKind::NewAnswer { .. } => vec!["Answer label", "Key (optional, one letter)"],
+        PromptKind::EditAnswer { .. } => vec![
+            "Answer label",
+            "Key (optional, one letter)",
+            "Description (optional)",
+        ],
+        PromptKind::EditChoicePrompt { .. } => vec!["Prompt (optional)"],
+        PromptKind::ReplaceText => vec!["Find (matching case)", "Replace with"],
     }
 }
 
@@ -1075,6 +1224,7 @@ fn form_sections(form: &FormState) -> Vec<(FieldSlot, &'static str, u16)> {
             (FieldSlot::Art, "Art", n(art.buffer.len())),
             (FieldSlot::Alt, "Description", n(alt.buffer.len())),
         ],
+        FormState::Video { src, .. } => vec![(FieldSlot::Src, "Video path", n(src.buffer.len()))],
         FormState::Prompt { kind, fields, .. } => prompt_field_labels(kind)
             .into_iter()
             .zip(fields)
@@ -1092,6 +1242,9 @@ fn form_hints(form: &FormState) -> Vec<String> {
         FormState::Picture { .. } => vec![
             "Pictures render as their description in the terminal \u{2014} the image itself never displays.".to_owned(),
         ],
+        FormState::Video { .. } => vec![
+            "Videos can't play in the terminal \u{2014} the presenter shows the poster, or a placeholder naming the file.".to_owned(),
+        ],
         FormState::TextArt { .. } if form.art_too_wide() => vec![format!(
             "This art is wider than {} columns \u{2014} shorten it or generate a new one.",
             forms::MAX_ART_WIDTH
@@ -1106,7 +1259,7 @@ fn form_hints(form: &FormState) -> Vec<String> {
 /// vocabulary gate denies) and the container kind "Columns / box /
 /// stack" — the same plain names `.claude/plans/2026-07-19-wysiwyg-editor-plan.md`
 /// specifies.
-const PALETTE_CARDS: [(BlockKind, &str); 8] = [
+const PALETTE_CARDS: [(BlockKind, &str); 10] = [
     (
         BlockKind::Heading,
         "Heading \u{2014} a big title or section heading",
@@ -1130,6 +1283,14 @@ const PALETTE_CARDS: [(BlockKind, &str); 8] = [
         BlockKind::AsciiArt,
         "Text art \u{2014} a banner made of characters",
     ),
+    (
+        BlockKind::Video,
+        "Video \u{2014} a recording, shown by its poster frame",
+    ),
+    (
+        BlockKind::Columns,
+        "Two columns \u{2014} a two-up layout, each column its own blocks",
+    ),
 ];
 
 fn form_chip_defs(form: &FormState) -> Vec<(FormChipKind, String)> {
@@ -1151,6 +1312,10 @@ fn form_chip_defs(form: &FormState) -> Vec<(FormChipKind, String)> {
             FormChipKind::GenerateFromPhrase,
             "[ Generate from a phrase\u{2026} ]",
         )],
+        FormState::Prompt {
+            kind: PromptKind::EditAnswer { .. },
+            ..
+        } => vec![(FormChipKind::ChooseTarget, "[ Change target \u{2192} ]")],
         FormState::Container { layout, .. } => vec![
             (
                 FormChipKind::CycleLayout,
@@ -1158,6 +1323,7 @@ fn form_chip_defs(form: &FormState) -> Vec<(FormChipKind, String)> {
                     ContainerLayout::Stack => "[ Layout: Stack \u{25be} ]",
                     ContainerLayout::Columns => "[ Layout: Columns \u{25be} ]",
                     ContainerLayout::Center => "[ Layout: Centered \u{25be} ]",
+                    ContainerLayout::Grid => "[ Layout: Grid \u{25be} ]",
                 },
             ),
             (FormChipKind::AddChild, "[ + Add a block inside ]"),
@@ -1589,7 +1755,12 @@ pub(crate) fn resolve_outline_drop(
     }
     let idx = outline_scroll_offset(app, outline) + (row - outline.y) as usize;
     let lines = outline_lines(app.working_graph());
-    match lines.get(idx) {
+    // A section header stands for the first slide under it.
+    let line = match lines.get(idx) {
+        Some(OutlineLine::Section(_)) => lines.get(idx + 1),
+        line => line,
+    };
+    match line {
         Some(OutlineLine::Row(r)) => Some(Some(r.node_id.clone())),
         _ => Some(None),
     }
@@ -1677,6 +1848,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn a_section_change_puts_a_header_row_above_the_slide() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[]},
+                {"id":"b","section":"Demo","traversal":"c","content":[]},
+                {"id":"c","section":"Demo","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        let lines = outline_lines(&graph);
+        let shape: Vec<String> = lines
+            .iter()
+            .map(|line| match line {
+                OutlineLine::Row(r) => r.node_id.clone(),
+                OutlineLine::Section(name) => format!("== {name}"),
+                OutlineLine::Divider => "--".to_owned(),
+                OutlineLine::NewSlide => "+".to_owned(),
+            })
+            .collect();
+        assert_eq!(shape, ["a", "== Demo", "b", "c", "+"]);
+
+        let app = EditorApp::new(graph);
+        let outline = editor_areas(area()).outline;
+        assert_eq!(hit(&app, area(), outline.x, outline.y + 1), None);
+        assert_eq!(
+            resolve_outline_drop(&app, outline, outline.x, outline.y + 1),
+            Some(Some("b".to_owned()))
+        );
+    }
+
+    #[test]
+    fn slide_settings_mark_inherited_values_as_defaults() {
+        let graph = Graph::from_json(
+            r#"{"defaults":{"view-mode":"fullscreen"},"nodes":[
+                {"id":"a","content":[]},
+                {"id":"b","view-mode":"default","transition":"fade","content":[]}
+            ]}"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            slide_settings(&graph, &graph.nodes[0]),
+            "fullscreen (default) \u{b7} no transition (default)"
+        );
+        assert_eq!(
+            slide_settings(&graph, &graph.nodes[1]),
+            "standard view \u{b7} fade in"
+        );
+    }
+
     #[test]
     fn the_row_after_the_last_slide_is_the_new_slide_row() {
         let app = app();
@@ -1694,17 +1915,22 @@ mod tests {
         let node = app.working_graph().node("a").expect("node a");
         let tokens = Tokens::default();
         let view_mode = node.resolved_view_mode(app.working_graph().defaults.as_ref());
-        let surf = surface(view_mode, areas.canvas);
+        let surf = surface(view_mode, areas.canvas, None);
         let extents = block_extents(node, surf.width, &tokens, u32::MAX);
         let view = SlideView {
             node,
+            content: Cow::Borrowed(&node.content),
             reveal_level: u32::MAX,
             has_pending_reveal: false,
             branch_selected: 0,
             fading: false,
             scroll: 0,
             view_mode,
-            history_titles: Vec::new(),
+            max_width: None,
+            route: Vec::new(),
+            spotlight: None,
+            last_choice: None,
+            choice_left: None,
         };
         let NodeLines { lines, .. } = node_lines(&view, surf.width, &tokens);
         let (_, inner) = content_inner(areas.canvas, &surf, lines.len() as u16);
@@ -1739,17 +1965,22 @@ mod tests {
         let node = app.working_graph().node("a").expect("node a");
         let tokens = Tokens::default();
         let view_mode = node.resolved_view_mode(app.working_graph().defaults.as_ref());
-        let surf = surface(view_mode, areas.canvas);
+        let surf = surface(view_mode, areas.canvas, None);
         let extents = block_extents(node, surf.width, &tokens, u32::MAX);
         let view = SlideView {
             node,
+            content: Cow::Borrowed(&node.content),
             reveal_level: u32::MAX,
             has_pending_reveal: false,
             branch_selected: 0,
             fading: false,
             scroll: 0,
             view_mode,
-            history_titles: Vec::new(),
+            max_width: None,
+            route: Vec::new(),
+            spotlight: None,
+            last_choice: None,
+            choice_left: None,
         };
         let NodeLines { lines, .. } = node_lines(&view, surf.width, &tokens);
         let (_, inner) = content_inner(areas.canvas, &surf, lines.len() as u16);
@@ -1790,17 +2021,22 @@ mod tests {
         let node = app.working_graph().node("a").expect("node a");
         let tokens = Tokens::default();
         let view_mode = node.resolved_view_mode(app.working_graph().defaults.as_ref());
-        let surf = surface(view_mode, areas.canvas);
+        let surf = surface(view_mode, areas.canvas, None);
         let extents = block_extents(node, surf.width, &tokens, u32::MAX);
         let view = SlideView {
             node,
+            content: Cow::Borrowed(&node.content),
             reveal_level: u32::MAX,
             has_pending_reveal: false,
             branch_selected: 0,
             fading: false,
             scroll: 0,
             view_mode,
-            history_titles: Vec::new(),
+            max_width: None,
+            route: Vec::new(),
+            spotlight: None,
+            last_choice: None,
+            choice_left: None,
         };
         let NodeLines { lines, .. } = node_lines(&view, surf.width, &tokens);
         let (_, inner) = content_inner(areas.canvas, &surf, lines.len() as u16);
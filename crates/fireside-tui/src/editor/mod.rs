@@ -12,20 +12,22 @@
 pub(crate) mod forms;
 pub(crate) mod hit;
 
+use std::collections::HashMap;
 use std::io;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 use crossterm::event::{
-    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
-    KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
 };
 use crossterm::execute;
 use crossterm::tty::IsTty;
-use fireside_engine::authoring::{self, AuthoringError, BlockPath, Op};
-use fireside_engine::validate;
+use fireside_engine::authoring::{self, AuthoringError, BlockPath, IdStrategy, Op};
+use fireside_engine::diff::{ChangeSummary, diff_graphs};
+use fireside_engine::{DECK_WIDE_RULES, ReverseRefs, validate, validate_incremental};
 use ratatui::layout::Rect;
 
-use fireside_core::{ContainerLayout, ContentBlock, Graph};
+use fireside_core::{ContainerLayout, ContentBlock, Graph, format_duration, parse_duration};
 
 use crate::app::App as PresenterApp;
 use crate::app::FlashKind;
@@ -85,11 +87,49 @@ pub(crate) struct HistorySnapshot {
     pub(crate) selection: Selection,
 }
 
+/// One line of the edit journal: an edit that changed `working_graph`,
+/// and when. Unlike `history`, the journal is append-only and never
+/// capped, so an editing session can be reconstructed past the 100-step
+/// undo limit.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JournalEntry {
+    pub(crate) at: SystemTime,
+    pub(crate) edit: JournalEdit,
+}
+
+/// What a [`JournalEntry`] records.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JournalEdit {
+    /// An authoring op that applied.
+    Op(Op),
+    /// A metadata edit with no `Op` — see [`EditorApp::apply_direct`].
+    DeckDetails {
+        title: Option<String>,
+        author: Option<String>,
+        date: Option<String>,
+        description: Option<String>,
+    },
+    Notes {
+        node: String,
+        notes: Option<String>,
+    },
+    Duration {
+        node: String,
+        duration: Option<Duration>,
+    },
+    Undo,
+    Redo,
+}
+
 /// How long a flash message stays on screen — same duration the presenter
 /// itself uses (`app::FLASH_DURATION`), not shared as a symbol since the
 /// two `App`/`EditorApp` types are otherwise independent.
 const FLASH_DURATION: Duration = Duration::from_millis(3000);
 
+/// How many slides' canvas scroll offsets the editor remembers. Past it,
+/// another slide's is forgotten — that slide just opens at its top again.
+const SCROLL_MEMORY: usize = 64;
+
 /// A transient feedback message shown on the hint line — the editor's
 /// equivalent of the presenter's footer flash (design brief principle 4:
 /// every action produces immediate visible feedback).
@@ -139,9 +179,20 @@ pub(crate) struct EditorApp {
     open_form: Option<FormState>,
     history: Vec<HistorySnapshot>,
     redo: Vec<HistorySnapshot>,
+    /// Every edit in the order it happened — independent of `history`,
+    /// which undo pops and the cap truncates.
+    journal: Vec<JournalEntry>,
     terminal_size: (u16, u16),
     status: Vec<fireside_engine::Diagnostic>,
-    scroll: u16,
+    /// Which slides point at which, so an edit to one slide re-checks only
+    /// it and its referrers ([`Self::revalidate`]); rebuilt on every full
+    /// validation.
+    refs: ReverseRefs,
+    /// How far the canvas is scrolled on each slide scrolled so far, by
+    /// id, so coming back to a slide finds it where it was left. A slide
+    /// not in it sits at its top. Holds at most [`SCROLL_MEMORY`] slides;
+    /// clamped at read time by `hit::canvas_layout`.
+    scroll: HashMap<String, u16>,
     /// The outline pane's own scroll offset (spec 013 E4, T068) — separate
     /// from the canvas's `scroll` so scrolling one pane never disturbs the
     /// other; clamped at read time by `hit::outline_scroll_offset`, the
@@ -173,12 +224,18 @@ pub(crate) struct EditorApp {
     /// forever after that (design brief E4) — an author who has already
     /// saved once doesn't need to keep being taught the basics.
     hint_tour_dismissed: bool,
+    /// How slides added or duplicated here get their ids (`fireside edit
+    /// --ids`).
+    ids: IdStrategy,
+    /// The slide `C` marked to copy blocks from, until `C` on another
+    /// slide copies them there or Esc lets it go.
+    copy_source: Option<String>,
     quit: bool,
 }
 
 /// Every distinct positive reveal step used by `content`, excluding the
 /// block at `path` itself (spec 013 US3, T053's reveal-cycle ceiling) —
-/// recurses into `Container` children like `Node::reveal_levels()` does,
+/// recurses into container children and columns like `Node::reveal_levels()` does,
 /// so a step held only by a nested block still counts.
 fn other_reveal_levels(content: &[ContentBlock], path: &[usize]) -> Vec<u32> {
     fn walk(blocks: &[ContentBlock], path: &[usize], out: &mut Vec<u32>) {
@@ -190,7 +247,7 @@ fn other_reveal_levels(content: &[ContentBlock], path: &[usize]) -> Vec<u32> {
             {
                 out.push(v);
             }
-            if let ContentBlock::Container { children, .. } = block {
+            for children in block.child_blocks() {
                 let child_path: &[usize] = if path.first() == Some(&i) {
                     &path[1..]
                 } else {
@@ -246,6 +303,7 @@ impl EditorApp {
     #[must_use]
     pub(crate) fn new(graph: Graph) -> Self {
         let status = validate(&graph);
+        let refs = ReverseRefs::new(&graph);
         let saved_graph = graph.clone();
         Self {
             working_graph: graph,
@@ -255,9 +313,11 @@ impl EditorApp {
             open_form: None,
             history: Vec::new(),
             redo: Vec::new(),
+            journal: Vec::new(),
             terminal_size: (80, 24),
             status,
-            scroll: 0,
+            refs,
+            scroll: HashMap::new(),
             outline_scroll: 0,
             hover: None,
             dirty_since_draft: false,
@@ -272,10 +332,19 @@ impl EditorApp {
             flash: None,
             opened_at: Instant::now(),
             hint_tour_dismissed: false,
+            ids: IdStrategy::default(),
+            copy_source: None,
             quit: false,
         }
     }
 
+    /// Sets how slides added or duplicated in this session get their ids.
+    #[must_use]
+    pub(crate) fn with_id_strategy(mut self, ids: IdStrategy) -> Self {
+        self.ids = ids;
+        self
+    }
+
     /// Opens a fresh editor session over `graph` (the deck file's own
     /// content), gated behind the open-time draft-vs-saved-file prompt
     /// (spec 013 US4, FR-020) — the studio itself doesn't draw until
@@ -299,7 +368,45 @@ impl EditorApp {
 
     #[must_use]
     pub(crate) fn scroll(&self) -> u16 {
-        self.scroll
+        hit::selected_node(self)
+            .and_then(|node| self.scroll.get(&node.id))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Scrolls the selected slide's canvas one line, from where it's
+    /// actually drawn — an offset left past the end by an edit that
+    /// shortened the slide counts as its last line.
+    fn scroll_canvas(&mut self, down: bool) {
+        let (w, h) = self.terminal_size;
+        let canvas = hit::editor_areas(Rect::new(0, 0, w, h)).canvas;
+        let Some(layout) = hit::canvas_layout(self, canvas) else {
+            return;
+        };
+        let offset = if down {
+            layout.scroll.saturating_add(1)
+        } else {
+            layout.scroll.saturating_sub(1)
+        };
+        self.set_scroll(offset);
+    }
+
+    /// Remembers `offset` as the selected slide's canvas scroll.
+    fn set_scroll(&mut self, offset: u16) {
+        let Some(id) = hit::selected_node(self).map(|node| node.id.clone()) else {
+            return;
+        };
+        if offset == 0 {
+            self.scroll.remove(&id);
+            return;
+        }
+        if self.scroll.len() >= SCROLL_MEMORY
+            && !self.scroll.contains_key(&id)
+            && let Some(other) = self.scroll.keys().next().cloned()
+        {
+            self.scroll.remove(&other);
+        }
+        self.scroll.insert(id, offset);
     }
 
     #[must_use]
@@ -355,6 +462,20 @@ impl EditorApp {
         self.working_graph != self.saved_graph
     }
 
+    /// How the unsaved changes add up, slide by slide, against the deck
+    /// as it was opened or last saved — the quit prompt's summary line.
+    #[must_use]
+    pub(crate) fn change_summary(&self) -> ChangeSummary {
+        diff_graphs(&self.saved_graph, &self.working_graph)
+    }
+
+    fn record(&mut self, edit: JournalEdit) {
+        self.journal.push(JournalEntry {
+            at: SystemTime::now(),
+            edit,
+        });
+    }
+
     #[must_use]
     #[allow(dead_code)] // read by tests; a visible undo-depth indicator is future polish
     pub(crate) fn history_len(&self) -> usize {
@@ -490,7 +611,6 @@ impl EditorApp {
             (Some(i), true) => (i + rows.len() - 1) % rows.len(),
         };
         self.selection = Selection::Slide(rows[next].node_id.clone());
-        self.scroll = 0;
     }
 
     /// Tab/Shift+Tab: selects the next/previous block on the canvas's
@@ -535,7 +655,6 @@ impl EditorApp {
     fn jump_to_diagnostic(&mut self) {
         if let Some(id) = self.status.iter().find_map(|d| d.node.clone()) {
             self.selection = Selection::Slide(id);
-            self.scroll = 0;
         }
     }
 
@@ -597,15 +716,62 @@ impl EditorApp {
     /// 013 US3, T054): deck-title rename and per-slide notes have no
     /// `Op` — they're metadata this feature's contract deliberately leaves
     /// out of the authoring-ops table — but still need undo, so this
-    /// pushes history exactly like [`Self::apply_op`] does.
-    fn apply_direct(&mut self, mutate: impl FnOnce(&mut Graph)) {
+    /// pushes history exactly like [`Self::apply_op`] does, and journals
+    /// `edit`.
+    fn apply_direct(&mut self, edit: JournalEdit, mutate: impl FnOnce(&mut Graph)) {
+        let edited = match &edit {
+            JournalEdit::Notes { node, .. } | JournalEdit::Duration { node, .. } => {
+                Some(node.clone())
+            }
+            _ => None,
+        };
+        self.record(edit);
         self.push_history();
         mutate(&mut self.working_graph);
+        self.revalidate(edited.as_deref());
         self.redo.clear();
         self.dirty_since_draft = true;
     }
 
-    /// `[ Done ]` on a direct-effect `Prompt` (`NewSlide`/`DeckTitle`/
+    /// Brings the status banner up to date after an edit. An edit confined
+    /// to slide `edited` (see [`Op::edited_slide`]) re-checks just that
+    /// slide and the slides pointing at it, replacing their earlier
+    /// findings; anything else — `None` — re-runs the whole validator and
+    /// rebuilds the reference map. Either way the canvas scroll offsets
+    /// follow the edit: deleted slides' are forgotten, and the selected
+    /// slide's is pulled back within its new height.
+    fn revalidate(&mut self, edited: Option<&str>) {
+        let graph = &self.working_graph;
+        self.scroll.retain(|id, _| graph.node(id).is_some());
+        let (w, h) = self.terminal_size;
+        let canvas = hit::editor_areas(Rect::new(0, 0, w, h)).canvas;
+        if let Some(layout) = hit::canvas_layout(self, canvas) {
+            self.set_scroll(layout.scroll);
+        }
+        let Some((index, id)) = edited.and_then(|id| {
+            self.working_graph
+                .nodes
+                .iter()
+                .position(|n| n.id == id)
+                .map(|i| (i, id))
+        }) else {
+            self.status = validate(&self.working_graph);
+            self.refs = ReverseRefs::new(&self.working_graph);
+            return;
+        };
+        let fresh = validate_incremental(&self.working_graph, index, &self.refs);
+        let referrers = self.refs.referrers(id);
+        self.status.retain(|d| {
+            DECK_WIDE_RULES.contains(&d.rule)
+                || d.node
+                    .as_deref()
+                    .is_none_or(|n| n != id && !referrers.iter().any(|r| r == n))
+        });
+        self.status.extend(fresh);
+        self.status.sort_by_key(|d| std::cmp::Reverse(d.severity));
+    }
+
+    /// `[ Done ]` on a direct-effect `Prompt` (`NewSlide`/`DeckDetails`/
     /// `Notes`) — `ChoicePrompt`/`NewAnswer` never reach here (their
     /// `[ Choose target → ]` chip routes to [`Self::begin_picker`]
     /// instead, per `FormState::prompt_commits_directly`).
@@ -621,6 +787,7 @@ impl EditorApp {
                     return;
                 }
                 if self.apply_op(Op::AddSlide {
+                    ids: self.ids,
                     after: after.clone(),
                     title,
                 }) && let Some(idx) = self.working_graph.nodes.iter().position(|n| n.id == after)
@@ -629,25 +796,149 @@ impl EditorApp {
                     self.selection = Selection::Slide(new_node.id.clone());
                 }
             }
-            PromptKind::DeckTitle => {
-                let title = fields[0].text();
-                self.apply_direct(|g| {
-                    g.title = (!title.trim().is_empty()).then_some(title);
-                });
+            PromptKind::DeckDetails => {
+                let value = |i: usize| {
+                    let text = fields[i].text().trim().to_owned();
+                    (!text.is_empty()).then_some(text)
+                };
+                let (title, author, date, description) = (value(0), value(1), value(2), value(3));
+                let g = &self.working_graph;
+                let unchanged = (&g.title, &g.author, &g.date, &g.description)
+                    == (&title, &author, &date, &description);
+                if !unchanged {
+                    let edit = JournalEdit::DeckDetails {
+                        title: title.clone(),
+                        author: author.clone(),
+                        date: date.clone(),
+                        description: description.clone(),
+                    };
+                    self.apply_direct(edit, |g| {
+                        g.title = title;
+                        g.author = author;
+                        g.date = date;
+                        g.description = description;
+                    });
+                }
             }
             PromptKind::Notes { node } => {
                 let notes = fields[0].text();
-                self.apply_direct(|g| {
+                let notes = (!notes.trim().is_empty()).then_some(notes);
+                let edit = JournalEdit::Notes {
+                    node: node.clone(),
+                    notes: notes.clone(),
+                };
+                self.apply_direct(edit, |g| {
+                    if let Some(n) = g.nodes.iter_mut().find(|n| n.id == node) {
+                        n.speaker_notes = notes;
+                    }
+                });
+            }
+            PromptKind::Duration { node } => {
+                let text = fields[0].text();
+                let duration = match text.trim() {
+                    "" => None,
+                    text => match parse_duration(text) {
+                        Ok(duration) => Some(duration),
+                        Err(err) => {
+                            self.set_flash(err.to_string(), FlashKind::Error);
+                            return;
+                        }
+                    },
+                };
+                let edit = JournalEdit::Duration {
+                    node: node.clone(),
+                    duration,
+                };
+                self.apply_direct(edit, |g| {
                     if let Some(n) = g.nodes.iter_mut().find(|n| n.id == node) {
-                        n.speaker_notes = (!notes.trim().is_empty()).then_some(notes);
+                        n.duration = duration;
                     }
                 });
             }
+            PromptKind::SlideId { node } => {
+                let new_id = fields[0].text().trim().to_owned();
+                if new_id != node {
+                    if !self.apply_op(Op::SetSlideId {
+                        id: node,
+                        new_id: new_id.clone(),
+                    }) {
+                        return;
+                    }
+                    self.selection = Selection::Slide(new_id);
+                }
+            }
+            PromptKind::EditChoicePrompt { node } => {
+                let prompt = fields[0].text();
+                if !self.apply_op(Op::SetChoicePrompt {
+                    id: node,
+                    prompt: Some(prompt),
+                }) {
+                    return;
+                }
+            }
+            PromptKind::EditAnswer { node, index } => {
+                if !self.apply_answer_edit(&node, index, &fields) {
+                    return;
+                }
+            }
+            PromptKind::ReplaceText => {
+                let find = fields[0].text();
+                let replace = fields[1].text();
+                if find.is_empty() {
+                    self.set_flash("Type the text to find first", FlashKind::Info);
+                    return;
+                }
+                let count = self
+                    .working_graph
+                    .clone()
+                    .replace_text(&find, &replace, true);
+                if !self.apply_op(Op::ReplaceText {
+                    find,
+                    replace,
+                    case_sensitive: true,
+                }) {
+                    return;
+                }
+                let noun = if count == 1 { "match" } else { "matches" };
+                self.set_flash(
+                    format!("Replaced {count} {noun} \u{b7} u undoes it"),
+                    FlashKind::Info,
+                );
+            }
             PromptKind::ChoicePrompt { .. } | PromptKind::NewAnswer { .. } => return,
         }
         self.open_form = None;
     }
 
+    /// Applies an `EditAnswer` prompt's label, key, and description, unless
+    /// none of them changed — so `[ Change target → ]` on an untouched form doesn't
+    /// leave an empty undo step behind. Returns `false` when the op was
+    /// refused (its reason is already flashed) and the form should stay
+    /// open.
+    fn apply_answer_edit(&mut self, node: &str, index: usize, fields: &[EditableField]) -> bool {
+        let label = fields[0].text();
+        let key = fields[1].text().trim().to_owned();
+        let key = (!key.is_empty()).then_some(key);
+        let description = fields[2].text().trim().to_owned();
+        let description = (!description.is_empty()).then_some(description);
+        let unchanged = self
+            .working_graph
+            .node(node)
+            .and_then(|n| n.branch_point())
+            .and_then(|bp| bp.options.get(index))
+            .is_some_and(|opt| {
+                opt.label == label && opt.key == key && opt.description == description
+            });
+        unchanged
+            || self.apply_op(Op::EditAnswer {
+                id: node.to_owned(),
+                index,
+                label,
+                key,
+                description,
+            })
+    }
+
     /// `[ Cancel ]`/Esc while a form is open: discards it with no op
     /// applied.
     fn cancel_form(&mut self) {
@@ -721,7 +1012,9 @@ impl EditorApp {
     }
 
     /// `[ Choose target → ]` on a `ChoicePrompt`/`NewAnswer` prompt: reads
-    /// the typed fields and hands off to `FormState::SlidePicker`.
+    /// the typed fields and hands off to `FormState::SlidePicker`. On an
+    /// `EditAnswer` prompt (`[ Change target → ]`) the typed fields are
+    /// applied first, so nothing typed is lost to the picker.
     fn begin_picker(&mut self) {
         let Some(FormState::Prompt { kind, fields, .. }) = &self.open_form else {
             return;
@@ -753,7 +1046,20 @@ impl EditorApp {
                     key: (!key.trim().is_empty()).then_some(key),
                 }
             }
-            PromptKind::NewSlide { .. } | PromptKind::DeckTitle | PromptKind::Notes { .. } => {
+            PromptKind::EditAnswer { node, index } => {
+                let fields = fields.clone();
+                if !self.apply_answer_edit(&node, index, &fields) {
+                    return;
+                }
+                PickerTarget::RetargetAnswer { node, index }
+            }
+            PromptKind::NewSlide { .. }
+            | PromptKind::DeckDetails
+            | PromptKind::Notes { .. }
+            | PromptKind::Duration { .. }
+            | PromptKind::SlideId { .. }
+            | PromptKind::EditChoicePrompt { .. }
+            | PromptKind::ReplaceText => {
                 return;
             }
         };
@@ -832,14 +1138,9 @@ impl EditorApp {
             return;
         };
         let origin = target.origin().to_owned();
-        let existing: Vec<String> = self
-            .working_graph
-            .nodes
-            .iter()
-            .map(|n| n.id.clone())
-            .collect();
-        let new_id = authoring::slug("New slide", &existing);
+        let new_id = authoring::generate_node_id(&self.working_graph, self.ids, "New slide");
         if !self.apply_op(Op::AddSlide {
+            ids: self.ids,
             after: origin,
             title: "New slide".to_owned(),
         }) {
@@ -923,11 +1224,28 @@ impl EditorApp {
         });
     }
 
-    fn open_rename_deck_prompt(&mut self) {
-        let title = self.working_graph.title.clone().unwrap_or_default();
+    /// `f`: find-and-replace across every slide, as one undoable step.
+    fn open_replace_prompt(&mut self) {
+        self.open_form = Some(FormState::Prompt {
+            kind: PromptKind::ReplaceText,
+            fields: vec![
+                EditableField::single_line(Vec::new(), ""),
+                EditableField::single_line(Vec::new(), ""),
+            ],
+            focus: 0,
+        });
+    }
+
+    /// `d`, or a click on the toolbar's title: the deck's title, author,
+    /// date, and description, Tab moving between them.
+    fn open_deck_details_prompt(&mut self) {
+        let g = &self.working_graph;
+        let fields = [&g.title, &g.author, &g.date, &g.description]
+            .map(|value| EditableField::single_line(Vec::new(), value.as_deref().unwrap_or("")))
+            .to_vec();
         self.open_form = Some(FormState::Prompt {
-            kind: PromptKind::DeckTitle,
-            fields: vec![EditableField::single_line(Vec::new(), &title)],
+            kind: PromptKind::DeckDetails,
+            fields,
             focus: 0,
         });
     }
@@ -949,6 +1267,31 @@ impl EditorApp {
         });
     }
 
+    /// `[ Duration ]`: the slide's auto-advance duration as `"M:SS"`, or
+    /// an empty field when it has none.
+    fn open_duration_prompt(&mut self, node: String) {
+        let duration = self
+            .working_graph
+            .node(&node)
+            .and_then(|n| n.duration)
+            .map(format_duration)
+            .unwrap_or_default();
+        self.open_form = Some(FormState::Prompt {
+            kind: PromptKind::Duration { node },
+            fields: vec![EditableField::single_line(Vec::new(), &duration)],
+            focus: 0,
+        });
+    }
+
+    /// `[ Id ]`: the slide's id, ready to rename.
+    fn open_slide_id_prompt(&mut self, node: String) {
+        self.open_form = Some(FormState::Prompt {
+            fields: vec![EditableField::single_line(Vec::new(), &node)],
+            kind: PromptKind::SlideId { node },
+            focus: 0,
+        });
+    }
+
     fn open_choice_prompt(&mut self, node: String) {
         self.open_form = Some(FormState::Prompt {
             kind: PromptKind::ChoicePrompt { node },
@@ -971,14 +1314,58 @@ impl EditorApp {
         });
     }
 
+    /// `[ Edit prompt ]`/`e` on a branch slide: its current prompt in a
+    /// one-field form.
+    fn open_edit_choice_prompt(&mut self, node: String) {
+        let Some(bp) = self
+            .working_graph
+            .node(&node)
+            .and_then(|n| n.branch_point())
+        else {
+            return;
+        };
+        let prompt = bp.prompt.clone().unwrap_or_default();
+        self.open_form = Some(FormState::Prompt {
+            kind: PromptKind::EditChoicePrompt { node },
+            fields: vec![EditableField::single_line(Vec::new(), &prompt)],
+            focus: 0,
+        });
+    }
+
+    /// Clicking an answer in the "Branches to" strip, or `1`-`9` on a
+    /// selected branch slide: that answer's label, key, and description,
+    /// ready to edit.
+    fn open_edit_answer_prompt(&mut self, node: String, index: usize) {
+        let Some(opt) = self
+            .working_graph
+            .node(&node)
+            .and_then(|n| n.branch_point())
+            .and_then(|bp| bp.options.get(index))
+        else {
+            return;
+        };
+        let fields = vec![
+            EditableField::single_line(Vec::new(), &opt.label),
+            EditableField::single_line(Vec::new(), opt.key.as_deref().unwrap_or_default()),
+            EditableField::single_line(Vec::new(), opt.description.as_deref().unwrap_or_default()),
+        ];
+        self.open_form = Some(FormState::Prompt {
+            kind: PromptKind::EditAnswer { node, index },
+            fields,
+            focus: 0,
+        });
+    }
+
     fn on_slide_chip(&mut self, node: String, action: SlideAction) {
         match action {
             SlideAction::Duplicate => {
                 let Some(idx) = self.working_graph.nodes.iter().position(|n| n.id == node) else {
                     return;
                 };
-                if self.apply_op(Op::DuplicateSlide { id: node })
-                    && let Some(dup) = self.working_graph.nodes.get(idx + 1)
+                if self.apply_op(Op::DuplicateSlide {
+                    ids: self.ids,
+                    id: node,
+                }) && let Some(dup) = self.working_graph.nodes.get(idx + 1)
                 {
                     self.selection = Selection::Slide(dup.id.clone());
                 }
@@ -1019,7 +1406,22 @@ impl EditorApp {
                     );
                 }
             }
+            SlideAction::EditPrompt => self.open_edit_choice_prompt(node),
+            SlideAction::AssignKeys => {
+                if self.apply_op(Op::AssignAnswerKeys {
+                    id: node.clone(),
+                    preserve: true,
+                }) {
+                    self.selection = Selection::Slide(node);
+                    self.set_flash(
+                        "Keys assigned \u{2014} press \u{21b6} Undo to put the old ones back",
+                        FlashKind::Info,
+                    );
+                }
+            }
             SlideAction::Notes => self.open_notes_prompt(node),
+            SlideAction::Duration => self.open_duration_prompt(node),
+            SlideAction::Id => self.open_slide_id_prompt(node),
         }
     }
 
@@ -1027,16 +1429,16 @@ impl EditorApp {
     /// the flash carries a `[ take me there ]`-style link, resolved by
     /// finding the branch point whose answer targets `bad_id`, if any.
     fn attempt_reorder(&mut self, id: String, before: Option<String>) {
-        match authoring::apply(
-            &self.working_graph,
-            &Op::ReorderSlide {
-                id: id.clone(),
-                before,
-            },
-        ) {
+        let op = Op::ReorderSlide {
+            id: id.clone(),
+            before,
+        };
+        match authoring::apply(&self.working_graph, &op) {
             Ok(next) => {
+                self.record(JournalEdit::Op(op));
                 self.push_history();
                 self.working_graph = next;
+                self.revalidate(None);
                 self.redo.clear();
                 self.selection = Selection::Slide(id);
             }
@@ -1098,6 +1500,8 @@ impl EditorApp {
         let alt_text = alt.text();
         let content = ContentBlock::AsciiArt {
             reveal: None,
+            id: None,
+            visible_if: None,
             art: String::new(),
             alt: (!alt_text.trim().is_empty()).then_some(alt_text),
         };
@@ -1110,7 +1514,7 @@ impl EditorApp {
     }
 
     /// The container form's `[ Layout ▾ ]` chip (T033): cycles
-    /// Stack → Columns → Center → Stack, applied immediately (not staged
+    /// Stack → Columns → Center → Grid → Stack, applied immediately (not staged
     /// behind `[ Done ]`) since it is a single enum toggle, not free text —
     /// consistent with every other single-click structural change in this
     /// app.
@@ -1126,20 +1530,25 @@ impl EditorApp {
         let next = match layout {
             ContainerLayout::Stack => ContainerLayout::Columns,
             ContainerLayout::Columns => ContainerLayout::Center,
-            ContainerLayout::Center => ContainerLayout::Stack,
+            ContainerLayout::Center => ContainerLayout::Grid,
+            ContainerLayout::Grid => ContainerLayout::Stack,
         };
         let Some(node_ref) = self.working_graph.node(&node) else {
             return;
         };
-        let Some(ContentBlock::Container { children, .. }) =
-            forms::block_at(&node_ref.content, &path)
+        let Some(ContentBlock::Container {
+            children, columns, ..
+        }) = forms::block_at(&node_ref.content, &path)
         else {
             return;
         };
         let content = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             children: children.clone(),
             layout: Some(next),
+            columns: *columns,
         };
         self.apply_op(Op::EditBlock {
             node: node.clone(),
@@ -1181,7 +1590,8 @@ impl EditorApp {
         match self.open_form.as_mut()? {
             FormState::Heading { field, .. }
             | FormState::Text { field, .. }
-            | FormState::List { field, .. } => Some(field),
+            | FormState::List { field, .. }
+            | FormState::Video { src: field, .. } => Some(field),
             FormState::Code {
                 language,
                 source,
@@ -1218,7 +1628,7 @@ impl EditorApp {
                 focus: CodeFocus::Language,
                 ..
             })
-            | Some(FormState::Picture { .. })
+            | Some(FormState::Picture { .. } | FormState::Video { .. })
             | Some(FormState::TextArt {
                 focus: TextArtFocus::Alt,
                 ..
@@ -1284,8 +1694,11 @@ impl EditorApp {
     fn apply_op(&mut self, op: Op) -> bool {
         match authoring::apply(&self.working_graph, &op) {
             Ok(next) => {
+                let edited = op.edited_slide().map(str::to_owned);
+                self.record(JournalEdit::Op(op));
                 self.push_history();
                 self.working_graph = next;
+                self.revalidate(edited.as_deref());
                 self.redo.clear();
                 self.dirty_since_draft = true;
                 true
@@ -1332,6 +1745,25 @@ impl EditorApp {
     /// flashes a reversible, word-labeled notice rather than a blocking
     /// dialog (spec US2 acceptance scenario 2, FR-008/FR-017) — the
     /// toolbar's `[ ↶ Undo ]` chip/`u` key is the actual undo path.
+    /// Splits `node` before the top-level block at `path`
+    /// (`Op::SplitSlide`), selecting the new second half; one undo puts
+    /// the slide back together.
+    fn split_slide_at(&mut self, node: String, path: &BlockPath) {
+        let [at] = path[..] else { return };
+        let Some(idx) = self.working_graph.nodes.iter().position(|n| n.id == node) else {
+            return;
+        };
+        if self.apply_op(Op::SplitSlide {
+            id: node,
+            at,
+            new_id: None,
+            ids: self.ids,
+        }) && let Some(second) = self.working_graph.nodes.get(idx + 1)
+        {
+            self.selection = Selection::Slide(second.id.clone());
+        }
+    }
+
     fn delete_block(&mut self, node: String, path: BlockPath) {
         let deleted_index = path.last().copied().unwrap_or(0);
         let deleted_parent = path[..path.len().saturating_sub(1)].to_vec();
@@ -1368,7 +1800,7 @@ impl EditorApp {
         }
     }
 
-    /// `[ ↶ Undo ]`/`u`/`U`: restores the most recent pre-op snapshot,
+    /// `[ ↶ Undo ]`/`u`: restores the most recent pre-op snapshot,
     /// including the selection at that point, and closes any open form
     /// (its staged content no longer corresponds to anything on screen).
     fn undo(&mut self) {
@@ -1376,13 +1808,31 @@ impl EditorApp {
             self.set_flash("Nothing to undo", FlashKind::Info);
             return;
         };
+        self.record(JournalEdit::Undo);
         self.redo.push(HistorySnapshot {
             graph: self.working_graph.clone(),
             selection: self.selection.clone(),
         });
         self.working_graph = snapshot.graph;
+        self.revalidate(None);
+        self.selection = snapshot.selection;
+        self.open_form = None;
+    }
+
+    /// `U`: re-applies the most recently undone change, until any new
+    /// change clears the redo stack.
+    fn redo(&mut self) {
+        let Some(snapshot) = self.redo.pop() else {
+            self.set_flash("Nothing to redo", FlashKind::Info);
+            return;
+        };
+        self.record(JournalEdit::Redo);
+        self.push_history();
+        self.working_graph = snapshot.graph;
+        self.revalidate(None);
         self.selection = snapshot.selection;
         self.open_form = None;
+        self.dirty_since_draft = true;
     }
 
     /// `[ Save ]`/Ctrl+S: commits an open form first (so "save" always
@@ -1432,6 +1882,7 @@ impl EditorApp {
             }
             Msg::Terminal(Event::Key(key)) => self.on_key(key),
             Msg::Terminal(Event::Mouse(mouse)) => self.on_mouse(mouse),
+            Msg::Terminal(Event::Paste(text)) => self.on_paste(&text),
             Msg::Terminal(_) => {}
             Msg::SaveResult(result) => self.on_save_result(result),
             Msg::ArtGenerated(result) => self.on_art_generated(result),
@@ -1512,7 +1963,7 @@ impl EditorApp {
         };
         if use_draft {
             self.working_graph = choice.draft;
-            self.status = validate(&self.working_graph);
+            self.revalidate(None);
         }
     }
 
@@ -1542,7 +1993,9 @@ impl EditorApp {
                 self.request_save();
             }
             KeyCode::Esc => {
-                if self.drag != DragState::Idle {
+                if self.copy_source.take().is_some() {
+                    self.set_flash("Stopped copying", FlashKind::Info);
+                } else if self.drag != DragState::Idle {
                     // A drag cancels without applying anything — nothing
                     // was ever written to `working_graph` until release,
                     // so "cancel" is just discarding the in-progress
@@ -1554,7 +2007,8 @@ impl EditorApp {
             }
             KeyCode::Char('?') => self.showing_help = true,
             KeyCode::Char('p' | 'P') => self.present_requested = true,
-            KeyCode::Char('u' | 'U') => self.undo(),
+            KeyCode::Char('u') => self.undo(),
+            KeyCode::Char('U') => self.redo(),
             KeyCode::Enter => self.open_form_for_selection(),
             KeyCode::Tab => self.select_adjacent_block(false),
             KeyCode::BackTab => self.select_adjacent_block(true),
@@ -1562,11 +2016,17 @@ impl EditorApp {
             KeyCode::Char('[') => self.select_adjacent_slide(true),
             KeyCode::Char('n') => self.open_new_slide_prompt(),
             KeyCode::Char('r') => self.on_reveal_key(),
+            KeyCode::Char('l') => self.on_list_key(),
             KeyCode::Char('c') => self.on_choice_key(),
+            KeyCode::Char('C') => self.on_copy_blocks_key(),
             KeyCode::Char('a') => self.on_add_answer_key(),
             KeyCode::Char('g') => self.on_goes_to_key(),
-            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
-            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Char('e') => self.on_edit_prompt_key(),
+            KeyCode::Char('f') => self.open_replace_prompt(),
+            KeyCode::Char('d') => self.open_deck_details_prompt(),
+            KeyCode::Char(c @ '1'..='9') => self.on_answer_digit(c),
+            KeyCode::Up => self.scroll_canvas(false),
+            KeyCode::Down if self.canvas_overflows() => self.scroll_canvas(true),
             _ => {}
         }
     }
@@ -1580,6 +2040,39 @@ impl EditorApp {
         }
     }
 
+    /// `l`: turns the selected text block into a list, a line per item, or
+    /// the selected list back into text — one undoable edit either way, and
+    /// a no-op for any other block.
+    fn on_list_key(&mut self) {
+        let Selection::Block(node, path) = self.selection.clone() else {
+            return;
+        };
+        let Some(block) = self
+            .working_graph
+            .node(&node)
+            .and_then(|n| forms::block_at(&n.content, &path))
+        else {
+            return;
+        };
+        let content = match block {
+            ContentBlock::Text { body, .. } if body.trim().is_empty() => {
+                self.set_flash(
+                    "This text is empty \u{2014} nothing to list",
+                    FlashKind::Info,
+                );
+                return;
+            }
+            ContentBlock::Text { body, .. } => authoring::text_to_list(body),
+            ContentBlock::List { items, .. } => authoring::list_to_text(items),
+            _ => return,
+        };
+        self.apply_op(Op::EditBlock {
+            node,
+            path,
+            content,
+        });
+    }
+
     /// `c`: the selected slide's keyboard equivalent of
     /// `[ Turn into a choice ]`/`[ Turn back into a normal slide ]` — a
     /// no-op unless a slide (not a block) is selected.
@@ -1597,6 +2090,64 @@ impl EditorApp {
         }
     }
 
+    /// `C`: two steps. On the first slide it marks it as the one to copy
+    /// from; on a second it appends copies of all the first's blocks to
+    /// it, one undoable edit. A slide can't be copied onto itself.
+    fn on_copy_blocks_key(&mut self) {
+        let (Selection::Slide(id) | Selection::Block(id, _)) = self.selection.clone() else {
+            return;
+        };
+        let Some(source) = self.copy_source.clone() else {
+            let blocks = self.working_graph.node(&id).map_or(0, |n| n.content.len());
+            if blocks == 0 {
+                self.set_flash("This slide has no blocks to copy", FlashKind::Info);
+            } else {
+                self.set_flash(
+                    format!(
+                        "Copying {blocks} block{} from \"{id}\" \u{2014} select a slide and press C again, or Esc",
+                        if blocks == 1 { "" } else { "s" }
+                    ),
+                    FlashKind::Info,
+                );
+                self.copy_source = Some(id);
+            }
+            return;
+        };
+        if source == id {
+            self.set_flash(
+                "That's the slide being copied \u{2014} select another, or Esc",
+                FlashKind::Info,
+            );
+            return;
+        }
+        self.copy_source = None;
+        let (Some(from), Some(to)) = (
+            self.working_graph.node(&source),
+            self.working_graph.node(&id),
+        ) else {
+            self.set_flash(
+                format!("\"{source}\" is gone \u{2014} nothing to copy"),
+                FlashKind::Error,
+            );
+            return;
+        };
+        let copied = from.content.len();
+        let mut content = to.content.clone();
+        content.extend(from.content.iter().cloned());
+        if self.apply_op(Op::ReplaceContent {
+            node: id.clone(),
+            content,
+        }) {
+            self.set_flash(
+                format!(
+                    "Copied {copied} block{} from \"{source}\" to \"{id}\" \u{2014} u undoes",
+                    if copied == 1 { "" } else { "s" }
+                ),
+                FlashKind::Info,
+            );
+        }
+    }
+
     /// `a`: the selected branch-point slide's keyboard equivalent of
     /// `[ + Add answer ]` — a no-op unless the selected slide is already a
     /// branch point.
@@ -1612,6 +2163,25 @@ impl EditorApp {
         }
     }
 
+    /// `e`: the selected branch slide's keyboard equivalent of
+    /// `[ Edit prompt ]`.
+    fn on_edit_prompt_key(&mut self) {
+        if let Selection::Slide(id) = self.selection.clone() {
+            self.open_edit_choice_prompt(id);
+        }
+    }
+
+    /// `1`-`9`: on a selected branch slide, edit that answer — the
+    /// keyboard equivalent of clicking it in the "Branches to" strip.
+    fn on_answer_digit(&mut self, digit: char) {
+        let Selection::Slide(id) = self.selection.clone() else {
+            return;
+        };
+        if let Some(n) = digit.to_digit(10) {
+            self.open_edit_answer_prompt(id, n as usize - 1);
+        }
+    }
+
     /// `g`: the selected non-branch slide's keyboard equivalent of the
     /// "Goes to" strip's `[ change ]` chip.
     fn on_goes_to_key(&mut self) {
@@ -1680,6 +2250,30 @@ impl EditorApp {
         }
     }
 
+    /// A bracketed paste: the whole chunk goes into the open form's
+    /// focused field. Line breaks become rows where the field takes
+    /// several lines (code, text, notes); in a one-line field they become
+    /// spaces, trailing ones dropped. Without an open form it is ignored,
+    /// rather than replayed as commands.
+    fn on_paste(&mut self, text: &str) {
+        if self.draft_choice.is_some() || self.quit_prompt || self.showing_help {
+            return;
+        }
+        let single_line = self.focused_field_is_single_line();
+        let Some(field) = self.focused_field_mut() else {
+            return;
+        };
+        if single_line {
+            // Many terminals paste line breaks as a lone `\r`, which
+            // `str::lines` wouldn't split on.
+            let text = text.replace("\r\n", "\n").replace('\r', "\n");
+            let flat: Vec<&str> = text.trim_end_matches('\n').split('\n').collect();
+            field.insert_str(&flat.join(" "));
+        } else {
+            field.insert_str(text);
+        }
+    }
+
     fn on_mouse(&mut self, event: MouseEvent) {
         if self.draft_choice.is_some() {
             if let MouseEventKind::Down(MouseButton::Left) = event.kind {
@@ -1718,18 +2312,32 @@ impl EditorApp {
     fn scroll_at(&mut self, col: u16, row: u16, down: bool) {
         let (w, h) = self.terminal_size;
         let areas = hit::editor_areas(Rect::new(0, 0, w, h));
-        let target = if hit::rect_contains(areas.outline, col, row) {
-            &mut self.outline_scroll
-        } else {
-            &mut self.scroll
-        };
-        if down {
-            *target = target.saturating_add(1);
+        if !hit::rect_contains(areas.outline, col, row) {
+            if !down || self.canvas_overflows() {
+                self.scroll_canvas(down);
+            }
+        } else if down {
+            self.outline_scroll = self.outline_scroll.saturating_add(1);
         } else {
-            *target = target.saturating_sub(1);
+            self.outline_scroll = self.outline_scroll.saturating_sub(1);
         }
     }
 
+    /// Whether the selected slide looks taller than the canvas, going by
+    /// [`fireside_core::Node::estimated_render_lines`]. Only then does the
+    /// canvas scroll down, so a slide that fits never drifts off its first
+    /// line; the exact clamp still happens in [`hit::canvas_layout`].
+    fn canvas_overflows(&self) -> bool {
+        let Some(node) = hit::selected_node(self) else {
+            return false;
+        };
+        let (w, h) = self.terminal_size;
+        let canvas = hit::editor_areas(Rect::new(0, 0, w, h)).canvas;
+        let view_mode = node.resolved_view_mode(self.working_graph().defaults.as_ref());
+        let surf = render::surface(view_mode, canvas, None);
+        node.estimated_render_lines(surf.width) > usize::from(surf.height)
+    }
+
     /// A pointer move while the left button is held (crossterm reports
     /// this as `Drag`, distinct from button-less `Moved`): re-resolves
     /// the drop candidate for the block being lifted, auto-scrolling the
@@ -1748,16 +2356,20 @@ impl EditorApp {
                 let (w, h) = self.terminal_size;
                 let areas = hit::editor_areas(Rect::new(0, 0, w, h));
                 if row <= areas.canvas.y {
-                    self.scroll = self.scroll.saturating_sub(1);
+                    self.scroll_canvas(false);
                 } else if row.saturating_add(1) >= areas.canvas.bottom() {
-                    self.scroll = self.scroll.saturating_add(1);
+                    self.scroll_canvas(true);
                 }
+                // Off the block list there is no slot: the drag goes back
+                // to `Lifting`, so the drop indicator disappears and a
+                // release there drops nothing rather than the last slot
+                // the pointer passed over.
                 let parent = &path[..path.len().saturating_sub(1)];
-                if let Some(to) =
-                    hit::resolve_drop_slot(self, &node, parent, areas.canvas, col, row)
-                {
-                    self.drag = DragState::Over { node, path, to };
-                }
+                self.drag =
+                    match hit::resolve_drop_slot(self, &node, parent, areas.canvas, col, row) {
+                        Some(to) => DragState::Over { node, path, to },
+                        None => DragState::Lifting { node, path },
+                    };
             }
             DragState::OutlineLifting { .. } | DragState::OutlineOver { .. } => {
                 let id = match &self.drag {
@@ -1821,7 +2433,6 @@ impl EditorApp {
         match hit::hit(self, Rect::new(0, 0, w, h), col, row) {
             Some(hit::Target::OutlineRow(id)) => {
                 self.selection = Selection::Slide(id.clone());
-                self.scroll = 0;
                 self.drag = DragState::OutlineLifting { id };
             }
             Some(hit::Target::Block(node_id, path)) => {
@@ -1837,6 +2448,12 @@ impl EditorApp {
             Some(hit::Target::BlockChip(node, path, hit::BlockAction::Edit)) => {
                 self.open_form_at(&node, &path);
             }
+            Some(hit::Target::BlockChip(node, path, hit::BlockAction::DescribeImage)) => {
+                self.open_form_at(&node, &path);
+                if let Some(FormState::Picture { focus, .. }) = &mut self.open_form {
+                    *focus = PictureFocus::Alt;
+                }
+            }
             Some(hit::Target::BlockChip(node, path, hit::BlockAction::AddBelow)) => {
                 let mut parent = path;
                 let at = parent.pop().map_or(0, |i| i + 1);
@@ -1848,6 +2465,9 @@ impl EditorApp {
             Some(hit::Target::BlockChip(node, path, hit::BlockAction::Reveal)) => {
                 self.cycle_reveal_step(node, path);
             }
+            Some(hit::Target::BlockChip(node, path, hit::BlockAction::SplitHere)) => {
+                self.split_slide_at(node, &path);
+            }
             Some(hit::Target::ToolbarChip(hit::ToolbarAction::Present)) => {
                 self.present_requested = true;
             }
@@ -1864,7 +2484,7 @@ impl EditorApp {
             | Some(hit::Target::OutlineNewSlide) => {
                 self.open_new_slide_prompt();
             }
-            Some(hit::Target::ToolbarTitle) => self.open_rename_deck_prompt(),
+            Some(hit::Target::ToolbarTitle) => self.open_deck_details_prompt(),
             Some(hit::Target::GoesToChip(node)) => {
                 let rows = self.picker_rows();
                 self.open_form = Some(FormState::SlidePicker {
@@ -1874,11 +2494,7 @@ impl EditorApp {
             }
             Some(hit::Target::SlideChip(node, action)) => self.on_slide_chip(node, action),
             Some(hit::Target::AnswerChip(node, index)) => {
-                let rows = self.picker_rows();
-                self.open_form = Some(FormState::SlidePicker {
-                    target: PickerTarget::RetargetAnswer { node, index },
-                    rows,
-                });
+                self.open_edit_answer_prompt(node, index);
             }
             Some(hit::Target::FlashAction(hit::FlashAction::SelectSlide(id))) => {
                 self.selection = Selection::Slide(id);
@@ -1935,19 +2551,24 @@ pub type DraftSink<'a> = &'a mut dyn FnMut(&Graph);
 /// flash message (2026-07-23 follow-up audit, P1-1) — the caller's
 /// create-if-missing `println!` runs before the alternate screen takes
 /// over and is invisible in a real terminal session; this is the message
-/// an author actually sees once inside the tool.
+/// an author actually sees once inside the tool. `ids` picks how new and
+/// duplicated slides are named. `copy` takes a code block copied while
+/// `[ ▶ Present ]` is running, as [`crate::CopySink`] describes.
 ///
 /// # Errors
 ///
 /// Returns [`TuiError::NotATty`] outside an interactive terminal and
 /// [`TuiError::Io`] for terminal failures.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     graph: Graph,
     draft: Option<DraftPrompt>,
     created_notice: Option<String>,
+    ids: IdStrategy,
     sink: EditorWriteBackSink<'_>,
     draft_sink: DraftSink<'_>,
     art_generator: Option<ArtGenerator<'_>>,
+    copy: crate::CopySink<'_>,
 ) -> Result<(), TuiError> {
     if !io::stdout().is_tty() || !io::stdin().is_tty() {
         return Err(TuiError::NotATty);
@@ -1955,17 +2576,27 @@ pub fn run(
     let mut app = match draft {
         Some(prompt) => EditorApp::new_with_draft(graph, prompt),
         None => EditorApp::new(graph),
-    };
+    }
+    .with_id_strategy(ids);
     if let Some(notice) = created_notice {
         app.set_flash(notice, FlashKind::Info);
     }
     let mut terminal = ratatui::try_init()?;
     // Mouse capture is enabled once for the whole editor session — both
     // the studio's own loop and the in-process presenter loop `present_now`
-    // enters share it, per research.md §6.
-    let _ = execute!(io::stdout(), EnableMouseCapture);
-    let result = editor_event_loop(&mut terminal, &mut app, sink, draft_sink, art_generator);
-    let _ = execute!(io::stdout(), DisableMouseCapture);
+    // enters share it, per research.md §6. Bracketed paste is on for the
+    // same window, so a pasted chunk arrives whole rather than as keys
+    // whose first Enter would end the field.
+    let _ = execute!(io::stdout(), EnableMouseCapture, EnableBracketedPaste);
+    let result = editor_event_loop(
+        &mut terminal,
+        &mut app,
+        sink,
+        draft_sink,
+        art_generator,
+        copy,
+    );
+    let _ = execute!(io::stdout(), DisableBracketedPaste, DisableMouseCapture);
     ratatui::restore();
     result
 }
@@ -1976,6 +2607,7 @@ fn editor_event_loop(
     sink: EditorWriteBackSink<'_>,
     draft_sink: DraftSink<'_>,
     mut art_generator: Option<ArtGenerator<'_>>,
+    copy: crate::CopySink<'_>,
 ) -> Result<(), TuiError> {
     if let Ok(size) = terminal.size() {
         app.set_terminal_size(size.width, size.height);
@@ -2000,7 +2632,7 @@ fn editor_event_loop(
             app.update(Msg::Terminal(event::read()?));
         }
         if let Some(start) = app.take_present_request() {
-            present_now(terminal, app.working_graph(), Some(&start))?;
+            present_now(terminal, app.working_graph(), Some(&start), copy)?;
             if let Ok(size) = terminal.size() {
                 app.set_terminal_size(size.width, size.height);
             }
@@ -2014,11 +2646,13 @@ fn editor_event_loop(
 /// second `try_init` (research.md §6). The embedded run never touches
 /// resume state or the live session-state file: a no-op reload source, an
 /// `Unavailable`-reporting write-back sink, and no-op position/tick sinks.
+/// Copying still works, through the editor's own `copy` sink.
 /// Control falls back to the editor loop on quit, which repaints.
 fn present_now(
     terminal: &mut ratatui::DefaultTerminal,
     working_graph: &Graph,
     start_node: Option<&str>,
+    copy: crate::CopySink<'_>,
 ) -> Result<(), TuiError> {
     let mut session = fireside_engine::Session::new(working_graph.clone())?;
     if let Some(id) = start_node {
@@ -2030,17 +2664,18 @@ fn present_now(
         &mut presenter,
         &mut || None,
         &mut |_| Err(WriteBackError::Unavailable),
+        copy,
         &mut |_| {},
         &mut |_| {},
+        None,
     )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing;
     use fireside_core::ContentBlock;
-    use ratatui::Terminal;
-    use ratatui::backend::TestBackend;
 
     const FIXTURE: &str = r#"{"nodes":[
         {"id":"a","title":"Welcome","traversal":"b","content":[
@@ -2116,15 +2751,11 @@ mod tests {
     }
 
     fn linear3_app() -> EditorApp {
-        let mut app = EditorApp::new(Graph::from_json(LINEAR3).expect("fixture parses"));
-        app.set_terminal_size(100, 30);
-        app
+        testing::editor_from_json(LINEAR3, 100, 30)
     }
 
     fn branch_app() -> EditorApp {
-        let mut app = EditorApp::new(Graph::from_json(BRANCH).expect("fixture parses"));
-        app.set_terminal_size(100, 30);
-        app
+        testing::editor_from_json(BRANCH, 100, 30)
     }
 
     /// A linear deck of `n` slides at a `height` short enough that the
@@ -2149,6 +2780,16 @@ mod tests {
         app
     }
 
+    /// One slide with forty short text blocks — far taller than any
+    /// canvas the tests draw, so the canvas has somewhere to scroll.
+    fn tall_slide_app() -> EditorApp {
+        let blocks = vec![r#"{"kind":"text","body":"line"}"#; 40].join(",");
+        let json = format!(r#"{{"nodes":[{{"id":"tall","content":[{blocks}]}}]}}"#);
+        let mut app = EditorApp::new(Graph::from_json(&json).expect("fixture parses"));
+        app.set_terminal_size(100, 30);
+        app
+    }
+
     /// A single slide whose `traversal` points nowhere — the fixture for
     /// T067's status-banner jump-to-diagnostic test.
     const DANGLING_TARGET: &str = r#"{"nodes":[
@@ -2212,23 +2853,29 @@ mod tests {
     }
 
     fn draw(app: &EditorApp, width: u16, height: u16) -> String {
-        let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("backend");
-        terminal
-            .draw(|frame| render::draw_editor(frame, app))
-            .expect("draw");
-        terminal
-            .backend()
-            .buffer()
-            .content
-            .iter()
-            .map(ratatui::buffer::Cell::symbol)
-            .collect::<String>()
+        testing::render_editor_to_string(app, width, height)
     }
 
     fn select_block(app: &mut EditorApp, node: &str, index: usize) {
         app.selection = Selection::Block(node.to_owned(), vec![index]);
     }
 
+    #[test]
+    fn outline_marks_hidden_slides_but_still_lists_them() {
+        let app = EditorApp::new(
+            Graph::from_json(
+                r#"{"nodes":[
+                    {"id":"a","title":"Welcome","traversal":"b","content":[]},
+                    {"id":"b","title":"Backup","hidden":true,"content":[]}
+                ]}"#,
+            )
+            .expect("fixture parses"),
+        );
+        let screen = draw(&app, 100, 30);
+        assert!(screen.contains("Backup hidden"), "marker follows the title");
+        assert!(!screen.contains("Welcome hidden"));
+    }
+
     #[test]
     fn opens_read_only_showing_the_entry_slide() {
         let app = app();
@@ -2276,7 +2923,7 @@ mod tests {
 
     #[test]
     fn wheel_scrolls_the_canvas() {
-        let mut app = app();
+        let mut app = tall_slide_app();
         assert_eq!(app.scroll(), 0);
         app.update(Msg::Terminal(Event::Mouse(MouseEvent {
             kind: MouseEventKind::ScrollDown,
@@ -2289,13 +2936,75 @@ mod tests {
 
     #[test]
     fn arrow_keys_scroll_too() {
-        let mut app = app();
+        let mut app = tall_slide_app();
         press(&mut app, KeyCode::Down);
         assert_eq!(app.scroll(), 1);
         press(&mut app, KeyCode::Up);
         assert_eq!(app.scroll(), 0);
     }
 
+    #[test]
+    fn each_slide_keeps_its_own_scroll_offset() {
+        let blocks = vec![r#"{"kind":"text","body":"line"}"#; 40].join(",");
+        let json = format!(
+            r#"{{"nodes":[{{"id":"a","traversal":"b","content":[{blocks}]}},
+                {{"id":"b","content":[{blocks}]}}]}}"#
+        );
+        let mut app = testing::editor_from_json(&json, 100, 30);
+        app.select_adjacent_slide(false);
+        app.select_adjacent_slide(false);
+        assert_eq!(app.selection(), &Selection::Slide("b".into()));
+        for _ in 0..3 {
+            press(&mut app, KeyCode::Down);
+        }
+        assert_eq!(app.scroll(), 3);
+
+        app.select_adjacent_slide(true);
+        assert_eq!(app.scroll(), 0, "a slide never scrolled starts at its top");
+        app.select_adjacent_slide(false);
+        assert_eq!(app.scroll(), 3, "coming back finds b where it was left");
+
+        app.on_slide_chip("b".into(), SlideAction::Delete);
+        assert!(app.working_graph().node("b").is_none());
+        assert!(
+            app.scroll.is_empty(),
+            "a deleted slide's offset is forgotten"
+        );
+    }
+
+    #[test]
+    fn an_edit_that_shortens_a_slide_pulls_its_scroll_back() {
+        let mut app = tall_slide_app();
+        for _ in 0..20 {
+            press(&mut app, KeyCode::Down);
+        }
+        assert_eq!(app.scroll(), 20);
+        for _ in 0..35 {
+            assert!(app.apply_op(Op::DeleteBlock {
+                node: "tall".into(),
+                path: vec![0],
+            }));
+        }
+        let (w, h) = app.terminal_size;
+        let canvas = hit::editor_areas(Rect::new(0, 0, w, h)).canvas;
+        let max = hit::canvas_layout(&app, canvas).expect("a slide").scroll;
+        assert!(max < 20);
+        assert_eq!(app.scroll(), max);
+    }
+
+    #[test]
+    fn a_slide_that_fits_the_canvas_does_not_scroll() {
+        let mut app = app();
+        press(&mut app, KeyCode::Down);
+        app.update(Msg::Terminal(Event::Mouse(MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 0,
+            row: 0,
+            modifiers: crossterm::event::KeyModifiers::empty(),
+        })));
+        assert_eq!(app.scroll(), 0);
+    }
+
     /// Spec 013 E4, T068: wheel scroll over the outline advances the
     /// outline's own offset, not the canvas's — the two panes scroll
     /// independently.
@@ -2339,6 +3048,33 @@ mod tests {
         assert_eq!(app.selection(), &Selection::Slide("a".to_owned()));
     }
 
+    #[test]
+    fn the_status_banner_follows_every_edit() {
+        let mut app = dangling_target_app();
+        let rules = |app: &EditorApp| app.status().iter().map(|d| d.rule).collect::<Vec<_>>();
+        assert_eq!(rules(&app), ["valid-traversal-target"]);
+
+        assert!(app.apply_op(Op::AddBlock {
+            node: "a".into(),
+            path: Vec::new(),
+            kind: authoring::BlockKind::Image,
+            at: 1,
+        }));
+        assert_eq!(
+            rules(&app),
+            ["valid-traversal-target", "missing-alt-text"],
+            "the edited slide is re-checked, keeping its other problem"
+        );
+        assert!(draw(&app, 100, 30).contains("won't present yet: 1 problem"));
+
+        assert!(app.apply_op(Op::ClearNext { id: "a".into() }));
+        assert_eq!(rules(&app), ["missing-alt-text"]);
+        assert!(draw(&app, 100, 30).contains("ready to present"));
+
+        press(&mut app, KeyCode::Char('u'));
+        assert_eq!(rules(&app), ["valid-traversal-target", "missing-alt-text"]);
+    }
+
     /// Spec 013 E4, T066: a fresh session starts with the first-run hint
     /// tour un-dismissed and showing its (steady, click-to-select)
     /// message at rest.
@@ -2398,6 +3134,7 @@ mod tests {
         assert!(app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         }));
         assert!(app.dirty());
 
@@ -2412,6 +3149,7 @@ mod tests {
         app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         });
         press(&mut app, KeyCode::Char('q'));
         press(&mut app, KeyCode::Char('k'));
@@ -2426,6 +3164,7 @@ mod tests {
         app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         });
         press(&mut app, KeyCode::Char('q'));
         press(&mut app, KeyCode::Char('d'));
@@ -2439,6 +3178,7 @@ mod tests {
         app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         });
         press(&mut app, KeyCode::Char('q'));
         press(&mut app, KeyCode::Char('s'));
@@ -2452,12 +3192,40 @@ mod tests {
         assert!(!app.dirty());
     }
 
+    #[test]
+    fn change_summary_counts_slides_since_the_last_save() {
+        let mut app = linear3_app();
+        assert!(app.change_summary().is_empty());
+        app.apply_op(Op::AddSlide {
+            after: "c".to_owned(),
+            title: "Four".to_owned(),
+            ids: IdStrategy::Slug,
+        });
+        app.apply_op(Op::RetitleSlide {
+            id: "a".to_owned(),
+            title: "Edited".to_owned(),
+            ids: IdStrategy::Sequential,
+        });
+        // "c" now leads to the new slide, so it changed too.
+        assert_eq!(
+            app.change_summary().to_string(),
+            "2 slides changed, 1 added"
+        );
+
+        app.update(Msg::SaveResult(Ok(())));
+        assert!(
+            app.change_summary().is_empty(),
+            "saving is the new baseline"
+        );
+    }
+
     #[test]
     fn quit_prompt_save_failure_keeps_editing_rather_than_quitting() {
         let mut app = app();
         app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         });
         press(&mut app, KeyCode::Char('q'));
         press(&mut app, KeyCode::Char('s'));
@@ -2475,6 +3243,7 @@ mod tests {
         app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         });
         press(&mut app, KeyCode::Char('q'));
         let area = Rect::new(0, 0, 100, 30);
@@ -2592,6 +3361,7 @@ mod tests {
         app.apply_op(Op::RetitleSlide {
             id: "a".to_owned(),
             title: "Edited".to_owned(),
+            ids: IdStrategy::Slug,
         });
         let pending = app.take_pending_draft();
         assert_eq!(pending.as_ref(), Some(app.working_graph()));
@@ -2697,6 +3467,8 @@ mod tests {
             app.working_graph().node("a").unwrap().content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "!World".to_owned(),
             }
         );
@@ -2728,6 +3500,8 @@ mod tests {
             node.content[0],
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 1,
                 text: "Hello there".to_owned(),
             }
@@ -2740,6 +3514,8 @@ mod tests {
             node.content[0],
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 1,
                 text: "Hello".to_owned(),
             },
@@ -2747,6 +3523,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn the_journal_records_each_edit_in_order_and_outlives_the_undo_cap() {
+        let mut app = app();
+        let edit = |i: usize| Op::EditBlock {
+            node: "a".to_owned(),
+            path: vec![1],
+            content: ContentBlock::Text {
+                reveal: None,
+                id: None,
+                visible_if: None,
+                body: format!("Body {i}"),
+            },
+        };
+        for i in 0..3 {
+            assert!(app.apply_op(edit(i)));
+        }
+        app.undo();
+        let journal = &app.journal;
+        assert_eq!(journal.len(), 4);
+        for (i, entry) in journal[..3].iter().enumerate() {
+            assert_eq!(entry.edit, JournalEdit::Op(edit(i)));
+        }
+        assert_eq!(journal[3].edit, JournalEdit::Undo);
+        assert!(journal.windows(2).all(|pair| pair[0].at <= pair[1].at));
+
+        for i in 3..150 {
+            assert!(app.apply_op(edit(i)));
+        }
+        assert_eq!(app.history_len(), 100);
+        assert_eq!(app.journal.len(), 151);
+    }
+
     /// Spec 013 US4, FR-016/acceptance scenario 3: at least the 100 most
     /// recent actions must each undo in order, restoring the exact prior
     /// state. 100 ops exactly fits the cap (`push_history`'s `> 100`
@@ -2761,6 +3569,8 @@ mod tests {
                 path: vec![1],
                 content: ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: format!("Body {i}"),
                 },
             }));
@@ -2797,6 +3607,8 @@ mod tests {
                 path: vec![1],
                 content: ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "Body 0".to_owned(),
                 },
             }));
@@ -2808,6 +3620,8 @@ mod tests {
                 path: vec![1],
                 content: ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: format!("Body {i}"),
                 },
             }));
@@ -2861,6 +3675,8 @@ mod tests {
             app.working_graph().node("a").unwrap().content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "World".to_owned(),
             },
             "unedited text round-trips unchanged"
@@ -2879,6 +3695,8 @@ mod tests {
             app.working_graph().node("a").unwrap().content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "World".to_owned(),
             },
             "Esc must discard, never commit"
@@ -2924,6 +3742,39 @@ mod tests {
         assert_eq!(source, "fn main() {}");
     }
 
+    #[test]
+    fn pasting_lines_into_a_code_form_keeps_them_as_lines() {
+        let mut app = all_kinds_app();
+        select_block(&mut app, "a", 2); // the code block
+        press(&mut app, KeyCode::Enter);
+        {
+            let Some(FormState::Code {
+                language, source, ..
+            }) = &mut app.open_form
+            else {
+                panic!("code form open");
+            };
+            language.buffer = vec![String::new()];
+            source.buffer = vec![String::new()];
+        }
+        app.update(Msg::Terminal(Event::Paste("a\nb".to_owned())));
+        assert!(
+            app.open_form().is_some(),
+            "a pasted newline must not commit"
+        );
+        press(&mut app, KeyCode::Tab); // over to Language, a one-line field
+        app.update(Msg::Terminal(Event::Paste("python\r\n".to_owned())));
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let ContentBlock::Code {
+            language, source, ..
+        } = &app.working_graph().node("a").unwrap().content[2]
+        else {
+            panic!("still a code block");
+        };
+        assert_eq!(source, "a\nb");
+        assert_eq!(language.as_deref(), Some("python"));
+    }
+
     #[test]
     fn list_form_edits_items_one_per_line_and_drops_blanks() {
         let mut app = all_kinds_app();
@@ -3016,6 +3867,75 @@ mod tests {
         assert!(app.flash().is_some());
     }
 
+    #[test]
+    fn l_turns_text_into_a_list_and_back_undoably() {
+        let mut app = EditorApp::new(
+            Graph::from_json(
+                r#"{"nodes":[{"id":"a","content":[
+                    {"kind":"text","reveal":1,"body":"Plan\n\nShip"},
+                    {"kind":"divider"}
+                ]}]}"#,
+            )
+            .expect("fixture parses"),
+        );
+        select_block(&mut app, "a", 0);
+        press(&mut app, KeyCode::Char('l'));
+        let block = &app.working_graph().node("a").unwrap().content[0];
+        let ContentBlock::List { items, .. } = block else {
+            panic!("now a list");
+        };
+        assert_eq!(items, &["Plan", "Ship"]);
+        assert_eq!(block.reveal(), Some(1), "the reveal step carries over");
+
+        press(&mut app, KeyCode::Char('l'));
+        assert!(matches!(
+            &app.working_graph().node("a").unwrap().content[0],
+            ContentBlock::Text { body, .. } if body == "Plan\nShip"
+        ));
+        app.undo();
+        app.undo();
+        assert!(matches!(
+            &app.working_graph().node("a").unwrap().content[0],
+            ContentBlock::Text { body, .. } if body == "Plan\n\nShip"
+        ));
+
+        select_block(&mut app, "a", 1);
+        press(&mut app, KeyCode::Char('l'));
+        assert_eq!(app.history_len(), 0, "a divider has nothing to convert");
+    }
+
+    #[test]
+    fn f_replaces_across_the_deck_and_undo_restores_it_exactly() {
+        let original = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","traversal":"b","content":[{"kind":"heading","level":1,"text":"Acme"}]},
+                {"id":"b","content":[{"kind":"list","items":["Acme one","acme two"]}]}
+            ]}"#,
+        )
+        .expect("fixture parses");
+        let mut app = EditorApp::new(original.clone());
+        press(&mut app, KeyCode::Char('f'));
+        type_text(&mut app, "Acme");
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "Globex");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+        assert!(app.open_form().is_none());
+        assert_eq!(app.working_graph().nodes[0].plain_text(), "Globex");
+        assert_eq!(
+            app.working_graph().nodes[1].plain_text(),
+            "Globex one\nacme two"
+        );
+        assert_eq!(
+            app.flash().map(|f| f.text.as_str()),
+            Some("Replaced 2 matches \u{b7} u undoes it")
+        );
+        assert_eq!(app.history_len(), 1, "one undo step for the whole deck");
+
+        app.undo();
+        assert_eq!(app.working_graph(), &original);
+    }
+
     #[test]
     fn container_layout_cycle_commits_immediately_and_is_undoable() {
         let mut app = all_kinds_app();
@@ -3135,6 +4055,8 @@ mod tests {
             node.content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "New text".to_owned(),
             }
         );
@@ -3144,7 +4066,7 @@ mod tests {
     #[test]
     fn every_palette_card_inserts_its_own_block_kind() {
         type KindCheck = fn(&ContentBlock) -> bool;
-        let cases: [(authoring::BlockKind, KindCheck); 8] = [
+        let cases: [(authoring::BlockKind, KindCheck); 10] = [
             (authoring::BlockKind::Heading, |b| {
                 matches!(b, ContentBlock::Heading { .. })
             }),
@@ -3169,6 +4091,13 @@ mod tests {
             (authoring::BlockKind::AsciiArt, |b| {
                 matches!(b, ContentBlock::AsciiArt { .. })
             }),
+            (authoring::BlockKind::Video, |b| {
+                matches!(b, ContentBlock::Video { .. })
+            }),
+            (
+                authoring::BlockKind::Columns,
+                |b| matches!(b, ContentBlock::Columns { columns, .. } if columns.len() == 2),
+            ),
         ];
         let area = Rect::new(0, 0, 100, 30);
         let areas = hit::editor_areas(area);
@@ -3195,8 +4124,12 @@ mod tests {
                 "{kind:?}'s card inserted the wrong block kind: {:?}",
                 node.content[1]
             );
-            // Every kind but Divider opens its own form immediately.
-            if kind != authoring::BlockKind::Divider {
+            // Every kind but Divider and Columns opens its own form
+            // immediately.
+            if !matches!(
+                kind,
+                authoring::BlockKind::Divider | authoring::BlockKind::Columns
+            ) {
                 assert!(
                     app.open_form().is_some(),
                     "{kind:?}'s new block should open its own form"
@@ -3229,6 +4162,49 @@ mod tests {
         assert!(!app.dirty());
     }
 
+    #[test]
+    fn an_undescribed_picture_offers_its_alt_field_and_a_decorative_one_does_not() {
+        let mut app = EditorApp::new(
+            Graph::from_json(
+                r#"{"nodes":[{"id":"a","content":[
+                    {"kind":"image","src":"chart.png"},
+                    {"kind":"image","src":"rule.png","alt":"","decorative":true}
+                ]}]}"#,
+            )
+            .expect("fixture parses"),
+        );
+        app.set_terminal_size(100, 30);
+        let alt_chip = |app: &EditorApp| {
+            hit::selected_block_chips(app)
+                .into_iter()
+                .any(|(a, _)| a == hit::BlockAction::DescribeImage)
+        };
+        select_block(&mut app, "a", 1);
+        assert!(!alt_chip(&app), "decorative pictures need no alt text");
+
+        select_block(&mut app, "a", 0);
+        let areas = hit::editor_areas(Rect::new(0, 0, 100, 30));
+        let chips = hit::selected_block_chips(&app);
+        let (_, rect) = hit::chip_rects(areas.hint, &chips)
+            .into_iter()
+            .find(|(a, _)| *a == hit::BlockAction::DescribeImage)
+            .expect("an alt-text chip exists");
+        click(&mut app, rect.x, rect.y);
+        assert!(matches!(
+            app.open_form(),
+            Some(FormState::Picture {
+                focus: PictureFocus::Alt,
+                ..
+            })
+        ));
+
+        for c in "Sales by month".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(!alt_chip(&app), "described now");
+    }
+
     #[test]
     fn delete_chip_removes_the_block_flashes_and_is_undoable() {
         let mut app = app();
@@ -3257,11 +4233,90 @@ mod tests {
             node.content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "World".to_owned(),
             }
         );
     }
 
+    fn split_chip(app: &EditorApp) -> Option<Rect> {
+        let areas = hit::editor_areas(Rect::new(0, 0, 100, 30));
+        hit::chip_rects(areas.hint, &hit::selected_block_chips(app))
+            .into_iter()
+            .find(|(a, _)| *a == hit::BlockAction::SplitHere)
+            .map(|(_, rect)| rect)
+    }
+
+    #[test]
+    fn split_chip_breaks_the_slide_in_two_and_one_undo_rejoins_it() {
+        let mut app = app();
+        select_block(&mut app, "a", 0);
+        assert!(split_chip(&app).is_none(), "nothing above the first block");
+        select_block(&mut app, "a", 1);
+        let rect = split_chip(&app).expect("a Split here chip exists");
+        let before = app.working_graph().clone();
+        let history = app.history_len();
+        click(&mut app, rect.x, rect.y);
+
+        let ids: Vec<&str> = app
+            .working_graph()
+            .nodes
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(ids, ["a", "welcome", "b"]);
+        let second = app.working_graph().node("welcome").expect("the new half");
+        assert_eq!(second.next_target(), Some("b"));
+        assert_eq!(app.working_graph().node("a").unwrap().content.len(), 1);
+        assert_eq!(app.selection(), &Selection::Slide("welcome".into()));
+        assert_eq!(app.history_len(), history + 1, "one step to undo");
+
+        app.undo();
+        assert_eq!(app.working_graph(), &before, "undo rejoins it exactly");
+        app.redo();
+        assert_eq!(app.working_graph().nodes.len(), 3, "redo splits it again");
+    }
+
+    #[test]
+    fn shift_c_twice_copies_one_slides_blocks_onto_another_as_one_undo() {
+        let mut app = linear3_app();
+        let before = app.working_graph().clone();
+        app.selection = Selection::Slide("a".to_owned());
+        press(&mut app, KeyCode::Char('C'));
+        assert_eq!(app.copy_source.as_deref(), Some("a"));
+        press(&mut app, KeyCode::Char('C'));
+        assert_eq!(app.working_graph(), &before, "not onto itself");
+        assert!(
+            app.flash()
+                .is_some_and(|f| f.text.contains("select another"))
+        );
+
+        press(&mut app, KeyCode::Char(']'));
+        press(&mut app, KeyCode::Char(']'));
+        assert_eq!(app.selection(), &Selection::Slide("c".to_owned()));
+        let history = app.history_len();
+        press(&mut app, KeyCode::Char('C'));
+        let content = app.working_graph().node("c").unwrap().content.clone();
+        assert_eq!(
+            content,
+            [
+                before.node("c").unwrap().content[0].clone(),
+                before.node("a").unwrap().content[0].clone(),
+            ]
+        );
+        assert_eq!(
+            app.working_graph().node("a"),
+            before.node("a"),
+            "the source keeps its own"
+        );
+        assert_eq!(app.copy_source, None);
+        assert_eq!(app.history_len(), history + 1, "one step to undo");
+
+        app.undo();
+        assert_eq!(app.working_graph(), &before);
+    }
+
     #[test]
     fn drag_reorders_blocks_mouse_only() {
         let mut app = app();
@@ -3301,6 +4356,8 @@ mod tests {
             node.content[0],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "World".to_owned(),
             },
             "the text block is now first"
@@ -3309,6 +4366,8 @@ mod tests {
             node.content[1],
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 1,
                 text: "Hello".to_owned(),
             },
@@ -3322,6 +4381,8 @@ mod tests {
             node.content[0],
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 1,
                 text: "Hello".to_owned(),
             },
@@ -3329,6 +4390,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dragging_the_first_block_onto_the_third_moves_it_there_in_one_undo_step() {
+        let mut app = all_kinds_app();
+        let areas = hit::editor_areas(Rect::new(0, 0, 100, 40));
+        let layout = hit::canvas_layout(&app, areas.canvas).expect("canvas has layout");
+        let (_, end2) = layout.block_extents[2];
+        let history = app.history_len();
+
+        click(&mut app, layout.inner.x, layout.inner.y);
+        let drop_row = layout.inner.y + (end2 - 1) as u16;
+        drag_to(&mut app, layout.inner.x, drop_row);
+        release(&mut app, layout.inner.x, drop_row);
+
+        let content = &app.working_graph().node("a").unwrap().content;
+        assert!(matches!(content[0], ContentBlock::Text { .. }));
+        assert!(matches!(content[1], ContentBlock::Code { .. }));
+        assert!(
+            matches!(content[2], ContentBlock::Heading { .. }),
+            "the heading lands third"
+        );
+        assert_eq!(app.selection(), &Selection::Block("a".to_owned(), vec![2]));
+        assert_eq!(app.history_len(), history + 1, "one step to undo");
+    }
+
+    #[test]
+    fn a_drag_released_off_the_canvas_drops_nothing() {
+        let mut app = app();
+        let areas = hit::editor_areas(Rect::new(0, 0, 100, 30));
+        let layout = hit::canvas_layout(&app, areas.canvas).expect("canvas has layout");
+        let (_, end1) = layout.block_extents[1];
+
+        click(&mut app, layout.inner.x, layout.inner.y);
+        drag_to(&mut app, layout.inner.x, layout.inner.y + (end1 - 1) as u16);
+        assert!(matches!(app.drag(), DragState::Over { .. }));
+        drag_to(&mut app, areas.outline.x, areas.outline.y + 1);
+        assert!(
+            matches!(app.drag(), DragState::Lifting { .. }),
+            "off the block list there is no drop slot"
+        );
+        release(&mut app, areas.outline.x, areas.outline.y + 1);
+        assert!(!app.dirty());
+    }
+
     #[test]
     fn esc_cancels_a_drag_leaving_the_block_where_it_was() {
         let mut app = app();
@@ -3349,6 +4453,8 @@ mod tests {
             node.content[0],
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 1,
                 text: "Hello".to_owned(),
             },
@@ -3489,6 +4595,17 @@ mod tests {
         assert_eq!(app.selection(), &Selection::Slide("bonus".to_owned()));
     }
 
+    #[test]
+    fn pasting_carriage_returns_into_a_prompt_keeps_it_one_line() {
+        let mut app = linear3_app();
+        click_outline_row(&mut app, 3);
+        app.update(Msg::Terminal(Event::Paste("Bonus\rround\r".to_owned())));
+        let Some(FormState::Prompt { fields, .. }) = app.open_form() else {
+            panic!("the new-slide prompt is open");
+        };
+        assert_eq!(fields[0].buffer, ["Bonus round"]);
+    }
+
     #[test]
     fn slide_duplicate_and_delete_round_trip() {
         let mut app = linear3_app();
@@ -3574,6 +4691,145 @@ mod tests {
         );
     }
 
+    fn clear_focused_field(app: &mut EditorApp, len: usize) {
+        for _ in 0..len {
+            press(app, KeyCode::Delete);
+        }
+    }
+
+    fn branch_point_of<'a>(app: &'a EditorApp, id: &str) -> &'a fireside_core::BranchPoint {
+        app.working_graph()
+            .node(id)
+            .and_then(|n| n.branch_point())
+            .expect("a branch point")
+    }
+
+    #[test]
+    fn adding_an_answer_undoes_and_redoes() {
+        let mut app = branch_app();
+        app.selection = Selection::Slide("a".to_owned());
+        click_slide_chip(&mut app, hit::SlideAction::AddAnswer);
+        type_text(&mut app, "Back to the start");
+        click_form_chip(&mut app, hit::FormChipKind::ChooseTarget);
+        click_picker_row(&mut app, "Start");
+        assert_eq!(branch_point_of(&app, "a").options.len(), 3);
+
+        press(&mut app, KeyCode::Char('u'));
+        assert_eq!(branch_point_of(&app, "a").options.len(), 2);
+        press(&mut app, KeyCode::Char('U'));
+        let bp = branch_point_of(&app, "a");
+        assert_eq!(bp.options.len(), 3);
+        assert_eq!(bp.options[2].label, "Back to the start");
+        assert_eq!(bp.options[2].target, "a");
+    }
+
+    #[test]
+    fn assigning_keys_fills_the_blanks_and_undo_puts_the_old_ones_back() {
+        let mut app = branch_app();
+        app.selection = Selection::Slide("a".to_owned());
+        let keys = |app: &EditorApp| -> Vec<Option<String>> {
+            branch_point_of(app, "a")
+                .options
+                .iter()
+                .map(|o| o.key.clone())
+                .collect()
+        };
+        click_slide_chip(&mut app, hit::SlideAction::AssignKeys);
+        assert_eq!(keys(&app), [Some("1".to_owned()), Some("2".to_owned())]);
+
+        click_slide_chip(&mut app, hit::SlideAction::AssignKeys);
+        assert_eq!(app.history_len(), 1, "a no-op spends no undo step");
+
+        press(&mut app, KeyCode::Char('u'));
+        assert_eq!(keys(&app), [None, None]);
+    }
+
+    #[test]
+    fn editing_an_answer_and_the_prompt_round_trips_through_undo_and_redo() {
+        let mut app = branch_app();
+        app.selection = Selection::Slide("a".to_owned());
+        press(&mut app, KeyCode::Char('2'));
+        assert!(matches!(
+            app.open_form(),
+            Some(FormState::Prompt {
+                kind: PromptKind::EditAnswer { index: 1, .. },
+                ..
+            })
+        ));
+        clear_focused_field(&mut app, "To C".len());
+        type_text(&mut app, "Skip ahead");
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "x");
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "For anyone short on time");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(app.open_form().is_none());
+
+        press(&mut app, KeyCode::Char('e'));
+        type_text(&mut app, "Where next?");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let bp = branch_point_of(&app, "a");
+        assert_eq!(bp.prompt.as_deref(), Some("Where next?"));
+        assert_eq!(bp.options[1].label, "Skip ahead");
+        assert_eq!(bp.options[1].key.as_deref(), Some("x"));
+        assert_eq!(
+            bp.options[1].description.as_deref(),
+            Some("For anyone short on time")
+        );
+        assert_eq!(bp.options[1].target, "c", "the target is untouched");
+
+        press(&mut app, KeyCode::Char('u'));
+        assert_eq!(branch_point_of(&app, "a").prompt, None);
+        press(&mut app, KeyCode::Char('u'));
+        assert_eq!(branch_point_of(&app, "a").options[1].label, "To C");
+        assert_eq!(branch_point_of(&app, "a").options[1].description, None);
+        press(&mut app, KeyCode::Char('U'));
+        press(&mut app, KeyCode::Char('U'));
+        let bp = branch_point_of(&app, "a");
+        assert_eq!(bp.options[1].label, "Skip ahead");
+        assert_eq!(bp.prompt.as_deref(), Some("Where next?"));
+    }
+
+    #[test]
+    fn an_answer_key_already_in_use_is_refused_and_the_form_stays_open() {
+        let mut app = branch_app();
+        app.selection = Selection::Slide("a".to_owned());
+        press(&mut app, KeyCode::Char('1'));
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "x");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        press(&mut app, KeyCode::Char('2'));
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "X");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(app.open_form().is_some(), "the clash keeps the form open");
+        assert!(
+            app.flash()
+                .is_some_and(|f| f.text.contains("already uses 'X'"))
+        );
+        assert_eq!(branch_point_of(&app, "a").options[1].key, None);
+    }
+
+    #[test]
+    fn change_target_keeps_the_typed_label_then_opens_the_picker() {
+        let mut app = branch_app();
+        app.selection = Selection::Slide("a".to_owned());
+        press(&mut app, KeyCode::Char('1'));
+        type_text(&mut app, "Now: ");
+        click_form_chip(&mut app, hit::FormChipKind::ChooseTarget);
+        assert!(matches!(
+            app.open_form(),
+            Some(FormState::SlidePicker {
+                target: PickerTarget::RetargetAnswer { index: 0, .. },
+                ..
+            })
+        ));
+        click_picker_row(&mut app, "C slide");
+        let bp = branch_point_of(&app, "a");
+        assert_eq!(bp.options[0].label, "Now: To B");
+        assert_eq!(bp.options[0].target, "c");
+    }
+
     /// The same build as the mouse-driven test above, but entirely via
     /// the keyboard — `n`/`c`/`a`/`g`/`r` plus a picker's digit-row
     /// shortcuts (spec 013 US3, ADR-017's keyboard-complete posture).
@@ -3777,6 +5033,14 @@ mod tests {
             hit::wiring_answer_spans(app.working_graph(), app.working_graph().node("a").unwrap());
         let first = &spans[0];
         click(&mut app, areas.wiring.x + first.start, areas.wiring.y);
+        assert!(matches!(
+            app.open_form(),
+            Some(FormState::Prompt {
+                kind: PromptKind::EditAnswer { index: 0, .. },
+                ..
+            })
+        ));
+        click_form_chip(&mut app, hit::FormChipKind::ChooseTarget);
         assert!(matches!(
             app.open_form(),
             Some(FormState::SlidePicker { .. })
@@ -3799,7 +5063,7 @@ mod tests {
         assert!(matches!(
             app.open_form(),
             Some(FormState::Prompt {
-                kind: PromptKind::DeckTitle,
+                kind: PromptKind::DeckDetails,
                 ..
             })
         ));
@@ -3821,6 +5085,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn d_edits_the_deck_details_as_one_undoable_step() {
+        let mut app = linear3_app();
+        press(&mut app, KeyCode::Char('d'));
+        type_text(&mut app, "Graph Talks");
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "Ada Lovelace");
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Tab);
+        type_text(&mut app, "  ");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+        assert!(app.open_form().is_none());
+        let graph = app.working_graph();
+        assert_eq!(graph.title.as_deref(), Some("Graph Talks"));
+        assert_eq!(graph.author.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(graph.date, None);
+        assert_eq!(graph.description, None, "a blank field stays absent");
+        assert!(app.dirty());
+
+        app.undo();
+        assert_eq!(app.working_graph().title, None);
+        assert_eq!(app.working_graph().author, None);
+    }
+
+    #[test]
+    fn the_duration_chip_parses_any_form_and_refuses_nonsense() {
+        let mut app = linear3_app();
+        app.selection = Selection::Slide("a".to_owned());
+        click_slide_chip(&mut app, hit::SlideAction::Duration);
+        type_text(&mut app, "1m30s");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let duration = app.working_graph().node("a").unwrap().duration;
+        assert_eq!(duration, Some(Duration::from_secs(90)));
+
+        click_slide_chip(&mut app, hit::SlideAction::Duration);
+        let Some(FormState::Prompt { fields, .. }) = app.open_form() else {
+            panic!("duration prompt is open");
+        };
+        assert_eq!(fields[0].text(), "1:30");
+        type_text(&mut app, "x");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(
+            app.open_form().is_some(),
+            "a bad duration keeps the form open"
+        );
+        assert_eq!(app.working_graph().node("a").unwrap().duration, duration);
+    }
+
+    #[test]
+    fn renaming_a_slide_moves_its_references_and_undo_restores_both() {
+        let mut app = linear3_app();
+        app.selection = Selection::Slide("b".to_owned());
+        click_slide_chip(&mut app, hit::SlideAction::Id);
+        press(&mut app, KeyCode::Delete);
+        type_text(&mut app, "middle");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(app.selection(), &Selection::Slide("middle".to_owned()));
+        let graph = app.working_graph();
+        assert!(graph.node("b").is_none());
+        assert_eq!(graph.node("a").unwrap().next_target(), Some("middle"));
+
+        app.undo();
+        let graph = app.working_graph();
+        assert!(graph.node("middle").is_none());
+        assert_eq!(graph.node("a").unwrap().next_target(), Some("b"));
+        assert_eq!(app.selection(), &Selection::Slide("b".to_owned()));
+
+        click_slide_chip(&mut app, hit::SlideAction::Id);
+        press(&mut app, KeyCode::Delete);
+        type_text(&mut app, "c");
+        press_with(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(app.open_form().is_some(), "a taken id keeps the form open");
+        assert!(app.working_graph().node("b").is_some());
+    }
+
     // ─── Container children (spec 014) ─────────────────────────────────────
 
     #[test]
@@ -3888,6 +5228,8 @@ mod tests {
             children[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "!Tagline".to_owned(),
             }
         );
@@ -3947,6 +5289,8 @@ mod tests {
             children[0],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "Tagline".to_owned(),
             },
             "the tagline is now first within the container"
@@ -3955,6 +5299,8 @@ mod tests {
             children[1],
             ContentBlock::Heading {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 level: 1,
                 text: "Title".to_owned(),
             },
@@ -3972,6 +5318,8 @@ mod tests {
             app.working_graph().node("a").unwrap().content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "After the container".to_owned(),
             }
         );
@@ -3992,6 +5340,8 @@ mod tests {
             children[0],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "Tagline".to_owned(),
             }
         );
@@ -4033,6 +5383,8 @@ mod tests {
             children[2],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "New childNew text".to_owned(),
             }
         );
@@ -4041,6 +5393,8 @@ mod tests {
             app.working_graph().node("a").unwrap().content[1],
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "After the container".to_owned(),
             }
         );
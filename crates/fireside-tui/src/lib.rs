@@ -9,22 +9,35 @@ pub mod app;
 pub mod editor;
 pub mod error;
 mod follower;
+pub mod rehearsal;
+pub mod remote;
 pub mod render;
+pub mod side_timer;
+#[cfg(test)]
+pub(crate) mod testing;
 pub mod theme;
 
 use std::fmt;
 use std::io;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture};
 use crossterm::execute;
+use crossterm::style::Print;
 use crossterm::terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate};
 use crossterm::tty::IsTty;
 use fireside_core::Graph;
-use fireside_engine::{Outcome, Session};
+use fireside_engine::{BranchCoverage, EndBehavior, Session};
 
-pub use app::{App, Msg};
+pub use app::{
+    App, AutoAdvance, AutoBranch, Copied, DEFAULT_COUNTDOWN, DEFAULT_ROUTE_STOPS, Msg,
+    ProgressStyle, ViewAudience,
+};
 pub use error::TuiError;
+pub use rehearsal::{Rehearsal, SlideTime};
+pub use remote::RemoteCommand;
+pub use side_timer::SideTimer;
+pub use theme::Theme;
 
 /// A live-reload source: polled on every event tick, it returns `Some`
 /// when the deck changed on disk — a fresh graph, or a human-readable
@@ -37,12 +50,24 @@ pub type ReloadSource<'a> = &'a mut dyn FnMut() -> Option<Result<Graph, String>>
 /// caller owns the I/O and reports back whether the save succeeded.
 pub type WriteBackSink<'a> = &'a mut dyn FnMut(&Graph) -> Result<(), WriteBackError>;
 
+/// A copy sink: called with a code block's source when the presenter
+/// copies it (`y`), and reports where the text went or why it couldn't be
+/// copied. The presenter itself never touches the clipboard or the
+/// filesystem; the caller owns both.
+pub type CopySink<'a> = &'a mut dyn FnMut(&str) -> Result<Copied, String>;
+
 /// A position-changed sink: called with the new current node id every time
 /// it changes (including once, immediately, with the starting node). The
 /// presenter itself never touches the filesystem; a caller that wants to
 /// persist "where the presenter is" (e.g. resume-on-relaunch) owns all I/O.
 pub type PositionSink<'a> = &'a mut dyn FnMut(&str);
 
+/// A remote-control source: drained on every event-loop tick, it returns
+/// each command that arrived since the last one, then `None`. The
+/// presenter itself never opens a socket; the caller owns the listener
+/// and hands over commands it has already parsed.
+pub type RemoteSource<'a> = &'a mut dyn FnMut() -> Option<RemoteCommand>;
+
 /// What the presenter hands to [`SessionTickSink`] every event-loop tick
 /// (not only on navigation change — a caller persisting a live heartbeat,
 /// e.g. for `fireside notes`, needs it to advance even while the presenter
@@ -108,7 +133,7 @@ pub type SessionSource<'a> = &'a mut dyn FnMut() -> SessionStatus;
 /// see `specs/010-presenter-polish/research.md` §3) so a caller can report
 /// a rehearsal summary. `fireside-tui` never prints this itself — the
 /// caller owns all terminal output outside the TUI's own frames.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct PresentSummary {
     /// Distinct slides visited this session.
     pub seen: usize,
@@ -116,6 +141,14 @@ pub struct PresentSummary {
     pub total: usize,
     /// Wall-clock time since the presentation started.
     pub elapsed: Duration,
+    /// The path walked, as a [`Session::encode_path`] code — what a
+    /// presenter sends for someone else to replay.
+    pub path: String,
+    /// Time on each slide visited, in deck order — for a `--rehearse`
+    /// report.
+    pub slide_times: Vec<SlideTime>,
+    /// Which branch options were taken — for a `--coverage` report.
+    pub coverage: BranchCoverage,
 }
 
 /// Why a quick-edit save could not be applied.
@@ -126,11 +159,36 @@ pub enum WriteBackError {
     /// The on-disk file changed since it was last loaded; the save was
     /// refused rather than risk silently discarding either version.
     Conflict,
-    /// The write failed for a reason other than a conflict (permissions,
-    /// disk full, etc.).
+    /// The file at this path can't be written: its permissions, or its
+    /// folder's, say no.
+    PermissionDenied(String),
+    /// The folder the file belongs in — this path — doesn't exist (it was
+    /// moved or deleted since the deck was opened). A caller that creates
+    /// it when the save is repeated says so in [`fmt::Display`]'s remedy.
+    NoSuchDirectory(String),
+    /// The disk holding the file at this path has no room left.
+    DiskFull(String),
+    /// The write failed for any other reason.
     Io(String),
 }
 
+impl WriteBackError {
+    /// Sorts an I/O failure writing `path` into the variant that says what
+    /// to do about it. A missing file is taken to mean a missing folder:
+    /// writing a file creates it, but never the folders above it.
+    #[must_use]
+    pub fn from_io(err: &io::Error, path: &std::path::Path) -> Self {
+        match err.kind() {
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied(path.display().to_string()),
+            io::ErrorKind::NotFound => {
+                Self::NoSuchDirectory(path.parent().unwrap_or(path).display().to_string())
+            }
+            io::ErrorKind::StorageFull => Self::DiskFull(path.display().to_string()),
+            _ => Self::Io(err.to_string()),
+        }
+    }
+}
+
 impl fmt::Display for WriteBackError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -139,20 +197,33 @@ impl fmt::Display for WriteBackError {
                 f,
                 "Save skipped — the file changed on disk; Ctrl+S again to overwrite, Esc to discard your edit"
             ),
+            Self::PermissionDenied(path) => write!(
+                f,
+                "Save failed — no permission to write {path}; check who owns it and its folder"
+            ),
+            Self::NoSuchDirectory(dir) => write!(
+                f,
+                "Save failed — the folder {dir} doesn't exist; Ctrl+S again to create it"
+            ),
+            Self::DiskFull(path) => write!(
+                f,
+                "Save failed — the disk holding {path} is full; free some space and save again"
+            ),
             Self::Io(message) => write!(f, "Save failed — {message}"),
         }
     }
 }
 
 /// Present a graph: set up the terminal, run the event loop, and always
-/// restore the terminal — even on error.
+/// restore the terminal — even on error. A copied code block goes to
+/// `copy`.
 ///
 /// # Errors
 ///
 /// Returns [`TuiError::Engine`] for an unpresentable graph and
 /// [`TuiError::Io`] for terminal failures.
-pub fn present(graph: Graph) -> Result<PresentSummary, TuiError> {
-    present_watching(graph, &mut || None)
+pub fn present(graph: Graph, copy: CopySink<'_>) -> Result<PresentSummary, TuiError> {
+    present_watching(graph, &mut || None, copy)
 }
 
 /// Present a graph with live reload: while presenting, `source` is polled
@@ -166,36 +237,103 @@ pub fn present(graph: Graph) -> Result<PresentSummary, TuiError> {
 pub fn present_watching(
     graph: Graph,
     source: ReloadSource<'_>,
+    copy: CopySink<'_>,
 ) -> Result<PresentSummary, TuiError> {
     present_impl(
         graph,
         source,
         &mut |_| Err(WriteBackError::Unavailable),
-        None,
+        copy,
         &mut |_| {},
         &mut |_| {},
         false,
-        false,
+        None,
+        PresentOptions::default(),
     )
 }
 
+/// How [`present_authoring`] opens and runs a presentation: everything
+/// beyond the deck and the caller's I/O. [`Default`] is the plain
+/// presenter — the entry node, animated, stopping at the ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentOptions<'a> {
+    /// A node the presenter asked to open on. Takes precedence over
+    /// `initial_node` and, unlike it, opens without the "Resumed" notice.
+    pub start: Option<&'a str>,
+    /// A saved position to resume at. An unknown id is a guarded no-op,
+    /// per `Session::goto`, falling back to the entry node exactly as an
+    /// unrecognized `goto` always has.
+    pub initial_node: Option<&'a str>,
+    /// A [`Session::encode_path`] code, taking precedence over both of the
+    /// above: the presentation opens with that walk already taken, or —
+    /// for a code that can't be decoded — at the entry node.
+    pub path: Option<&'a str>,
+    /// Start with the `f`-key view toggle already set, as if it were
+    /// pressed once before the first frame.
+    pub fullscreen: bool,
+    /// Off presents with no fades, no blinking, and no redraws on a timer
+    /// beyond clearing a flash or ending the countdown.
+    pub animations: bool,
+    /// The colors to draw with.
+    pub theme: Theme,
+    /// Ring the terminal bell on arriving at a slide that asks for a
+    /// choice.
+    pub notify_on_branch: bool,
+    /// Make an option's number, key, or a click only select it, leaving
+    /// Enter to take it.
+    pub confirm_branch_choice: bool,
+    /// The header's starting progress style, which `P` cycles from there.
+    pub progress: ProgressStyle,
+    /// Caps the content column's width, centering it on a wider screen.
+    pub max_width: Option<u16>,
+    /// How long the side countdown `T` starts runs.
+    pub countdown: Duration,
+    /// The most stops the ending's route trace shows.
+    pub route_stops: usize,
+    /// Whether the deck stops at its ends, restarts, bounces, or holds on
+    /// the last slide.
+    pub end: EndBehavior,
+    /// Plays the deck unattended, when set.
+    pub auto: Option<AutoAdvance>,
+}
+
+impl Default for PresentOptions<'_> {
+    fn default() -> Self {
+        Self {
+            start: None,
+            initial_node: None,
+            path: None,
+            fullscreen: false,
+            animations: true,
+            theme: Theme::default(),
+            notify_on_branch: false,
+            confirm_branch_choice: false,
+            progress: ProgressStyle::default(),
+            max_width: None,
+            countdown: DEFAULT_COUNTDOWN,
+            route_stops: DEFAULT_ROUTE_STOPS,
+            end: EndBehavior::Stop,
+            auto: None,
+        }
+    }
+}
+
 /// Present a graph with live reload and quick-edit write-back: on top of
 /// `present_watching`'s reload polling, a presenter can quick-edit the
 /// current node's heading/text/list blocks and save — the edited graph is
 /// handed to `sink`, which owns all file I/O (`fireside-tui` performs
-/// none), per ADR-005. `initial_node` (when it names a real node) opens the
-/// presentation there instead of the graph's normal entry node — an unknown
-/// id is a guarded no-op, per `Session::goto`, falling back to the entry
-/// node exactly as an unrecognized `goto` always has. `on_position_changed`
-/// is called with the current node id once at startup and again every time
-/// it changes, for a caller that wants to persist "where the presenter is"
-/// (e.g. resume-on-relaunch) — `fireside-tui` performs no file I/O itself.
+/// none), per ADR-005. A copied code block goes to `copy`, which owns the
+/// clipboard the same way. `on_position_changed` is called with the
+/// current node id once at startup and again every time it changes, for a
+/// caller that wants to persist "where the presenter is" (e.g.
+/// resume-on-relaunch) — `fireside-tui` performs no file I/O itself.
 /// `tick_sink` is called once every event-loop tick, unconditionally
 /// (unlike `on_position_changed`, which only fires on change), with the
 /// current position and reveal progress — for a caller maintaining a live
-/// heartbeat (e.g. `fireside notes`'s session-state file). `fullscreen`
-/// starts the presentation with the existing `f`-key view toggle already
-/// set, equivalent to pressing it once before the first frame.
+/// heartbeat (e.g. `fireside notes`'s session-state file). `remote`, when
+/// set, is drained every tick for [`RemoteCommand`]s, each applied as if
+/// its key had been pressed. Where the presentation opens and how it runs
+/// come from `options`.
 ///
 /// # Errors
 ///
@@ -206,20 +344,22 @@ pub fn present_authoring(
     graph: Graph,
     source: ReloadSource<'_>,
     sink: WriteBackSink<'_>,
-    initial_node: Option<&str>,
+    copy: CopySink<'_>,
     on_position_changed: PositionSink<'_>,
     tick_sink: SessionTickSink<'_>,
-    fullscreen: bool,
+    remote: Option<RemoteSource<'_>>,
+    options: PresentOptions<'_>,
 ) -> Result<PresentSummary, TuiError> {
     present_impl(
         graph,
         source,
         sink,
-        initial_node,
+        copy,
         on_position_changed,
         tick_sink,
         true,
-        fullscreen,
+        remote,
+        options,
     )
 }
 
@@ -228,18 +368,39 @@ fn present_impl(
     graph: Graph,
     source: ReloadSource<'_>,
     sink: WriteBackSink<'_>,
-    initial_node: Option<&str>,
+    copy: CopySink<'_>,
     on_position_changed: PositionSink<'_>,
     tick_sink: SessionTickSink<'_>,
     sink_available: bool,
-    fullscreen: bool,
+    remote: Option<RemoteSource<'_>>,
+    options: PresentOptions<'_>,
 ) -> Result<PresentSummary, TuiError> {
+    let PresentOptions {
+        start,
+        initial_node,
+        path,
+        fullscreen,
+        animations,
+        theme,
+        notify_on_branch,
+        confirm_branch_choice,
+        progress,
+        max_width,
+        countdown,
+        route_stops,
+        end,
+        auto,
+    } = options;
     if !io::stdout().is_tty() || !io::stdin().is_tty() {
         return Err(TuiError::NotATty);
     }
     let total = graph.nodes.len();
     let mut session = Session::new(graph)?;
-    let resumed = initial_node.is_some_and(|id| matches!(session.goto(id), Outcome::Moved));
+    session.set_end_behavior(end);
+    let replayed = path.is_some_and(|code| session.decode_path(code).is_ok());
+    let started = !replayed && start.is_some_and(|id| session.goto_by_id(id).is_ok());
+    let resumed =
+        !replayed && !started && initial_node.is_some_and(|id| session.goto_by_id(id).is_ok());
     let mut app = App::new(session);
     if !sink_available {
         app = app.without_sink();
@@ -247,7 +408,30 @@ fn present_impl(
     if fullscreen {
         app = app.with_fullscreen();
     }
-    if resumed {
+    if !animations {
+        app = app.without_animations();
+    }
+    if notify_on_branch {
+        app = app.with_notify_on_branch();
+    }
+    if confirm_branch_choice {
+        app = app.with_confirm_branch_choice();
+    }
+    app = app
+        .with_theme(theme)
+        .with_progress(progress)
+        .with_content_max_width(max_width)
+        .with_countdown(countdown)
+        .with_route_stops(route_stops);
+    if let Some(auto) = auto {
+        app = app.with_auto_advance(auto);
+    }
+    if replayed {
+        app.set_flash(
+            "Replayed the path — ← walks back along it",
+            app::FlashKind::Info,
+        );
+    } else if resumed {
         app.set_flash(
             "Resumed where you left off — --restart starts over",
             app::FlashKind::Info,
@@ -264,8 +448,10 @@ fn present_impl(
         &mut app,
         source,
         sink,
+        copy,
         on_position_changed,
         tick_sink,
+        remote,
     );
     let _ = execute!(io::stdout(), DisableMouseCapture);
     ratatui::restore();
@@ -273,9 +459,24 @@ fn present_impl(
         seen: app.session().visited().len(),
         total,
         elapsed: app.elapsed(),
+        path: app.session().encode_path(),
+        slide_times: app
+            .rehearsal()
+            .report(app.session().graph(), Instant::now()),
+        coverage: app.session().branch_coverage(),
     })
 }
 
+/// The longest the presenter's event loop blocks waiting for input: well
+/// inside the follower's staleness window, and quick enough that a live
+/// reload or an auto-advance step never waits noticeably.
+const IDLE_WAKE: Duration = Duration::from_millis(250);
+
+/// The longest a remote-control command waits before the presenter acts
+/// on it: crossterm can only wait on the terminal, so with `--control` on
+/// the loop wakes this often to drain the command channel.
+const REMOTE_WAKE: Duration = Duration::from_millis(50);
+
 /// Visibility note (spec 013, T022): `pub(crate)`, not private — the
 /// authoring editor's `[ ▶ Present ]` (`crates/fireside-tui/src/editor/mod.rs`)
 /// calls this exact loop in-process against its own already-initialized
@@ -287,11 +488,19 @@ pub(crate) fn event_loop(
     app: &mut App,
     source: ReloadSource<'_>,
     sink: WriteBackSink<'_>,
+    copy: CopySink<'_>,
     on_position_changed: PositionSink<'_>,
     tick_sink: SessionTickSink<'_>,
+    mut remote: Option<RemoteSource<'_>>,
 ) -> Result<(), TuiError> {
+    let idle_wake = if remote.is_some() {
+        REMOTE_WAKE
+    } else {
+        IDLE_WAKE
+    };
     let mut last_id = app.session().current().id.clone();
     on_position_changed(&last_id);
+    let mut next_frame: Option<Instant> = None;
     while !app.should_quit() {
         // A pending save is handled before any reload check, in the very
         // next iteration after the save keypress. The keypress that sets
@@ -308,6 +517,9 @@ pub(crate) fn event_loop(
             let result = sink(&graph).map_err(|err| err.to_string());
             app.update(Msg::SaveResult(result));
         }
+        if let Some(text) = app.take_pending_copy() {
+            app.update(Msg::CopyResult(copy(&text)));
+        }
         // Reload is paused while the quick-edit modal is open: otherwise an
         // external edit lands mid-edit, `on_reload` silently swaps the
         // session out from under the open modal, and the eventual save
@@ -318,24 +530,45 @@ pub(crate) fn event_loop(
         {
             app.update(Msg::Reload(result));
         }
-        // Synchronized output eliminates any visible tearing mid-transition;
-        // it is just an escape-sequence pair a terminal either honors or
-        // silently ignores (DEC private mode 2026), so no capability query
-        // is needed — the same "invisible if unsupported" reasoning already
-        // used for the `fade` transition's fallback (Appendix C).
-        let _ = execute!(io::stdout(), BeginSynchronizedUpdate);
-        terminal.draw(|frame| render::draw(frame, app))?;
-        let _ = execute!(io::stdout(), EndSynchronizedUpdate);
-        // The timeout lets expired flash messages clear without input; a
-        // fading slide polls fast so it brightens on time.
-        let timeout = if app.fading() {
-            Duration::from_millis(30)
-        } else {
-            Duration::from_millis(250)
-        };
+        if let Some(remote) = remote.as_mut() {
+            while let Some(command) = remote() {
+                app.update(Msg::Remote(command));
+            }
+        }
+        if app.take_pending_bell() {
+            let _ = execute!(io::stdout(), Print('\x07'));
+        }
+        // A frame is drawn only when something changed — a message, or a
+        // deadline `next_tick` set (a fade frame, a flash clearing, a clock
+        // second) — so a still slide costs no rendering at all.
+        let now = Instant::now();
+        if app.take_needs_redraw() || next_frame.is_some_and(|due| now >= due) {
+            // Synchronized output eliminates any visible tearing
+            // mid-transition; it is just an escape-sequence pair a terminal
+            // either honors or silently ignores (DEC private mode 2026), so
+            // no capability query is needed — the same "invisible if
+            // unsupported" reasoning already used for the `fade`
+            // transition's fallback (Appendix C).
+            let _ = execute!(io::stdout(), BeginSynchronizedUpdate);
+            terminal.draw(|frame| render::draw(frame, app))?;
+            let _ = execute!(io::stdout(), EndSynchronizedUpdate);
+        }
+        let tick = app.next_tick(now);
+        next_frame = tick.map(|wait| now + wait);
+        // Even idle, the loop wakes every `IDLE_WAKE` to poll live reload,
+        // check auto-advance, and keep the session heartbeat fresh — or
+        // every `REMOTE_WAKE`, so a clicker never feels laggy.
+        let timeout = tick.map_or(idle_wake, |wait| wait.min(idle_wake));
         if event::poll(timeout)? {
             app.update(Msg::Terminal(event::read()?));
         }
+        let now = Instant::now();
+        if app.auto_advance_due(now) {
+            app.update(Msg::AutoAdvance(now));
+        }
+        if app.choice_timeout_due(now) {
+            app.update(Msg::ChoiceTimeout(now));
+        }
         let current_id = &app.session().current().id;
         if *current_id != last_id {
             last_id = current_id.clone();
@@ -0,0 +1,168 @@
+//! Time spent on each slide, for `--rehearse`'s end-of-session report: a
+//! speaker practising wants to know which slides ran long, not just how
+//! long the whole talk took.
+//!
+//! Like [`crate::SideTimer`], nothing here reads the clock: `App` passes
+//! `Instant::now()` at every slide change, and tests pass instants they
+//! built.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use fireside_core::Graph;
+
+/// The time banked on each slide, by index, plus the slide on screen now
+/// and when it came up. A slide's clock starts over each time it's
+/// arrived at; coming back to it adds to what it already has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rehearsal {
+    spent: HashMap<usize, Duration>,
+    current: usize,
+    since: Instant,
+}
+
+/// One line of the report: a slide and the time spent on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlideTime {
+    /// The slide's id.
+    pub id: String,
+    /// Time on the slide, across every visit.
+    pub spent: Duration,
+    /// The slide's own `duration`, when it sets one.
+    pub budget: Option<Duration>,
+}
+
+impl SlideTime {
+    /// How far past its `duration` the slide ran; `None` without one, or
+    /// when it finished in time.
+    #[must_use]
+    pub fn overrun(&self) -> Option<Duration> {
+        self.budget
+            .filter(|&budget| self.spent > budget)
+            .map(|budget| self.spent - budget)
+    }
+}
+
+impl Rehearsal {
+    /// Starts timing on slide `start`.
+    #[must_use]
+    pub fn new(start: usize, now: Instant) -> Self {
+        Self {
+            spent: HashMap::from([(start, Duration::ZERO)]),
+            current: start,
+            since: now,
+        }
+    }
+
+    /// Banks the time on the slide that was up and starts the clock on
+    /// `node`. The same slide again changes nothing.
+    pub fn record_node_change(&mut self, node: usize, now: Instant) {
+        if node == self.current {
+            return;
+        }
+        *self.spent.entry(self.current).or_default() += now.saturating_duration_since(self.since);
+        self.spent.entry(node).or_default();
+        self.current = node;
+        self.since = now;
+    }
+
+    /// Time on the slide that's up, since it was last arrived at.
+    #[must_use]
+    pub fn on_current(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.since)
+    }
+
+    /// Time spent on slide `node` so far, counting the visit under way.
+    #[must_use]
+    pub fn spent(&self, node: usize, now: Instant) -> Duration {
+        let banked = self.spent.get(&node).copied().unwrap_or_default();
+        if node == self.current {
+            banked + self.on_current(now)
+        } else {
+            banked
+        }
+    }
+
+    /// Every slide visited, in deck order, with its time as of `now`.
+    /// Indices the deck no longer has (it was reloaded shorter) are left
+    /// out.
+    #[must_use]
+    pub fn report(&self, graph: &Graph, now: Instant) -> Vec<SlideTime> {
+        graph
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.spent.contains_key(index))
+            .map(|(index, node)| SlideTime {
+                id: node.id.clone(),
+                spent: self.spent(index, now),
+                budget: node.duration,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(n: u64) -> Duration {
+        Duration::from_secs(n)
+    }
+
+    #[test]
+    fn each_slide_accumulates_the_time_between_changes() {
+        let t0 = Instant::now();
+        let mut rehearsal = Rehearsal::new(0, t0);
+        rehearsal.record_node_change(1, t0 + secs(30));
+        rehearsal.record_node_change(0, t0 + secs(50));
+        rehearsal.record_node_change(2, t0 + secs(65));
+        assert_eq!(rehearsal.spent(0, t0 + secs(80)), secs(45), "30s, then 15s");
+        assert_eq!(rehearsal.spent(1, t0 + secs(80)), secs(20));
+        assert_eq!(rehearsal.spent(2, t0 + secs(80)), secs(15), "still up");
+        assert_eq!(rehearsal.on_current(t0 + secs(80)), secs(15));
+    }
+
+    #[test]
+    fn staying_on_a_slide_keeps_its_clock_running() {
+        let t0 = Instant::now();
+        let mut rehearsal = Rehearsal::new(3, t0);
+        rehearsal.record_node_change(3, t0 + secs(10));
+        assert_eq!(rehearsal.on_current(t0 + secs(20)), secs(20));
+        assert_eq!(rehearsal.spent(3, t0 + secs(20)), secs(20));
+    }
+
+    #[test]
+    fn the_report_lists_visited_slides_in_deck_order_with_overruns() {
+        let graph = Graph::from_json(
+            r#"{"nodes":[
+                {"id":"a","duration":"1m","content":[]},
+                {"id":"b","content":[]},
+                {"id":"c","duration":"30s","content":[]}
+            ]}"#,
+        )
+        .expect("fixture parses");
+        let t0 = Instant::now();
+        let mut rehearsal = Rehearsal::new(2, t0);
+        rehearsal.record_node_change(0, t0 + secs(20));
+        let report = rehearsal.report(&graph, t0 + secs(95));
+        assert_eq!(
+            report,
+            vec![
+                SlideTime {
+                    id: "a".into(),
+                    spent: secs(75),
+                    budget: Some(secs(60)),
+                },
+                SlideTime {
+                    id: "c".into(),
+                    spent: secs(20),
+                    budget: Some(secs(30)),
+                },
+            ],
+            "b was never visited"
+        );
+        assert_eq!(report[0].overrun(), Some(secs(15)));
+        assert_eq!(report[1].overrun(), None);
+    }
+}
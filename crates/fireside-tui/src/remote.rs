@@ -0,0 +1,123 @@
+//! Remote-control commands — what a clicker bridge or a phone sends over
+//! `fireside present --control`'s socket, one per line.
+//!
+//! Only the parsing lives here, so it is tested without a socket; the
+//! listener, its thread, and every byte of I/O belong to the caller, which
+//! hands parsed commands to the event loop through a
+//! [`RemoteSource`](crate::RemoteSource).
+
+use std::fmt;
+use std::str::FromStr;
+
+/// One line of the control protocol, parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteCommand {
+    /// `next` — the next reveal step or slide, exactly like `→`.
+    Next,
+    /// `back` — the previous reveal step or slide, exactly like `←`.
+    Back,
+    /// `goto N` — jump to slide N, counting from 1 in deck order.
+    Goto(usize),
+    /// `choose KEY` — take the option whose key starts with this character.
+    Choose(char),
+}
+
+impl FromStr for RemoteCommand {
+    type Err = String;
+
+    /// Parses one command line. Surrounding whitespace and the verb's case
+    /// don't matter; anything else malformed comes back as a one-line
+    /// message fit to send straight back to the client.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let Some(verb) = words.next() else {
+            return Err("empty command — try next, back, goto N, or choose KEY".to_owned());
+        };
+        let arg = words.next();
+        if words.next().is_some() {
+            return Err(format!("too many arguments to \"{verb}\""));
+        }
+        let command = match (verb.to_ascii_lowercase().as_str(), arg) {
+            ("next", None) => Self::Next,
+            ("back", None) => Self::Back,
+            ("goto", Some(n)) => match n.parse::<usize>() {
+                Ok(n) if n >= 1 => Self::Goto(n),
+                _ => return Err(format!("\"{n}\" isn't a slide number (1 is the first)")),
+            },
+            ("choose", Some(key)) => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Self::Choose(c),
+                    _ => return Err(format!("\"{key}\" isn't a single-character key")),
+                }
+            }
+            ("next" | "back", Some(_)) => {
+                return Err(format!("\"{verb}\" takes no argument"));
+            }
+            ("goto", None) => return Err("goto needs a slide number".to_owned()),
+            ("choose", None) => return Err("choose needs a key".to_owned()),
+            _ => {
+                return Err(format!(
+                    "unknown command \"{verb}\" — try next, back, goto N, or choose KEY"
+                ));
+            }
+        };
+        Ok(command)
+    }
+}
+
+impl fmt::Display for RemoteCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Next => write!(f, "next"),
+            Self::Back => write!(f, "back"),
+            Self::Goto(n) => write!(f, "goto {n}"),
+            Self::Choose(key) => write!(f, "choose {key}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_verb_parses_with_loose_whitespace_and_case() {
+        assert_eq!("next".parse(), Ok(RemoteCommand::Next));
+        assert_eq!("  BACK \r".parse(), Ok(RemoteCommand::Back));
+        assert_eq!("goto 12".parse(), Ok(RemoteCommand::Goto(12)));
+        assert_eq!("Choose  b".parse(), Ok(RemoteCommand::Choose('b')));
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected_with_a_reason() {
+        for line in [
+            "",
+            "   ",
+            "jump",
+            "next 2",
+            "goto",
+            "goto 0",
+            "goto -1",
+            "goto two",
+            "goto 1 2",
+            "choose",
+            "choose ab",
+        ] {
+            let err = line.parse::<RemoteCommand>().expect_err(line);
+            assert!(!err.is_empty() && !err.contains('\n'), "{line:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn a_command_displays_as_the_line_that_parses_back_to_it() {
+        for command in [
+            RemoteCommand::Next,
+            RemoteCommand::Back,
+            RemoteCommand::Goto(3),
+            RemoteCommand::Choose('x'),
+        ] {
+            assert_eq!(command.to_string().parse(), Ok(command));
+        }
+    }
+}
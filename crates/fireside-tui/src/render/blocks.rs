@@ -6,8 +6,10 @@
 //! side-by-side zip, and centering is a uniform left offset that preserves
 //! the internal alignment of code boxes and lists.
 
-use fireside_core::{ContainerLayout, ContentBlock};
-use ratatui::style::Modifier;
+use std::ops::Range;
+
+use fireside_core::{CodeWrap, ContainerLayout, ContentBlock, DividerStyle};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
@@ -44,15 +46,30 @@ pub fn render_blocks(
     tokens: &Tokens,
     reveal_level: u32,
 ) -> Vec<Line<'static>> {
+    render_blocks_with_extents(blocks, width, tokens, reveal_level).0
+}
+
+/// [`render_blocks`], plus the line range each visible block occupies in
+/// the flow, in order — the gaps between them are the blank separators.
+#[must_use]
+pub(crate) fn render_blocks_with_extents(
+    blocks: &[ContentBlock],
+    width: u16,
+    tokens: &Tokens,
+    reveal_level: u32,
+) -> (Vec<Line<'static>>, Vec<Range<usize>>) {
     let visible = visible_blocks(blocks, reveal_level);
     let mut lines = Vec::new();
+    let mut extents = Vec::with_capacity(visible.len());
     for (i, block) in visible.into_iter().enumerate() {
         if i > 0 {
             lines.push(Line::default());
         }
+        let start = lines.len();
         lines.extend(render_block(block, width, tokens, reveal_level));
+        extents.push(start..lines.len());
     }
-    lines
+    (lines, extents)
 }
 
 fn render_block(
@@ -72,32 +89,61 @@ fn render_block(
             source,
             highlight_lines,
             show_line_numbers,
+            runnable,
+            wrap,
             ..
         } => code(
             language.as_deref(),
             source,
             highlight_lines.as_deref().unwrap_or_default(),
             show_line_numbers.unwrap_or(false),
+            *runnable,
+            wrap.unwrap_or_default(),
+            width,
+            tokens,
+        ),
+        ContentBlock::List { ordered, .. } => list(
+            ordered.unwrap_or(false),
+            block.revealed_items(reveal_level),
             width,
             tokens,
         ),
-        ContentBlock::List { ordered, items, .. } => {
-            list(ordered.unwrap_or(false), items, width, tokens)
-        }
         ContentBlock::Image {
             src, alt, caption, ..
         } => image(src, alt.as_deref(), caption.as_deref(), width, tokens),
-        ContentBlock::Divider { .. } => divider(width, tokens),
+        ContentBlock::Divider { style, label, .. } => {
+            divider(style.unwrap_or_default(), label.as_deref(), width, tokens)
+        }
         ContentBlock::Container {
-            children, layout, ..
+            children,
+            layout,
+            columns,
+            ..
         } => container(
             children,
             layout.unwrap_or_default(),
+            *columns,
             width,
             tokens,
             reveal_level,
         ),
+        ContentBlock::Columns {
+            columns, ratios, ..
+        } => split_columns(columns, ratios.as_deref(), width, tokens, reveal_level),
         ContentBlock::AsciiArt { art, alt, .. } => ascii_art(art, alt.as_deref(), width, tokens),
+        ContentBlock::Video {
+            src,
+            poster,
+            caption,
+            ..
+        } => video(src, poster.as_deref(), caption.as_deref(), width, tokens),
+        ContentBlock::Embed {
+            resolved: Some(inner),
+            ..
+        } => render_block(inner, width, tokens, reveal_level),
+        ContentBlock::Embed { source, .. } => {
+            plate("⧉", &format!("embed: {source}"), None, width, tokens)
+        }
     }
 }
 
@@ -138,17 +184,60 @@ fn heading(level: u8, text: &str, width: u16, tokens: &Tokens) -> Vec<Line<'stat
 
 /// A divider is a pause, not a wall: a short centered rule. The line is
 /// padded on both sides to the full width so that outer containers (e.g.
-/// `center`) never re-center it off axis.
-fn divider(width: u16, tokens: &Tokens) -> Vec<Line<'static>> {
-    let rule = usize::from((width / 3).clamp(2, 24).min(width));
-    let pad = (usize::from(width) - rule) / 2;
-    vec![Line::from(vec![
+/// `center`) never re-center it off axis. A label sits in the middle of
+/// the rule with at least [`DIVIDER_LABEL_RUN`] glyphs either side,
+/// clipped when even it alone is wider than the block.
+fn divider(
+    style: DividerStyle,
+    label: Option<&str>,
+    width: u16,
+    tokens: &Tokens,
+) -> Vec<Line<'static>> {
+    let glyph = match style {
+        DividerStyle::Solid => "─",
+        DividerStyle::Dashed => "╌",
+        DividerStyle::Dotted => "┈",
+        DividerStyle::Double => "═",
+    };
+    let width = usize::from(width);
+    let short = (width / 3).clamp(2, 24).min(width);
+    let label = label.filter(|l| !l.trim().is_empty()).map(|l| {
+        let mut fitted = String::new();
+        let mut used = 0;
+        for c in l.trim().chars() {
+            used += c.width().unwrap_or(0);
+            if used > width.saturating_sub(2) {
+                break;
+            }
+            fitted.push(c);
+        }
+        format!(" {fitted} ")
+    });
+    let label_width = label.as_deref().map_or(0, UnicodeWidthStr::width);
+    let rule = short
+        .max(label_width + 2 * DIVIDER_LABEL_RUN)
+        .min(width)
+        .max(label_width);
+    let left = (rule - label_width) / 2;
+    let right = rule - label_width - left;
+    let pad = (width - rule) / 2;
+    let mut spans = vec![
         Span::raw(" ".repeat(pad)),
-        Span::styled("─".repeat(rule), tokens.border),
-        Span::raw(" ".repeat(usize::from(width) - pad - rule)),
-    ])]
+        Span::styled(glyph.repeat(left), tokens.border),
+    ];
+    if let Some(label) = label {
+        spans.push(Span::styled(label, tokens.muted));
+        spans.push(Span::styled(glyph.repeat(right), tokens.border));
+    } else {
+        spans[1] = Span::styled(glyph.repeat(rule), tokens.border);
+    }
+    spans.push(Span::raw(" ".repeat(width - pad - rule)));
+    vec![Line::from(spans)]
 }
 
+/// The least run of rule either side of a divider's label.
+const DIVIDER_LABEL_RUN: usize = 4;
+
 /// Code blocks with no language, or `"text"`/`"ascii"`, are the only way to
 /// author ASCII art today — they get sized to their content and centered
 /// instead of stretched full-width, which is what a real source listing
@@ -172,11 +261,21 @@ fn centered_box_width<'a>(
     (prefix + content_max).max(label_width).min(full_width)
 }
 
+/// What a `runnable` code block shows in its top rule: the presenter can
+/// copy it (`Y`) to paste into a real shell.
+const RUNNABLE_BADGE: &str = " ▶ copyable ";
+
+/// What starts each row a word-wrapped code line continues onto.
+const CONTINUATION: &str = "↪";
+
+#[allow(clippy::too_many_arguments)]
 fn code(
     language: Option<&str>,
     source: &str,
     highlight: &[u32],
     line_numbers: bool,
+    runnable: bool,
+    wrap: CodeWrap,
     width: u16,
     tokens: &Tokens,
 ) -> Vec<Line<'static>> {
@@ -196,17 +295,33 @@ fn code(
     };
     let prefix = if line_numbers { num_width + 4 } else { 2 };
 
-    let box_width = if is_ascii_art(language) {
-        centered_box_width(label_prefix.width(), source.lines(), prefix, full_width)
+    // Art is sized to itself and only ever clipped: wrapping would tear
+    // the picture apart.
+    let (box_width, wrap) = if is_ascii_art(language) {
+        (
+            centered_box_width(label_prefix.width(), source.lines(), prefix, full_width),
+            CodeWrap::None,
+        )
     } else {
-        full_width
+        (full_width, wrap)
     };
 
+    // A runnable block's badge sits at the right end of the top rule,
+    // dropped when the box is too narrow to keep a rule either side of it.
+    let badge = (runnable && box_width >= label_prefix.width() + RUNNABLE_BADGE.width() + 2)
+        .then_some(RUNNABLE_BADGE);
     let mut top = label_prefix;
-    let fill = box_width.saturating_sub(top.width());
+    let fill = box_width.saturating_sub(top.width() + badge.map_or(0, |b| b.width() + 1));
     top.push_str(&"─".repeat(fill));
 
-    let mut lines = vec![Line::styled(top, tokens.border)];
+    let mut lines = vec![match badge {
+        Some(badge) => Line::from(vec![
+            Span::styled(top, tokens.border),
+            Span::styled(badge.to_owned(), tokens.accent),
+            Span::styled("─".to_owned(), tokens.border),
+        ]),
+        None => Line::styled(top, tokens.border),
+    }];
     let colored = syntax::highlight(language, source, tokens);
     // When the author picked lines to highlight, focus means dimming the
     // rest — the chosen lines keep their full colors.
@@ -215,40 +330,64 @@ fn code(
     for (i, raw) in source.lines().enumerate() {
         let n = i + 1;
         let emphasized = highlight.contains(&(n as u32));
-
-        let mut spans = Vec::new();
-        if line_numbers {
-            let gutter = if emphasized {
-                tokens.accent.add_modifier(Modifier::BOLD)
-            } else {
-                tokens.muted
-            };
-            spans.push(Span::styled(format!(" {n:num_width$} │ "), gutter));
-        } else if emphasized {
-            spans.push(Span::styled("▎ ".to_owned(), tokens.accent));
-        } else {
-            spans.push(Span::styled("  ".to_owned(), tokens.muted));
-        }
         let avail = box_width.saturating_sub(prefix);
 
-        let mut content: Vec<Span<'static>> = match &colored {
-            Some(rows) => clip_spans(rows[i].clone(), avail, tokens),
+        let content: Vec<Span<'static>> = match &colored {
+            Some(rows) => rows[i].clone(),
             None => {
                 let style = if emphasized {
                     tokens.code_highlight
                 } else {
                     tokens.code
                 };
-                vec![Span::styled(clip(raw, avail), style)]
+                vec![Span::styled(raw.to_owned(), style)]
             }
         };
-        if focused && !emphasized {
-            for span in &mut content {
-                span.style = span.style.add_modifier(Modifier::DIM);
+        let rows = match wrap {
+            CodeWrap::None => vec![clip_spans(content, avail, tokens)],
+            CodeWrap::Char | CodeWrap::Word => wrap_spans(content, avail, wrap),
+        };
+        for (row, mut content) in rows.into_iter().enumerate() {
+            // Continuation rows leave the number out; word-wrapped ones
+            // mark the gutter so a soft break never reads as a new line.
+            let mark = if row > 0 && wrap == CodeWrap::Word {
+                CONTINUATION
+            } else {
+                "│"
+            };
+            let mut spans = Vec::new();
+            if line_numbers {
+                let gutter = if emphasized {
+                    tokens.accent.add_modifier(Modifier::BOLD)
+                } else {
+                    tokens.muted
+                };
+                let text = if row == 0 {
+                    format!(" {n:num_width$} {mark} ")
+                } else {
+                    format!(" {:num_width$} {mark} ", "")
+                };
+                spans.push(Span::styled(text, gutter));
+            } else if mark == CONTINUATION {
+                let style = if emphasized {
+                    tokens.accent
+                } else {
+                    tokens.muted
+                };
+                spans.push(Span::styled(format!("{CONTINUATION} "), style));
+            } else if emphasized {
+                spans.push(Span::styled("▎ ".to_owned(), tokens.accent));
+            } else {
+                spans.push(Span::styled("  ".to_owned(), tokens.muted));
+            }
+            if focused && !emphasized {
+                for span in &mut content {
+                    span.style = span.style.add_modifier(Modifier::DIM);
+                }
             }
+            spans.extend(content);
+            lines.push(Line::from(spans));
         }
-        spans.extend(content);
-        lines.push(Line::from(spans));
     }
     lines.push(Line::styled("─".repeat(box_width), tokens.border));
 
@@ -343,8 +482,67 @@ fn clip_spans(spans: Vec<Span<'static>>, width: usize, tokens: &Tokens) -> Vec<S
     out
 }
 
-/// Clip a code line to `width` columns, marking the cut with an ellipsis.
-fn clip(text: &str, width: usize) -> String {
+/// Break a row of styled spans into rows at most `width` columns wide,
+/// keeping each character's style. [`CodeWrap::Word`] breaks after the last
+/// space that fits (dropping a space the break lands on) and falls back to
+/// a character break for a word longer than the whole row; any other mode
+/// breaks at the last character that fits. A row exactly `width` wide stays
+/// one row.
+fn wrap_spans(spans: Vec<Span<'static>>, width: usize, mode: CodeWrap) -> Vec<Vec<Span<'static>>> {
+    let cells: Vec<(char, Style, usize)> = spans
+        .iter()
+        .flat_map(|span| {
+            span.content
+                .chars()
+                .map(|ch| (ch, span.style, UnicodeWidthChar::width(ch).unwrap_or(0)))
+        })
+        .collect();
+    if width == 0 || cells.is_empty() {
+        return vec![Vec::new()];
+    }
+    let mut rows = Vec::new();
+    let mut start = 0;
+    while start < cells.len() {
+        let mut end = start;
+        let mut used = 0;
+        while end < cells.len() && used + cells[end].2 <= width {
+            used += cells[end].2;
+            end += 1;
+        }
+        if end == cells.len() {
+            rows.push(&cells[start..end]);
+            break;
+        }
+        // A character wider than the whole row still has to go somewhere.
+        let end = end.max(start + 1);
+        let (brk, next) = if mode != CodeWrap::Word {
+            (end, end)
+        } else if cells[end].0 == ' ' {
+            (end, end + 1)
+        } else if let Some(space) = (start + 1..end).rev().find(|&k| cells[k].0 == ' ') {
+            (space + 1, space + 1)
+        } else {
+            (end, end)
+        };
+        rows.push(&cells[start..brk]);
+        start = next;
+    }
+    rows.into_iter()
+        .map(|row| {
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            for &(ch, style, _) in row {
+                match spans.last_mut() {
+                    Some(last) if last.style == style => last.content.to_mut().push(ch),
+                    _ => spans.push(Span::styled(ch.to_string(), style)),
+                }
+            }
+            spans
+        })
+        .collect()
+}
+
+/// Clip a line to `width` columns, marking the cut with an ellipsis.
+pub(super) fn clip(text: &str, width: usize) -> String {
     if text.width() <= width {
         return text.to_owned();
     }
@@ -401,7 +599,33 @@ fn image(
     width: u16,
     tokens: &Tokens,
 ) -> Vec<Line<'static>> {
-    let label = alt.unwrap_or(src);
+    plate("▨", alt.unwrap_or(src), caption, width, tokens)
+}
+
+/// A terminal can't play a video: its poster takes the image path, and
+/// without one a `▶` plate names the source.
+fn video(
+    src: &str,
+    poster: Option<&str>,
+    caption: Option<&str>,
+    width: u16,
+    tokens: &Tokens,
+) -> Vec<Line<'static>> {
+    match poster {
+        Some(poster) => image(poster, None, caption, width, tokens),
+        None => plate("▶", &format!("video: {src}"), caption, width, tokens),
+    }
+}
+
+/// The framed, centered placeholder an image or video renders as: `glyph`
+/// in the top border, `label` inside, `caption` beneath.
+fn plate(
+    glyph: &str,
+    label: &str,
+    caption: Option<&str>,
+    width: u16,
+    tokens: &Tokens,
+) -> Vec<Line<'static>> {
     let w = usize::from(width);
     // Too narrow for a frame: a single quiet line.
     if width < 16 {
@@ -426,7 +650,7 @@ fn image(
     let mut lines = vec![Line::from(vec![
         Span::raw(lead.clone()),
         Span::styled("╭─ ".to_owned(), tokens.border),
-        Span::styled("▨".to_owned(), tokens.accent),
+        Span::styled(glyph.to_owned(), tokens.accent),
         Span::styled(format!(" {}╮", "─".repeat(plate_w - 6)), tokens.border),
     ])];
     for row in body {
@@ -461,6 +685,7 @@ fn image(
 fn container(
     children: &[ContentBlock],
     layout: ContainerLayout,
+    grid_columns: Option<u8>,
     width: u16,
     tokens: &Tokens,
     reveal_level: u32,
@@ -469,11 +694,19 @@ fn container(
         ContainerLayout::Stack => render_blocks(children, width, tokens, reveal_level),
         ContainerLayout::Columns => columns(children, width, tokens, reveal_level),
         ContainerLayout::Center => center(children, width, tokens, reveal_level),
+        ContainerLayout::Grid => grid(children, grid_columns, width, tokens, reveal_level),
     }
 }
 
 const GUTTER: u16 = 2;
 
+/// The width of each of `n` side-by-side columns, or `None` when they
+/// would be too narrow to read and the caller should stack instead.
+fn column_width(width: u16, n: u16) -> Option<u16> {
+    let col_width = width.saturating_sub(GUTTER * n.saturating_sub(1)) / n.max(1);
+    (col_width >= 8).then_some(col_width)
+}
+
 /// Side-by-side children: equal column widths, in array order. A child not
 /// yet revealed is excluded before the column count/width is computed, so
 /// it never reserves a blank slot.
@@ -488,16 +721,72 @@ fn columns(
     if n == 0 {
         return Vec::new();
     }
-    let col_width = width.saturating_sub(GUTTER * (n - 1)) / n;
-    if col_width < 8 {
+    let Some(col_width) = column_width(width, n) else {
         // Too narrow to read side by side — gracefully fall back to a stack.
         return render_blocks(children, width, tokens, reveal_level);
-    }
+    };
 
     let cols: Vec<Vec<Line<'static>>> = visible
         .into_iter()
         .map(|c| render_block(c, col_width, tokens, reveal_level))
         .collect();
+    side_by_side(&cols, &vec![col_width; cols.len()])
+}
+
+/// The width of each column of a `columns` block: `width` less the
+/// gutters, shared out by `ratios` (equal shares when there are none, or
+/// not one positive ratio per column), the last column taking any
+/// rounding remainder. `None` when any column would be too narrow to read.
+fn ratio_widths(width: u16, ratios: Option<&[u16]>, n: usize) -> Option<Vec<u16>> {
+    let gutters = GUTTER.saturating_mul(u16::try_from(n.saturating_sub(1)).ok()?);
+    let usable = u32::from(width.saturating_sub(gutters));
+    let ratios: Vec<u32> = match ratios {
+        Some(ratios) if ratios.len() == n && ratios.iter().all(|&r| r > 0) => {
+            ratios.iter().map(|&r| u32::from(r)).collect()
+        }
+        _ => vec![1; n],
+    };
+    let total: u32 = ratios.iter().sum();
+    let mut widths: Vec<u16> = ratios
+        .iter()
+        .map(|r| u16::try_from(usable * r / total.max(1)).unwrap_or(u16::MAX))
+        .collect();
+    let used: u32 = widths.iter().map(|&w| u32::from(w)).sum();
+    if let Some(last) = widths.last_mut() {
+        *last += u16::try_from(usable - used).unwrap_or(0);
+    }
+    widths.iter().all(|&w| w >= 8).then_some(widths)
+}
+
+/// A `columns` block: each column's blocks rendered at its share of the
+/// width and laid side by side. A column keeps its slot even while its
+/// blocks are hidden, so revealing them never shifts its neighbours. Too
+/// narrow to read side by side, the columns stack instead.
+fn split_columns(
+    columns: &[Vec<ContentBlock>],
+    ratios: Option<&[u16]>,
+    width: u16,
+    tokens: &Tokens,
+    reveal_level: u32,
+) -> Vec<Line<'static>> {
+    if columns.is_empty() {
+        return Vec::new();
+    }
+    let Some(widths) = ratio_widths(width, ratios, columns.len()) else {
+        let all: Vec<ContentBlock> = columns.iter().flatten().cloned().collect();
+        return render_blocks(&all, width, tokens, reveal_level);
+    };
+    let cols: Vec<Vec<Line<'static>>> = columns
+        .iter()
+        .zip(&widths)
+        .map(|(blocks, &w)| render_blocks(blocks, w, tokens, reveal_level))
+        .collect();
+    side_by_side(&cols, &widths)
+}
+
+/// Already-rendered columns laid next to each other, each padded to its
+/// own width plus the gutter, as tall as the tallest of them.
+fn side_by_side(cols: &[Vec<Line<'static>>], widths: &[u16]) -> Vec<Line<'static>> {
     let rows = cols.iter().map(Vec::len).max().unwrap_or(0);
 
     let mut lines = Vec::with_capacity(rows);
@@ -516,6 +805,7 @@ fn columns(
                 None => 0,
             };
             if i + 1 < cols.len() {
+                let col_width = widths.get(i).copied().unwrap_or(0);
                 spans.push(Span::raw(
                     " ".repeat((col_width as usize).saturating_sub(used)),
                 ));
@@ -526,6 +816,52 @@ fn columns(
     lines
 }
 
+/// How many cells wide a grid of `n` children is: the container's own
+/// `columns` when set, otherwise the smallest square that holds them all —
+/// never wider than there are children, so a short grid leaves no empty
+/// cells on its only row. `None` for an empty grid.
+fn grid_width(n: usize, columns: Option<u8>) -> Option<usize> {
+    if n == 0 {
+        return None;
+    }
+    let square = (1..=n).find(|c| c * c >= n).unwrap_or(n);
+    let cols = columns.map_or(square, usize::from).clamp(1, n);
+    Some(cols)
+}
+
+/// Equally sized cells, filled row by row in array order with a blank line
+/// between rows. Each child renders at the cell width, so text longer than
+/// its cell wraps inside it rather than running into a neighbour. Like
+/// `columns`, a child not yet revealed is dropped before the grid is sized,
+/// and cells too narrow to read fall back to a stack.
+fn grid(
+    children: &[ContentBlock],
+    columns: Option<u8>,
+    width: u16,
+    tokens: &Tokens,
+    reveal_level: u32,
+) -> Vec<Line<'static>> {
+    let visible = visible_blocks(children, reveal_level);
+    let Some(cols) = grid_width(visible.len(), columns) else {
+        return Vec::new();
+    };
+    let Some(cell_width) = column_width(width, cols as u16) else {
+        return render_blocks(children, width, tokens, reveal_level);
+    };
+    let mut lines = Vec::new();
+    for (i, row) in visible.chunks(cols).enumerate() {
+        if i > 0 {
+            lines.push(Line::default());
+        }
+        let cells: Vec<Vec<Line<'static>>> = row
+            .iter()
+            .map(|c| render_block(c, cell_width, tokens, reveal_level))
+            .collect();
+        lines.extend(side_by_side(&cells, &vec![cell_width; cells.len()]));
+    }
+    lines
+}
+
 /// The visible column span (start, end) of a line's non-space content, or
 /// `None` for a blank line.
 fn content_span(line: &Line<'static>) -> Option<(usize, usize)> {
@@ -637,8 +973,8 @@ fn center(
 /// flow, relative to the container's own row 0 (spec 014). `cols: None`
 /// means the child spans the container's full width, exactly like a
 /// top-level block (`Stack`/`Center`); `Some((x0, x1))` gives its column
-/// sub-range for a side-by-side `Columns` layout, whose children share
-/// the container's rows but not its columns.
+/// sub-range for a side-by-side `Columns` or `Grid` layout, whose
+/// children share the container's rows but not its columns.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct ChildGeometry {
     pub(crate) rows: (usize, usize),
@@ -660,12 +996,19 @@ pub(crate) struct ChildGeometry {
 pub(crate) fn container_child_geometry(
     children: &[ContentBlock],
     layout: ContainerLayout,
+    grid_columns: Option<u8>,
     width: u16,
     tokens: &Tokens,
     reveal_level: u32,
 ) -> Vec<ChildGeometry> {
-    if layout == ContainerLayout::Columns {
-        return columns_child_geometry(children, width, tokens, reveal_level);
+    match layout {
+        ContainerLayout::Columns => {
+            return columns_child_geometry(children, width, tokens, reveal_level);
+        }
+        ContainerLayout::Grid => {
+            return grid_child_geometry(children, grid_columns, width, tokens, reveal_level);
+        }
+        ContainerLayout::Stack | ContainerLayout::Center => {}
     }
     // `Stack` and `Center` both render each child independently (no
     // cross-child layout decision like column-width division), so the
@@ -676,7 +1019,15 @@ pub(crate) fn container_child_geometry(
     let mut out = Vec::with_capacity(children.len());
     let mut prev = 0usize;
     for i in 0..children.len() {
-        let cumulative = container(&children[..=i], layout, width, tokens, reveal_level).len();
+        let cumulative = container(
+            &children[..=i],
+            layout,
+            grid_columns,
+            width,
+            tokens,
+            reveal_level,
+        )
+        .len();
         let start = if i == 0 { 0 } else { prev + 1 };
         out.push(ChildGeometry {
             rows: (start, cumulative),
@@ -703,16 +1054,16 @@ fn columns_child_geometry(
     if n == 0 {
         return Vec::new();
     }
-    let col_width = width.saturating_sub(GUTTER * (n - 1)) / n;
-    if col_width < 8 {
+    let Some(col_width) = column_width(width, n) else {
         return container_child_geometry(
             children,
             ContainerLayout::Stack,
+            None,
             width,
             tokens,
             reveal_level,
         );
-    }
+    };
     let mut out = Vec::with_capacity(children.len());
     let mut x = 0u16;
     for child in children {
@@ -726,6 +1077,48 @@ fn columns_child_geometry(
     out
 }
 
+/// `Grid`'s own child geometry: `columns_child_geometry` once per grid
+/// row, each row starting a blank line below the tallest cell above it —
+/// the same sizing `grid()` draws with, including its stack fallback.
+fn grid_child_geometry(
+    children: &[ContentBlock],
+    columns: Option<u8>,
+    width: u16,
+    tokens: &Tokens,
+    reveal_level: u32,
+) -> Vec<ChildGeometry> {
+    let Some(cols) = grid_width(children.len(), columns) else {
+        return Vec::new();
+    };
+    let Some(cell_width) = column_width(width, cols as u16) else {
+        return container_child_geometry(
+            children,
+            ContainerLayout::Stack,
+            None,
+            width,
+            tokens,
+            reveal_level,
+        );
+    };
+    let mut out = Vec::with_capacity(children.len());
+    let mut top = 0usize;
+    for row in children.chunks(cols) {
+        let mut x = 0u16;
+        let mut bottom = top;
+        for child in row {
+            let rows_len = render_block(child, cell_width, tokens, reveal_level).len();
+            out.push(ChildGeometry {
+                rows: (top, top + rows_len),
+                cols: Some((x, x + cell_width)),
+            });
+            bottom = bottom.max(top + rows_len);
+            x += cell_width + GUTTER;
+        }
+        top = bottom + 1;
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -753,6 +1146,8 @@ mod tests {
     fn h1_gets_an_underline_rule() {
         let block = ContentBlock::Heading {
             reveal: None,
+            id: None,
+            visible_if: None,
             level: 1,
             text: "Hi".into(),
         };
@@ -782,6 +1177,8 @@ mod tests {
 
         let block = ContentBlock::Heading {
             reveal: None,
+            id: None,
+            visible_if: None,
             level: 1,
             text: text.into(),
         };
@@ -801,6 +1198,8 @@ mod tests {
     fn heading_with_cjk_wraps_without_overflowing_narrow_width() {
         let block = ContentBlock::Heading {
             reveal: None,
+            id: None,
+            visible_if: None,
             level: 1,
             text: "你好世界这是一个很长的标题".into(),
         };
@@ -818,6 +1217,8 @@ mod tests {
     fn h2_gets_an_accent_bar() {
         let block = ContentBlock::Heading {
             reveal: None,
+            id: None,
+            visible_if: None,
             level: 2,
             text: "Section".into(),
         };
@@ -828,7 +1229,13 @@ mod tests {
     #[test]
     fn divider_is_a_short_centered_rule() {
         let lines = flat(&render(
-            &ContentBlock::Divider { reveal: None },
+            &ContentBlock::Divider {
+                reveal: None,
+                id: None,
+                visible_if: None,
+                style: None,
+                label: None,
+            },
             30,
             &Tokens::default(),
         ));
@@ -840,14 +1247,34 @@ mod tests {
         assert!((8..=12).contains(&lead), "centered, got lead {lead}");
     }
 
+    #[test]
+    fn labeled_double_divider_centers_its_label_in_the_rule() {
+        let lines = flat(&render(
+            &ContentBlock::Divider {
+                reveal: None,
+                id: None,
+                visible_if: None,
+                style: Some(DividerStyle::Double),
+                label: Some("Part 2".into()),
+            },
+            40,
+            &Tokens::default(),
+        ));
+        assert_eq!(lines, [format!("{0}════ Part 2 ════{0}", " ".repeat(12))]);
+    }
+
     #[test]
     fn code_renders_rules_line_numbers_and_clipping() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: Some("rust".into()),
             source: "fn main() {}\nlet x = 1;".into(),
             highlight_lines: Some(vec![2]),
             show_line_numbers: Some(true),
+            runnable: false,
+            wrap: None,
         };
         let lines = flat(&render(&block, 24, &Tokens::default()));
         assert!(lines[0].starts_with("─ rust "));
@@ -856,16 +1283,101 @@ mod tests {
         assert_eq!(lines.len(), 4);
     }
 
+    #[test]
+    fn code_wrap_breaks_a_line_only_once_it_passes_the_limit() {
+        // Width 12 leaves 10 columns for code after the 2-column gutter.
+        let block = |source: &str, wrap| ContentBlock::Code {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            language: Some("sh".into()),
+            source: source.into(),
+            highlight_lines: None,
+            show_line_numbers: None,
+            runnable: false,
+            wrap: Some(wrap),
+        };
+        let rows = |source, wrap| {
+            let lines = flat(&render(&block(source, wrap), 12, &Tokens::default()));
+            lines[1..lines.len() - 1].to_vec()
+        };
+        for wrap in [CodeWrap::None, CodeWrap::Char, CodeWrap::Word] {
+            assert_eq!(rows("0123456789", wrap), ["  0123456789"], "{wrap:?}");
+        }
+        assert_eq!(rows("01234567890", CodeWrap::None), ["  012345678…"]);
+        assert_eq!(rows("01234567890", CodeWrap::Char), ["  0123456789", "  0"]);
+        assert_eq!(rows("01234567890", CodeWrap::Word), ["  0123456789", "↪ 0"]);
+        assert_eq!(
+            rows("alpha beta gamma", CodeWrap::Word),
+            ["  alpha beta", "↪ gamma"]
+        );
+        assert_eq!(
+            rows("let alpha = beta;", CodeWrap::Word),
+            ["  let alpha ", "↪ = beta;"]
+        );
+        assert_eq!(
+            rows("let alpha = beta;", CodeWrap::Char),
+            ["  let alpha ", "  = beta;"]
+        );
+    }
+
+    #[test]
+    fn word_wrapped_code_marks_continuations_in_the_number_gutter() {
+        let block = ContentBlock::Code {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            language: Some("sh".into()),
+            source: "one two three four".into(),
+            highlight_lines: None,
+            show_line_numbers: Some(true),
+            runnable: false,
+            wrap: Some(CodeWrap::Word),
+        };
+        let lines = flat(&render(&block, 15, &Tokens::default()));
+        assert_eq!(lines[1], " 1 │ one two ");
+        assert_eq!(lines[2], "   ↪ three four");
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn runnable_code_badges_its_top_rule_when_there_is_room() {
+        let block = |width| {
+            let block = ContentBlock::Code {
+                reveal: None,
+                id: None,
+                visible_if: None,
+                language: Some("sh".into()),
+                source: "ls".into(),
+                highlight_lines: None,
+                show_line_numbers: None,
+                runnable: true,
+                wrap: None,
+            };
+            flat(&render(&block, width, &Tokens::default()))
+        };
+        let wide = block(30);
+        assert!(wide[0].starts_with("─ sh ─"), "{wide:?}");
+        assert!(wide[0].ends_with(" ▶ copyable ─"), "{wide:?}");
+        assert_eq!(wide[0].width(), 30);
+        let narrow = block(12);
+        assert!(!narrow[0].contains("copyable"), "{narrow:?}");
+    }
+
     /// P1-3: a gofmt'd (tab-indented) code block must keep its indentation
     /// on screen instead of ratatui silently dropping the raw `\t`.
     #[test]
     fn code_expands_tabs_instead_of_dropping_indentation() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: Some("go".into()),
             source: "func main() {\n\tfmt.Println(\"hi\")\n}".into(),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         let lines = flat(&render(&block, 40, &Tokens::default()));
         assert!(
@@ -884,8 +1396,11 @@ mod tests {
     fn ordered_list_numbers_items_and_indents_wraps() {
         let block = ContentBlock::List {
             reveal: None,
+            id: None,
+            visible_if: None,
             ordered: Some(true),
             items: vec!["first point that wraps onto another line".into()],
+            incremental: false,
         };
         let lines = flat(&render(&block, 24, &Tokens::default()));
         assert!(lines[0].starts_with(" 1. first"));
@@ -896,14 +1411,21 @@ mod tests {
     fn columns_render_side_by_side_in_array_order() {
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Columns),
+            columns: None,
             children: vec![
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "left".into(),
                 },
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "right".into(),
                 },
             ],
@@ -915,6 +1437,36 @@ mod tests {
         assert!(pos_l < pos_r);
     }
 
+    #[test]
+    fn columns_block_splits_the_width_by_its_ratios() {
+        let text = |body: &str| ContentBlock::Text {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            body: body.into(),
+        };
+        let split = |ratios| ContentBlock::Columns {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            columns: vec![vec![text("left")], vec![text("right")]],
+            ratios,
+        };
+        // 32 columns less one gutter leaves 30: 20 and 10 at 2:1.
+        let lines = flat(&render(&split(Some(vec![2, 1])), 32, &Tokens::default()));
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].find("left"), Some(0));
+        assert_eq!(lines[0].find("right"), Some(20 + GUTTER as usize));
+
+        let equal = flat(&render(&split(None), 32, &Tokens::default()));
+        assert_eq!(equal[0].find("right"), Some(15 + GUTTER as usize));
+        let mismatched = flat(&render(&split(Some(vec![3])), 32, &Tokens::default()));
+        assert_eq!(mismatched, equal, "a ratio per column, or equal shares");
+
+        let narrow = flat(&render(&split(Some(vec![2, 1])), 20, &Tokens::default()));
+        assert_eq!(narrow.len(), 3, "too narrow: the columns stack");
+    }
+
     /// Spec 008 US4: a column's right-hand neighbor starts at a fixed
     /// offset (`col_width + GUTTER`) computed purely from the container
     /// width — it must be identical whether the left column holds
@@ -936,14 +1488,21 @@ mod tests {
 
         let build = |left: &str| ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Columns),
+            columns: None,
             children: vec![
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: left.to_owned(),
                 },
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "MARK".into(),
                 },
             ],
@@ -971,18 +1530,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn four_children_make_a_two_by_two_grid_that_wraps_inside_its_cells() {
+        let text = |body: &str| ContentBlock::Text {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            body: body.into(),
+        };
+        let children = vec![
+            text("north"),
+            text("east and then a great deal more east"),
+            text("south"),
+            text("west"),
+        ];
+        let block = ContentBlock::Container {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            layout: Some(ContainerLayout::Grid),
+            columns: None,
+            children: children.clone(),
+        };
+        let tokens = Tokens::default();
+        let lines = flat(&render(&block, 40, &tokens));
+        // 19-column cells: row one's right cell wraps over three lines, and
+        // row two starts a blank line below it.
+        assert!(lines[0].contains("north") && lines[0].contains("east"));
+        let south = lines
+            .iter()
+            .position(|l| l.contains("south"))
+            .expect("south");
+        assert!(south > 2 && lines[south - 1].trim().is_empty());
+        assert!(lines[south].contains("west"));
+        assert_eq!(lines[south].find("west"), lines[0].find("east"));
+        assert!(lines.iter().all(|l| l.width() <= 40), "nothing bleeds past");
+        assert!(lines[..south].iter().all(|l| !l.contains("south")));
+
+        let geometry = container_child_geometry(
+            &children,
+            ContainerLayout::Grid,
+            None,
+            40,
+            &tokens,
+            u32::MAX,
+        );
+        assert_eq!(geometry[1].cols, Some((21, 40)));
+        assert_eq!(geometry[3].rows.0, south);
+        assert_eq!(geometry[3].cols, geometry[1].cols);
+    }
+
+    #[test]
+    fn grid_columns_hint_sets_the_row_length() {
+        assert_eq!(grid_width(4, None), Some(2));
+        assert_eq!(grid_width(5, None), Some(3));
+        assert_eq!(grid_width(4, Some(4)), Some(4));
+        assert_eq!(
+            grid_width(2, Some(3)),
+            Some(2),
+            "never wider than it is full"
+        );
+        assert_eq!(grid_width(0, Some(2)), None);
+    }
+
     #[test]
     fn narrow_columns_fall_back_to_stack() {
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Columns),
+            columns: None,
             children: vec![
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "left".into(),
                 },
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "right".into(),
                 },
             ],
@@ -995,9 +1624,14 @@ mod tests {
     fn center_offsets_content_into_the_middle() {
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Center),
+            columns: None,
             children: vec![ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "hi".into(),
             }],
         };
@@ -1011,13 +1645,20 @@ mod tests {
     fn centered_code_keeps_its_internal_alignment() {
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Center),
+            columns: None,
             children: vec![ContentBlock::Code {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 language: None,
                 source: "short\na longer line".into(),
                 highlight_lines: None,
                 show_line_numbers: None,
+                runnable: false,
+                wrap: None,
             }],
         };
         let lines = flat(&render(&block, 40, &Tokens::default()));
@@ -1045,13 +1686,20 @@ mod tests {
         let source = format!("{}\n{}", "x".repeat(20), "y".repeat(10));
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Center),
+            columns: None,
             children: vec![ContentBlock::Code {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 language: None,
                 source,
                 highlight_lines: None,
                 show_line_numbers: None,
+                runnable: false,
+                wrap: None,
             }],
         };
         let width: usize = 60;
@@ -1085,8 +1733,11 @@ mod tests {
     fn image_renders_a_framed_plate_with_caption() {
         let block = ContentBlock::Image {
             reveal: None,
+            id: None,
+            visible_if: None,
             src: "fire.png".into(),
             alt: Some("A campfire".into()),
+            decorative: false,
             caption: Some("Warm".into()),
             width: None,
             height: None,
@@ -1102,12 +1753,52 @@ mod tests {
         assert!(lead > 0, "centered plate: {lines:?}");
     }
 
+    #[test]
+    fn video_without_a_poster_renders_a_play_plate() {
+        let block = ContentBlock::Video {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            src: "demo.mp4".into(),
+            poster: None,
+            caption: Some("The demo".into()),
+        };
+        let lines = flat(&render(&block, 40, &Tokens::default()));
+        assert!(
+            lines[0].contains("╭─ ▶"),
+            "play glyph in the frame: {lines:?}"
+        );
+        assert!(
+            lines[1].contains("video: demo.mp4"),
+            "source named: {lines:?}"
+        );
+        assert!(lines[3].contains("The demo"), "caption beneath: {lines:?}");
+
+        let with_poster = ContentBlock::Video {
+            reveal: None,
+            id: None,
+            visible_if: None,
+            src: "demo.mp4".into(),
+            poster: Some("demo.png".into()),
+            caption: None,
+        };
+        let lines = flat(&render(&with_poster, 40, &Tokens::default()));
+        assert!(
+            lines[0].contains("╭─ ▨"),
+            "poster takes the image path: {lines:?}"
+        );
+        assert!(lines[1].contains("demo.png"), "{lines:?}");
+    }
+
     #[test]
     fn narrow_image_falls_back_to_a_quiet_line() {
         let block = ContentBlock::Image {
             reveal: None,
+            id: None,
+            visible_if: None,
             src: "fire.png".into(),
             alt: Some("A campfire".into()),
+            decorative: false,
             caption: None,
             width: None,
             height: None,
@@ -1121,10 +1812,14 @@ mod tests {
     fn ascii_art_code_block_centers_to_its_content_width() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: None,
             source: " /\\_/\\ \n( o.o )\n > ^ < ".into(),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         let lines = flat(&render(&block, 40, &Tokens::default()));
         let box_width = lines.iter().map(|l| l.width()).max().unwrap_or(0);
@@ -1154,10 +1849,14 @@ mod tests {
         let none_lines = flat(&render(
             &ContentBlock::Code {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 language: None,
                 source: source.into(),
                 highlight_lines: None,
                 show_line_numbers: None,
+                runnable: false,
+                wrap: None,
             },
             40,
             &Tokens::default(),
@@ -1166,10 +1865,14 @@ mod tests {
             let lines = flat(&render(
                 &ContentBlock::Code {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     language: Some(lang.into()),
                     source: source.into(),
                     highlight_lines: None,
                     show_line_numbers: None,
+                    runnable: false,
+                    wrap: None,
                 },
                 40,
                 &Tokens::default(),
@@ -1187,10 +1890,14 @@ mod tests {
     fn explicit_language_code_block_stays_full_width() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: Some("rust".into()),
             source: " /\\_/\\ \n( o.o )\n > ^ < ".into(),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         let lines = flat(&render(&block, 40, &Tokens::default()));
         assert!(lines[0].starts_with("─ rust "), "{lines:?}");
@@ -1216,10 +1923,14 @@ mod tests {
         let long_line = "x".repeat(200);
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: None,
             source: long_line,
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         let lines = flat(&render(&block, 30, &Tokens::default()));
         let box_width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
@@ -1231,10 +1942,14 @@ mod tests {
     fn ascii_art_never_panics_across_a_range_of_widths() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: None,
             source: "x".repeat(200),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         for width in [0u16, 1, 2, 5, 10, 40, 200] {
             let _ = render(&block, width, &Tokens::default());
@@ -1245,10 +1960,14 @@ mod tests {
     fn empty_ascii_art_code_block_does_not_collapse_or_panic() {
         let block = ContentBlock::Code {
             reveal: None,
+            id: None,
+            visible_if: None,
             language: None,
             source: String::new(),
             highlight_lines: None,
             show_line_numbers: None,
+            runnable: false,
+            wrap: None,
         };
         let lines = flat(&render(&block, 40, &Tokens::default()));
         assert!(
@@ -1263,6 +1982,8 @@ mod tests {
     fn ascii_art_block_renders_unframed_with_alt_as_caption() {
         let block = ContentBlock::AsciiArt {
             reveal: None,
+            id: None,
+            visible_if: None,
             art: " /\\_/\\ \n( o.o )\n > ^ < ".into(),
             alt: Some("A sleepy cat".into()),
         };
@@ -1286,6 +2007,8 @@ mod tests {
     fn ascii_art_block_without_alt_has_no_caption() {
         let block = ContentBlock::AsciiArt {
             reveal: None,
+            id: None,
+            visible_if: None,
             art: " /\\_/\\ \n( o.o )\n > ^ < ".into(),
             alt: None,
         };
@@ -1310,10 +2033,14 @@ mod tests {
         let blocks = vec![
             ContentBlock::Text {
                 reveal: None,
+                id: None,
+                visible_if: None,
                 body: "always".into(),
             },
             ContentBlock::Text {
                 reveal: Some(1),
+                id: None,
+                visible_if: None,
                 body: "first reveal".into(),
             },
         ];
@@ -1327,14 +2054,21 @@ mod tests {
     fn hidden_column_reserves_no_width_until_revealed() {
         let block = ContentBlock::Container {
             reveal: None,
+            id: None,
+            visible_if: None,
             layout: Some(ContainerLayout::Columns),
+            columns: None,
             children: vec![
                 ContentBlock::Text {
                     reveal: None,
+                    id: None,
+                    visible_if: None,
                     body: "left".into(),
                 },
                 ContentBlock::Text {
                     reveal: Some(1),
+                    id: None,
+                    visible_if: None,
                     body: "right".into(),
                 },
             ],
@@ -0,0 +1,119 @@
+//! The travelled route as a breadcrumb, compacted to fit: a long walk
+//! keeps its first stop, the last two, and every branch point it chose at,
+//! and middle-ellipses the rest — `Intro → … → Deep → Current`.
+
+/// One place in a breadcrumb.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Segment {
+    /// A slide on the route, by title (or id).
+    Stop {
+        title: String,
+        /// The slide asks for a choice, so the route forked here.
+        fork: bool,
+    },
+    /// One or more stops left out.
+    Gap,
+}
+
+/// `segments` with no more than `max` stops — never fewer than three, the
+/// first and the last two — replacing each run of dropped stops with one
+/// [`Segment::Gap`]. The first, the last two, and every fork stay; the
+/// most recent forks win when there are more than `max` allows, and any
+/// room left over goes to the stops nearest the end.
+#[must_use]
+pub(crate) fn compact_breadcrumb(segments: &[Segment], max: usize) -> Vec<Segment> {
+    let n = segments.len();
+    let max = max.max(3);
+    if n <= max {
+        return segments.to_vec();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 2] = true;
+    keep[n - 1] = true;
+    let mut room = max - 3;
+    let forks = (1..n - 2).rev().filter(|&i| is_fork(&segments[i]));
+    let rest = (1..n - 2).rev().filter(|&i| !is_fork(&segments[i]));
+    for i in forks.chain(rest) {
+        if room == 0 {
+            break;
+        }
+        keep[i] = true;
+        room -= 1;
+    }
+
+    let mut out = Vec::with_capacity(max + 2);
+    for (segment, kept) in segments.iter().zip(keep) {
+        if kept {
+            out.push(segment.clone());
+        } else if out.last() != Some(&Segment::Gap) {
+            out.push(Segment::Gap);
+        }
+    }
+    out
+}
+
+fn is_fork(segment: &Segment) -> bool {
+    matches!(segment, Segment::Stop { fork: true, .. })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(forks: &[usize], len: usize) -> Vec<Segment> {
+        (0..len)
+            .map(|i| Segment::Stop {
+                title: format!("s{i}"),
+                fork: forks.contains(&i),
+            })
+            .collect()
+    }
+
+    fn titles(segments: &[Segment]) -> Vec<&str> {
+        segments
+            .iter()
+            .map(|s| match s {
+                Segment::Stop { title, .. } => title.as_str(),
+                Segment::Gap => "…",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_short_route_is_left_alone() {
+        let route = path(&[], 4);
+        assert_eq!(compact_breadcrumb(&route, 4), route);
+    }
+
+    #[test]
+    fn a_deep_route_compacts_to_the_limit_keeping_its_forks_and_ends() {
+        let route = path(&[2, 5], 10);
+        let crumbs = compact_breadcrumb(&route, 5);
+        assert_eq!(
+            titles(&crumbs),
+            ["s0", "…", "s2", "…", "s5", "…", "s8", "s9"]
+        );
+        let stops = crumbs.iter().filter(|s| **s != Segment::Gap).count();
+        assert_eq!(stops, 5);
+
+        let crumbs = compact_breadcrumb(&route, 7);
+        assert_eq!(
+            titles(&crumbs),
+            ["s0", "…", "s2", "…", "s5", "s6", "s7", "s8", "s9"]
+        );
+    }
+
+    #[test]
+    fn more_forks_than_room_keeps_the_most_recent_and_never_the_floor() {
+        let route = path(&[1, 3, 5, 7], 10);
+        assert_eq!(
+            titles(&compact_breadcrumb(&route, 4)),
+            ["s0", "…", "s7", "s8", "s9"]
+        );
+        assert_eq!(
+            titles(&compact_breadcrumb(&route, 0)),
+            ["s0", "…", "s8", "s9"]
+        );
+    }
+}
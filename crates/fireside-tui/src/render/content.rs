@@ -2,17 +2,22 @@
 //! end-of-path marker), the card/notes-panel geometry around them, and the
 //! "▲/▼ more" scroll indicators.
 
-use fireside_core::{Node, ViewMode};
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+
+use fireside_core::{BranchOption, ContentBlock, Node, ViewMode};
 use ratatui::Frame;
 use ratatui::layout::{Margin, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+use unicode_width::UnicodeWidthStr;
 
 use crate::app::App;
 use crate::theme::Tokens;
 
-use super::{PAD_X, PAD_Y, Surface, blocks, markdown, surface};
+use super::breadcrumb::{Segment, compact_breadcrumb};
+use super::{PAD_X, PAD_Y, Surface, blocks, markdown, reflow, surface};
 
 /// Everything the content-rendering path needs to draw one slide, decoupled
 /// from `App`/`Session` — the seam that lets the authoring editor's canvas
@@ -26,40 +31,68 @@ use super::{PAD_X, PAD_Y, Surface, blocks, markdown, surface};
 /// same layout, not a parallel guess at it.
 pub(crate) struct SlideView<'a> {
     pub(crate) node: &'a Node,
+    /// The blocks to draw: `node`'s own, less any its `visible-if` hides
+    /// for the choices made so far. Every block in the editor.
+    pub(crate) content: Cow<'a, [ContentBlock]>,
     pub(crate) reveal_level: u32,
     pub(crate) has_pending_reveal: bool,
     pub(crate) branch_selected: usize,
     pub(crate) fading: bool,
     pub(crate) scroll: u16,
     pub(crate) view_mode: ViewMode,
-    /// Titles (or ids) of nodes visited before `node`, oldest first — feeds
+    /// The presenter's `--max-width` cap; always `None` in the editor.
+    pub(crate) max_width: Option<u16>,
+    /// The route travelled to `node`, oldest first and ending at `node`
+    /// itself, already compacted to the presenter's `--route-stops` — feeds
     /// the end-marker's route trace. Empty when there is no traversal
     /// history to show — always true for the editor's at-rest canvas,
     /// which has never "traveled" anywhere; `end_marker` already handles
     /// this the same way a fresh session landing immediately on an ending
     /// does.
-    pub(crate) history_titles: Vec<String>,
+    pub(crate) route: Vec<Segment>,
+    /// The visible block the presenter spotlit with `o`; every other block
+    /// dims. Always `None` in the editor.
+    pub(crate) spotlight: Option<usize>,
+    /// The branch option chosen last time the presenter was at `node`,
+    /// marked "✓ last chosen". Always `None` in the editor.
+    pub(crate) last_choice: Option<usize>,
+    /// What's left of a timed choice's countdown, shown under its
+    /// options. Always `None` in the editor.
+    pub(crate) choice_left: Option<Duration>,
 }
 
 impl<'a> SlideView<'a> {
     pub(super) fn from_app(app: &'a App) -> Self {
         let session = app.session();
         let graph = session.graph();
-        let history_titles = session
+        let stop = |n: &Node| Segment::Stop {
+            title: n.title.clone().unwrap_or_else(|| n.id.clone()),
+            fork: n.branch_point().is_some(),
+        };
+        let mut route: Vec<Segment> = session
             .history()
             .iter()
             .filter_map(|id| graph.node(id))
-            .map(|n| n.title.clone().unwrap_or_else(|| n.id.clone()))
+            .map(stop)
             .collect();
+        if !route.is_empty() {
+            route.push(stop(session.current()));
+            route = compact_breadcrumb(&route, app.route_stops());
+        }
         Self {
             node: session.current(),
+            content: session.visible_content(),
             reveal_level: session.reveal_level(),
             has_pending_reveal: session.has_pending_reveal(),
             branch_selected: app.branch_selected(),
             fading: app.fading(),
             scroll: app.scroll(),
             view_mode: app.view_mode(),
-            history_titles,
+            max_width: app.content_max_width(),
+            route,
+            spotlight: app.spotlight(),
+            last_choice: session.last_choice(&session.current().id),
+            choice_left: app.choice_left(Instant::now()),
         }
     }
 }
@@ -80,7 +113,15 @@ pub(crate) struct NodeLines {
 /// end-of-path marker.
 pub(crate) fn node_lines(view: &SlideView, width: u16, tokens: &Tokens) -> NodeLines {
     let node = view.node;
-    let mut lines = blocks::render_blocks(&node.content, width, tokens, view.reveal_level);
+    let (mut lines, extents) =
+        reflow::node_flow(&node.id, &view.content, width, tokens, view.reveal_level);
+    if let Some(lit) = view.spotlight.and_then(|i| extents.get(i)) {
+        for (i, line) in lines.iter_mut().enumerate() {
+            if !lit.contains(&i) {
+                dim(line);
+            }
+        }
+    }
     let mut option_rows = Vec::new();
 
     let pending_reveal = view.has_pending_reveal;
@@ -96,6 +137,7 @@ pub(crate) fn node_lines(view: &SlideView, width: u16, tokens: &Tokens) -> NodeL
             tokens,
         ));
         lines.push(Line::default());
+        let columns = OptionColumns::measure(&bp.options, width);
         for (i, opt) in bp.options.iter().enumerate() {
             let selected = i == view.branch_selected;
             let mut spans = vec![
@@ -104,28 +146,53 @@ pub(crate) fn node_lines(view: &SlideView, width: u16, tokens: &Tokens) -> NodeL
                 } else {
                     Span::raw("   ".to_owned())
                 },
-                Span::styled(format!("{}. ", i + 1), tokens.muted),
+                Span::styled(format!("{:>w$}. ", i + 1, w = columns.number), tokens.muted),
             ];
-            let label_style = if selected {
+            if columns.icon > 0 {
+                let icon = opt.icon.as_deref().unwrap_or_default();
+                spans.push(Span::styled(pad(icon, columns.icon + 1), tokens.text));
+            }
+            let mut label_style = if selected {
                 tokens.selected
             } else {
                 tokens.text
             };
-            spans.push(Span::styled(format!(" {} ", opt.label), label_style));
+            // A label with a description under it reads as its heading.
+            if opt.description.is_some() {
+                label_style = label_style.add_modifier(Modifier::BOLD);
+            }
+            let label = pad(&blocks::clip(&opt.label, columns.label), columns.label);
+            spans.push(Span::styled(format!(" {label} "), label_style));
             if let Some(key) = &opt.key {
                 spans.push(Span::styled(format!("  [{key}]"), tokens.muted));
             }
+            if view.last_choice == Some(i) {
+                spans.push(Span::styled("  ✓ last chosen".to_owned(), tokens.muted));
+            }
             option_rows.push(lines.len());
             lines.push(Line::from(spans));
             if let Some(desc) = &opt.description {
-                for d in markdown::wrap_styled(desc, width.saturating_sub(7), tokens.muted, tokens)
-                {
-                    let mut spans = vec![Span::raw("       ".to_owned())];
+                let indent = columns.label_start();
+                let desc_width = width.saturating_sub(indent as u16);
+                for d in markdown::wrap_styled(desc, desc_width, tokens.muted, tokens) {
+                    let mut spans = vec![Span::raw(" ".repeat(indent))];
                     spans.extend(d.spans);
                     lines.push(Line::from(spans));
                 }
             }
         }
+        if let Some(left) = view.choice_left {
+            let fallback = bp.options.get(bp.default_index());
+            let seconds = left.as_millis().div_ceil(1000);
+            let then = fallback.map_or(String::new(), |o| format!(" — then \"{}\"", o.label));
+            lines.push(Line::default());
+            lines.extend(markdown::wrap_styled(
+                &format!("{seconds}s to choose{then}"),
+                width,
+                tokens.muted,
+                tokens,
+            ));
+        }
     } else if node.is_terminal() && !pending_reveal {
         if !lines.is_empty() {
             lines.push(Line::default());
@@ -135,6 +202,66 @@ pub(crate) fn node_lines(view: &SlideView, width: u16, tokens: &Tokens) -> NodeL
     NodeLines { lines, option_rows }
 }
 
+/// Display widths of the branch menu's columns — number, icon, label —
+/// each sized to its widest option so every row lines up. Widths are
+/// terminal cells, so a double-width emoji or CJK icon or label pads the
+/// same as any other. A label too wide for what's left of `width` after
+/// the other columns is clipped with an ellipsis.
+struct OptionColumns {
+    number: usize,
+    /// 0 when no option has an icon: the column isn't drawn at all.
+    icon: usize,
+    label: usize,
+}
+
+impl OptionColumns {
+    fn measure(options: &[BranchOption], width: u16) -> Self {
+        let number = options.len().to_string().len();
+        let icon = options
+            .iter()
+            .filter_map(|o| o.icon.as_deref())
+            .map(UnicodeWidthStr::width)
+            .max()
+            .unwrap_or(0);
+        let key = options
+            .iter()
+            .filter_map(|o| o.key.as_deref())
+            .map(|k| k.width() + 4) // "  [" + "]"
+            .max()
+            .unwrap_or(0);
+        let widest = options.iter().map(|o| o.label.width()).max().unwrap_or(0);
+        let mut columns = Self {
+            number,
+            icon,
+            label: 0,
+        };
+        let fixed = columns.label_start() + 1 + key;
+        columns.label = widest.min((width as usize).saturating_sub(fixed)).max(1);
+        columns
+    }
+
+    /// The column a label's first character sits at — where a
+    /// description's wrapped lines indent to.
+    fn label_start(&self) -> usize {
+        let icon = if self.icon > 0 { self.icon + 1 } else { 0 };
+        3 + self.number + 2 + icon + 1
+    }
+}
+
+/// `text` padded with spaces to `width` display cells.
+fn pad(text: &str, width: usize) -> String {
+    format!("{text}{}", " ".repeat(width.saturating_sub(text.width())))
+}
+
+/// Dims every span of `line`, keeping its colors — a block out of the
+/// spotlight stays readable, just quieter.
+fn dim(line: &mut Line<'static>) {
+    line.style = line.style.add_modifier(Modifier::DIM);
+    for span in &mut line.spans {
+        span.style = span.style.add_modifier(Modifier::DIM);
+    }
+}
+
 /// The content card/flow's inner rect for a line flow of `total` lines —
 /// pure geometry, no drawing. Shared by `draw_content` (which additionally
 /// paints the card border) and mouse hit-testing (which only needs to know
@@ -199,23 +326,16 @@ fn end_marker(view: &SlideView, width: u16, tokens: &Tokens) -> Vec<Line<'static
         ]),
     ];
 
-    let current_title = view.node.title.as_deref().unwrap_or(&view.node.id);
-    let mut stations: Vec<&str> = view
-        .history_titles
-        .iter()
-        .map(String::as_str)
-        .chain([current_title])
-        .collect();
-    if stations.len() > 1 {
-        // Long journeys keep their tail: the recent stops tell the story.
-        let overflow = stations.len() > 8;
-        if overflow {
-            stations.drain(..stations.len() - 8);
-        }
-        let mut trace = stations.join(" → ");
-        if overflow {
-            trace = format!("… → {trace}");
-        }
+    if view.route.len() > 1 {
+        let trace = view
+            .route
+            .iter()
+            .map(|segment| match segment {
+                Segment::Stop { title, .. } => title.as_str(),
+                Segment::Gap => "…",
+            })
+            .collect::<Vec<_>>()
+            .join(" → ");
         lines.push(Line::default());
         for row in markdown::wrap_styled(
             &trace,
@@ -233,7 +353,7 @@ fn end_marker(view: &SlideView, width: u16, tokens: &Tokens) -> Vec<Line<'static
 }
 
 pub(super) fn draw_content(frame: &mut Frame, body: Rect, view: &SlideView, tokens: &Tokens) {
-    let surf = surface(view.view_mode, body);
+    let surf = surface(view.view_mode, body, view.max_width);
     let NodeLines { lines, .. } = node_lines(view, surf.width, tokens);
     let total = lines.len() as u16;
     // During a fade-in the whole slide starts dim and brightens.
@@ -314,10 +434,18 @@ pub(super) fn draw_notes(frame: &mut Frame, area: Rect, app: &App, tokens: &Toke
         .speaker_notes
         .clone()
         .unwrap_or_default();
+    // What `next` leads to, so the presenter can set it up before moving.
+    let coming = app.session().peek_next().map_or_else(String::new, |i| {
+        let node = &app.session().graph().nodes[i];
+        format!("· next: {} ", node.title.as_deref().unwrap_or(&node.id))
+    });
     let block = Block::default()
         .borders(Borders::TOP)
         .border_style(tokens.border)
-        .title(Span::styled(" Notes — s hides ".to_owned(), tokens.muted));
+        .title(Span::styled(
+            format!(" Notes — s hides {coming}"),
+            tokens.muted,
+        ));
     let inner = block.inner(area);
     frame.render_widget(block, area);
     let lines = markdown::wrap_styled(&notes, inner.width, tokens.muted, tokens);
@@ -5,10 +5,12 @@
 //! step: the editor shows the whole slide at once, badges (not omission)
 //! are how later stories (US3, T053) will mark staged content.
 
+use std::borrow::Cow;
+
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Rect};
 use ratatui::text::Span;
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Block, Paragraph};
 
 use crate::editor::hit;
 use crate::editor::{DragState, EditorApp, Selection};
@@ -23,17 +25,26 @@ pub(super) fn draw(frame: &mut Frame, area: Rect, app: &EditorApp, tokens: &Toke
         );
         return;
     };
+    // The preview wears the slide's own colors; the studio around it
+    // keeps the theme's.
+    let tokens = &tokens.for_node(node);
+    frame.render_widget(Block::new().style(tokens.background), area);
     let is_empty = node.content.is_empty();
     let view_mode = node.resolved_view_mode(app.working_graph().defaults.as_ref());
     let view = SlideView {
         node,
+        content: Cow::Borrowed(&node.content),
         reveal_level: u32::MAX,
         has_pending_reveal: false,
         branch_selected: 0,
         fading: false,
         scroll: app.scroll(),
         view_mode,
-        history_titles: Vec::new(),
+        max_width: None,
+        route: Vec::new(),
+        spotlight: None,
+        last_choice: None,
+        choice_left: None,
     };
     draw_content(frame, area, &view, tokens);
     draw_selection_marker(frame, area, app, tokens);
@@ -77,7 +77,8 @@ fn field_for(form: &FormState, slot: hit::FieldSlot) -> (&EditableField, bool) {
         (
             FormState::Heading { field, .. }
             | FormState::Text { field, .. }
-            | FormState::List { field, .. },
+            | FormState::List { field, .. }
+            | FormState::Video { src: field, .. },
             _,
         ) => (field, true),
         (
@@ -55,7 +55,7 @@ pub(crate) fn draw(frame: &mut Frame, app: &EditorApp) {
     // Drawn last so it sits on top of everything else, exactly like the
     // help overlay (spec 013 US4, FR-019).
     if app.quit_prompt() {
-        draw_quit_prompt(frame, area, &tokens);
+        draw_quit_prompt(frame, area, app, &tokens);
     }
 }
 
@@ -215,6 +215,11 @@ fn draw_hint(frame: &mut Frame, area: Rect, app: &EditorApp, tokens: &Tokens) {
             };
             spans.push(Span::styled(label.clone(), style));
         }
+        // After the chips, so it never shifts what a click lands on.
+        if let Some(node) = hit::selected_node(app) {
+            let settings = hit::slide_settings(app.working_graph(), node);
+            spans.push(Span::styled(format!("  \u{b7}  {settings}"), tokens.muted));
+        }
         frame.render_widget(Paragraph::new(Line::from(spans)), area);
         return;
     }
@@ -265,9 +270,14 @@ fn draw_help(frame: &mut Frame, area: Rect, tokens: &Tokens) {
         Line::from("Enter             edit the selected block"),
         Line::from("n                 new slide \u{b7} c turn into/back a choice"),
         Line::from("a                 add an answer \u{b7} g change where it goes"),
+        Line::from("1-9 / e           edit a choice's answer / its prompt"),
         Line::from("r                 cycle the selected block's reveal step"),
+        Line::from("l                 turn text into a list, or back"),
+        Line::from("f                 find and replace across the deck"),
+        Line::from("d                 the deck's title, author, date"),
+        Line::from("C, then C         copy a slide's blocks onto another"),
         Line::from("1-9, n, e         in a picker: pick a row, a new slide, or an ending"),
-        Line::from("Ctrl+S            save \u{b7} u/U undo"),
+        Line::from("Ctrl+S            save \u{b7} u/U undo/redo"),
         Line::from("p                 present from the selected slide"),
         Line::from("\u{2191}/\u{2193}, wheel       scroll the canvas"),
         Line::from("Esc               deselect"),
@@ -287,7 +297,7 @@ fn draw_help(frame: &mut Frame, area: Rect, tokens: &Tokens) {
 /// The quit-with-unsaved-changes prompt (spec 013 US4, FR-019) — its
 /// chips draw into exactly the rects `hit::quit_prompt_chip_rects`
 /// resolves clicks against, so drawing and hit-testing can never disagree.
-fn draw_quit_prompt(frame: &mut Frame, area: Rect, tokens: &Tokens) {
+fn draw_quit_prompt(frame: &mut Frame, area: Rect, app: &EditorApp, tokens: &Tokens) {
     let rect = hit::quit_prompt_rect(area);
     frame.render_widget(Clear, rect);
     let block = Block::bordered()
@@ -295,8 +305,19 @@ fn draw_quit_prompt(frame: &mut Frame, area: Rect, tokens: &Tokens) {
         .border_style(tokens.border);
     let inner = block.inner(rect);
     frame.render_widget(block, rect);
+    // Moving slides or retitling the deck changes no slide; say so rather
+    // than "no slides changed".
+    let summary = app.change_summary();
+    let summary = if summary.is_empty() {
+        "Only the deck's order or details changed.".to_owned()
+    } else {
+        format!("{summary}.")
+    };
     frame.render_widget(
-        Paragraph::new(Span::styled("You have unsaved changes.", tokens.accent)),
+        Paragraph::new(vec![
+            Line::from(Span::styled("You have unsaved changes.", tokens.accent)),
+            Line::from(Span::styled(summary, tokens.muted)),
+        ]),
         inner,
     );
     for (action, chip_area) in hit::quit_prompt_chip_rects(area) {
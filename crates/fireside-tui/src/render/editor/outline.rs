@@ -1,15 +1,18 @@
 //! The outline pane: every slide in the deck's depth-first display order
-//! (`fireside_engine::authoring::outline_order`), a divider before any
-//! slide not yet reachable from the start, and the permanent
-//! "+ new slide" row (spec 013).
+//! (`fireside_engine::authoring::outline_order`), a header wherever the
+//! section changes, a divider before any slide not yet reachable from the
+//! start, and the permanent "+ new slide" row (spec 013).
 
+use fireside_core::Node;
 use ratatui::Frame;
 use ratatui::layout::Rect;
+use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::editor::hit::OutlineLine;
 use crate::editor::{EditorApp, Selection, hit};
+use crate::render::icon::node_icon;
 use crate::theme::Tokens;
 
 pub(super) fn draw(frame: &mut Frame, area: Rect, app: &EditorApp, tokens: &Tokens) {
@@ -29,21 +32,17 @@ fn render_line(item: &OutlineLine, app: &EditorApp, tokens: &Tokens) -> Line<'st
             " \u{2500}\u{2500} not linked yet \u{2500}\u{2500}",
             tokens.muted,
         )),
+        OutlineLine::Section(name) => Line::from(Span::styled(
+            format!(" {name}"),
+            tokens.accent.add_modifier(Modifier::BOLD),
+        )),
         OutlineLine::NewSlide => Line::from(Span::styled(" + new slide", tokens.affordance)),
         OutlineLine::Row(row) => {
             let node = app.working_graph().node(&row.node_id);
             let title = node
                 .and_then(|n| n.title.clone())
                 .unwrap_or_else(|| row.node_id.clone());
-            let marker = node.map_or(' ', |n| {
-                if n.branch_point().is_some() {
-                    '\u{2442}'
-                } else if n.is_terminal() {
-                    '\u{25a0}'
-                } else {
-                    ' '
-                }
-            });
+            let icon = node.map_or(' ', node_icon);
             let selected = matches!(
                 app.selection(),
                 Selection::Slide(id) | Selection::Block(id, _) if *id == row.node_id
@@ -56,11 +55,20 @@ fn render_line(item: &OutlineLine, app: &EditorApp, tokens: &Tokens) -> Line<'st
             } else {
                 tokens.text
             };
-            Line::from(vec![
+            let mut spans = vec![
                 Span::styled(format!(" {:>2} ", row.display_number), tokens.muted),
+                Span::styled(format!("{icon} "), tokens.muted),
                 Span::styled(format!("{title} "), style),
-                Span::styled(marker.to_string(), tokens.muted),
-            ])
+            ];
+            // Hidden slides stay in the outline, always — only the
+            // presenter steps over them.
+            if node.is_some_and(|n| n.hidden) {
+                spans.push(Span::styled("hidden ", tokens.muted));
+            }
+            if node.is_some_and(Node::is_terminal) {
+                spans.push(Span::styled("\u{25a0}", tokens.muted));
+            }
+            Line::from(spans)
         }
     }
 }
@@ -1,5 +1,7 @@
 //! The footer: contextual key hints, flash messages, and the optional
-//! elapsed-time display.
+//! side countdown and elapsed-time displays.
+
+use std::time::{Duration, Instant};
 
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Rect};
@@ -8,7 +10,8 @@ use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::Paragraph;
 use unicode_width::UnicodeWidthStr;
 
-use crate::app::{App, FlashKind};
+use crate::app::{App, FlashKind, Screen, goto_matches};
+use crate::side_timer::SideTimer;
 use crate::theme::Tokens;
 
 /// The separator between footer segments — both key-hint segments and the
@@ -23,6 +26,9 @@ const SEP: &str = "  ·  ";
 /// of the content area instead of being clipped.
 #[must_use]
 pub(super) fn footer_rows(app: &App, width: u16) -> u16 {
+    if matches!(app.screen(), Screen::Goto { .. }) {
+        return 1;
+    }
     match app.flash() {
         Some(flash) => flash_lines(&flash.text, width).len().max(1) as u16,
         None => 1,
@@ -30,6 +36,10 @@ pub(super) fn footer_rows(app: &App, width: u16) -> u16 {
 }
 
 pub(super) fn draw_footer(frame: &mut Frame, area: Rect, app: &App, tokens: &Tokens) {
+    if let Screen::Goto { buffer, cycle } = app.screen() {
+        draw_goto(frame, area, app, buffer, *cycle, tokens);
+        return;
+    }
     if let Some(flash) = app.flash() {
         let style = match flash.kind {
             FlashKind::Info => tokens.accent,
@@ -126,6 +136,45 @@ pub(super) fn draw_footer(frame: &mut Frame, area: Rect, app: &App, tokens: &Tok
     draw_timer(frame, area, app, tokens);
 }
 
+/// The goto prompt, in place of the key hints: what has been typed, then
+/// where Enter would land — `2/5 intro-2` while cycling through id-prefix
+/// matches, the slide a number names, or `no match`.
+fn draw_goto(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    buffer: &str,
+    cycle: usize,
+    tokens: &Tokens,
+) {
+    let nodes = &app.session().graph().nodes;
+    let status = match buffer.parse::<usize>() {
+        _ if buffer.is_empty() => "slide number or id".to_owned(),
+        Ok(number) if (1..=nodes.len()).contains(&number) => {
+            format!("slide {number} of {}", nodes.len())
+        }
+        Ok(_) => "no match".to_owned(),
+        Err(_) => {
+            let matches = goto_matches(app.session().graph(), buffer);
+            match matches.len() {
+                0 => "no match".to_owned(),
+                1 => nodes[matches[0]].id.clone(),
+                count => {
+                    let pick = cycle % count;
+                    format!("{}/{count} {}", pick + 1, nodes[matches[pick]].id)
+                }
+            }
+        }
+    };
+    let line = Line::from(vec![
+        Span::styled(" Go to: ", tokens.accent.add_modifier(Modifier::BOLD)),
+        Span::styled(format!("{buffer}▏"), tokens.text),
+        Span::styled(SEP.to_owned(), tokens.border),
+        Span::styled(status, tokens.muted),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
 /// The display width of the footer line built from `reveal_prefix` (if
 /// any) and `hints`, joined by [`SEP`] — mirrors `draw_footer`'s span
 /// assembly exactly, so fit-checking never drifts from what's rendered.
@@ -212,28 +261,73 @@ fn flash_lines(text: &str, width: u16) -> Vec<String> {
     lines
 }
 
-/// The elapsed timer, right-aligned in the footer when switched on.
+/// The timers, right-aligned in the footer: the side countdown once `T`
+/// has started it, then the elapsed timer when switched on.
 fn draw_timer(frame: &mut Frame, area: Rect, app: &App, tokens: &Tokens) {
-    if !app.show_timer() {
+    let mut spans = Vec::new();
+    if let Some(timer) = app.side_timer() {
+        spans.push(countdown_span(
+            timer,
+            Instant::now(),
+            app.animations(),
+            tokens,
+        ));
+    }
+    if app.show_timer() {
+        if !spans.is_empty() {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(clock(app.elapsed()), tokens.muted));
+    }
+    if spans.is_empty() {
         return;
     }
-    let secs = app.elapsed().as_secs();
-    let text = if secs >= 3600 {
-        format!(
-            "{}:{:02}:{:02} ",
-            secs / 3600,
-            (secs % 3600) / 60,
-            secs % 60
-        )
-    } else {
-        format!("{}:{:02} ", secs / 60, secs % 60)
-    };
+    spans.push(Span::raw(" "));
     frame.render_widget(
-        Paragraph::new(Span::styled(text, tokens.muted)).alignment(Alignment::Right),
+        Paragraph::new(Line::from(spans)).alignment(Alignment::Right),
         area,
     );
 }
 
+/// The side countdown's readout: time left (muted while paused), `0:00`
+/// in the warning color at zero, and the overrun in the error color past
+/// it, blinking on alternate seconds so it can't go unnoticed — or, with
+/// `blink` off, reversed throughout.
+fn countdown_span(timer: &SideTimer, now: Instant, blink: bool, tokens: &Tokens) -> Span<'static> {
+    let overrun = timer.overrun(now);
+    if !overrun.is_zero() {
+        let style = if !blink || overrun.as_secs().is_multiple_of(2) {
+            tokens
+                .error
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else {
+            tokens.error.add_modifier(Modifier::BOLD)
+        };
+        return Span::styled(format!("+{} over", clock(overrun)), style);
+    }
+    let remaining = timer.remaining(now);
+    let (label, style) = if timer.is_expired(now) {
+        ("left", tokens.warning.add_modifier(Modifier::BOLD))
+    } else if timer.is_running() {
+        ("left", tokens.accent)
+    } else {
+        ("paused", tokens.muted)
+    };
+    // Round up, so a countdown reads 0:00 only once it has truly run out.
+    let shown = Duration::from_secs(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0));
+    Span::styled(format!("{} {label}", clock(shown)), style)
+}
+
+/// `m:ss`, or `h:mm:ss` from an hour up.
+fn clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -267,6 +361,29 @@ mod tests {
         assert_eq!(flash_lines("Saved", 80).len(), 1);
     }
 
+    #[test]
+    fn countdown_readout_counts_down_then_reports_the_overrun() {
+        let tokens = Tokens::default();
+        let t0 = Instant::now();
+        let mut timer = SideTimer::new(Duration::from_secs(90));
+        assert_eq!(
+            countdown_span(&timer, t0, true, &tokens).content,
+            "1:30 paused"
+        );
+        timer.start(t0);
+        let running = countdown_span(&timer, t0 + Duration::from_millis(500), true, &tokens);
+        assert_eq!(running.content, "1:30 left");
+        let zero = countdown_span(&timer, t0 + Duration::from_secs(90), true, &tokens);
+        assert_eq!(zero.content, "0:00 left");
+        assert_eq!(zero.style.fg, tokens.warning.fg);
+        let over = countdown_span(&timer, t0 + Duration::from_secs(95), true, &tokens);
+        assert_eq!(over.content, "+0:05 over");
+        assert_eq!(over.style.fg, tokens.error.fg);
+        let still = countdown_span(&timer, t0 + Duration::from_secs(95), false, &tokens);
+        assert!(!over.style.add_modifier.contains(Modifier::REVERSED));
+        assert!(still.style.add_modifier.contains(Modifier::REVERSED));
+    }
+
     #[test]
     fn drop_to_fit_keeps_everything_when_it_fits() {
         let hints: &[(&str, &str)] = &[("Space", "next"), ("m", "map"), ("e", "edit")];
@@ -1,12 +1,18 @@
-//! The header: deck title, current node title, progress count, and the
-//! mini "rail" rule that shows travelled/current/upcoming stations.
+//! The header: deck title, current node title, progress readout, and the
+//! mini "rail" rule that shows travelled/current/upcoming stations and the
+//! branch points among them.
 
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use fireside_engine::Session;
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
+use super::progress::progress_text;
 use crate::app::App;
 use crate::theme::Tokens;
 
@@ -14,9 +20,12 @@ pub(super) fn draw_header(frame: &mut Frame, area: Rect, app: &App, tokens: &Tok
     let graph = app.session().graph();
     let deck = graph.title.as_deref().unwrap_or("Fireside");
     let node = app.session().current();
-    let here = node.title.as_deref().unwrap_or(&node.id);
-    let seen = app.session().visited().len();
-    let total = graph.nodes.len();
+    let title = node.title.as_deref().unwrap_or(&node.id);
+    // A breadcrumb when the slide is part of a chapter.
+    let here = match &node.section {
+        Some(section) => format!("{section} › {title}"),
+        None => title.to_owned(),
+    };
 
     let [text_row, rule_row] =
         Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(area);
@@ -27,12 +36,18 @@ pub(super) fn draw_header(frame: &mut Frame, area: Rect, app: &App, tokens: &Tok
         ])),
         text_row,
     );
+    // The right side gets whatever the deck title leaves; the node title
+    // goes first when both don't fit, since the header rail already marks
+    // where you are.
+    let budget = usize::from(area.width).saturating_sub(deck.chars().count() + 4);
+    let progress = progress_text(app.session(), app.progress_style(), budget).unwrap_or_default();
+    let mut right = vec![Span::styled(progress.clone(), tokens.muted)];
+    if !progress.is_empty() && here.chars().count() + progress.chars().count() + 5 <= budget {
+        right.insert(0, Span::styled(format!("{here}  ·  "), tokens.muted));
+    }
+    right.push(Span::raw(" "));
     frame.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled(here.to_owned(), tokens.muted),
-            Span::styled(format!("  ·  {seen}/{total} seen "), tokens.muted),
-        ]))
-        .alignment(Alignment::Right),
+        Paragraph::new(Line::from(right)).alignment(Alignment::Right),
         text_row,
     );
     frame.render_widget(
@@ -41,23 +56,38 @@ pub(super) fn draw_header(frame: &mut Frame, area: Rect, app: &App, tokens: &Tok
     );
 }
 
-/// The header rule doubles as a rail strip: stations you have travelled,
-/// the one you stand at, and the straight track ahead — the deck's shape,
-/// always in the corner of your eye.
-fn header_rail(app: &App, width: u16, tokens: &Tokens) -> Line<'static> {
-    let w = usize::from(width);
-    if w < 24 {
-        return Line::styled("─".repeat(w), tokens.border);
-    }
-    let session = app.session();
+/// Where a header-rail station sits relative to the presenter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Place {
+    Behind,
+    Here,
+    Ahead,
+}
+
+/// One station on the header rail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Station<'a> {
+    id: &'a str,
+    place: Place,
+    /// A branch point, drawn with a fork glyph.
+    fork: bool,
+    /// The option taken at the nearest fork behind this station, so the
+    /// stations of a branch share its rail color; `None` on the main line
+    /// before any fork.
+    lane: Option<usize>,
+}
+
+/// The header rail's stations: the travelled path, the current node, then
+/// the straight track ahead of it — a fork or the end of the line stops
+/// the lookahead, and the fork's routes are summarised rather than drawn.
+fn stations(session: &Session) -> Vec<Station<'_>> {
     let graph = session.graph();
+    let forks = graph.branch_structure();
 
-    // Stations: the travelled path, then the linear track ahead of the
-    // cursor (a fork or the end of the line stops the lookahead).
     let mut ids: Vec<&str> = session.history().iter().map(String::as_str).collect();
     let behind = ids.len();
     ids.push(&session.current().id);
-    let mut seen: std::collections::HashSet<&str> = ids.iter().copied().collect();
+    let mut seen: HashSet<&str> = ids.iter().copied().collect();
     let mut cursor = session.current();
     while let Some(next) = cursor.next_target().and_then(|id| graph.node(id)) {
         if !seen.insert(&next.id) || ids.len() >= 24 {
@@ -67,42 +97,102 @@ fn header_rail(app: &App, width: u16, tokens: &Tokens) -> Line<'static> {
         cursor = next;
     }
 
+    let mut lane = None;
+    let mut out: Vec<Station<'_>> = Vec::with_capacity(ids.len());
+    for (k, &id) in ids.iter().enumerate() {
+        if k > 0 && k <= behind {
+            // A jump that didn't come through the fork's options (goto,
+            // the map) keeps whatever branch the presenter was already on.
+            if let Some(j) = forks
+                .targets(ids[k - 1])
+                .and_then(|targets| targets.iter().position(|t| t == id))
+            {
+                lane = Some(j);
+            }
+        }
+        out.push(Station {
+            id,
+            place: match k.cmp(&behind) {
+                Ordering::Less => Place::Behind,
+                Ordering::Equal => Place::Here,
+                Ordering::Greater => Place::Ahead,
+            },
+            fork: forks.is_branch_point(id),
+            lane,
+        });
+    }
+    out
+}
+
+/// The header rule doubles as a rail strip: stations you have travelled,
+/// the one you stand at, and the straight track ahead — the deck's shape,
+/// always in the corner of your eye. Branch points wear a fork glyph, the
+/// stations after a choice take that option's rail color, and a fork at
+/// the end of the visible track reports how many routes leave it.
+fn header_rail(app: &App, width: u16, tokens: &Tokens) -> Line<'static> {
+    let w = usize::from(width);
+    if w < 24 {
+        return Line::styled("─".repeat(w), tokens.border);
+    }
+    let session = app.session();
+    let graph = session.graph();
+    let ids = stations(session);
+
+    let routes = ids
+        .last()
+        .filter(|s| s.fork)
+        .and_then(|s| graph.node(s.id)?.branch_point())
+        .map(|bp| {
+            let n = bp.options.len();
+            format!("─┬─ {n} route{} ", if n == 1 { "" } else { "s" })
+        })
+        .unwrap_or_default();
+
     // Each station takes 4 cells (glyph + track). Keep the tail when the
     // path outgrows the row: where you are matters more than where you began.
     const STEP: usize = 4;
-    let max = (w.saturating_sub(6)) / STEP;
-    let cut = ids.len().saturating_sub(max);
-    let current_at = behind.saturating_sub(cut);
+    let max = (w.saturating_sub(6 + routes.chars().count())) / STEP;
+    let cut = ids.len().saturating_sub(max.max(1));
     let shown = &ids[cut..];
 
+    let line_style = |lane: Option<usize>| lane.map_or(tokens.accent, |j| tokens.rail(j));
     let mut spans = vec![Span::styled(
         if cut > 0 { "┄─" } else { "──" }.to_owned(),
         tokens.border,
     )];
     let mut used = 2;
-    for (k, id) in shown.iter().enumerate() {
-        let terminal = graph.node(id).is_some_and(fireside_core::Node::is_terminal);
-        let (glyph, style) = match k.cmp(&current_at) {
-            std::cmp::Ordering::Less => ("●", tokens.accent),
-            std::cmp::Ordering::Equal => ("◉", tokens.accent.add_modifier(Modifier::BOLD)),
-            std::cmp::Ordering::Greater => ("○", tokens.muted),
+    for (k, station) in shown.iter().enumerate() {
+        let terminal = graph
+            .node(station.id)
+            .is_some_and(fireside_core::Node::is_terminal);
+        let (glyph, style) = match (station.place, station.fork) {
+            (Place::Behind, false) => ("●", line_style(station.lane)),
+            (Place::Behind, true) => ("◆", line_style(station.lane)),
+            (Place::Here, false) => ("◉", line_style(station.lane).add_modifier(Modifier::BOLD)),
+            (Place::Here, true) => ("◈", line_style(station.lane).add_modifier(Modifier::BOLD)),
+            (Place::Ahead, false) => ("○", tokens.muted),
+            (Place::Ahead, true) => ("◇", tokens.muted),
         };
-        spans.push(Span::styled((*glyph).to_owned(), style));
+        spans.push(Span::styled(glyph.to_owned(), style));
         used += 1;
         if terminal && k + 1 == shown.len() {
             spans.push(Span::styled("─■".to_owned(), style));
             used += 2;
             break;
         }
-        if k + 1 < shown.len() {
-            // Track between stations is bright once ridden.
-            let track = if k < current_at {
-                tokens.accent
-            } else {
+        if let Some(next) = shown.get(k + 1) {
+            // Track between stations is bright once ridden, in the color
+            // of the branch it leads into.
+            let track = if next.place == Place::Ahead {
                 tokens.border
+            } else {
+                line_style(next.lane)
             };
             spans.push(Span::styled("───".to_owned(), track));
             used += 3;
+        } else if !routes.is_empty() {
+            used += routes.chars().count();
+            spans.push(Span::styled(routes.clone(), tokens.muted));
         }
     }
     spans.push(Span::styled(
@@ -111,3 +201,68 @@ fn header_rail(app: &App, width: u16, tokens: &Tokens) -> Line<'static> {
     ));
     Line::from(spans)
 }
+
+#[cfg(test)]
+mod tests {
+    use fireside_core::Graph;
+
+    use super::*;
+
+    /// Two branch points: `start` forks to `left`/`right`, both rejoin at
+    /// `mid`, which forks again to `deep` or `end`.
+    const TWO_FORKS: &str = r#"{"nodes":[
+        {"id":"start","traversal":{"branch-point":{"options":[
+            {"label":"Left","target":"left"},{"label":"Right","target":"right"}
+        ]}},"content":[]},
+        {"id":"left","traversal":"mid","content":[]},
+        {"id":"right","traversal":"mid","content":[]},
+        {"id":"mid","traversal":{"branch-point":{"options":[
+            {"label":"Deep","target":"deep"},{"label":"End","target":"end"}
+        ]}},"content":[]},
+        {"id":"deep","traversal":"end","content":[]},
+        {"id":"end","content":[]}
+    ]}"#;
+
+    fn session() -> Session {
+        Session::new(Graph::from_json(TWO_FORKS).expect("fixture parses")).expect("non-empty")
+    }
+
+    fn summary<'a>(stations: &[Station<'a>]) -> Vec<(&'a str, Place, bool, Option<usize>)> {
+        stations
+            .iter()
+            .map(|s| (s.id, s.place, s.fork, s.lane))
+            .collect()
+    }
+
+    #[test]
+    fn a_fork_at_the_cursor_stops_the_lookahead() {
+        let s = session();
+        assert_eq!(summary(&stations(&s)), [("start", Place::Here, true, None)]);
+    }
+
+    #[test]
+    fn stations_after_a_choice_take_that_options_lane() {
+        let mut s = session();
+        s.choose(1);
+        assert_eq!(
+            summary(&stations(&s)),
+            [
+                ("start", Place::Behind, true, None),
+                ("right", Place::Here, false, Some(1)),
+                ("mid", Place::Ahead, true, Some(1)),
+            ]
+        );
+        s.next();
+        s.choose(0);
+        assert_eq!(
+            summary(&stations(&s)),
+            [
+                ("start", Place::Behind, true, None),
+                ("right", Place::Behind, false, Some(1)),
+                ("mid", Place::Behind, true, Some(1)),
+                ("deep", Place::Here, false, Some(0)),
+                ("end", Place::Ahead, false, Some(0)),
+            ]
+        );
+    }
+}
@@ -30,7 +30,7 @@ pub fn branch_option_hit(app: &App, frame_area: Rect, col: u16, row: u16) -> Opt
     if let Some(notes) = notes_panel(app, content) {
         content.height = content.height.saturating_sub(notes.height);
     }
-    let surf = surface(app.view_mode(), content);
+    let surf = surface(app.view_mode(), content, app.content_max_width());
     let view = super::content::SlideView::from_app(app);
     let NodeLines { lines, option_rows } = node_lines(&view, surf.width, &tokens);
     if option_rows.is_empty() {
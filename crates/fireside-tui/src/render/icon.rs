@@ -0,0 +1,113 @@
+//! One glyph summing up what a slide holds, shared by the editor's outline
+//! and the presenter's map so a slide looks the same in both.
+//!
+//! The whole slide counts, nested blocks included, not just its first
+//! block. The most notable thing on it wins, in this order:
+//!
+//! | Glyph | The slide…                                        |
+//! | ----- | ------------------------------------------------- |
+//! | `⑂`   | asks for a choice                                 |
+//! | `λ`   | has a code block anywhere                         |
+//! | `▣`   | has an image, a video, or ASCII art anywhere      |
+//! | `¶`   | has any other content                             |
+//! | ` `   | is empty                                          |
+
+use fireside_core::{ContentBlock, Node};
+
+/// A slide with a branch point.
+const BRANCH: char = '\u{2442}';
+/// A slide with code on it.
+const CODE: char = 'λ';
+/// A slide with a picture on it.
+const IMAGE: char = '▣';
+/// A slide of words.
+const TEXT: char = '¶';
+
+/// The glyph for `node` — see the module docs for the precedence.
+#[must_use]
+pub(crate) fn node_icon(node: &Node) -> char {
+    if node.branch_point().is_some() {
+        return BRANCH;
+    }
+    let mut has = Has::default();
+    has.scan(&node.content);
+    if has.code {
+        CODE
+    } else if has.image {
+        IMAGE
+    } else if has.other {
+        TEXT
+    } else {
+        ' '
+    }
+}
+
+/// Which kinds of block turned up anywhere on a slide.
+#[derive(Default)]
+struct Has {
+    code: bool,
+    image: bool,
+    other: bool,
+}
+
+impl Has {
+    fn scan(&mut self, blocks: &[ContentBlock]) {
+        for block in blocks {
+            match block {
+                ContentBlock::Code { .. } => self.code = true,
+                ContentBlock::Image { .. }
+                | ContentBlock::Video { .. }
+                | ContentBlock::AsciiArt { .. } => self.image = true,
+                ContentBlock::Container { .. } | ContentBlock::Columns { .. } => {}
+                _ => self.other = true,
+            }
+            for children in block.child_blocks() {
+                self.scan(children);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fireside_core::Graph;
+
+    use super::*;
+
+    /// The icon of `node`, read as the first slide of a deck that also
+    /// holds `t`, the slide a choice leads to.
+    fn icon(node: &str) -> char {
+        let json = format!(r#"{{"nodes":[{node},{{"id":"t","content":[]}}]}}"#);
+        let graph = Graph::from_json(&json).expect("deck parses");
+        node_icon(&graph.nodes[0])
+    }
+
+    const TEXT_BLOCK: &str = r#"{"kind":"text","body":"words"}"#;
+    const CODE_BLOCK: &str = r#"{"kind":"code","language":"rust","source":"fn main() {}"}"#;
+    const IMAGE_BLOCK: &str = r#"{"kind":"image","src":"cat.png","alt":"a cat"}"#;
+
+    #[test]
+    fn branch_beats_code_beats_image_beats_text() {
+        let slide = |blocks: &[&str]| format!(r#"{{"id":"s","content":[{}]}}"#, blocks.join(","));
+        let all = [TEXT_BLOCK, IMAGE_BLOCK, CODE_BLOCK];
+        let choice = format!(
+            r#"{{"id":"s","content":[{}],"traversal":{{"branch-point":{{"options":[
+                {{"label":"On","target":"t"}}]}}}}}}"#,
+            all.join(",")
+        );
+        assert_eq!(icon(&choice), BRANCH);
+        assert_eq!(icon(&slide(&all)), CODE);
+        assert_eq!(icon(&slide(&[TEXT_BLOCK, IMAGE_BLOCK])), IMAGE);
+        assert_eq!(icon(&slide(&[TEXT_BLOCK])), TEXT);
+        assert_eq!(icon(&slide(&[])), ' ');
+    }
+
+    #[test]
+    fn the_whole_slide_counts_not_just_its_first_block() {
+        let nested = format!(
+            r#"{{"id":"s","content":[{TEXT_BLOCK},
+                {{"kind":"container","layout":"stack","children":[{CODE_BLOCK}]}}]}}"#
+        );
+        assert_eq!(icon(&nested), CODE);
+    }
+}
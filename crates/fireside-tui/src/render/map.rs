@@ -22,6 +22,8 @@ use ratatui::widgets::{Block, BorderType, Clear, Paragraph};
 use crate::app::App;
 use crate::theme::Tokens;
 
+use super::icon::node_icon;
+
 /// Horizontal cells from one rail slot to the next.
 const PITCH: usize = 3;
 
@@ -472,7 +474,10 @@ fn build(app: &App, selected: usize, tokens: &Tokens) -> Built {
                 } else {
                     tokens.muted
                 };
-                spans.push(Span::styled(format!(" {} ", title_of(n)), style));
+                spans.push(Span::styled(
+                    format!(" {} {} ", node_icon(n), title_of(n)),
+                    style,
+                ));
                 if n.is_terminal() {
                     spans.push(Span::styled(" ■".to_owned(), tokens.muted));
                 }
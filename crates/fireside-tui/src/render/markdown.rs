@@ -48,7 +48,13 @@ pub(crate) fn link_url(index: usize) -> Option<String> {
     LINKS.with(|links| links.borrow().get(index).cloned())
 }
 
-fn register_link(url: &str) -> usize {
+/// How many links the registry holds so far this frame.
+#[must_use]
+pub(crate) fn link_count() -> usize {
+    LINKS.with(|links| links.borrow().len())
+}
+
+pub(super) fn register_link(url: &str) -> usize {
     LINKS.with(|links| {
         let mut links = links.borrow_mut();
         links.push(url.to_owned());
@@ -7,15 +7,19 @@
 //! that contract is what makes the presenter learnable without a manual.
 
 pub mod blocks;
+mod breadcrumb;
 pub(crate) mod content;
 mod editor;
 mod footer;
 mod header;
 mod hits;
+mod icon;
 mod map;
 pub mod markdown;
 mod notes;
 mod overlays;
+mod progress;
+mod reflow;
 pub mod syntax;
 
 pub(crate) use editor::draw as draw_editor;
@@ -74,9 +78,10 @@ mod expand_tabs_tests {
 use fireside_core::ViewMode;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::widgets::Paragraph;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Paragraph};
 
-use crate::app::{App, Screen};
+use crate::app::{App, BlankKind, Screen, ViewAudience};
 use crate::theme::Tokens;
 
 use content::indicator;
@@ -89,27 +94,51 @@ const PAD_X: u16 = 3;
 /// Rows of padding between the card border and the content.
 const PAD_Y: u16 = 1;
 
-/// Paint one frame.
+/// Paint one frame, for whoever [`App::view_audience`] says is watching.
 pub fn draw(frame: &mut Frame, app: &App) {
-    let tokens = Tokens::default();
+    draw_view(frame, app, app.view_audience());
+}
+
+/// Paint one frame for `audience`: the presenter gets everything, the
+/// audience only the header and the slide, in the same places — so a
+/// second screen can show the room the slide the presenter is on.
+pub fn draw_view(frame: &mut Frame, app: &App, audience: ViewAudience) {
+    let presenter = audience == ViewAudience::Presenter;
+    let tokens = app.tokens().for_node(app.session().current());
     // Every link fragment parsed this frame registers its URL under a
     // fresh index (`markdown::register_link`) — clearing first means a
     // link's index (and thus its `Tokens::link` marker style) never
     // accidentally survives from the previous frame's registry.
     markdown::reset_links();
     let area = frame.area();
+    if let Some(kind) = app.blank() {
+        draw_blank(frame, area, kind, &tokens);
+        return;
+    }
     if area.width < 10 || area.height < 4 {
         frame.render_widget(Paragraph::new("Too small"), area);
         return;
     }
 
+    frame.render_widget(Block::new().style(tokens.background), area);
     let (header, content_area, footer) = areas(app.view_mode(), area);
-    let (mut content_area, footer) = grow_footer_for_flash(app, content_area, footer);
 
     if let Some(header) = header {
         header::draw_header(frame, header, app, &tokens);
     }
 
+    if !presenter {
+        content::draw_content(
+            frame,
+            content_area,
+            &content::SlideView::from_app(app),
+            &tokens,
+        );
+        apply_hyperlinks(frame.buffer_mut());
+        return;
+    }
+
+    let (mut content_area, footer) = grow_footer_for_flash(app, content_area, footer);
     if let Some(notes) = content::notes_panel(app, content_area) {
         content_area.height = content_area.height.saturating_sub(notes.height);
         content::draw_notes(frame, notes, app, &tokens);
@@ -124,8 +153,13 @@ pub fn draw(frame: &mut Frame, app: &App) {
     footer::draw_footer(frame, footer, app, &tokens);
 
     match app.screen() {
-        Screen::Present => {}
+        Screen::Present | Screen::Goto { .. } => {}
         Screen::Help => overlays::draw_help(frame, area, &tokens),
+        Screen::Copy { selected } => overlays::draw_copy(frame, area, app, *selected, &tokens),
+        Screen::Jumps { selected } => overlays::draw_jumps(frame, area, app, *selected, &tokens),
+        Screen::Outline { selected } => {
+            overlays::draw_outline(frame, area, app, *selected, &tokens);
+        }
         Screen::Map { selected } => map::draw(frame, area, app, *selected, &tokens),
         Screen::Edit { fields, focused } => {
             overlays::draw_edit(frame, area, fields, *focused, app.sink_available(), &tokens);
@@ -135,6 +169,16 @@ pub fn draw(frame: &mut Frame, app: &App) {
     apply_hyperlinks(frame.buffer_mut());
 }
 
+/// A blanked screen: nothing but one solid fill, redrawn at whatever size
+/// the terminal is now so a resize while blank restores cleanly.
+fn draw_blank(frame: &mut Frame, area: Rect, kind: BlankKind, tokens: &Tokens) {
+    let style = match kind {
+        BlankKind::Black => Style::default(),
+        BlankKind::White => tokens.text.add_modifier(Modifier::REVERSED),
+    };
+    frame.render_widget(Block::new().style(style), area);
+}
+
 /// Rewrites every contiguous run of [`Tokens::link`]-styled cells in the
 /// frame's buffer into a real OSC 8 hyperlink: the run's first cell gets
 /// the OSC 8 open sequence + the run's visible text + OSC 8 close, with
@@ -190,12 +234,7 @@ fn apply_hyperlinks(buffer: &mut ratatui::buffer::Buffer) {
 /// `App::update` so scrolling clamps to real geometry.
 #[must_use]
 pub fn max_scroll(app: &App, width: u16, height: u16) -> u16 {
-    let (_, body, footer) = areas(app.view_mode(), Rect::new(0, 0, width, height));
-    let (mut body, _) = grow_footer_for_flash(app, body, footer);
-    if let Some(notes) = content::notes_panel(app, body) {
-        body.height = body.height.saturating_sub(notes.height);
-    }
-    let surf = surface(app.view_mode(), body);
+    let surf = body_surface(app, width, height);
     let view = content::SlideView::from_app(app);
     let total = content::node_lines(&view, surf.width, &Tokens::default())
         .lines
@@ -203,6 +242,39 @@ pub fn max_scroll(app: &App, width: u16, height: u16) -> u16 {
     total.saturating_sub(surf.height)
 }
 
+/// How many of the current node's rows the body shows at once at the
+/// given terminal size — what one PageUp/PageDown scrolls by.
+#[must_use]
+pub fn scroll_page(app: &App, width: u16, height: u16) -> u16 {
+    body_surface(app, width, height).height
+}
+
+/// The line of the current node's flow holding the highlighted branch
+/// option's label, or `None` when no branch menu is showing — so moving
+/// the highlight can scroll it into view.
+#[must_use]
+pub fn selected_option_line(app: &App, width: u16, height: u16) -> Option<u16> {
+    let surf = body_surface(app, width, height);
+    let view = content::SlideView::from_app(app);
+    let rows = content::node_lines(&view, surf.width, &Tokens::default()).option_rows;
+    rows.get(app.branch_selected())
+        .map(|&row| u16::try_from(row).unwrap_or(u16::MAX))
+}
+
+/// The surface the current node's content flows into at the given terminal
+/// size, after the footer, any flash, and the notes panel take their rows
+/// — the last two only in the presenter's view.
+fn body_surface(app: &App, width: u16, height: u16) -> Surface {
+    let (_, mut body, footer) = areas(app.view_mode(), Rect::new(0, 0, width, height));
+    if app.view_audience() == ViewAudience::Presenter {
+        (body, _) = grow_footer_for_flash(app, body, footer);
+        if let Some(notes) = content::notes_panel(app, body) {
+            body.height = body.height.saturating_sub(notes.height);
+        }
+    }
+    surface(app.view_mode(), body, app.content_max_width())
+}
+
 /// Shrinks `content_area` and grows `footer` by however many extra rows
 /// (P1-6) a currently-showing flash needs to word-wrap without truncation
 /// — borrowed from the bottom of the content area, never from the header.
@@ -258,22 +330,26 @@ pub(crate) struct Surface {
 /// reads as a stage rather than a fence around the whole screen.
 const CARD_GAP: u16 = 2;
 
-pub(crate) fn surface(view: ViewMode, body: Rect) -> Surface {
+/// The content column for `body`. `max_width`, when set, narrows it
+/// further — the card shrinks around it, and the bare fullscreen column is
+/// centered by [`content_inner`] like any other narrow flow.
+pub(crate) fn surface(view: ViewMode, body: Rect, max_width: Option<u16>) -> Surface {
     let chrome_w = 2 + 2 * PAD_X;
     let chrome_h = 2 + 2 * PAD_Y;
     let card = view == ViewMode::Default
         && body.width >= chrome_w + 16
         && body.height >= chrome_h + CARD_GAP + 3;
+    let cap = max_width.unwrap_or(u16::MAX).max(1);
     if card {
         let card_width = body.width.min(MEASURE + chrome_w);
         Surface {
-            width: card_width - chrome_w,
+            width: (card_width - chrome_w).min(cap),
             height: body.height - chrome_h - CARD_GAP,
             card: true,
         }
     } else {
         Surface {
-            width: body.width.saturating_sub(2),
+            width: body.width.saturating_sub(2).min(cap),
             height: body.height,
             card: false,
         }
@@ -1,6 +1,8 @@
 //! Full-screen overlays drawn on top of the presenting view: the quick-edit
-//! modal and the help screen.
+//! modal, the help screen, the copy picker, the jump list, and the table
+//! of contents.
 
+use fireside_core::OutlineKind;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::Modifier;
@@ -8,6 +10,7 @@ use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, BorderType, Clear, Paragraph};
 use unicode_width::UnicodeWidthChar;
 
+use crate::app::{App, code_snippets, jump_list};
 use crate::editor::forms::{EditableField, EditableKind};
 use crate::theme::Tokens;
 
@@ -354,11 +357,24 @@ pub(super) fn draw_help(frame: &mut Frame, area: Rect, tokens: &Tokens) {
         ("↑ / ↓", "pick a choice · scroll"),
         ("1–9 or a letter", "take a choice directly"),
         ("m", "map — see and jump anywhere"),
+        (":", "go to a slide number or id"),
+        ("Home / End", "first / last slide"),
+        ("[ / ]", "previous / next section"),
+        ("{ / }", "previous / next choice"),
         ("click", "select a map row or branch option"),
         ("f", "fullscreen on/off"),
         ("s", "speaker notes"),
+        ("A", "audience view — the slide alone"),
         ("e", "quick-edit this slide's text"),
+        ("Y", "copy a code block"),
+        ("H", "jump back to a recent slide"),
+        ("O", "table of contents"),
+        ("o", "spotlight the next block"),
         ("t", "elapsed timer"),
+        ("P", "cycle the progress style"),
+        ("T", "start/pause the countdown"),
+        ("R", "reset the countdown"),
+        ("b / w", "blank the screen black / white"),
     ];
     // Wide enough for the longest row so nothing clips, capped by the
     // terminal itself inside `overlay_rect`.
@@ -419,6 +435,249 @@ pub(super) fn draw_help(frame: &mut Frame, area: Rect, tokens: &Tokens) {
     );
 }
 
+/// The copy picker's footer hint.
+const COPY_FOOTER: &str = "↑↓ choose  ·  Enter copy  ·  Esc cancel";
+
+/// The copy picker: one row per visible code block on the current slide —
+/// its number, language (▶ for a runnable one), and first non-blank line —
+/// with the highlighted
+/// row the one Enter copies.
+pub(super) fn draw_copy(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    selected: usize,
+    tokens: &Tokens,
+) {
+    let session = app.session();
+    let content = session.visible_content();
+    let snippets = code_snippets(&content, session.reveal_level());
+    let rect = overlay_rect(area, MEASURE, snippets.len() as u16 + 4);
+    frame.render_widget(Clear, rect);
+    let block = Block::bordered()
+        .border_type(BorderType::Rounded)
+        .border_style(tokens.border)
+        .title(Span::styled(
+            " Copy which block? ".to_owned(),
+            tokens.accent.add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(rect);
+    frame.render_widget(block, rect);
+    if inner.height == 0 {
+        return;
+    }
+    let [list_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    let lines: Vec<Line<'static>> = snippets
+        .iter()
+        .enumerate()
+        .map(|(i, snippet)| {
+            let first = snippet
+                .source
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or_default()
+                .trim();
+            let marker = if i == selected { "▸" } else { " " };
+            let style = if i == selected {
+                tokens.accent.add_modifier(Modifier::BOLD)
+            } else {
+                tokens.text
+            };
+            Line::from(vec![
+                Span::styled(format!("{marker}{} ", i + 1), style),
+                Span::styled(
+                    if snippet.runnable { "▶ " } else { "  " }.to_owned(),
+                    tokens.accent,
+                ),
+                Span::styled(
+                    format!("{:<8} ", snippet.language.unwrap_or("code")),
+                    tokens.muted,
+                ),
+                Span::styled(first.to_owned(), style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Text::from(lines)), list_area);
+    frame.render_widget(
+        Paragraph::new(Line::styled(format!(" {COPY_FOOTER}"), tokens.muted)),
+        footer_area,
+    );
+}
+
+/// The jump list's footer hint.
+const JUMPS_FOOTER: &str = "↑↓ choose  ·  Enter jump back  ·  Esc cancel";
+
+/// The most jump-list rows shown at once; the list scrolls to keep the
+/// highlighted one in view.
+const JUMPS_VISIBLE: usize = 10;
+
+/// The jump list: one row per recently visited slide, most recent first —
+/// its slide number, id, and `⑂` when a choice led there.
+pub(super) fn draw_jumps(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    selected: usize,
+    tokens: &Tokens,
+) {
+    let session = app.session();
+    let entries = jump_list(session);
+    let rows = entries.len().min(JUMPS_VISIBLE);
+    let rect = overlay_rect(area, MEASURE, rows as u16 + 4);
+    frame.render_widget(Clear, rect);
+    let block = Block::bordered()
+        .border_type(BorderType::Rounded)
+        .border_style(tokens.border)
+        .title(Span::styled(
+            " Jump back to… ".to_owned(),
+            tokens.accent.add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(rect);
+    frame.render_widget(block, rect);
+    if inner.height == 0 {
+        return;
+    }
+    let [list_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    let visible = (list_area.height as usize).clamp(1, JUMPS_VISIBLE);
+    let offset = selected.saturating_sub(visible - 1);
+    let number_width = session.graph().nodes.len().to_string().len();
+    let lines: Vec<Line<'static>> = entries
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, entry)| {
+            let marker = if i == selected { "▸" } else { " " };
+            let style = if i == selected {
+                tokens.accent.add_modifier(Modifier::BOLD)
+            } else {
+                tokens.text
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{marker}{:>number_width$} ", entry.index + 1),
+                    tokens.muted,
+                ),
+                Span::styled(
+                    if entry.via_branch { "⑂ " } else { "  " }.to_owned(),
+                    tokens.accent,
+                ),
+                Span::styled(session.graph().nodes[entry.index].id.clone(), style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Text::from(lines)), list_area);
+    let position = if entries.len() > visible {
+        format!("  ·  {}/{}", selected + 1, entries.len())
+    } else {
+        String::new()
+    };
+    frame.render_widget(
+        Paragraph::new(Line::styled(
+            format!(" {JUMPS_FOOTER}{position}"),
+            tokens.muted,
+        )),
+        footer_area,
+    );
+}
+
+/// The table of contents' footer hint.
+const OUTLINE_FOOTER: &str = "↑↓ choose  ·  Enter go  ·  Esc cancel";
+
+/// The most table-of-contents lines shown at once; the list scrolls to
+/// keep the highlighted one in view.
+const OUTLINE_VISIBLE: usize = 16;
+
+/// The table of contents: [`Graph::outline`](fireside_core::Graph::outline)
+/// indented as a tree — sections in bold, each slide by number, its
+/// headings after a `·` — with the slide on screen marked `●`.
+pub(super) fn draw_outline(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    selected: usize,
+    tokens: &Tokens,
+) {
+    let session = app.session();
+    let outline = session.graph().outline();
+    let rows = outline.len().min(OUTLINE_VISIBLE);
+    let rect = overlay_rect(area, MEASURE, rows as u16 + 4);
+    frame.render_widget(Clear, rect);
+    let block = Block::bordered()
+        .border_type(BorderType::Rounded)
+        .border_style(tokens.border)
+        .title(Span::styled(
+            " Contents ".to_owned(),
+            tokens.accent.add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(rect);
+    frame.render_widget(block, rect);
+    if inner.height == 0 {
+        return;
+    }
+    let [list_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    let visible = (list_area.height as usize).clamp(1, OUTLINE_VISIBLE);
+    let offset = selected.saturating_sub(visible - 1);
+    let number_width = session.graph().nodes.len().to_string().len();
+    let current = session.current_index();
+    let lines: Vec<Line<'static>> = outline
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, entry)| {
+            let marker = if i == selected { "▸" } else { " " };
+            let style = if i == selected {
+                tokens.accent.add_modifier(Modifier::BOLD)
+            } else {
+                tokens.text
+            };
+            let indent = "  ".repeat(entry.depth);
+            let mut spans = vec![Span::styled(format!("{marker}{indent}"), tokens.muted)];
+            match entry.kind {
+                OutlineKind::Section => {
+                    spans.push(Span::styled(
+                        entry.label.clone(),
+                        style.add_modifier(Modifier::BOLD),
+                    ));
+                }
+                OutlineKind::Node => {
+                    let here = if entry.index == current { "●" } else { " " };
+                    spans.push(Span::styled(
+                        format!("{:>number_width$}{here}", entry.index + 1),
+                        tokens.muted,
+                    ));
+                    spans.push(Span::styled(entry.label.clone(), style));
+                }
+                OutlineKind::Heading => {
+                    spans.push(Span::styled("· ".to_owned(), tokens.muted));
+                    spans.push(Span::styled(
+                        entry.label.clone(),
+                        if i == selected { style } else { tokens.muted },
+                    ));
+                }
+            }
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Text::from(lines)), list_area);
+    let position = if outline.len() > visible {
+        format!("  ·  {}/{}", selected + 1, outline.len())
+    } else {
+        String::new()
+    };
+    frame.render_widget(
+        Paragraph::new(Line::styled(
+            format!(" {OUTLINE_FOOTER}{position}"),
+            tokens.muted,
+        )),
+        footer_area,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,159 @@
+//! The header's progress readout, in whichever [`ProgressStyle`] the
+//! presenter picked with `P`: a count, a filled bar, a row of dots, or a
+//! bar for just the stretch of path since the last choice.
+//!
+//! Every style is one line and is handed a column budget; a style that
+//! can't fit shrinks its bar, then falls back to the bare count, and only
+//! shows nothing when even that won't fit.
+
+use std::collections::HashSet;
+
+use fireside_engine::Session;
+
+use crate::app::ProgressStyle;
+
+/// The widest a bar or dot row grows, however many slides the deck has.
+const MAX_CELLS: usize = 12;
+
+/// The readout for `style` in at most `budget` columns, or `None` when
+/// nothing fits.
+#[must_use]
+pub(super) fn progress_text(
+    session: &Session,
+    style: ProgressStyle,
+    budget: usize,
+) -> Option<String> {
+    let seen = session.visited().len();
+    let total = session.graph().nodes.len();
+    let count = format!("{seen}/{total}");
+    let text = match style {
+        ProgressStyle::Count => Some(format!("{count} seen")),
+        ProgressStyle::Bar => cells(seen, total, budget, &count, '▰', '▱'),
+        ProgressStyle::Dots => cells(seen, total, budget, "", '●', '○'),
+        ProgressStyle::Path => {
+            let (at, len) = path_segment(session);
+            cells(at, len, budget, &format!("{at}/{len} this path"), '▰', '▱')
+        }
+    };
+    [text, Some(count)]
+        .into_iter()
+        .flatten()
+        .find(|t| t.chars().count() <= budget)
+}
+
+/// `done` of `total` as a row of cells, one per slide when there is room
+/// and scaled down otherwise, followed by `suffix`. `None` when the budget
+/// leaves room for fewer than three cells.
+fn cells(
+    done: usize,
+    total: usize,
+    budget: usize,
+    suffix: &str,
+    full: char,
+    empty: char,
+) -> Option<String> {
+    let suffix_w = if suffix.is_empty() {
+        0
+    } else {
+        suffix.chars().count() + 1
+    };
+    let width = total.min(MAX_CELLS).min(budget.saturating_sub(suffix_w));
+    if width < 3 || total == 0 {
+        return None;
+    }
+    let filled = (done * width).div_ceil(total).min(width);
+    let mut out: String = std::iter::repeat_n(full, filled)
+        .chain(std::iter::repeat_n(empty, width - filled))
+        .collect();
+    if !suffix.is_empty() {
+        out.push(' ');
+        out.push_str(suffix);
+    }
+    Some(out)
+}
+
+/// Where the presenter stands within the current stretch of path: the
+/// slides walked since the last branch choice (or the start), the one
+/// shown now, and the straight track ahead until the next fork or ending.
+/// Returns `(position, length)`, both counting the current slide.
+fn path_segment(session: &Session) -> (usize, usize) {
+    let graph = session.graph();
+    let history = session.history();
+    let behind = history
+        .iter()
+        .rev()
+        .take_while(|id| graph.node(id).is_none_or(|n| n.branch_point().is_none()))
+        .count();
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut ahead = 0;
+    let mut cursor = session.current();
+    seen.insert(&cursor.id);
+    while let Some(next) = cursor.next_target().and_then(|id| graph.node(id)) {
+        if !seen.insert(&next.id) {
+            break;
+        }
+        ahead += 1;
+        cursor = next;
+    }
+    (behind + 1, behind + 1 + ahead)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fireside_core::Graph;
+
+    fn session(json: &str) -> Session {
+        Session::new(Graph::from_json(json).expect("fixture parses")).expect("session")
+    }
+
+    const FORKED: &str = r#"{"nodes":[
+        {"id":"a","traversal":"b","content":[]},
+        {"id":"b","traversal":{"branch-point":{"options":[{"label":"C","target":"c"}]}},"content":[]},
+        {"id":"c","traversal":"d","content":[]},
+        {"id":"d","traversal":"e","content":[]},
+        {"id":"e","content":[]}
+    ]}"#;
+
+    #[test]
+    fn every_style_fits_a_wide_budget() {
+        let s = session(FORKED);
+        assert_eq!(
+            progress_text(&s, ProgressStyle::Count, 40).as_deref(),
+            Some("1/5 seen")
+        );
+        assert_eq!(
+            progress_text(&s, ProgressStyle::Bar, 40).as_deref(),
+            Some("▰▱▱▱▱ 1/5")
+        );
+        assert_eq!(
+            progress_text(&s, ProgressStyle::Dots, 40).as_deref(),
+            Some("●○○○○")
+        );
+    }
+
+    #[test]
+    fn the_path_bar_counts_from_the_last_choice() {
+        let mut s = session(FORKED);
+        s.next();
+        s.choose(0);
+        s.next();
+        assert_eq!(s.current().id, "d");
+        assert_eq!(
+            progress_text(&s, ProgressStyle::Path, 40).as_deref(),
+            Some("▰▰▱ 2/3 this path")
+        );
+    }
+
+    #[test]
+    fn a_narrow_budget_falls_back_to_the_count_then_nothing() {
+        let s = session(FORKED);
+        assert_eq!(
+            progress_text(&s, ProgressStyle::Bar, 6).as_deref(),
+            Some("1/5"),
+            "three cells plus the count need 7 columns"
+        );
+        assert_eq!(progress_text(&s, ProgressStyle::Dots, 2), None);
+    }
+}
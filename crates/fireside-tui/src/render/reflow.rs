@@ -0,0 +1,197 @@
+//! A small memo of rendered block flows, so a frame that shows the same
+//! content at the same width as the last one skips re-wrapping and
+//! re-highlighting it.
+//!
+//! The presenter redraws on every poll tick, and the event loop plus
+//! mouse hit-testing each rebuild the same node's line flow several times
+//! per frame. For a node with a 500-line Rust code block at width 100,
+//! rebuilding costs about 60 ms per call in the workspace's dev profile
+//! (syntax highlighting dominates), against about 0.6 ms for a cache hit's
+//! comparison and clone. Several rebuilds a frame is a visible stall on
+//! every keypress; the cache only pays again when the width, reveal
+//! level, theme, or content actually changes.
+//!
+//! Entries are keyed by node id, width, reveal level, and the [`Tokens`]
+//! they were styled with, and every hit is checked against a copy of the
+//! content it was rendered from. That check is what invalidates on
+//! mutation: a reload, a quick-edit save, an editor op, or the editor's
+//! live form preview all change the content, so no caller has to remember
+//! to clear anything and a stale render can never show.
+//!
+//! Like the link registry in [`super::markdown`], the memo is per-thread
+//! render state, never part of `App`.
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+use fireside_core::ContentBlock;
+use ratatui::text::Line;
+
+use super::{blocks, markdown};
+use crate::theme::Tokens;
+
+/// Enough for the presenter's current node plus the editor's canvas and
+/// a few neighbours; least recently used entries fall out first.
+const CAPACITY: usize = 8;
+
+struct Entry {
+    node_id: String,
+    width: u16,
+    reveal_level: u32,
+    tokens: Tokens,
+    content: Vec<ContentBlock>,
+    lines: Vec<Line<'static>>,
+    extents: Vec<Range<usize>>,
+    /// The URLs this flow registered, in order, and the registry index the
+    /// first of them had — so a hit in a later frame can re-register them
+    /// and re-point the cached link styles at their new indices.
+    links: Vec<String>,
+    first_link: usize,
+}
+
+thread_local! {
+    static CACHE: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// `blocks::render_blocks_with_extents(content, width, tokens,
+/// reveal_level)` for the node `id`, served from the memo when the same
+/// content was last rendered the same way.
+pub(crate) fn node_flow(
+    id: &str,
+    content: &[ContentBlock],
+    width: u16,
+    tokens: &Tokens,
+    reveal_level: u32,
+) -> (Vec<Line<'static>>, Vec<Range<usize>>) {
+    let hit = CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let pos = cache.iter().position(|e| {
+            e.width == width
+                && e.reveal_level == reveal_level
+                && e.node_id == id
+                && e.tokens == *tokens
+                && e.content == content
+        })?;
+        let entry = cache.remove(pos);
+        let flow = (replay(&entry), entry.extents.clone());
+        cache.push(entry);
+        Some(flow)
+    });
+    if let Some(flow) = hit {
+        return flow;
+    }
+
+    let first_link = markdown::link_count();
+    let (lines, extents) = blocks::render_blocks_with_extents(content, width, tokens, reveal_level);
+    let links = (first_link..markdown::link_count())
+        .filter_map(markdown::link_url)
+        .collect();
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= CAPACITY {
+            cache.remove(0);
+        }
+        cache.push(Entry {
+            node_id: id.to_owned(),
+            width,
+            reveal_level,
+            tokens: tokens.clone(),
+            content: content.to_vec(),
+            lines: lines.clone(),
+            extents: extents.clone(),
+            links,
+            first_link,
+        });
+    });
+    (lines, extents)
+}
+
+/// A cached flow's lines, with its links registered for the current frame.
+fn replay(entry: &Entry) -> Vec<Line<'static>> {
+    let base = markdown::link_count();
+    for url in &entry.links {
+        markdown::register_link(url);
+    }
+    if entry.links.is_empty() || base == entry.first_link {
+        return entry.lines.clone();
+    }
+    let mut lines = entry.lines.clone();
+    for span in lines.iter_mut().flat_map(|line| line.spans.iter_mut()) {
+        if let Some(old) = Tokens::link_index(span.style) {
+            let offset = (old + 255 - entry.first_link % 255) % 255;
+            span.style = Tokens::relink(span.style, base + offset);
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use fireside_core::Node;
+
+    use super::*;
+
+    fn node(content: &str) -> Node {
+        let json = format!(r#"{{"nodes":[{{"id":"a","content":{content}}}]}}"#);
+        let mut graph = fireside_core::Graph::from_json(&json).expect("parse");
+        graph.nodes.remove(0)
+    }
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn a_hit_matches_a_fresh_render() {
+        let tokens = Tokens::default();
+        let n = node(r#"[{"kind":"text","body":"alpha beta gamma delta"}]"#);
+        let first = node_flow(&n.id, &n.content, 12, &tokens, 0);
+        let second = node_flow(&n.id, &n.content, 12, &tokens, 0);
+        assert_eq!(first, second);
+        assert_eq!(second.0, blocks::render_blocks(&n.content, 12, &tokens, 0));
+    }
+
+    #[test]
+    fn changed_content_under_the_same_id_is_never_served_stale() {
+        let tokens = Tokens::default();
+        let before = node(r#"[{"kind":"text","body":"before"}]"#);
+        let after = node(r#"[{"kind":"text","body":"after"}]"#);
+        let _ = node_flow(&before.id, &before.content, 20, &tokens, 0);
+        assert_eq!(
+            text(&node_flow(&after.id, &after.content, 20, &tokens, 0).0),
+            vec!["after"]
+        );
+    }
+
+    #[test]
+    fn a_width_change_rewraps() {
+        let tokens = Tokens::default();
+        let n = node(r#"[{"kind":"text","body":"alpha beta gamma delta"}]"#);
+        let wide = node_flow(&n.id, &n.content, 40, &tokens, 0).0;
+        let narrow = node_flow(&n.id, &n.content, 11, &tokens, 0).0;
+        assert_eq!(wide.len(), 1);
+        assert!(narrow.len() > 1);
+    }
+
+    #[test]
+    fn a_hit_in_a_later_frame_re_registers_its_links() {
+        let tokens = Tokens::default();
+        let n = node(r#"[{"kind":"text","body":"see [docs](https://example.com)"}]"#);
+        markdown::reset_links();
+        let _ = node_flow(&n.id, &n.content, 40, &tokens, 0);
+
+        // Next frame: something else registers a link before the content.
+        markdown::reset_links();
+        let _ = markdown::wrap_styled("[x](https://other.example)", 40, tokens.text, &tokens);
+        let lines = node_flow(&n.id, &n.content, 40, &tokens, 0).0;
+        let index = lines
+            .iter()
+            .flat_map(|l| &l.spans)
+            .find_map(|s| Tokens::link_index(s.style))
+            .expect("link span");
+        assert_eq!(
+            markdown::link_url(index).as_deref(),
+            Some("https://example.com")
+        );
+    }
+}
@@ -1,12 +1,15 @@
+use std::borrow::Cow;
+
 use super::*;
-use crate::app::{FlashKind, Msg};
+use crate::app::{BlankKind, Copied, FlashKind, Msg};
 use crate::editor::forms::EditableKind;
+use crate::testing;
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 use fireside_core::{ContentBlock, Graph};
 use fireside_engine::{Outcome, RESERVED_PRESENTER_KEYS, Session};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
-use ratatui::style::Modifier;
+use ratatui::style::{Color, Modifier};
 
 /// A node with only non-editable content — a `code` block, plus a
 /// container whose children are `image`/`divider` (no heading/text
@@ -35,8 +38,7 @@ fn press_with(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
 const HELLO: &str = include_str!("../../../../docs/examples/hello.json");
 
 fn app() -> App {
-    let graph = Graph::from_json(HELLO).expect("hello parses");
-    App::new(Session::new(graph).expect("non-empty"))
+    testing::app_from_json(HELLO)
 }
 
 fn press(app: &mut App, code: KeyCode) {
@@ -45,17 +47,7 @@ fn press(app: &mut App, code: KeyCode) {
 
 /// Render the app to a plain-text screen, lines joined by '\n'.
 fn screen(app: &App, width: u16, height: u16) -> String {
-    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("backend");
-    terminal.draw(|f| draw(f, app)).expect("draw");
-    let buffer = terminal.backend().buffer().clone();
-    let mut out = String::new();
-    for y in 0..height {
-        for x in 0..width {
-            out.push_str(buffer[(x, y)].symbol());
-        }
-        out.push('\n');
-    }
-    out
+    testing::render_to_string(app, width, height)
 }
 
 #[test]
@@ -68,6 +60,28 @@ fn first_slide_shows_title_content_and_footer_contract() {
     assert!(s.contains("? help"));
 }
 
+#[test]
+fn shift_p_cycles_the_header_progress_style() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('P'));
+    assert_eq!(app.progress_style(), crate::app::ProgressStyle::Bar);
+    assert!(screen(&app, 80, 24).contains("▰▱▱▱▱▱ 1/6"));
+    press(&mut app, KeyCode::Char('P'));
+    assert!(screen(&app, 80, 24).contains("●○○○○○"));
+    press(&mut app, KeyCode::Char('P'));
+    press(&mut app, KeyCode::Char('P'));
+    assert_eq!(app.progress_style(), crate::app::ProgressStyle::Count);
+}
+
+#[test]
+fn a_narrow_header_drops_the_slide_title_before_the_progress() {
+    let app = app();
+    let s = screen(&app, 34, 12);
+    let header = s.lines().next().expect("a header row");
+    assert!(header.contains("1/6 seen"), "{header}");
+    assert!(!header.contains("·"), "{header}");
+}
+
 #[test]
 fn branch_point_renders_as_a_menu_with_selection() {
     let mut app = app();
@@ -81,6 +95,97 @@ fn branch_point_renders_as_a_menu_with_selection() {
     assert!(s.contains("Enter go"), "footer switches to branch keys");
 }
 
+#[test]
+fn branch_menu_columns_line_up_across_wide_icons_and_labels() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"a","traversal":{"branch-point":{"options":[
+                {"label":"Short","key":"a","icon":"🔥","target":"b"},
+                {"label":"Wide 漢字","key":"b","icon":"*","target":"b"},
+                {"label":"No icon","key":"c","target":"b"}
+            ]}},"content":[]},
+            {"id":"b","content":[]}
+        ]}"#,
+    )
+    .expect("fixture parses");
+    let app = App::new(Session::new(graph).expect("non-empty"));
+    let (w, h) = (60, 16);
+    let buf = buffer(&app, w, h);
+    // Screen columns by cell, not by char: a wide glyph spans two cells.
+    let cell = |needle: &str| {
+        (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .find(|&(x, y)| {
+                let rest: String = (x..w).map(|x| buf[(x, y)].symbol()).collect();
+                rest.starts_with(needle)
+            })
+            .unwrap_or_else(|| panic!("{needle:?} not on screen"))
+    };
+    let labels = [cell("Short"), cell("Wide"), cell("No icon")];
+    assert!(
+        labels.iter().all(|&(x, _)| x == labels[0].0),
+        "labels share one column: {labels:?}"
+    );
+    let keys = [cell("[a]"), cell("[b]"), cell("[c]")];
+    assert!(
+        keys.iter().all(|&(x, _)| x == keys[0].0),
+        "hotkeys share one column: {keys:?}"
+    );
+}
+
+#[test]
+fn branch_descriptions_add_their_wrapped_lines_under_a_bold_label() {
+    let menu = |description: &str| {
+        testing::app_from_json(&format!(
+            r#"{{"nodes":[
+                {{"id":"a","traversal":{{"branch-point":{{"options":[
+                    {{"label":"Quick","target":"b"{description}}},
+                    {{"label":"Deep","target":"b"}}
+                ]}}}},"content":[]}},
+                {{"id":"b","content":[]}}
+            ]}}"#
+        ))
+    };
+    let lines = |app: &App| {
+        let view = content::SlideView::from_app(app);
+        content::node_lines(&view, 40, &Tokens::default())
+    };
+    let plain = menu("");
+    let described =
+        menu(r#","description":"Five minutes on the essentials, then straight to questions""#);
+    let (plain, described_lines) = (lines(&plain), lines(&described));
+    assert_eq!(described_lines.lines.len(), plain.lines.len() + 2);
+    assert_eq!(
+        described_lines.option_rows[1],
+        plain.option_rows[1] + 2,
+        "the next option moves down past the description"
+    );
+
+    let buf = buffer(&described, 80, 24);
+    let (x, y) = locate(&buf, 80, 24, "Quick");
+    assert!(buf[(x, y)].modifier.contains(Modifier::BOLD));
+    let (x, y) = locate(&buf, 80, 24, "Deep");
+    assert!(!buf[(x, y)].modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn a_branch_label_too_wide_for_the_menu_is_clipped_with_an_ellipsis() {
+    let long = "word ".repeat(30);
+    let json = format!(
+        r#"{{"nodes":[
+            {{"id":"a","traversal":{{"branch-point":{{"options":[
+                {{"label":"{long}","key":"a","target":"b"}}
+            ]}}}},"content":[]}},
+            {{"id":"b","content":[]}}
+        ]}}"#
+    );
+    let graph = Graph::from_json(&json).expect("fixture parses");
+    let app = App::new(Session::new(graph).expect("non-empty"));
+    let s = screen(&app, 60, 16);
+    assert!(s.contains("…"), "clipped label is marked:\n{s}");
+    assert!(s.contains("[a]"), "the hotkey still fits:\n{s}");
+}
+
 #[test]
 fn reserved_presenter_keys_are_all_consumed_globally() {
     // Regression guard for the class of bug in
@@ -518,6 +623,127 @@ fn mouse_wheel_scrolls_present_content_like_arrow_keys() {
     assert_eq!(app.scroll(), 0, "wheel-up scrolls back up like ↑");
 }
 
+/// A slide of thirty short paragraphs ending in a two-way choice, then
+/// the two slides it leads to — far taller than the 60×14 viewport the
+/// presenter-scroll tests use.
+fn tall_deck_app() -> App {
+    let paras = vec![r#"{"kind":"text","body":"para"}"#; 30].join(",");
+    let json = format!(
+        r#"{{"nodes":[
+            {{"id":"tall","content":[{paras}],"traversal":"choice"}},
+            {{"id":"choice","content":[{paras}],"traversal":{{"branch-point":{{"options":[
+                {{"label":"Left","target":"left"}},{{"label":"Right","target":"right"}}
+            ]}}}}}},
+            {{"id":"left","content":[]}},
+            {{"id":"right","content":[]}}
+        ]}}"#
+    );
+    let graph = Graph::from_json(&json).expect("fixture parses");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    app.update(Msg::Terminal(Event::Resize(60, 14)));
+    app
+}
+
+#[test]
+fn page_down_pages_through_a_tall_slide_before_moving_on() {
+    let mut app = tall_deck_app();
+    let max = max_scroll(&app, 60, 14);
+    assert!(max > 0, "the fixture overflows");
+
+    press(&mut app, KeyCode::PageDown);
+    assert!(app.scroll() > 1, "a page, not a line: {}", app.scroll());
+    assert_eq!(app.session().current_index(), 0, "still on the tall slide");
+    let s = screen(&app, 60, 14);
+    assert!(s.contains('▲') && s.contains("▼ more"), "{s}");
+
+    press(&mut app, KeyCode::PageUp);
+    assert_eq!(app.scroll(), 0);
+    assert_eq!(
+        app.session().current_index(),
+        0,
+        "PageUp scrolled back first"
+    );
+
+    while app.scroll() < max {
+        press(&mut app, KeyCode::PageDown);
+        assert_eq!(app.session().current_index(), 0);
+    }
+    assert!(
+        screen(&app, 60, 14).contains("Space next"),
+        "footer stays put"
+    );
+    press(&mut app, KeyCode::PageDown);
+    assert_eq!(
+        app.session().current().id,
+        "choice",
+        "at the bottom it moves on"
+    );
+    assert_eq!(app.scroll(), 0, "a new slide starts at the top");
+}
+
+#[test]
+fn going_back_to_a_branch_point_starts_on_the_option_chosen_last_time() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char(' '));
+    assert_eq!(app.session().current().id, "choose");
+    assert!(!screen(&app, 80, 24).contains("last chosen"));
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.session().current().id, "layout-demo");
+    press(&mut app, KeyCode::Left);
+    assert_eq!(app.session().current().id, "choose");
+    assert_eq!(
+        app.branch_selected(),
+        1,
+        "the menu starts on the last choice"
+    );
+    let s = screen(&app, 80, 24);
+    let marked: Vec<&str> = s.lines().filter(|l| l.contains("✓ last chosen")).collect();
+    assert_eq!(marked.len(), 1);
+    assert!(marked[0].contains("▸ 2."), "{marked:?}");
+}
+
+#[test]
+fn a_timed_choice_counts_down_under_its_options() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"a","traversal":{"branch-point":{"timeout":30,"default-option":"n",
+                "options":[
+                    {"label":"Yes","key":"y","target":"b"},
+                    {"label":"No","key":"n","target":"b"}
+                ]}},"content":[]},
+            {"id":"b","content":[]}
+        ]}"#,
+    )
+    .expect("parse");
+    let app = App::new(Session::new(graph).expect("non-empty"));
+    let s = screen(&app, 80, 24);
+    let line = s
+        .lines()
+        .find(|l| l.contains("to choose"))
+        .expect("a countdown line");
+    assert!(line.contains("30s to choose — then \"No\""), "{line:?}");
+}
+
+#[test]
+fn moving_the_branch_highlight_scrolls_the_menu_into_view() {
+    let mut app = tall_deck_app();
+    press(&mut app, KeyCode::Char(' '));
+    assert_eq!(app.session().current().id, "choice");
+    assert!(
+        !screen(&app, 60, 14).contains("Right"),
+        "menu starts below the fold"
+    );
+
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.branch_selected(), 1);
+    assert_eq!(app.session().current().id, "choice");
+    let s = screen(&app, 60, 14);
+    assert!(s.contains("▸") && s.contains("Right"), "{s}");
+}
+
 #[test]
 fn mouse_wheel_moves_the_map_selection() {
     let mut app = app();
@@ -537,6 +763,99 @@ fn mouse_wheel_moves_the_map_selection() {
     );
 }
 
+fn type_goto(app: &mut App, text: &str) {
+    press(app, KeyCode::Char(':'));
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+#[test]
+fn goto_cycles_through_ambiguous_id_prefixes() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"start","content":[]},
+            {"id":"intro","content":[]},
+            {"id":"intro-2","content":[]},
+            {"id":"intro-3","content":[]}
+        ]}"#,
+    )
+    .expect("fixture parses");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    type_goto(&mut app, "intro");
+    assert!(
+        screen(&app, 60, 12).contains("1/3 intro"),
+        "prompt shows the match count"
+    );
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.session().current().id, "intro");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(
+        app.session().current().id,
+        "intro-2",
+        "a second Enter moves on"
+    );
+    assert!(screen(&app, 60, 12).contains("2/3 intro-2"));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.session().current().id, "intro-3");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.session().current().id, "intro", "cycling wraps around");
+
+    press(&mut app, KeyCode::Tab);
+    assert!(
+        screen(&app, 60, 12).contains("2/3 intro-2"),
+        "Tab moves the highlight"
+    );
+    assert_eq!(
+        app.session().current().id,
+        "intro",
+        "Tab alone doesn't jump"
+    );
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(*app.screen(), Screen::Present);
+}
+
+#[test]
+fn goto_falls_back_to_a_fuzzy_search_of_titles() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"start","title":"Welcome","content":[]},
+            {"id":"s2","title":"Quarterly budget","content":[]},
+            {"id":"s3","title":"Big design goals","content":[]}
+        ]}"#,
+    )
+    .expect("fixture parses");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    type_goto(&mut app, "budget");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.session().current().id, "s2", "no id starts with it");
+
+    type_goto(&mut app, "bdg");
+    assert!(
+        screen(&app, 60, 12).contains("1/2 s2"),
+        "the closer title ranks first"
+    );
+}
+
+#[test]
+fn goto_keeps_numbers_as_one_based_slide_jumps() {
+    let mut app = app();
+    type_goto(&mut app, "2");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(*app.screen(), Screen::Present);
+    assert_eq!(app.session().current().id, "features");
+
+    type_goto(&mut app, "999");
+    press(&mut app, KeyCode::Enter);
+    assert!(screen(&app, 60, 12).contains("no match"));
+    assert_eq!(
+        app.session().current().id,
+        "features",
+        "out of range moves nothing"
+    );
+}
+
 #[test]
 fn clicking_a_map_row_navigates_to_that_slide() {
     let mut app = app();
@@ -640,6 +959,16 @@ fn the_ending_lists_the_route_travelled() {
     assert!(s.contains("→"), "path trace shown on the ending: {s}");
 }
 
+#[test]
+fn a_route_longer_than_route_stops_collapses_its_middle() {
+    let mut app = app().with_route_stops(3);
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char('c'));
+    let s = screen(&app, 80, 24);
+    assert!(s.contains("intro → … → choose → thanks"), "{s}");
+}
+
 #[test]
 fn help_overlay_opens_and_any_key_closes() {
     let mut app = app();
@@ -689,6 +1018,57 @@ fn speaker_notes_toggle_and_absence_flashes() {
     assert!(s.contains("Demonstrate fullscreen view mode"));
 }
 
+fn screen_for(app: &App, audience: ViewAudience) -> String {
+    testing::render_view_to_string(app, audience, 80, 24)
+}
+
+#[test]
+fn the_audience_view_leaves_out_notes_and_timers_the_presenter_has_on() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Char('a')); // code-demo has notes
+    press(&mut app, KeyCode::Char('f')); // standard frame for the panel
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Char('t'));
+    assert!(app.show_notes() && app.show_timer());
+
+    let presenter = screen_for(&app, ViewAudience::Presenter);
+    assert!(
+        presenter.contains("Demonstrate fullscreen view mode"),
+        "{presenter}"
+    );
+    assert!(presenter.contains("next: thanks"), "{presenter}");
+    assert!(presenter.contains("0:0"), "the elapsed timer: {presenter}");
+
+    let audience = screen_for(&app, ViewAudience::Audience);
+    assert!(
+        !audience.contains("Demonstrate fullscreen view mode"),
+        "{audience}"
+    );
+    assert!(!audience.contains("Notes"), "{audience}");
+    assert!(!audience.contains("0:0"), "{audience}");
+    assert!(audience.contains("Code"), "the slide itself: {audience}");
+}
+
+#[test]
+fn a_toggles_the_audience_view_and_an_overlay_brings_the_presenter_back() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('A'));
+    assert_eq!(app.view_audience(), ViewAudience::Audience);
+    assert_eq!(
+        screen(&app, 80, 24),
+        screen_for(&app, ViewAudience::Audience)
+    );
+    press(&mut app, KeyCode::Char('m'));
+    assert_eq!(app.view_audience(), ViewAudience::Presenter);
+    assert!(matches!(app.screen(), Screen::Map { .. }));
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('A'));
+    press(&mut app, KeyCode::Char('A'));
+    assert_eq!(app.view_audience(), ViewAudience::Presenter);
+}
+
 #[test]
 fn q_quits() {
     let mut app = app();
@@ -705,9 +1085,7 @@ fn tiny_terminal_degrades_gracefully() {
 
 /// Render and return the raw buffer for style-level assertions.
 fn buffer(app: &App, width: u16, height: u16) -> ratatui::buffer::Buffer {
-    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("backend");
-    terminal.draw(|f| draw(f, app)).expect("draw");
-    terminal.backend().buffer().clone()
+    testing::render_to_buffer(app, width, height)
 }
 
 /// The (x, y) of the first cell where `needle` starts on screen.
@@ -764,6 +1142,33 @@ fn fullscreen_uses_the_full_width_not_the_measure() {
     insta::assert_snapshot!(screen(&app, 120, 30));
 }
 
+#[test]
+fn max_width_caps_and_centers_the_fullscreen_column() {
+    // 40 columns of text: one line at a 40-column cap, two at 39.
+    let deck = format!(
+        r#"{{"nodes":[{{"id":"a","view-mode":"fullscreen","content":[
+            {{"kind":"text","body":"{}"}}
+        ]}}]}}"#,
+        ["abcdefghi"; 4].join(" ") + "x"
+    );
+    let capped = |width| {
+        App::new(Session::new(Graph::from_json(&deck).expect("parses")).expect("non-empty"))
+            .with_content_max_width(Some(width))
+    };
+
+    let at_limit = screen(&capped(40), 120, 20);
+    let rows: Vec<_> = at_limit.lines().filter(|l| l.contains("abc")).collect();
+    assert_eq!(rows.len(), 1, "{at_limit}");
+    assert_eq!(rows[0].find("abc"), Some(40), "centered: (120 - 40) / 2");
+
+    let over = screen(&capped(39), 120, 20);
+    assert_eq!(
+        over.lines().filter(|l| l.contains("abc")).count(),
+        2,
+        "{over}"
+    );
+}
+
 #[test]
 fn ascii_art_code_block_centers_within_the_card_at_80x24() {
     const ASCII_ART: &str = r#"{"nodes":[{"id":"a","content":[
@@ -903,6 +1308,42 @@ fn reveal_then_next_advances_normally_once_exhausted() {
     assert_eq!(app.session().current().id, "b", "second press navigates");
 }
 
+#[test]
+fn an_incremental_list_builds_item_by_item_and_back_unbuilds_it() {
+    const DECK: &str = r#"{"nodes":[
+        {"id":"a","traversal":"b","content":[
+            {"kind":"list","incremental":true,"items":["Alpha","Bravo","Charlie"]}
+        ]},
+        {"id":"b","content":[{"kind":"text","body":"On b"}]}
+    ]}"#;
+    let mut app =
+        App::new(Session::new(Graph::from_json(DECK).expect("fixture parses")).expect("non-empty"));
+    let s = screen(&app, 80, 24);
+    assert!(s.contains("Alpha") && !s.contains("Bravo"), "{s}");
+
+    press(&mut app, KeyCode::Char(' '));
+    let s = screen(&app, 80, 24);
+    assert!(s.contains("Bravo") && !s.contains("Charlie"), "{s}");
+    press(&mut app, KeyCode::Char(' '));
+    assert!(screen(&app, 80, 24).contains("Charlie"));
+    press(&mut app, KeyCode::Char(' '));
+    assert_eq!(app.session().current().id, "b", "all shown, so next moves");
+
+    press(&mut app, KeyCode::Left);
+    assert_eq!(app.session().current().id, "a");
+    assert!(
+        !screen(&app, 80, 24).contains("Bravo"),
+        "re-entry starts over"
+    );
+    press(&mut app, KeyCode::Char(' '));
+    press(&mut app, KeyCode::Left);
+    let s = screen(&app, 80, 24);
+    assert_eq!(app.session().current().id, "a", "back hides before leaving");
+    assert!(s.contains("Alpha") && !s.contains("Bravo"), "{s}");
+    press(&mut app, KeyCode::Left);
+    assert_eq!(app.session().current().id, "a", "nothing behind a");
+}
+
 #[test]
 fn branch_keys_continue_revealing_instead_of_choosing_early() {
     const DECK: &str = r#"{"nodes":[
@@ -1023,6 +1464,67 @@ fn highlight_lines_dim_the_rest_and_keep_focus_bright() {
     );
 }
 
+#[test]
+fn the_spotlit_block_stays_bright_while_the_rest_dim() {
+    let mut app = testing::app_from_json(
+        r#"{"nodes":[{"id":"only","content":[
+            {"kind":"heading","level":2,"text":"Agenda"},
+            {"kind":"text","body":"First point"},
+            {"kind":"text","body":"Second point"}
+        ]}]}"#,
+    );
+    let (w, h) = (60, 20);
+    let dimmed = |app: &App, needle: &str| {
+        let buf = buffer(app, w, h);
+        let (x, y) = locate(&buf, w, h, needle);
+        buf[(x, y)].style().add_modifier.contains(Modifier::DIM)
+    };
+    assert!(!dimmed(&app, "First point"), "no spotlight, nothing dims");
+
+    press(&mut app, KeyCode::Char('o'));
+    press(&mut app, KeyCode::Char('o'));
+    assert!(!dimmed(&app, "First point"), "the spotlit block");
+    assert!(dimmed(&app, "Agenda"));
+    assert!(dimmed(&app, "Second point"));
+
+    press(&mut app, KeyCode::Esc);
+    assert!(!dimmed(&app, "Agenda"), "Esc lifts the spotlight");
+}
+
+#[test]
+fn a_node_paints_its_own_background_and_accent_and_ignores_a_bad_one() {
+    let mut app = testing::app_from_json(
+        r##"{"nodes":[
+            {"id":"dark","background":"#101010","accent":"#fa0","traversal":"plain",
+             "content":[{"kind":"heading","level":1,"text":"Section one"}]},
+            {"id":"plain","background":"navy","content":[{"kind":"heading","level":1,"text":"Plain"}]}
+        ]}"##,
+    );
+    let tokens = Tokens::default().for_node(app.session().current());
+    assert_eq!(tokens.background.bg, Some(Color::Rgb(0x10, 0x10, 0x10)));
+
+    let (w, h) = (60, 20);
+    let buf = buffer(&app, w, h);
+    let (x, y) = locate(&buf, w, h, "Section one");
+    assert_eq!(buf[(x, y)].fg, Color::Rgb(0xff, 0xaa, 0x00));
+    assert_eq!(buf[(x, y)].bg, Color::Rgb(0x10, 0x10, 0x10));
+    assert_eq!(
+        buf[(0, h - 1)].bg,
+        Color::Rgb(0x10, 0x10, 0x10),
+        "the whole frame"
+    );
+
+    press(&mut app, KeyCode::Char(' '));
+    let buf = buffer(&app, w, h);
+    let (x, y) = locate(&buf, w, h, "Plain");
+    assert_eq!(
+        buf[(x, y)].bg,
+        Color::Reset,
+        "an unparseable color falls back"
+    );
+    assert_eq!(buf[(x, y)].fg, Color::Cyan);
+}
+
 #[test]
 fn fade_transition_starts_dim_and_is_only_for_fade_nodes() {
     let mut app = app();
@@ -1357,6 +1859,8 @@ fn quick_edit_cancel_leaves_the_session_and_pending_save_untouched() {
         app.session().current().content[0],
         ContentBlock::Heading {
             reveal: None,
+            id: None,
+            visible_if: None,
             level: 2,
             text: "Core Features".to_owned(),
         },
@@ -1476,8 +1980,20 @@ fn save_result_flashes_a_distinct_message_for_every_write_back_error() {
         (crate::WriteBackError::Unavailable, "no file to save to"),
         (crate::WriteBackError::Conflict, "changed on disk"),
         (
-            crate::WriteBackError::Io("disk full".to_owned()),
-            "disk full",
+            crate::WriteBackError::PermissionDenied("deck.json".to_owned()),
+            "no permission",
+        ),
+        (
+            crate::WriteBackError::NoSuchDirectory("talks".to_owned()),
+            "create it",
+        ),
+        (
+            crate::WriteBackError::DiskFull("deck.json".to_owned()),
+            "is full",
+        ),
+        (
+            crate::WriteBackError::Io("device busy".to_owned()),
+            "device busy",
         ),
     ] {
         let mut app = app();
@@ -1759,13 +2275,18 @@ fn preview_fidelity_editor_canvas_matches_the_presenter_for_every_fixture_deck()
 
         let editor_view = content::SlideView {
             node,
+            content: Cow::Borrowed(&node.content),
             reveal_level: u32::MAX,
             has_pending_reveal: false,
             branch_selected: 0,
             fading: false,
             scroll: 0,
             view_mode: node.resolved_view_mode(graph.defaults.as_ref()),
-            history_titles: Vec::new(),
+            max_width: None,
+            route: Vec::new(),
+            spotlight: None,
+            last_choice: None,
+            choice_left: None,
         };
         let mut editor_terminal = Terminal::new(TestBackend::new(w, h)).expect("backend");
         editor_terminal
@@ -1873,3 +2394,255 @@ fn container_child_selection_glow_and_edit_round_trip_through_the_real_render_pi
         "the edited child's new text must render on the canvas: {screen_after}"
     );
 }
+
+fn code_app(blocks: &str) -> App {
+    let json = format!(r#"{{"nodes":[{{"id":"a","content":{blocks}}}]}}"#);
+    let graph = Graph::from_json(&json).expect("fixture parses");
+    App::new(Session::new(graph).expect("non-empty"))
+}
+
+#[test]
+fn shift_y_copies_a_lone_code_block_straight_away() {
+    let mut app = code_app(r#"[{"kind":"code","source":"cargo run"}]"#);
+    press(&mut app, KeyCode::Char('Y'));
+    assert_eq!(app.take_pending_copy().as_deref(), Some("cargo run"));
+    assert_eq!(*app.screen(), Screen::Present);
+
+    app.update(Msg::CopyResult(Ok(Copied::File("/tmp/snippet.txt".into()))));
+    let s = screen(&app, 80, 24);
+    assert!(s.contains("saved the snippet to /tmp/snippet.txt"), "{s}");
+}
+
+#[test]
+fn shift_y_picks_between_several_code_blocks_starting_on_the_runnable_one() {
+    let mut app = code_app(
+        r#"[
+            {"kind":"code","language":"rust","source":"fn main() {}"},
+            {"kind":"code","language":"sh","source":"cargo run","runnable":true},
+            {"kind":"code","source":"not yet","reveal":1}
+        ]"#,
+    );
+    press(&mut app, KeyCode::Char('Y'));
+    assert_eq!(*app.screen(), Screen::Copy { selected: 1 });
+    let s = screen(&app, 80, 24);
+    assert!(s.contains("Copy which block?"), "{s}");
+    assert!(
+        !s.contains("not yet"),
+        "unrevealed code isn't offered:\n{s}"
+    );
+
+    press(&mut app, KeyCode::Char('1'));
+    assert_eq!(app.take_pending_copy().as_deref(), Some("fn main() {}"));
+    assert_eq!(*app.screen(), Screen::Present);
+}
+
+#[test]
+fn the_jump_list_scrolls_to_keep_the_highlighted_slide_in_view() {
+    let nodes: Vec<String> = (1..=15)
+        .map(|i| {
+            let next = if i < 15 {
+                format!(r#","traversal":"s{}""#, i + 1)
+            } else {
+                String::new()
+            };
+            format!(r#"{{"id":"s{i}"{next},"content":[]}}"#)
+        })
+        .collect();
+    let json = format!(r#"{{"nodes":[{}]}}"#, nodes.join(","));
+    let graph = Graph::from_json(&json).expect("fixture parses");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    for _ in 1..15 {
+        press(&mut app, KeyCode::Right);
+    }
+    press(&mut app, KeyCode::Char('H'));
+    let s = screen(&app, 80, 30);
+    assert!(s.contains("Jump back to"), "{s}");
+    assert!(s.contains("s14"), "{s}");
+    assert!(s.contains("1/14"), "{s}");
+
+    press(&mut app, KeyCode::End);
+    let s = screen(&app, 80, 30);
+    assert!(s.contains("14/14"), "{s}");
+    assert!(
+        s.contains("▸ 1"),
+        "the oldest slide scrolled into view:\n{s}"
+    );
+    assert!(!s.contains("s14"), "{s}");
+}
+
+#[test]
+fn the_contents_draw_a_numbered_tree_marking_the_slide_on_screen() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"intro","title":"Welcome","section":"Start","traversal":"deep","content":[]},
+            {"id":"deep","section":"Middle","content":[
+                {"kind":"heading","level":1,"text":"Going deeper"},
+                {"kind":"heading","level":2,"text":"Ownership"}]}
+        ]}"#,
+    )
+    .expect("fixture parses");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    press(&mut app, KeyCode::Char('O'));
+    let s = screen(&app, 80, 30);
+    assert!(s.contains("Contents"), "{s}");
+    assert!(s.contains("▸  1●Welcome"), "{s}");
+    assert!(s.contains("   2 Going deeper"), "{s}");
+    assert!(s.contains("· Ownership"), "{s}");
+}
+
+#[test]
+fn shift_y_on_a_slide_without_code_says_so() {
+    let mut app = code_app(r#"[{"kind":"text","body":"prose"}]"#);
+    press(&mut app, KeyCode::Char('Y'));
+    assert!(app.take_pending_copy().is_none());
+    assert!(screen(&app, 80, 24).contains("no code to copy"));
+}
+
+#[test]
+fn b_blanks_the_screen_and_the_next_key_only_brings_it_back() {
+    let mut app = app();
+    let here = app.session().current().id.clone();
+    press(&mut app, KeyCode::Char('b'));
+    assert_eq!(app.blank(), Some(BlankKind::Black));
+    assert!(screen(&app, 40, 10).trim().is_empty());
+
+    app.update(Msg::Terminal(Event::Resize(60, 20)));
+    press(&mut app, KeyCode::Char(' '));
+    assert_eq!(app.blank(), None);
+    assert_eq!(
+        app.session().current().id,
+        here,
+        "the restoring key didn't advance"
+    );
+    assert!(!screen(&app, 60, 20).trim().is_empty());
+}
+
+#[test]
+fn w_fills_the_screen_with_the_text_color() {
+    let mut app = app();
+    press(&mut app, KeyCode::Char('w'));
+    let backend = TestBackend::new(20, 6);
+    let mut terminal = Terminal::new(backend).expect("terminal");
+    terminal.draw(|f| draw(f, &app)).expect("draw");
+    let buffer = terminal.backend().buffer();
+    assert!(
+        buffer
+            .content()
+            .iter()
+            .all(|cell| cell.symbol() == " " && cell.modifier.contains(Modifier::REVERSED))
+    );
+}
+
+#[test]
+fn a_branch_option_keyed_b_wins_over_blanking() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"a","traversal":{"branch-point":{"options":[
+                {"label":"Basics","key":"b","target":"b"}
+            ]}},"content":[]},
+            {"id":"b","content":[]}
+        ]}"#,
+    )
+    .expect("fixture parses");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    press(&mut app, KeyCode::Char('b'));
+    assert_eq!(app.blank(), None);
+    assert_eq!(app.session().current().id, "b");
+    press(&mut app, KeyCode::Char('b'));
+    assert_eq!(app.blank(), Some(BlankKind::Black));
+}
+
+#[test]
+fn shift_t_starts_and_pauses_a_countdown_shown_in_the_footer() {
+    let mut app = app();
+    assert!(app.side_timer().is_none());
+    assert!(!screen(&app, 80, 24).contains("left"));
+
+    press(&mut app, KeyCode::Char('T'));
+    let timer = app.side_timer().expect("T creates the countdown");
+    assert!(timer.is_running());
+    assert_eq!(timer.target(), crate::DEFAULT_COUNTDOWN);
+
+    press(&mut app, KeyCode::Char('T'));
+    assert!(!app.side_timer().is_some_and(crate::SideTimer::is_running));
+    // The pause flash gives way to the hints; the readout stays.
+    press(&mut app, KeyCode::Char('R'));
+    assert!(screen(&app, 80, 24).contains("10:00 paused"));
+}
+
+#[test]
+fn brackets_jump_between_sections_and_the_header_names_the_chapter() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"cover","title":"Cover","traversal":"a1","content":[]},
+            {"id":"a1","title":"Why","section":"Intro","traversal":"a2","content":[]},
+            {"id":"a2","title":"Who","section":"Intro","traversal":"b1","content":[]},
+            {"id":"b1","title":"Setup","section":"Demo","content":[]}
+        ]}"#,
+    )
+    .expect("parse");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+
+    press(&mut app, KeyCode::Char(']'));
+    assert_eq!(app.session().current().id, "a1");
+    assert!(screen(&app, 80, 24).contains("Intro › Why"));
+
+    press(&mut app, KeyCode::Char(']'));
+    assert_eq!(app.session().current().id, "b1");
+    press(&mut app, KeyCode::Char(']'));
+    assert_eq!(app.session().current().id, "b1");
+    assert!(screen(&app, 80, 24).contains("This is the last section"));
+
+    press(&mut app, KeyCode::Char('['));
+    assert_eq!(app.session().current().id, "a1");
+}
+
+#[test]
+fn a_branch_option_keyed_with_a_bracket_wins_over_the_section_jump() {
+    let graph = Graph::from_json(
+        r#"{"nodes":[
+            {"id":"a","traversal":{"branch-point":{"options":[
+                {"label":"Later","key":"]","target":"c"}
+            ]}},"content":[]},
+            {"id":"b","section":"Next","content":[]},
+            {"id":"c","content":[]}
+        ]}"#,
+    )
+    .expect("parse");
+    let mut app = App::new(Session::new(graph).expect("non-empty"));
+    press(&mut app, KeyCode::Char(']'));
+    assert_eq!(app.session().current().id, "c", "the option, not section b");
+}
+
+#[test]
+fn home_and_end_jump_to_the_deck_boundaries() {
+    let mut app = app();
+    let last = app.session().graph().nodes.len() - 1;
+    press(&mut app, KeyCode::End);
+    assert_eq!(app.session().current_index(), last);
+    press(&mut app, KeyCode::Home);
+    assert_eq!(app.session().current_index(), 0);
+    // Both are explicit jumps: back retraces them.
+    press(&mut app, KeyCode::Left);
+    assert_eq!(app.session().current_index(), last);
+}
+
+#[test]
+fn a_block_whose_condition_fails_is_not_drawn() {
+    let mut app = testing::app_from_json(
+        r#"{"nodes":[
+            {"id":"ask","traversal":{"branch-point":{"options":[
+                {"label":"Deep","key":"d","target":"end"},
+                {"label":"Skim","key":"s","target":"end"}]}},"content":[]},
+            {"id":"end","content":[
+                {"kind":"text","body":"Everyone sees this"},
+                {"kind":"text","body":"The deep dive","visible-if":"ask == d"}
+            ]}
+        ]}"#,
+    );
+    press(&mut app, KeyCode::Char('2')); // Skim
+    assert_eq!(app.session().current().id, "end");
+    let s = screen(&app, 80, 24);
+    assert!(s.contains("Everyone sees this"), "got: {s}");
+    assert!(!s.contains("The deep dive"), "got: {s}");
+}
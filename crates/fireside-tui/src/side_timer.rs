@@ -0,0 +1,164 @@
+//! The presenter's side countdown — "ten minutes for Q&A" — kept apart
+//! from the deck's own elapsed timer so starting, pausing, or resetting it
+//! never disturbs how long the talk has run.
+//!
+//! Every method that depends on time takes `now` rather than reading the
+//! clock itself: `App` passes `Instant::now()`, and tests pass instants
+//! they built, so pause/resume arithmetic is checked exactly.
+
+use std::time::{Duration, Instant};
+
+/// A countdown toward `target` that can be paused and resumed. Time spent
+/// running is banked at each pause; time spent paused never counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideTimer {
+    target: Duration,
+    banked: Duration,
+    running_since: Option<Instant>,
+}
+
+impl SideTimer {
+    /// A paused countdown with the whole of `target` remaining.
+    #[must_use]
+    pub fn new(target: Duration) -> Self {
+        Self {
+            target,
+            banked: Duration::ZERO,
+            running_since: None,
+        }
+    }
+
+    /// Starts or resumes counting down. Already running: no change.
+    pub fn start(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Stops counting down, banking the time run since the last start.
+    /// Already paused: no change.
+    pub fn pause(&mut self, now: Instant) {
+        if let Some(since) = self.running_since.take() {
+            self.banked += now.saturating_duration_since(since);
+        }
+    }
+
+    /// Pauses a running countdown or resumes a paused one.
+    pub fn toggle(&mut self, now: Instant) {
+        if self.is_running() {
+            self.pause(now);
+        } else {
+            self.start(now);
+        }
+    }
+
+    /// Back to the whole target remaining, paused.
+    pub fn reset(&mut self) {
+        self.banked = Duration::ZERO;
+        self.running_since = None;
+    }
+
+    /// Whether the countdown is running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// The length of the countdown.
+    #[must_use]
+    pub fn target(&self) -> Duration {
+        self.target
+    }
+
+    /// Time counted so far, across every start and pause.
+    #[must_use]
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.banked
+            + self
+                .running_since
+                .map_or(Duration::ZERO, |since| now.saturating_duration_since(since))
+    }
+
+    /// Time left before zero; `Duration::ZERO` once it has been reached.
+    #[must_use]
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.target.saturating_sub(self.elapsed(now))
+    }
+
+    /// How far past zero the countdown has run; `Duration::ZERO` before.
+    #[must_use]
+    pub fn overrun(&self, now: Instant) -> Duration {
+        self.elapsed(now).saturating_sub(self.target)
+    }
+
+    /// Whether the countdown has reached zero.
+    #[must_use]
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.elapsed(now) >= self.target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(n: u64) -> Duration {
+        Duration::from_secs(n)
+    }
+
+    #[test]
+    fn a_new_timer_is_paused_with_everything_remaining() {
+        let t0 = Instant::now();
+        let timer = SideTimer::new(secs(600));
+        assert!(!timer.is_running());
+        assert_eq!(timer.remaining(t0 + secs(30)), secs(600));
+    }
+
+    #[test]
+    fn paused_time_never_counts_and_resumed_time_accumulates() {
+        let t0 = Instant::now();
+        let mut timer = SideTimer::new(secs(600));
+        timer.start(t0);
+        timer.pause(t0 + secs(100));
+        // A long pause: nothing counts.
+        assert_eq!(timer.elapsed(t0 + secs(400)), secs(100));
+        timer.start(t0 + secs(400));
+        assert_eq!(timer.elapsed(t0 + secs(450)), secs(150));
+        timer.pause(t0 + secs(460));
+        assert_eq!(timer.elapsed(t0 + secs(9_999)), secs(160));
+        assert_eq!(timer.remaining(t0 + secs(9_999)), secs(440));
+    }
+
+    #[test]
+    fn repeated_start_or_pause_changes_nothing() {
+        let t0 = Instant::now();
+        let mut timer = SideTimer::new(secs(60));
+        timer.start(t0);
+        timer.start(t0 + secs(10));
+        assert_eq!(timer.elapsed(t0 + secs(20)), secs(20));
+        timer.pause(t0 + secs(20));
+        timer.pause(t0 + secs(30));
+        assert_eq!(timer.elapsed(t0 + secs(40)), secs(20));
+    }
+
+    #[test]
+    fn running_past_zero_reports_the_overrun() {
+        let t0 = Instant::now();
+        let mut timer = SideTimer::new(secs(60));
+        timer.toggle(t0);
+        assert!(!timer.is_expired(t0 + secs(59)));
+        assert!(timer.is_expired(t0 + secs(60)));
+        assert_eq!(timer.remaining(t0 + secs(75)), Duration::ZERO);
+        assert_eq!(timer.overrun(t0 + secs(75)), secs(15));
+    }
+
+    #[test]
+    fn reset_restores_the_whole_target_paused() {
+        let t0 = Instant::now();
+        let mut timer = SideTimer::new(secs(60));
+        timer.start(t0);
+        timer.reset();
+        assert!(!timer.is_running());
+        assert_eq!(timer.remaining(t0 + secs(30)), secs(60));
+    }
+}
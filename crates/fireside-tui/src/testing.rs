@@ -0,0 +1,111 @@
+//! Headless rendering for tests: draw the presenter or the editor into a
+//! fixed-size buffer and read it back as plain text, so a layout can be
+//! pinned with an `insta` snapshot without a terminal.
+//!
+//! Frames are deterministic — one line per row, each exactly `width`
+//! cells, every row ending in `\n` — so the same app at the same size
+//! always produces the same string.
+
+use crossterm::event::Event;
+use fireside_core::Graph;
+use fireside_engine::Session;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+
+use crate::App;
+use crate::editor::{self, EditorApp};
+use crate::render;
+
+/// A presenter on the deck in `json`, at its first slide.
+///
+/// # Panics
+///
+/// When `json` isn't a deck or has no nodes — a broken fixture.
+pub(crate) fn app_from_json(json: &str) -> App {
+    let graph = Graph::from_json(json).expect("fixture parses");
+    App::new(Session::new(graph).expect("fixture has nodes"))
+}
+
+/// An editor on the deck in `json`, sized to `width`×`height` so its hit
+/// testing agrees with [`render_editor_to_string`] at the same size.
+///
+/// # Panics
+///
+/// When `json` isn't a deck — a broken fixture.
+pub(crate) fn editor_from_json(json: &str, width: u16, height: u16) -> EditorApp {
+    let mut app = EditorApp::new(Graph::from_json(json).expect("fixture parses"));
+    app.update(editor::Msg::Terminal(Event::Resize(width, height)));
+    app
+}
+
+/// The presenter's frame at `width`×`height`, as text.
+pub(crate) fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    to_text(&render_to_buffer(app, width, height))
+}
+
+/// The presenter's frame at `width`×`height` drawn for `audience`, as
+/// text — whichever view the app itself is in.
+pub(crate) fn render_view_to_string(
+    app: &App,
+    audience: crate::ViewAudience,
+    width: u16,
+    height: u16,
+) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("backend");
+    terminal
+        .draw(|f| render::draw_view(f, app, audience))
+        .expect("draw");
+    to_text(terminal.backend().buffer())
+}
+
+/// The presenter's frame at `width`×`height`, for style-level checks.
+pub(crate) fn render_to_buffer(app: &App, width: u16, height: u16) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("backend");
+    terminal.draw(|f| render::draw(f, app)).expect("draw");
+    terminal.backend().buffer().clone()
+}
+
+/// The editor's frame at `width`×`height`, as text.
+pub(crate) fn render_editor_to_string(app: &EditorApp, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("backend");
+    terminal
+        .draw(|f| render::draw_editor(f, app))
+        .expect("draw");
+    to_text(terminal.backend().buffer())
+}
+
+fn to_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            out.push_str(buffer[(x, y)].symbol());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+mod tests {
+    use super::*;
+
+    const HEADING_AND_TEXT: &str = r#"{
+        "title": "Golden",
+        "nodes": [{
+            "id": "only",
+            "content": [
+                { "kind": "heading", "level": 1, "text": "Snapshot testing" },
+                { "kind": "text", "body": "Every frame is plain text, so a layout change shows up as a diff." }
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn a_heading_and_text_slide_renders_the_same_frame_every_time() {
+        let app = app_from_json(HEADING_AND_TEXT);
+        let frame = render_to_string(&app, 60, 20);
+        assert_eq!(frame, render_to_string(&app, 60, 20));
+        insta::assert_snapshot!(frame);
+    }
+}
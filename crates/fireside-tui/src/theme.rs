@@ -1,15 +1,33 @@
 //! The design tokens — every color and text style in the presenter.
 //!
-//! One polished default theme. It deliberately uses ANSI palette colors and
-//! leaves the background untouched (`Color::Reset`), so it sits well on any
-//! terminal the presenter already likes. No render code may construct a
-//! `Style` from raw colors; everything goes through [`Tokens`].
+//! Two bundled themes, one for a dark terminal and one for a light one
+//! ([`Theme`]). Both deliberately use ANSI palette colors and leave the
+//! background untouched (`Color::Reset`), so they sit well on whatever
+//! terminal the presenter already likes — unless a node asks for its own
+//! `background` or `accent` ([`Tokens::for_node`]). No render code may
+//! construct a `Style` from raw colors; everything goes through [`Tokens`].
 
+use fireside_core::{Node, parse_hex_color};
 use ratatui::style::{Color, Modifier, Style};
 
+/// Which bundled theme to draw with: the one that reads on the terminal's
+/// background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// Light text colors for a dark background — the default.
+    #[default]
+    Dark,
+    /// Deeper colors for a light background, where the dark theme's
+    /// light gray code and cyan accent wash out.
+    Light,
+}
+
 /// Semantic styles for the presenter UI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Tokens {
+    /// The slide's fill: the terminal's own background, unless the node
+    /// sets one.
+    pub background: Style,
     /// Body text.
     pub text: Style,
     /// De-emphasized text: hints, captions, separators, metadata.
@@ -65,6 +83,7 @@ pub struct Tokens {
 impl Default for Tokens {
     fn default() -> Self {
         Self {
+            background: Style::new(),
             text: Style::new(),
             muted: Style::new().fg(Color::DarkGray),
             accent: Style::new().fg(Color::Cyan),
@@ -98,6 +117,54 @@ impl Default for Tokens {
 }
 
 impl Tokens {
+    /// The bundled tokens for `theme`. The light theme is the dark one
+    /// with the washed-out colors swapped: code in the terminal's own
+    /// foreground, and blue where the dark theme uses cyan.
+    #[must_use]
+    pub fn for_theme(theme: Theme) -> Self {
+        let dark = Self::default();
+        match theme {
+            Theme::Dark => dark,
+            Theme::Light => Self {
+                accent: Style::new().fg(Color::Blue),
+                code: Style::new(),
+                code_function: Style::new().fg(Color::Magenta),
+                code_keyword: Style::new().fg(Color::Red),
+                code_type: Style::new().fg(Color::Blue),
+                rail_lines: [
+                    Style::new().fg(Color::Magenta),
+                    Style::new().fg(Color::Red),
+                    Style::new().fg(Color::Green),
+                    Style::new().fg(Color::Cyan),
+                ],
+                affordance: Style::new().fg(Color::Blue),
+                selection: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+                ..dark
+            },
+        }
+    }
+
+    /// These tokens with `node`'s own `background` and `accent` laid over
+    /// them. A color that isn't hex is ignored — validation warns about it
+    /// (`invalid-node-color`) — so the theme's shows through.
+    #[must_use]
+    pub fn for_node(&self, node: &Node) -> Self {
+        let rgb = |value: &Option<String>| {
+            value
+                .as_deref()
+                .and_then(parse_hex_color)
+                .map(|[r, g, b]| Color::Rgb(r, g, b))
+        };
+        let mut tokens = self.clone();
+        if let Some(color) = rgb(&node.background) {
+            tokens.background = tokens.background.bg(color);
+        }
+        if let Some(color) = rgb(&node.accent) {
+            tokens.accent = tokens.accent.fg(color);
+        }
+        tokens
+    }
+
     /// The line style for the `i`-th parallel rail at a fork.
     #[must_use]
     pub fn rail(&self, i: usize) -> Style {
@@ -139,4 +206,12 @@ impl Tokens {
             _ => None,
         }
     }
+
+    /// Re-points a style produced by [`Tokens::link`] at a different link
+    /// index, leaving the rest of the style untouched.
+    #[must_use]
+    pub fn relink(style: Style, index: usize) -> Style {
+        let marker = (index % 255) as u8 + 1;
+        style.underline_color(Color::Rgb(marker, 0, 0))
+    }
 }
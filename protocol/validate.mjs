@@ -157,7 +157,7 @@ function checkNextBranchPointConflict(graph) {
     const t = node.traversal;
     if (t && typeof t === "object" && t.next && t["branch-point"]) {
       diagnostics.push(
-        diagnostic("error", "next-branch-point-conflict", `Node "${node.id}" has both "next" and "branch-point" — these are mutually exclusive`, {
+        diagnostic("error", "next-branch-point-conflict", `Node "${node.id}" has both "next" and "branch-point" — these are mutually exclusive; the branch point wins and "next" is never followed`, {
           nodeId: node.id,
         }),
       );
@@ -203,6 +203,42 @@ function checkUniqueBranchKeys(graph) {
   return diagnostics;
 }
 
+/**
+ * ERROR: a branch-point's `default-option` must be the key of one of its
+ * options (matched ignoring ASCII case, as a keypress is), or its timeout
+ * would take the first option instead of the one meant.
+ *
+ * Spec: §4 Validation — Required Check 6
+ */
+function checkDefaultOptions(graph) {
+  const diagnostics = [];
+
+  for (const node of graph.nodes) {
+    const t = node.traversal;
+    if (!t || typeof t === "string") continue;
+
+    const bp = t["branch-point"];
+    const wanted = bp?.["default-option"];
+    if (typeof wanted !== "string" || !bp.options) continue;
+
+    const named = bp.options.some(
+      (opt) => typeof opt.key === "string" && opt.key.toLowerCase() === wanted.toLowerCase(),
+    );
+    if (!named) {
+      diagnostics.push(
+        diagnostic(
+          "error",
+          "valid-default-option",
+          `"${node.id}" makes "${wanted}" its default option, but none of its options has that key`,
+          { nodeId: node.id, defaultOption: wanted },
+        ),
+      );
+    }
+  }
+
+  return diagnostics;
+}
+
 /**
  * The presenter's global single-key commands (`fireside-tui`'s
  * `App::on_present_key`) — a branch option keyed with one of these can
@@ -211,7 +247,7 @@ function checkUniqueBranchKeys(graph) {
  * mechanism exists); the two are kept in lockstep by the shared fixture
  * corpus (`fixtures/valid/reserved-branch-key.json`).
  */
-const RESERVED_PRESENTER_KEYS = new Set(["e", "f", "g", "h", "j", "k", "m", "n", "p", "q", "s", "t"]);
+const RESERVED_PRESENTER_KEYS = new Set([":", "e", "f", "g", "h", "j", "k", "m", "n", "p", "q", "s", "t"]);
 
 /**
  * WARNING: A branch option's `key` collides with one of the presenter's
@@ -247,6 +283,104 @@ function checkReservedBranchKeys(graph) {
   return diagnostics;
 }
 
+/**
+ * Longest branch option `description`, in characters, that doesn't draw a
+ * `long-branch-description` warning. Mirrors the Rust validator's
+ * `MAX_BRANCH_DESCRIPTION`.
+ */
+const MAX_BRANCH_DESCRIPTION = 240;
+
+/**
+ * WARNING: A branch option's `description` is long enough to crowd the
+ * other options out of the menu.
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkBranchDescriptionLength(graph) {
+  const diagnostics = [];
+
+  for (const node of graph.nodes) {
+    const t = node.traversal;
+    if (!t || typeof t === "string") continue;
+
+    const bp = t["branch-point"];
+    if (!bp?.options) continue;
+
+    for (const opt of bp.options) {
+      if (typeof opt.description !== "string") continue;
+      const length = [...opt.description].length;
+      if (length > MAX_BRANCH_DESCRIPTION) {
+        diagnostics.push(
+          diagnostic(
+            "warning",
+            "long-branch-description",
+            `"${node.id}" describes "${opt.label}" in ${length} characters — past ${MAX_BRANCH_DESCRIPTION} the menu crowds out the other options; keep it to a sentence or two`,
+            { nodeId: node.id, length },
+          ),
+        );
+      }
+    }
+  }
+
+  return diagnostics;
+}
+
+/** The `next` target of a node, whichever traversal form declares it. */
+function nextTarget(node) {
+  const t = node.traversal;
+  if (typeof t === "string") return t;
+  return t?.next ?? null;
+}
+
+/**
+ * WARNING: A branch option leads to a hidden node that presenting cannot
+ * step past — following `next` from it never reaches a visible node
+ * (no edge, a branch point, or a loop of hidden nodes), so choosing the
+ * option goes nowhere.
+ *
+ * Spec: Engine extension (hidden nodes)
+ */
+function checkHiddenBranchTargets(graph) {
+  const diagnostics = [];
+  const byId = new Map(graph.nodes.map((n) => [n.id, n]));
+
+  const continuesPastHidden = (start) => {
+    let node = start;
+    for (let i = 0; i < byId.size; i++) {
+      if (!node.hidden) return true;
+      if (typeof node.traversal === "object" && node.traversal?.["branch-point"]) return false;
+      const next = byId.get(nextTarget(node));
+      if (!next) return false;
+      node = next;
+    }
+    return !node.hidden;
+  };
+
+  for (const node of graph.nodes) {
+    const t = node.traversal;
+    if (!t || typeof t === "string") continue;
+
+    const bp = t["branch-point"];
+    if (!bp?.options) continue;
+
+    for (const opt of bp.options) {
+      const target = byId.get(opt.target);
+      if (target?.hidden && !continuesPastHidden(target)) {
+        diagnostics.push(
+          diagnostic(
+            "warning",
+            "hidden-branch-target",
+            `Node "${node.id}" offers "${opt.label}", but it leads to the hidden node "${opt.target}" with nowhere visible to go next — presenting skips hidden nodes, so this choice is a dead end`,
+            { nodeId: node.id, target: opt.target },
+          ),
+        );
+      }
+    }
+  }
+
+  return diagnostics;
+}
+
 /**
  * WARNING: A `traversal` object present but setting neither `next` nor
  * `branch-point` behaves like an absent `traversal` (terminal), but is
@@ -284,11 +418,24 @@ function checkEmptyTraversal(graph) {
  */
 const MAX_CONTAINER_NESTING_DEPTH = 8;
 
-/** `0` for a non-container leaf; `1 + max(child depth)` for a `container`. */
+/**
+ * The nested block lists inside `block`: a `container`'s children, or each
+ * of a `columns` block's columns. Empty for every leaf kind.
+ */
+function childBlocks(block) {
+  if (block.kind === "container") return [block.children ?? []];
+  if (block.kind === "columns") return block.columns ?? [];
+  return [];
+}
+
+/**
+ * `0` for a leaf; `1 + max(child depth)` for a `container` or a `columns`
+ * block, which nests the same way.
+ */
 function containerDepth(block) {
-  if (block.kind !== "container") return 0;
-  const children = block.children ?? [];
-  return 1 + children.reduce((max, child) => Math.max(max, containerDepth(child)), 0);
+  const groups = childBlocks(block);
+  if (groups.length === 0) return 0;
+  return 1 + groups.flat().reduce((max, child) => Math.max(max, containerDepth(child)), 0);
 }
 
 /**
@@ -332,9 +479,9 @@ function checkRevealMaskedByContainer(graph) {
 
   function walk(blocks, nodeId) {
     for (const block of blocks) {
-      if (block.kind !== "container") continue;
       const containerLevel = block.reveal ?? 0;
-      for (const child of block.children ?? []) {
+      for (const children of childBlocks(block)) walk(children, nodeId);
+      for (const child of childBlocks(block).flat()) {
         const childLevel = child.reveal ?? 0;
         if (childLevel < containerLevel) {
           diagnostics.push(
@@ -347,7 +494,6 @@ function checkRevealMaskedByContainer(graph) {
           );
         }
       }
-      walk(block.children ?? [], nodeId);
     }
   }
 
@@ -373,7 +519,7 @@ function checkRevealMaskedByContainer(graph) {
 const MAX_ASCII_ART_WIDTH = 76;
 
 /**
- * Walks `blocks` recursively (through `container` children, like
+ * Walks `blocks` recursively (through containers and columns, like
  * `checkRevealMaskedByContainer`/`checkMalformedLinkUrls`), calling
  * `check` on every `ascii-art` block's `art` string.
  */
@@ -381,8 +527,8 @@ function walkAsciiArt(blocks, nodeId, check) {
   for (const block of blocks) {
     if (block.kind === "ascii-art") {
       check(block.art ?? "", nodeId);
-    } else if (block.kind === "container") {
-      walkAsciiArt(block.children ?? [], nodeId, check);
+    } else {
+      for (const children of childBlocks(block)) walkAsciiArt(children, nodeId, check);
     }
   }
 }
@@ -443,6 +589,380 @@ function checkAsciiArtEmpty(graph) {
   return diagnostics;
 }
 
+/**
+ * WARNING: A `columns` block has a column with nothing in it — an empty
+ * slot that only pushes the other columns aside.
+ *
+ * Spec: §ColumnsBlock
+ */
+function checkEmptyColumns(graph) {
+  const diagnostics = [];
+
+  const walk = (blocks, nodeId) => {
+    for (const block of blocks) {
+      if (block.kind === "columns") {
+        (block.columns ?? []).forEach((column, i) => {
+          if (column.length === 0) {
+            diagnostics.push(
+              diagnostic(
+                "warning",
+                "empty-column",
+                `"${nodeId}" has a columns block whose column ${i + 1} is empty — add content to it or remove the column`,
+                { nodeId, column: i + 1 },
+              ),
+            );
+          }
+        });
+      }
+      for (const children of childBlocks(block)) walk(children, nodeId);
+    }
+  };
+
+  for (const node of graph.nodes) {
+    walk(node.content ?? [], node.id);
+  }
+
+  return diagnostics;
+}
+
+/**
+ * WARNING: Two blocks in the same node (at any container depth) share an
+ * anchor `id`. Anchors only need to be unique within their node; a repeat
+ * means a deep link can only ever reach the first one.
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkDuplicateBlockAnchors(graph) {
+  const diagnostics = [];
+
+  for (const node of graph.nodes) {
+    const seen = new Set();
+    const repeats = new Set();
+    const walk = (blocks) => {
+      for (const block of blocks) {
+        if (block.id != null) {
+          if (seen.has(block.id)) repeats.add(block.id);
+          seen.add(block.id);
+        }
+        for (const children of childBlocks(block)) walk(children);
+      }
+    };
+    walk(node.content ?? []);
+    for (const anchor of [...repeats].sort()) {
+      diagnostics.push(
+        diagnostic(
+          "error",
+          "duplicate-block-anchor",
+          `Node "${node.id}" has more than one block with the anchor "${anchor}" — rename one so links reach the block you meant`,
+          { nodeId: node.id, anchor },
+        ),
+      );
+    }
+  }
+
+  return diagnostics;
+}
+
+/**
+ * Parses a `visible-if` condition, mirroring `fireside_engine::expr`:
+ * comparisons (`ask == d`, `ask != "d"`), bare variables, `!`, `&&`
+ * (binding tighter), `||`, and parentheses. Returns `{ variables }`, every
+ * variable the condition reads, or `{ error }` with the 1-based column of
+ * the first thing that doesn't fit.
+ */
+function parseVisibleIf(text) {
+  const chars = [...text];
+  const isWordChar = (c) => /[\p{L}\p{N}_-]/u.test(c);
+  const describe = ([type, value]) =>
+    type === "word" ? `"${value}"` : type === "quoted" ? `the string "${value}"` : `"${type}"`;
+  const fail = (message, column) => {
+    throw { message, column };
+  };
+
+  const tokens = [];
+  let i = 0;
+  while (i < chars.length) {
+    const c = chars[i];
+    const pair = c + (chars[i + 1] ?? "");
+    if (/\s/u.test(c)) {
+      i += 1;
+    } else if (["==", "!=", "&&", "||"].includes(pair)) {
+      tokens.push([pair, null, i + 1]);
+      i += 2;
+    } else if (c === "!" || c === "(" || c === ")") {
+      tokens.push([c, null, i + 1]);
+      i += 1;
+    } else if (c === '"' || c === "'") {
+      const close = chars.indexOf(c, i + 1);
+      if (close === -1) fail("a string that never closes", i + 1);
+      tokens.push(["quoted", chars.slice(i + 1, close).join(""), i + 1]);
+      i = close + 1;
+    } else if (isWordChar(c)) {
+      let end = i;
+      while (end < chars.length && isWordChar(chars[end])) end += 1;
+      tokens.push(["word", chars.slice(i, end).join(""), i + 1]);
+      i = end;
+    } else {
+      fail(`unexpected "${c}"`, i + 1);
+    }
+  }
+
+  let pos = 0;
+  const variables = [];
+  const peek = () => tokens[pos];
+  const eat = (type) => {
+    const found = peek()?.[0] === type;
+    if (found) pos += 1;
+    return found;
+  };
+  const expected = (what) => {
+    const token = peek();
+    if (token) fail(`expected ${what}, found ${describe(token)}`, token[2]);
+    fail(`expected ${what}, but the condition ends`, chars.length + 1);
+  };
+  const unary = () => {
+    if (eat("!")) return unary();
+    if (eat("(")) {
+      or();
+      if (!eat(")")) expected('")"');
+      return;
+    }
+    if (peek()?.[0] !== "word") expected("a choice's id");
+    variables.push(tokens[pos][1]);
+    pos += 1;
+    if (eat("==") || eat("!=")) {
+      const type = peek()?.[0];
+      if (type !== "word" && type !== "quoted") expected("a value");
+      pos += 1;
+    }
+  };
+  const and = () => {
+    unary();
+    while (eat("&&")) unary();
+  };
+  const or = () => {
+    and();
+    while (eat("||")) and();
+  };
+
+  try {
+    or();
+    if (peek()) fail(`unexpected ${describe(peek())}`, peek()[2]);
+    return { variables };
+  } catch (error) {
+    return { error: `${error.message} at column ${error.column}` };
+  }
+}
+
+/**
+ * ERROR: a block's `visible-if` doesn't parse, so nothing can say when
+ * the block shows. WARNING: it reads a variable no branch point sets, so
+ * that part of the condition never sees a choice. Blocks are located by
+ * position, nested ones dotted after their parent's: `block 2.1`.
+ *
+ * Spec: §ContentBlock `visible-if`
+ */
+function checkVisibleIf(graph) {
+  const diagnostics = [];
+  const asksAt = new Set(graph.nodes.filter((n) => n.traversal?.["branch-point"] != null).map((n) => n.id));
+
+  const walk = (blocks, prefix, nodeId) => {
+    blocks.forEach((block, i) => {
+      const at = `${prefix}${i + 1}`;
+      if (block["visible-if"] != null) {
+        const parsed = parseVisibleIf(block["visible-if"]);
+        if (parsed.error) {
+          diagnostics.push(
+            diagnostic(
+              "error",
+              "valid-visible-if",
+              `Node "${nodeId}" block ${at} has a visible-if that can't be read: ${parsed.error}`,
+              { nodeId, block: at },
+            ),
+          );
+        } else {
+          for (const name of new Set(parsed.variables)) {
+            if (asksAt.has(name)) continue;
+            diagnostics.push(
+              diagnostic(
+                "warning",
+                "unknown-visible-if-choice",
+                `Node "${nodeId}" block ${at} shows depending on the choice at "${name}", but no slide "${name}" asks for one — check the id`,
+                { nodeId, block: at, variable: name },
+              ),
+            );
+          }
+        }
+      }
+      walk(childBlocks(block).flat(), `${at}.`, nodeId);
+    });
+  };
+
+  for (const node of graph.nodes) {
+    walk(node.content ?? [], "", node.id);
+  }
+
+  return diagnostics;
+}
+
+/**
+ * WARNING: a section's nodes are split by nodes outside it. Section
+ * navigation jumps to a section's first node, so the run after the gap can
+ * only be reached slide by slide. Reported once per section, at the first
+ * node that resumes it.
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkSectionContiguity(graph) {
+  const diagnostics = [];
+  const lastIndex = new Map();
+  const reported = new Set();
+
+  graph.nodes.forEach((node, i) => {
+    const name = node.section;
+    if (name == null) return;
+    if (lastIndex.has(name) && lastIndex.get(name) !== i - 1 && !reported.has(name)) {
+      reported.add(name);
+      diagnostics.push(
+        diagnostic(
+          "warning",
+          "section-not-contiguous",
+          `Node "${node.id}" is in section "${name}", but other slides come between it and the rest of that section — move the section's slides together`,
+          { nodeId: node.id, section: name },
+        ),
+      );
+    }
+    lastIndex.set(name, i);
+  });
+
+  return diagnostics;
+}
+
+/**
+ * WARNING: a node's `background` or `accent` isn't a hex color (`#rrggbb`
+ * or `#rgb`), so presenting ignores it and keeps the theme's.
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkNodeColors(graph) {
+  const diagnostics = [];
+
+  for (const node of graph.nodes) {
+    for (const field of ["background", "accent"]) {
+      const value = node[field];
+      if (typeof value !== "string") continue;
+      if (/^#(?:[0-9a-fA-F]{3}|[0-9a-fA-F]{6})$/.test(value)) continue;
+      diagnostics.push(
+        diagnostic(
+          "warning",
+          "invalid-node-color",
+          `"${node.id}" has ${field} "${value}", which isn't a hex color like "#1e1e2e" — the theme's color is used instead`,
+          { nodeId: node.id, [field]: value },
+        ),
+      );
+    }
+  }
+
+  return diagnostics;
+}
+
+/**
+ * Longest `duration`, in seconds, that doesn't draw a
+ * `duration-out-of-range` warning. Mirrors the Rust validator's
+ * `MAX_NODE_DURATION`.
+ */
+const MAX_NODE_DURATION = 60 * 60;
+
+/**
+ * A node's `duration` in whole seconds: a number as-is, or text in one of
+ * the `"90"`, `"90s"`, `"1m30s"`, `"1:30"` forms. `null` for anything else —
+ * the schema layer reports those.
+ */
+function durationSeconds(value) {
+  if (typeof value === "number") return Number.isInteger(value) && value >= 0 ? value : null;
+  if (typeof value !== "string") return null;
+  if (/^\d+$/.test(value)) return Number(value);
+  const clock = /^(\d+):([0-5]\d)$/.exec(value);
+  if (clock) return Number(clock[1]) * 60 + Number(clock[2]);
+  const units = /^(?:(\d+)h)?(?:(\d+)m)?(?:(\d+)s)?$/.exec(value);
+  if (!units || value === "") return null;
+  const [, h = 0, m = 0, s = 0] = units;
+  return Number(h) * 3600 + Number(m) * 60 + Number(s);
+}
+
+/** `seconds` as `"M:SS"`, the form Fireside saves durations in. */
+function formatDuration(seconds) {
+  return `${Math.floor(seconds / 60)}:${String(seconds % 60).padStart(2, "0")}`;
+}
+
+/**
+ * WARNING: a node's `duration` is zero, so auto-advance leaves it the
+ * moment it appears, or longer than an hour — likelier minutes typed where
+ * seconds were meant.
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkDurationRange(graph) {
+  const diagnostics = [];
+
+  for (const node of graph.nodes) {
+    const seconds = durationSeconds(node.duration);
+    if (seconds == null) continue;
+    let message;
+    if (seconds === 0) {
+      message = `"${node.id}" has a duration of 0:00, so auto-advance leaves it at once — give it a length or remove the duration`;
+    } else if (seconds > MAX_NODE_DURATION) {
+      message = `"${node.id}" has a duration of ${formatDuration(seconds)} — over an hour on one slide; check the units (90 is seconds, 90m is minutes)`;
+    } else {
+      continue;
+    }
+    diagnostics.push(
+      diagnostic("warning", "duration-out-of-range", message, { nodeId: node.id, duration: node.duration }),
+    );
+  }
+
+  return diagnostics;
+}
+
+/**
+ * WARNING: a node's headings skip a level on the way down (an h1 followed
+ * by an h3). Only heading-to-heading steps count, in document order at any
+ * container depth; repeats, step-ups, and a first heading that isn't an h1
+ * are fine.
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkHeadingHierarchy(graph) {
+  const diagnostics = [];
+
+  for (const node of graph.nodes) {
+    const headings = [];
+    const walk = (blocks) => {
+      for (const block of blocks) {
+        if (block.kind === "heading") headings.push(block);
+        for (const children of childBlocks(block)) walk(children);
+      }
+    };
+    walk(node.content ?? []);
+    for (let i = 1; i < headings.length; i++) {
+      const from = headings[i - 1].level;
+      const to = headings[i].level;
+      if (to > from + 1) {
+        diagnostics.push(
+          diagnostic(
+            "warning",
+            "heading-level-skip",
+            `Node "${node.id}" jumps from a level-${from} heading to a level-${to} one ("${headings[i].text}") — make it level ${from + 1} or add the missing heading between`,
+            { nodeId: node.id },
+          ),
+        );
+      }
+    }
+  }
+
+  return diagnostics;
+}
+
 /**
  * Extracts every link destination found in `text`'s `[label](url)` syntax
  * — mirrors `fireside-tui`'s inline-Markdown parser / `fireside-engine`'s
@@ -516,7 +1036,83 @@ function checkMalformedLinkUrls(graph) {
           }
         }
       }
-      if (block.kind === "container") walk(block.children ?? [], nodeId);
+      for (const children of childBlocks(block)) walk(children, nodeId);
+    }
+  }
+
+  for (const node of graph.nodes) {
+    walk(node.content ?? [], node.id);
+  }
+
+  return diagnostics;
+}
+
+/**
+ * ERROR: An embed's `range` must be one line (`"12"`) or a forward run of
+ * them (`"12-20"`), 1-based. Mirrors the Rust validator's
+ * `invalid-embed-range`; whether the file exists, and has that many
+ * lines, is checked where the document is loaded.
+ *
+ * Spec: Engine extension (embed blocks)
+ */
+function checkEmbedRanges(graph) {
+  const diagnostics = [];
+
+  function walk(blocks, nodeId) {
+    for (const block of blocks) {
+      if (block.kind === "embed" && block.range != null) {
+        const match = /^\s*(\d+)\s*(?:-\s*(\d+)\s*)?$/.exec(block.range);
+        const first = match ? Number(match[1]) : 0;
+        const last = match ? Number(match[2] ?? match[1]) : 0;
+        if (first < 1 || last < first) {
+          diagnostics.push(
+            diagnostic(
+              "error",
+              "invalid-embed-range",
+              `The embed of "${block.source}" on "${nodeId}" asks for lines "${block.range}" — write one line, like "12", or a run, like "12-20"`,
+              { nodeId, source: block.source, range: block.range },
+            ),
+          );
+        }
+      }
+      for (const children of childBlocks(block)) walk(children, nodeId);
+    }
+  }
+
+  for (const node of graph.nodes) {
+    walk(node.content ?? [], node.id);
+  }
+
+  return diagnostics;
+}
+
+/**
+ * INFO: An image with no alt text, or only whitespace, that isn't marked
+ * `decorative`. Mirrors the Rust validator's `missing-alt-text`; the
+ * reference CLI's `validate --require-alt` raises it to an error.
+ *
+ * Spec: Engine extension (image accessibility)
+ */
+function checkMissingAltText(graph) {
+  const diagnostics = [];
+
+  function walk(blocks, nodeId) {
+    for (const block of blocks) {
+      if (
+        block.kind === "image" &&
+        block.decorative !== true &&
+        (block.alt ?? "").trim() === ""
+      ) {
+        diagnostics.push(
+          diagnostic(
+            "info",
+            "missing-alt-text",
+            `Image "${block.src}" on "${nodeId}" has no alt text — describe it in "alt", or mark it "decorative": true if it carries no information`,
+            { nodeId, src: block.src },
+          ),
+        );
+      }
+      for (const children of childBlocks(block)) walk(children, nodeId);
     }
   }
 
@@ -537,9 +1133,29 @@ function checkReachability(graph, nodeIds) {
   if (graph.nodes.length === 0) return diagnostics;
 
   const entryId = graph.nodes[0].id;
-  const nodeMap = new Map(graph.nodes.map((n) => [n.id, n]));
+  const reachable = reachableIds(graph, nodeIds);
+
+  for (const node of graph.nodes) {
+    if (!reachable.has(node.id)) {
+      diagnostics.push(
+        diagnostic("warning", "unreachable-node", `Node "${node.id}" is not reachable from entry point "${entryId}"`, {
+          nodeId: node.id,
+          entryId,
+        }),
+      );
+    }
+  }
+
+  return diagnostics;
+}
+
+/** The ids of every node a walk from the entry point (index 0) can reach. */
+function reachableIds(graph, nodeIds) {
   const reachable = new Set();
-  const queue = [entryId];
+  if (graph.nodes.length === 0) return reachable;
+
+  const nodeMap = new Map(graph.nodes.map((n) => [n.id, n]));
+  const queue = [graph.nodes[0].id];
 
   while (queue.length > 0) {
     const id = queue.shift();
@@ -556,16 +1172,33 @@ function checkReachability(graph, nodeIds) {
     }
   }
 
-  for (const node of graph.nodes) {
-    if (!reachable.has(node.id)) {
-      diagnostics.push(
-        diagnostic("warning", "unreachable-node", `Node "${node.id}" is not reachable from entry point "${entryId}"`, {
-          nodeId: node.id,
-          entryId,
-        }),
-      );
-    }
-  }
+  return reachable;
+}
+
+/**
+ * WARNING: A reachable node with no traversal that is neither the last node
+ * nor marked `terminal` — usually a missing next rather than an ending.
+ * Hidden nodes are left alone; the presenter leaves them with back().
+ *
+ * Spec: §4 Validation — Recommended Checks
+ */
+function checkUnmarkedDeadEnds(graph, nodeIds) {
+  const diagnostics = [];
+  const reachable = reachableIds(graph, nodeIds);
+  const last = graph.nodes.length - 1;
+
+  graph.nodes.forEach((node, index) => {
+    if (index === last || node.traversal || node.terminal === true || node.hidden === true) return;
+    if (!reachable.has(node.id)) return;
+    diagnostics.push(
+      diagnostic(
+        "warning",
+        "unmarked-dead-end",
+        `Node "${node.id}" has no traversal but is not the last node \u2014 add a next, or mark it "terminal": true if the presentation can end here`,
+        { nodeId: node.id },
+      ),
+    );
+  });
 
   return diagnostics;
 }
@@ -680,14 +1313,27 @@ export function validate(graph) {
     ...checkValidTargets(graph, nodeIds),
     ...checkNextBranchPointConflict(graph),
     ...checkUniqueBranchKeys(graph),
+    ...checkDefaultOptions(graph),
     ...checkReservedBranchKeys(graph),
+    ...checkBranchDescriptionLength(graph),
+    ...checkHiddenBranchTargets(graph),
     ...checkContainerNestingDepth(graph),
     ...checkEmptyTraversal(graph),
     ...checkRevealMaskedByContainer(graph),
     ...checkAsciiArtTooWide(graph),
     ...checkAsciiArtEmpty(graph),
+    ...checkEmptyColumns(graph),
+    ...checkDuplicateBlockAnchors(graph),
+    ...checkVisibleIf(graph),
+    ...checkSectionContiguity(graph),
+    ...checkDurationRange(graph),
+    ...checkNodeColors(graph),
+    ...checkHeadingHierarchy(graph),
     ...checkMalformedLinkUrls(graph),
+    ...checkMissingAltText(graph),
+    ...checkEmbedRanges(graph),
     ...checkReachability(graph, nodeIds),
+    ...checkUnmarkedDeadEnds(graph, nodeIds),
     ...checkSelfLoops(graph),
     ...checkTrivialCycles(graph),
     ...checkDeadEndBranches(graph),
@@ -712,7 +1358,10 @@ Rules (errors):
   valid-traversal-target     All traversal/branch targets must reference existing nodes
   next-branch-point-conflict A node must not have both next and branch-point
   unique-branch-keys         Branch option keys must be unique per branch-point
+  valid-default-option       A branch-point's default-option must be one of its keys
   container-nesting-depth-exceeded  Containers must not nest deeper than 8 levels
+  invalid-embed-range        An embed's range must be one line ("12") or a forward run ("12-20")
+  duplicate-block-anchor     Block anchor ids must be unique within their node
 
 Rules (warnings):
   unreachable-node           Nodes should be reachable from entry point
@@ -722,11 +1371,18 @@ Rules (warnings):
   reveal-masked-by-container A child's reveal step is earlier than its enclosing group's
   ascii-art-too-wide         An ascii-art block's widest line exceeds 76 columns
   ascii-art-empty            An ascii-art block has no art content
+  empty-column               A columns block has a column with no blocks in it
   malformed-link-url        A [label](url) link's destination doesn't look like a URL
   reserved-branch-key       A branch option key collides with a reserved presenter key
+  long-branch-description    A branch option's description runs past 240 characters
+  section-not-contiguous     A section's slides are split up by slides outside it
+  duration-out-of-range      A node's duration is zero or longer than an hour
+  invalid-node-color         A node's background or accent isn't a hex color
+  unmarked-dead-end          A node other than the last stops the path without "terminal": true
 
 Rules (info):
   dead-end-branch            Branch targets with no traversal are terminal nodes
+  missing-alt-text           An image has no alt text and isn't marked decorative
 
 Exit codes:
   0  No errors (warnings may still be present)